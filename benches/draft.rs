@@ -0,0 +1,75 @@
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, Criterion};
+use drftr::{DraftItem, DraftPool, DraftType, League, PoolQuery};
+use poise::serenity_prelude as serenity;
+
+// a 400-pick league: 16 seats drafting 25 rounds
+const SEATS: u64 = 16;
+const ROUNDS: u32 = 25;
+
+fn names(count: u32) -> Vec<String> {
+    (0..count).map(|i| format!("Pokemon {i}")).collect()
+}
+
+fn league(draft_type: DraftType) -> League {
+    let users: Vec<serenity::UserId> = (1..=SEATS).map(serenity::UserId).collect();
+    let mut league = League::new(
+        &users,
+        69420,
+        "Creenis".to_string(),
+        None,
+        draft_type,
+        ROUNDS,
+    );
+    let pool = names(SEATS as u32 * ROUNDS * 2)
+        .into_iter()
+        .map(|name| Box::new(name) as Box<dyn DraftItem>)
+        .collect();
+    league.set_pool(DraftPool::new(pool)).unwrap();
+    league
+}
+
+fn seat_calc(c: &mut Criterion) {
+    let mut league = league(DraftType::Snake);
+    league.activate().unwrap();
+    c.bench_function("seat calc: every seat in a 400-pick snake", |b| {
+        b.iter(|| black_box(league.upcoming_picks(black_box(400))))
+    });
+}
+
+fn lock_cascade(c: &mut Criterion) {
+    // every seat queues its whole draft up front, so the first lock picks the other 399 from queues
+    c.bench_function("lock cascade: 400 picks from queues", |b| {
+        b.iter_batched(
+            || {
+                let mut league = league(DraftType::Snake);
+                let names = names(SEATS as u32 * ROUNDS);
+                for (i, name) in names.iter().enumerate().skip(1) {
+                    let user = serenity::UserId(i as u64 % SEATS + 1);
+                    league
+                        .add_to_player_queue(user, Box::new(name.clone()))
+                        .unwrap();
+                }
+                league.activate().unwrap();
+                league
+            },
+            |mut league| {
+                let picks = league.lock(Box::new("Pokemon 0")).unwrap();
+                assert_eq!(picks.len(), 400);
+                black_box(picks)
+            },
+            BatchSize::LargeInput,
+        )
+    });
+}
+
+fn pool_search(c: &mut Criterion) {
+    let league = league(DraftType::Snake);
+    let pool = league.pool().unwrap();
+    let query = PoolQuery::new().name("mon 7").per_page(25);
+    c.bench_function("pool search: 800 items by name", |b| {
+        b.iter(|| black_box(pool.search(black_box(&query)).total()))
+    });
+}
+
+criterion_group!(benches, seat_calc, lock_cascade, pool_search);
+criterion_main!(benches);
@@ -0,0 +1,126 @@
+//! `#[derive(DraftItem)]` for [DRFTR](https://docs.rs/drftr). Enable drftr's `derive` feature rather than depending on this crate directly.
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Fields, Member, Type};
+
+/// Implements `drftr::DraftItem` for a struct, reading each method from a marked field:
+///
+/// - `#[name]` (required): a `String` or `&str` field holding the item's unique name.
+/// - `#[category]`: a `String`, `&str`, or an `Option` of either.
+/// - `#[cost]` and `#[tier]`: a `u32` or `Option<u32>` (or any smaller unsigned integer).
+///
+/// Unmarked methods keep the trait's default of None.
+#[proc_macro_derive(DraftItem, attributes(name, category, cost, tier))]
+pub fn derive_draft_item(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match expand(&input) {
+        Ok(tokens) => tokens.into(),
+        Err(error) => error.to_compile_error().into(),
+    }
+}
+
+// a field marked with one of the attributes
+struct Marked<'a> {
+    member: Member,
+    ty: &'a Type,
+}
+
+fn expand(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let Data::Struct(data) = &input.data else {
+        return Err(syn::Error::new_spanned(
+            input,
+            "DraftItem can only be derived for structs",
+        ));
+    };
+    let name = marked(&data.fields, "name")?.ok_or_else(|| {
+        syn::Error::new_spanned(input, "mark the field holding the item's name with #[name]")
+    })?;
+    let name = {
+        let member = name.member;
+        quote! {
+            fn name(&self) -> &str {
+                ::core::convert::AsRef::<str>::as_ref(&self.#member)
+            }
+        }
+    };
+    let category = marked(&data.fields, "category")?.map(|field| {
+        let member = field.member;
+        let body = match is_option(field.ty) {
+            true => quote! { self.#member.as_ref().map(|c| ::core::convert::AsRef::<str>::as_ref(c)) },
+            false => quote! { ::core::option::Option::Some(::core::convert::AsRef::<str>::as_ref(&self.#member)) },
+        };
+        quote! {
+            fn category(&self) -> ::core::option::Option<&str> {
+                #body
+            }
+        }
+    });
+    let number = |attribute: &str| -> syn::Result<Option<TokenStream2>> {
+        let Some(field) = marked(&data.fields, attribute)? else {
+            return Ok(None);
+        };
+        let method = syn::Ident::new(attribute, proc_macro2::Span::call_site());
+        let member = field.member;
+        let body = match is_option(field.ty) {
+            true => quote! { self.#member.map(::core::convert::Into::into) },
+            false => {
+                quote! { ::core::option::Option::Some(::core::convert::Into::into(self.#member)) }
+            }
+        };
+        Ok(Some(quote! {
+            fn #method(&self) -> ::core::option::Option<u32> {
+                #body
+            }
+        }))
+    };
+    let cost = number("cost")?;
+    let tier = number("tier")?;
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::drftr::DraftItem for #ident #ty_generics #where_clause {
+            #name
+            #category
+            #cost
+            #tier
+        }
+    })
+}
+
+// the one field marked with the attribute, if any
+fn marked<'a>(fields: &'a Fields, attribute: &str) -> syn::Result<Option<Marked<'a>>> {
+    let mut found: Option<Marked> = None;
+    for (index, field) in fields.iter().enumerate() {
+        let Some(attr) = field.attrs.iter().find(|a| a.path().is_ident(attribute)) else {
+            continue;
+        };
+        attr.meta.require_path_only()?;
+        if found.is_some() {
+            return Err(syn::Error::new_spanned(
+                attr,
+                format!("only one field can be marked #[{attribute}]"),
+            ));
+        }
+        let member = match &field.ident {
+            Some(ident) => Member::Named(ident.clone()),
+            None => Member::Unnamed(index.into()),
+        };
+        found = Some(Marked {
+            member,
+            ty: &field.ty,
+        });
+    }
+    Ok(found)
+}
+
+fn is_option(ty: &Type) -> bool {
+    match ty {
+        Type::Path(path) => path
+            .path
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident == "Option"),
+        _ => false,
+    }
+}
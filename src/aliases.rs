@@ -0,0 +1,182 @@
+use crate::{DraftItem, Draftable, League, LeagueError};
+
+// an item given by one of its aliases, carrying the real name
+struct Aliased {
+    name: String,
+    item: Draftable,
+}
+
+impl DraftItem for Aliased {
+    fn name(&self) -> &str {
+        &self.name
+    }
+    fn category(&self) -> Option<&str> {
+        self.item.category()
+    }
+    fn cost(&self) -> Option<u32> {
+        self.item.cost()
+    }
+    fn tier(&self) -> Option<u32> {
+        self.item.tier()
+    }
+}
+
+impl League {
+    /// Registers a nickname for an item ("Zard" for "Charizard"), so players can pick, queue, trade and waiver it by either.
+    /// Aliases are matched ignoring case, and registering an alias again points it at the new item.
+    ///
+    /// Every method that takes an item, or an item's name, resolves aliases first (see [`League::resolve_name`]).
+    ///
+    /// # Errors
+    ///
+    /// If the alias is empty, or is itself the name of an item in the League's [`DraftPool`](crate::DraftPool),
+    /// returns [`LeagueError::InvalidAliasError`].
+    pub fn add_alias(&mut self, alias: &str, name: &str) -> Result<(), LeagueError> {
+        let alias = alias.trim();
+        if alias.is_empty() || alias.eq_ignore_ascii_case(name) {
+            return Err(LeagueError::InvalidAliasError);
+        }
+        let shadows_item = self
+            .pool
+            .as_ref()
+            .is_some_and(|pool| pool.iter().any(|i| i.name().eq_ignore_ascii_case(alias)));
+        if shadows_item {
+            return Err(LeagueError::InvalidAliasError);
+        }
+        self.aliases.insert(alias.to_lowercase(), name.to_string());
+        Ok(())
+    }
+    /// Removes an alias, returning the name of the item it pointed to.
+    pub fn remove_alias(&mut self, alias: &str) -> Option<String> {
+        self.aliases.remove(&alias.trim().to_lowercase())
+    }
+    /// Registers many aliases at once from text, one item per line, e.g. a file uploaded alongside your pool:
+    ///
+    /// ```text
+    /// # comments and blank lines are ignored
+    /// Charizard = Zard, Char
+    /// Christian McCaffrey = CMC
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// Returns the number of aliases registered.
+    ///
+    /// # Errors
+    ///
+    /// If a line is not in `name = alias, alias...` form, returns [`LeagueError::InvalidAliasMappingError`] with its line number
+    /// (starting at 1), and if one of its aliases is invalid (see [`League::add_alias`]), [`LeagueError::InvalidAliasError`].
+    /// Nothing is registered unless every line is valid.
+    pub fn import_aliases(&mut self, mapping: &str) -> Result<usize, LeagueError> {
+        let mut parsed = Vec::new();
+        for (idx, line) in mapping.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let Some((name, aliases)) = line.split_once('=') else {
+                return Err(LeagueError::InvalidAliasMappingError(idx + 1));
+            };
+            let name = name.trim();
+            let aliases: Vec<&str> = aliases
+                .split(',')
+                .map(str::trim)
+                .filter(|a| !a.is_empty())
+                .collect();
+            if name.is_empty() || aliases.is_empty() {
+                return Err(LeagueError::InvalidAliasMappingError(idx + 1));
+            }
+            parsed.extend(aliases.into_iter().map(|alias| (alias, name)));
+        }
+        let previous = self.aliases.clone();
+        for (alias, name) in &parsed {
+            if let Err(error) = self.add_alias(alias, name) {
+                self.aliases = previous;
+                return Err(error);
+            }
+        }
+        Ok(parsed.len())
+    }
+    /// Returns the name an alias stands for, or the name itself if it is not an alias.
+    pub fn resolve_name<'a>(&'a self, name: &'a str) -> &'a str {
+        match self.aliases.get(&name.trim().to_lowercase()) {
+            Some(resolved) => resolved,
+            None => name,
+        }
+    }
+    /// Returns every alias of an item, in no particular order.
+    pub fn aliases_for(&self, name: &str) -> Vec<&str> {
+        self.aliases
+            .iter()
+            .filter(|(_, n)| *n == name)
+            .map(|(alias, _)| alias.as_str())
+            .collect()
+    }
+    // an item given by an alias gets its real name, so pools, queues and rosters only ever hold real names
+    pub(crate) fn resolve_item(&self, item: Draftable) -> Draftable {
+        let name = self.resolve_name(item.name());
+        if name == item.name() {
+            return item;
+        }
+        let name = name.to_string();
+        Box::new(Aliased { name, item })
+    }
+}
+
+#[cfg(test)]
+mod aliases_tests {
+    use super::*;
+    use crate::test_support::{new_league, pokemon};
+    use crate::{DraftPool, DraftType};
+    use poise::serenity_prelude as serenity;
+
+    fn league() -> League {
+        let mut league = new_league(2, DraftType::Snake, 2);
+        let pool = ["Charizard", "Blastoise", "Venusaur", "Pikachu"];
+        league
+            .set_pool(DraftPool::new(pool.iter().map(|n| pokemon(n)).collect()))
+            .unwrap();
+        league
+    }
+
+    #[test]
+    fn aliases_resolve_everywhere() {
+        let mut league = league();
+        let imported = league
+            .import_aliases("# starters\nCharizard = Zard, Char\nBlastoise = Toise\n")
+            .unwrap();
+        assert_eq!(imported, 3);
+        assert_eq!(league.resolve_name("zard"), "Charizard");
+        assert_eq!(league.resolve_name("Mew"), "Mew");
+        league.activate().unwrap();
+        let picks = league.lock(pokemon("ZARD")).unwrap();
+        assert_eq!(picks[0].item, "Charizard");
+        assert!(!league.pool().unwrap().contains("Charizard"));
+        league
+            .add_to_player_queue(serenity::UserId(1), pokemon("Toise"))
+            .unwrap();
+        league
+            .delete_from_player_queue(serenity::UserId(1), "toise")
+            .unwrap();
+        match league.lock(pokemon("Char")) {
+            Err(LeagueError::DraftableInUseError) => {}
+            _ => panic!("Char is Charizard, and Charizard is taken"),
+        }
+        assert!(league.lock_item("Char"));
+        assert!(league.is_item_locked("Charizard"));
+    }
+
+    #[test]
+    fn aliases_cannot_shadow_items() {
+        let mut league = league();
+        match league.add_alias("pikachu", "Charizard") {
+            Err(LeagueError::InvalidAliasError) => {}
+            _ => panic!("Pikachu is a real item"),
+        }
+        match league.import_aliases("Charizard = Zard\nBlastoise Toise") {
+            Err(LeagueError::InvalidAliasMappingError(2)) => {}
+            _ => panic!("line 2 has no ="),
+        }
+        assert_eq!(league.resolve_name("Zard"), "Zard");
+    }
+}
@@ -0,0 +1,125 @@
+use crate::{DraftGuild, DraftGuildError, League, LeaguePhase};
+use chrono::{DateTime, Utc};
+
+/// A finished [`League`] kept for its history by [`DraftGuild::archive_league`], with the date it was archived and its final draft board.
+pub struct ArchivedLeague {
+    league: League,
+    completed: DateTime<Utc>,
+    board: Vec<Vec<Option<String>>>,
+}
+
+impl ArchivedLeague {
+    pub(crate) fn new(mut league: League, completed: DateTime<Utc>) -> ArchivedLeague {
+        if league.phase() == LeaguePhase::PostDraft {
+            league
+                .set_phase(LeaguePhase::Archived)
+                .expect("finished leagues can always be archived");
+        }
+        let board = league
+            .board_grid()
+            .into_iter()
+            .map(|round| round.into_iter().map(|i| i.map(str::to_string)).collect())
+            .collect();
+        ArchivedLeague {
+            league,
+            completed,
+            board,
+        }
+    }
+    /// Returns the League, in [`LeaguePhase::Archived`]: everything about it can be read, and nothing can change.
+    pub fn league(&self) -> &League {
+        &self.league
+    }
+    /// Returns the League's name.
+    pub fn name(&self) -> &str {
+        &self.league.name
+    }
+    /// Returns when the League was archived, closing its season.
+    pub fn completed(&self) -> DateTime<Utc> {
+        self.completed
+    }
+    /// Returns the final draft board: a row for each round, and in each row the name of every seat's pick, in seat order.
+    /// Skipped picks are None.
+    pub fn board(&self) -> &[Vec<Option<String>>] {
+        &self.board
+    }
+    /// Takes the League back out of the archive, e.g. to copy its settings into next season's League.
+    pub fn into_league(self) -> League {
+        self.league
+    }
+}
+
+impl DraftGuild {
+    /// Moves a finished League into the guild's archives, freeing its name for next season's League, and returns it as archived.
+    /// The League moves to [`LeaguePhase::Archived`], and now is recorded as its completion date.
+    ///
+    /// # Errors
+    ///
+    /// If there is no League with the name, returns [`DraftGuildError::LeagueNotFoundError`].
+    ///
+    /// If the League's draft has not finished, returns [`DraftGuildError::LeagueNotFinishedError`].
+    pub fn archive_league(
+        &mut self,
+        name: &str,
+        now: DateTime<Utc>,
+    ) -> Result<&ArchivedLeague, DraftGuildError> {
+        let league = self
+            .leagues
+            .get(name)
+            .ok_or(DraftGuildError::LeagueNotFoundError)?;
+        if !matches!(
+            league.phase(),
+            LeaguePhase::PostDraft | LeaguePhase::Archived
+        ) {
+            return Err(DraftGuildError::LeagueNotFinishedError);
+        }
+        let league = self.leagues.remove(name).unwrap();
+        self.archives.push(ArchivedLeague::new(league, now));
+        Ok(self.archives.last().unwrap())
+    }
+    /// Returns every archived League, oldest first. Names repeat when a League runs for several seasons.
+    pub fn archives(&self) -> &[ArchivedLeague] {
+        &self.archives
+    }
+    /// Returns an archived League by ID, if there is one.
+    pub fn archived_league(&self, id: u64) -> Option<&ArchivedLeague> {
+        self.archives.iter().find(|a| a.league.id == id)
+    }
+}
+
+#[cfg(test)]
+mod archive_tests {
+    use super::*;
+    use crate::test_support::new_league;
+    use crate::{DraftType, LeagueError};
+    use poise::serenity_prelude as serenity;
+
+    #[test]
+    fn finished_leagues_are_kept() {
+        let mut guild = DraftGuild::new(1, serenity::ChannelId(2));
+        let mut league = new_league(2, DraftType::Snake, 1);
+        league.activate().unwrap();
+        league.lock(Box::new("Pikachu")).unwrap();
+        guild.add_league(league).unwrap();
+        match guild.archive_league("Creenis", Utc::now()) {
+            Err(DraftGuildError::LeagueNotFinishedError) => {}
+            _ => panic!("seat 2 has not picked"),
+        }
+        guild
+            .league_by_name("Creenis".to_string())
+            .unwrap()
+            .skip()
+            .unwrap();
+        let archived = guild.archive_league("Creenis", Utc::now()).unwrap();
+        assert_eq!(archived.board(), [vec![Some("Pikachu".to_string()), None]]);
+        assert_eq!(archived.league().phase(), LeaguePhase::Archived);
+        assert!(guild.league_by_name("Creenis".to_string()).is_err());
+        let archived = guild.archived_league(69420).unwrap();
+        match archived.league().round_picks(1) {
+            Ok(picks) => assert_eq!(picks.len(), 1),
+            Err(LeagueError::InvalidRoundError) => panic!("round 1 happened"),
+            Err(_) => panic!("history can be read"),
+        }
+        assert_eq!(guild.archives().len(), 1);
+    }
+}
@@ -0,0 +1,419 @@
+use crate::{fuzzy, DraftEvent, DraftType, League, LeagueError, NominationOrder};
+use chrono::{DateTime, Utc};
+use poise::serenity_prelude as serenity;
+use serde::{Deserialize, Serialize};
+
+/// What the winner of a sealed-bid lot pays, set with [`League::set_auction_pricing`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AuctionPricing {
+    /// The winner pays their own bid.
+    #[default]
+    FirstPrice,
+    /// The winner pays the second highest bid (a Vickrey auction), or 1 if nobody else bid. Bidding what an item is really
+    /// worth to you is the best strategy.
+    SecondPrice,
+}
+
+/// The item up for auction in an [`DraftType::Auction`] League, nominated with [`League::nominate`].
+///
+/// Bids are sealed: a Lot tells who has bid, never how much, until [`League::resolve_lot`] reveals them.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Lot {
+    item: String,
+    nominator: serenity::UserId,
+    closes_at: DateTime<Utc>,
+    // (seat owner, amount), in the order they were placed
+    bids: Vec<(serenity::UserId, u32)>,
+}
+
+impl Lot {
+    /// The name of the item up for auction.
+    pub fn item(&self) -> &str {
+        &self.item
+    }
+    /// The player who nominated the item, by their seat's original owner.
+    pub fn nominator(&self) -> serenity::UserId {
+        self.nominator
+    }
+    /// When bidding closes.
+    pub fn closes_at(&self) -> DateTime<Utc> {
+        self.closes_at
+    }
+    /// The players who have bid so far, by their seat's original owner, in the order they first bid.
+    pub fn bidders(&self) -> Vec<serenity::UserId> {
+        self.bids.iter().map(|(user, _)| *user).collect()
+    }
+}
+
+/// How a lot went, from [`League::resolve_lot`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LotResult {
+    /// The name of the item that was up for auction.
+    pub item: String,
+    /// The player who won it, or None if nobody bid and it went back to the pool.
+    pub winner: Option<serenity::UserId>,
+    /// What the winner paid, or 0 if nobody won.
+    pub price: u32,
+    /// Every bid, now revealed, highest first. Equal bids are in the order they were placed.
+    pub bids: Vec<(serenity::UserId, u32)>,
+}
+
+// the auction side of a League with DraftType::Auction
+#[derive(Clone, Default, Serialize, Deserialize)]
+pub(crate) struct Auction {
+    pub(crate) pricing: AuctionPricing,
+    pub(crate) lot: Option<Lot>,
+    // (winner, item, price) for every item sold, in order
+    pub(crate) sold: Vec<(serenity::UserId, String, u32)>,
+    // what every seat starts with, if bids are limited
+    #[serde(default)]
+    pub(crate) budget: Option<u32>,
+    #[serde(default)]
+    pub(crate) order: NominationOrder,
+    // nomination turns taken so far, skips included
+    #[serde(default)]
+    pub(crate) nominations: u32,
+}
+
+impl League {
+    /// Sets what the winners of sealed-bid lots pay. Defaults to [`AuctionPricing::FirstPrice`].
+    ///
+    /// # Errors
+    ///
+    /// If the draft has started, returns [`LeagueError::LeagueActiveError`], or [`LeagueError::WrongPhaseError`] once it is over.
+    pub fn set_auction_pricing(&mut self, pricing: AuctionPricing) -> Result<(), LeagueError> {
+        self.require_pre_draft()?;
+        self.auction.pricing = pricing;
+        Ok(())
+    }
+    /// Returns what the winners of sealed-bid lots pay.
+    pub fn auction_pricing(&self) -> AuctionPricing {
+        self.auction.pricing
+    }
+    /// Puts an item from the [`DraftPool`](crate::DraftPool) up for a sealed-bid auction, open until closes_at, on behalf of
+    /// the player whose turn it is to nominate (see [`League::current_nominator`]). Every seat with room on its roster
+    /// may then bid once with [`League::bid`], without seeing anyone else's bid, and [`League::resolve_lot`] settles it - a good fit
+    /// for drafts played out over hours in Discord rather than live.
+    ///
+    /// # Errors
+    ///
+    /// If the League is not an auction, returns [`LeagueError::WrongDraftTypeError`].
+    ///
+    /// If the League is not drafting, returns [`LeagueError::LeagueInactiveError`], or [`LeagueError::LeaguePausedError`] while paused.
+    ///
+    /// If the League has no pool, returns [`LeagueError::NoPoolError`].
+    ///
+    /// If another lot is still open, returns [`LeagueError::LotOpenError`].
+    ///
+    /// If the player is not in the league, returns [`LeagueError::PlayerNotFoundError`], and if it is not their turn to
+    /// nominate, [`LeagueError::NotYourTurnError`].
+    ///
+    /// If the item is not in the pool, returns [`LeagueError::DraftableNotFoundError`] with the pool's closest names.
+    pub fn nominate(
+        &mut self,
+        id: serenity::UserId,
+        item: &str,
+        closes_at: DateTime<Utc>,
+    ) -> Result<&Lot, LeagueError> {
+        self.require_auction()?;
+        let pool = self.pool.as_ref().ok_or(LeagueError::NoPoolError)?;
+        if self.auction.lot.is_some() {
+            return Err(LeagueError::LotOpenError);
+        }
+        let nominator = self.seat_owner_checked(id)?;
+        let (turn, seat) = self
+            .nominating_turn()
+            .ok_or(LeagueError::LeagueInactiveError)?;
+        if self.players[seat as usize].id != nominator {
+            return Err(LeagueError::NotYourTurnError);
+        }
+        let item = self.resolve_name(item).to_string();
+        if !pool.contains(&item) {
+            return Err(fuzzy::not_found(&item, pool.iter()));
+        }
+        self.auction.nominations = turn + 1;
+        self.record(DraftEvent::Nominated {
+            user: nominator,
+            item: item.clone(),
+            closes_at,
+        });
+        Ok(self.auction.lot.insert(Lot {
+            item,
+            nominator,
+            closes_at,
+            bids: Vec::new(),
+        }))
+    }
+    /// Returns the lot up for auction, if there is one.
+    pub fn lot(&self) -> Option<&Lot> {
+        self.auction.lot.as_ref()
+    }
+    /// Places a player's sealed bid on the open lot, replacing any bid they placed before. A replaced bid counts as placed
+    /// now when breaking ties.
+    ///
+    /// # Errors
+    ///
+    /// If no lot is open, returns [`LeagueError::NoLotError`].
+    ///
+    /// If now is after the lot closes, returns [`LeagueError::LotClosedError`].
+    ///
+    /// If the amount is 0, returns [`LeagueError::InvalidBidError`].
+    ///
+    /// If the player is not in the league, returns [`LeagueError::PlayerNotFoundError`].
+    ///
+    /// If the player's roster is full, returns [`LeagueError::RosterFullError`], and if they already have the item,
+    /// [`LeagueError::DraftableInUseError`].
+    ///
+    /// If the League has a budget and the amount is over the player's [`Budget::max_bid`](crate::Budget::max_bid),
+    /// returns [`LeagueError::BidTooHighError`] with the max.
+    pub fn bid(
+        &mut self,
+        id: serenity::UserId,
+        amount: u32,
+        now: DateTime<Utc>,
+    ) -> Result<(), LeagueError> {
+        let lot = self.auction.lot.as_ref().ok_or(LeagueError::NoLotError)?;
+        if now > lot.closes_at {
+            return Err(LeagueError::LotClosedError);
+        }
+        self.place_bid(id, amount)
+    }
+    // a bid without the time check, so replays do not depend on when they run
+    pub(crate) fn place_bid(
+        &mut self,
+        id: serenity::UserId,
+        amount: u32,
+    ) -> Result<(), LeagueError> {
+        let lot = self.auction.lot.as_ref().ok_or(LeagueError::NoLotError)?;
+        if amount == 0 {
+            return Err(LeagueError::InvalidBidError);
+        }
+        let seat = self.seat_of(id)?;
+        let player = &self.players[seat as usize];
+        if player.picks.len() as u32 >= player.team_size.unwrap_or(self.team_size) {
+            return Err(LeagueError::RosterFullError);
+        }
+        if self.holds(seat, &lot.item) {
+            return Err(LeagueError::DraftableInUseError);
+        }
+        let user = player.id;
+        self.require_affordable(user, amount)?;
+        self.record(DraftEvent::BidPlaced { user, amount });
+        if let Some(lot) = &mut self.auction.lot {
+            lot.bids.retain(|(bidder, _)| *bidder != user);
+            lot.bids.push((user, amount));
+        }
+        Ok(())
+    }
+    /// Returns the amount a player has bid on the open lot, if they have bid, so they can check their own bid.
+    pub fn bid_of(&self, id: serenity::UserId) -> Option<u32> {
+        let user = self.get_player(id)?.id;
+        let lot = self.auction.lot.as_ref()?;
+        lot.bids
+            .iter()
+            .find(|(bidder, _)| *bidder == user)
+            .map(|(_, amount)| *amount)
+    }
+    /// Returns the players who may still bid on the open lot but have not, by their seat's original owner.
+    /// Empty when no lot is open.
+    pub fn missing_bids(&self) -> Vec<serenity::UserId> {
+        let Some(lot) = &self.auction.lot else {
+            return Vec::new();
+        };
+        self.players
+            .iter()
+            .filter(|p| (p.picks.len() as u32) < p.team_size.unwrap_or(self.team_size))
+            .filter(|p| !self.holders(&lot.item).contains(&p.id))
+            .map(|p| p.id)
+            .filter(|id| !lot.bids.iter().any(|(bidder, _)| bidder == id))
+            .collect()
+    }
+    /// Settles the open lot once it has closed, or earlier if every player who may bid has: the highest bid wins, with
+    /// ties going to whoever bid first, and the winner pays according to the League's [`AuctionPricing`]. The item comes out
+    /// of the pool onto the winner's roster, or stays in the pool if nobody bid.
+    ///
+    /// The draft is over once every roster is full.
+    ///
+    /// # Errors
+    ///
+    /// If no lot is open, returns [`LeagueError::NoLotError`].
+    ///
+    /// If the lot has not closed and some players have yet to bid (see [`League::missing_bids`]), returns [`LeagueError::LotOpenError`].
+    pub fn resolve_lot(&mut self, now: DateTime<Utc>) -> Result<LotResult, LeagueError> {
+        let lot = self.auction.lot.as_ref().ok_or(LeagueError::NoLotError)?;
+        if now < lot.closes_at && !self.missing_bids().is_empty() {
+            return Err(LeagueError::LotOpenError);
+        }
+        self.settle_lot().ok_or(LeagueError::NoLotError)
+    }
+    // resolves the open lot whatever the time, or returns None if there is none
+    pub(crate) fn settle_lot(&mut self) -> Option<LotResult> {
+        let lot = self.auction.lot.take()?;
+        self.record(DraftEvent::LotResolved);
+        let mut bids = lot.bids;
+        // stable, so equal bids stay in the order they were placed
+        bids.sort_by_key(|(_, amount)| std::cmp::Reverse(*amount));
+        let (winner, price) = match (bids.first(), self.auction.pricing) {
+            (None, _) => (None, 0),
+            (Some((user, amount)), AuctionPricing::FirstPrice) => (Some(*user), *amount),
+            (Some((user, _)), AuctionPricing::SecondPrice) => {
+                (Some(*user), bids.get(1).map_or(1, |(_, amount)| *amount))
+            }
+        };
+        if let Some(winner) = winner {
+            let item = self.pool.as_mut().and_then(|pool| pool.take(&lot.item));
+            if let (Some(item), Ok(seat)) = (item, self.seat_of(winner)) {
+                self.give_pick(seat, item);
+            }
+            self.auction.sold.push((winner, lot.item.clone(), price));
+            self.finish_auction();
+        }
+        Some(LotResult {
+            item: lot.item,
+            winner,
+            price,
+            bids,
+        })
+    }
+    /// Returns every item sold so far with its winner and price, in the order they were sold.
+    pub fn auction_results(&self) -> &[(serenity::UserId, String, u32)] {
+        &self.auction.sold
+    }
+    // auctions and head-to-head drafts have no seat on the clock to lock in or skip
+    pub(crate) fn require_turns(&self) -> Result<(), LeagueError> {
+        if matches!(
+            self.draft_type,
+            DraftType::Auction | DraftType::Winston | DraftType::Grid | DraftType::Solomon
+        ) {
+            return Err(LeagueError::WrongDraftTypeError);
+        }
+        Ok(())
+    }
+    // lots can only be nominated while an auction League is drafting
+    pub(crate) fn require_auction(&self) -> Result<(), LeagueError> {
+        if self.draft_type != DraftType::Auction {
+            return Err(LeagueError::WrongDraftTypeError);
+        }
+        self.require_drafting()
+    }
+    fn seat_owner_checked(&self, id: serenity::UserId) -> Result<serenity::UserId, LeagueError> {
+        self.get_player(id)
+            .map(|p| p.id)
+            .ok_or(LeagueError::PlayerNotFoundError)
+    }
+    // an auction is over once every roster is full
+    fn finish_auction(&mut self) {
+        let full = self
+            .players
+            .iter()
+            .all(|p| p.picks.len() as u32 >= p.team_size.unwrap_or(self.team_size));
+        if full {
+            self.finish_draft();
+        }
+    }
+}
+
+#[cfg(test)]
+mod auction_tests {
+    use super::*;
+    use crate::test_support::{new_league, pokemon};
+    use crate::{DraftPool, LeaguePhase};
+    use chrono::Duration;
+
+    fn league(pricing: AuctionPricing) -> League {
+        let mut league = new_league(3, DraftType::Auction, 1);
+        let pool = ["Mewtwo", "Pikachu", "Eevee", "Ditto"]
+            .iter()
+            .map(|n| pokemon(n))
+            .collect();
+        league.set_pool(DraftPool::new(pool)).unwrap();
+        league.set_auction_pricing(pricing).unwrap();
+        league.activate().unwrap();
+        league
+    }
+
+    #[test]
+    fn sealed_bids_go_to_the_highest() {
+        let mut league = league(AuctionPricing::FirstPrice);
+        let now = Utc::now();
+        match league.lock(pokemon("Mewtwo")) {
+            Err(LeagueError::WrongDraftTypeError) => {}
+            _ => panic!("auction items are bid for"),
+        }
+        league
+            .nominate(serenity::UserId(1), "Mewtwo", now + Duration::hours(1))
+            .unwrap();
+        league.bid(serenity::UserId(1), 10, now).unwrap();
+        league.bid(serenity::UserId(2), 30, now).unwrap();
+        league.bid(serenity::UserId(1), 30, now).unwrap();
+        assert_eq!(league.bid_of(serenity::UserId(1)), Some(30));
+        assert_eq!(league.lot().unwrap().bidders().len(), 2);
+        match league.resolve_lot(now) {
+            Err(LeagueError::LotOpenError) => {}
+            _ => panic!("seat 3 has not bid and the lot is open"),
+        }
+        match league.bid(serenity::UserId(3), 50, now + Duration::hours(2)) {
+            Err(LeagueError::LotClosedError) => {}
+            _ => panic!("bidding has closed"),
+        }
+        let result = league.resolve_lot(now + Duration::hours(2)).unwrap();
+        assert_eq!(
+            result.winner,
+            Some(serenity::UserId(2)),
+            "seat 2 bid 30 first"
+        );
+        assert_eq!(result.price, 30);
+        assert!(!league.pool().unwrap().contains("Mewtwo"));
+        assert!(league.lot().is_none());
+
+        league
+            .nominate(serenity::UserId(3), "Pikachu", now + Duration::hours(1))
+            .unwrap();
+        match league.bid(serenity::UserId(2), 5, now) {
+            Err(LeagueError::RosterFullError) => {}
+            _ => panic!("seat 2 has a full roster"),
+        }
+        let result = league.resolve_lot(now + Duration::hours(2)).unwrap();
+        assert_eq!(result.winner, None);
+        assert!(league.pool().unwrap().contains("Pikachu"));
+    }
+
+    #[test]
+    fn second_price_lots_charge_the_runner_up_bid() {
+        let mut league = league(AuctionPricing::SecondPrice);
+        let now = Utc::now();
+        league
+            .nominate(serenity::UserId(1), "Pikachu", now + Duration::hours(1))
+            .unwrap();
+        for (user, amount) in [(1, 12), (2, 40), (3, 25)] {
+            league.bid(serenity::UserId(user), amount, now).unwrap();
+        }
+        // everyone has bid, so the lot can close early
+        let result = league.resolve_lot(now).unwrap();
+        assert_eq!(result.winner, Some(serenity::UserId(2)));
+        assert_eq!(result.price, 25);
+        assert_eq!(result.bids[0], (serenity::UserId(2), 40));
+        for (nominator, item, winner) in [(3, "Eevee", 1), (3, "Ditto", 3)] {
+            league
+                .nominate(serenity::UserId(nominator), item, now + Duration::hours(1))
+                .unwrap();
+            league.bid(serenity::UserId(winner), 7, now).unwrap();
+            assert_eq!(
+                league.resolve_lot(now + Duration::hours(1)).unwrap().price,
+                1
+            );
+        }
+        assert_eq!(league.phase(), LeaguePhase::PostDraft);
+
+        // pricing is configuration, not an event
+        let mut replayed = new_league(3, DraftType::Auction, 1);
+        replayed
+            .set_auction_pricing(AuctionPricing::SecondPrice)
+            .unwrap();
+        replayed
+            .replay(league.events(), |name| Some(pokemon(name)))
+            .unwrap();
+        assert_eq!(replayed.auction_results(), league.auction_results());
+        assert_eq!(replayed.phase(), LeaguePhase::PostDraft);
+    }
+}
@@ -0,0 +1,286 @@
+use crate::{metrics, ActivePlayer, Draftable, League, LeagueError, PickResult, PickSource};
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+use std::sync::{Mutex, PoisonError};
+
+/// Decides what to pick for a player who is not there to pick for themselves - players on autopick, or ones
+/// who were skipped or ran out of time (see [`League::autopick`]).
+///
+/// Set a League's strategy with [`League::set_autopick_strategy`]. The default is [`BestAvailable`].
+pub trait AutopickStrategy: Send + Sync {
+    /// Returns the name of the item to pick for the player, or None to leave them on the clock.
+    ///
+    /// available holds the items that can be picked right now, in ranking order: what is left in the League's [`DraftPool`](crate::DraftPool),
+    /// or the face-up pack of a Rochester draft. The player's queue can be read with [`ActivePlayer::queue`], and naming a queued item picks it.
+    fn choose(&self, player: &ActivePlayer, available: &[&Draftable]) -> Option<String>;
+}
+
+/// Picks the highest ranked item available.
+pub struct BestAvailable;
+
+impl AutopickStrategy for BestAvailable {
+    fn choose(&self, _player: &ActivePlayer, available: &[&Draftable]) -> Option<String> {
+        available.first().map(|item| item.name().to_string())
+    }
+}
+
+/// Picks the most expensive item available, by [`DraftItem::cost`](crate::DraftItem::cost), breaking ties by ranking.
+/// Items without a cost are only picked if nothing has one.
+pub struct BestAvailableByCost;
+
+impl AutopickStrategy for BestAvailableByCost {
+    fn choose(&self, _player: &ActivePlayer, available: &[&Draftable]) -> Option<String> {
+        // max_by_key keeps the last of equal elements, so walk the rankings backwards
+        available
+            .iter()
+            .rev()
+            .max_by_key(|item| item.cost())
+            .map(|item| item.name().to_string())
+    }
+}
+
+/// Picks a random item available.
+pub struct RandomAvailable {
+    rng: Mutex<StdRng>,
+}
+
+impl RandomAvailable {
+    /// Creates a RandomAvailable seeded from the operating system.
+    pub fn new() -> RandomAvailable {
+        RandomAvailable {
+            rng: Mutex::new(StdRng::from_entropy()),
+        }
+    }
+    /// Creates a RandomAvailable that makes the same picks every time, e.g. for tests or reproducible simulations.
+    pub fn with_seed(seed: u64) -> RandomAvailable {
+        RandomAvailable {
+            rng: Mutex::new(StdRng::seed_from_u64(seed)),
+        }
+    }
+}
+
+impl Default for RandomAvailable {
+    fn default() -> Self {
+        RandomAvailable::new()
+    }
+}
+
+impl AutopickStrategy for RandomAvailable {
+    fn choose(&self, _player: &ActivePlayer, available: &[&Draftable]) -> Option<String> {
+        let mut rng = self.rng.lock().unwrap_or_else(PoisonError::into_inner);
+        available
+            .choose(&mut *rng)
+            .map(|item| item.name().to_string())
+    }
+}
+
+/// Picks the first item in the player's queue that is still available, or a random item if there is none.
+pub struct QueueThenRandom {
+    random: RandomAvailable,
+}
+
+impl QueueThenRandom {
+    /// Creates a QueueThenRandom seeded from the operating system.
+    pub fn new() -> QueueThenRandom {
+        QueueThenRandom {
+            random: RandomAvailable::new(),
+        }
+    }
+    /// Creates a QueueThenRandom whose random picks are the same every time.
+    pub fn with_seed(seed: u64) -> QueueThenRandom {
+        QueueThenRandom {
+            random: RandomAvailable::with_seed(seed),
+        }
+    }
+}
+
+impl Default for QueueThenRandom {
+    fn default() -> Self {
+        QueueThenRandom::new()
+    }
+}
+
+impl AutopickStrategy for QueueThenRandom {
+    fn choose(&self, player: &ActivePlayer, available: &[&Draftable]) -> Option<String> {
+        // without a pool or pack to check against, everything queued is assumed available
+        let queued = player
+            .queue()
+            .iter()
+            .find(|q| available.is_empty() || available.iter().any(|a| a.name() == q.name()));
+        match queued {
+            Some(item) => Some(item.name().to_string()),
+            None => self.random.choose(player, available),
+        }
+    }
+}
+
+impl League {
+    /// Sets the [`AutopickStrategy`] used for players on autopick whose queue is empty, and by [`League::autopick`].
+    pub fn set_autopick_strategy(&mut self, strategy: Box<dyn AutopickStrategy>) {
+        self.autopick_strategy = strategy;
+    }
+    /// Picks for the player on the clock using the League's [`AutopickStrategy`], e.g. when they have run out of time,
+    /// then advances the draft as [`League::lock`] does and returns every pick made.
+    ///
+    /// # Errors
+    ///
+    /// If the league is not drafting, returns a [`LeagueError::LeagueInactiveError`].
+    ///
+    /// If the league is paused, returns a [`LeagueError::LeaguePausedError`].
+    ///
+    /// In an auction or a Winston, Grid or Solomon draft, returns [`LeagueError::WrongDraftTypeError`].
+    ///
+    /// If the strategy finds nothing to pick, returns a [`LeagueError::NoAutopickAvailableError`]. The player stays on the clock.
+    pub fn autopick(&mut self) -> Result<Vec<PickResult>, LeagueError> {
+        metrics::tracked(self.require_drafting())?;
+        metrics::tracked(self.require_turns())?;
+        let Some(pick) = self.strategy_pick(None) else {
+            return metrics::tracked(Err(LeagueError::NoAutopickAvailableError));
+        };
+        let (picks, _) = self.lock_private(pick, PickSource::Autopick, Vec::new());
+        metrics::picks_made(picks.len());
+        Ok(picks)
+    }
+    // asks the strategy (the League's own if None) for the current player's pick and takes it from wherever it is
+    pub(crate) fn strategy_pick(
+        &mut self,
+        strategy: Option<&dyn AutopickStrategy>,
+    ) -> Option<Draftable> {
+        loop {
+            let name =
+                self.choose_for_current(strategy.unwrap_or(self.autopick_strategy.as_ref()))?;
+            let in_pool = self.pool.as_ref().is_some_and(|p| p.contains(&name));
+            if self.claim_shared(&name).is_ok() {
+                return self.take_for_current(&name);
+            }
+            // another League sharing the pool took it, so it leaves the pool and queue and the strategy chooses again
+            if self.dequeue(self.current_seat, &name).is_none() && !in_pool {
+                return None;
+            }
+        }
+    }
+    pub(crate) fn choose_for_current(&self, strategy: &dyn AutopickStrategy) -> Option<String> {
+        let available: Vec<&Draftable> = match (&self.packs, &self.pool) {
+            (Some(packs), _) => packs.revealed().iter().collect(),
+            (None, Some(pool)) => pool.iter().collect(),
+            (None, None) => Vec::new(),
+        };
+        let player = &self.players[self.current_seat as usize];
+        // with copies in the pool, the player may already hold one of what is left
+        let available: Vec<&Draftable> = available
+            .into_iter()
+            .filter(|i| !self.holds(self.current_seat, i.name()))
+            .filter(|i| self.fits_round(i, self.total_picks))
+            .filter(|i| self.fits_roster(self.current_seat, i))
+            .collect();
+        strategy.choose(player, &available)
+    }
+    // the current player's queued copy of the item if they have one, otherwise the pack or pool's
+    pub(crate) fn take_for_current(&mut self, name: &str) -> Option<Draftable> {
+        if let Some(queued) = self.dequeue(self.current_seat, name) {
+            return Some(queued);
+        }
+        match (&mut self.packs, &mut self.pool) {
+            (Some(packs), _) => packs.take(name),
+            (None, Some(pool)) => pool.take(name),
+            (None, None) => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod autopick_tests {
+    use super::*;
+    use crate::test_support::new_league;
+    use crate::{DraftItem, DraftPool, DraftType};
+    use poise::serenity_prelude as serenity;
+
+    struct Player {
+        name: String,
+        cost: u32,
+    }
+    impl DraftItem for Player {
+        fn name(&self) -> &str {
+            &self.name
+        }
+        fn cost(&self) -> Option<u32> {
+            Some(self.cost)
+        }
+    }
+
+    fn item(name: &str, cost: u32) -> Draftable {
+        Box::new(Player {
+            name: name.to_string(),
+            cost,
+        })
+    }
+
+    fn league(strategy: Box<dyn AutopickStrategy>) -> League {
+        let mut league = new_league(2, DraftType::Linear, 2);
+        league
+            .set_pool(DraftPool::new(vec![
+                item("Kelce", 40),
+                item("Mahomes", 60),
+                item("Hill", 60),
+                item("Diggs", 30),
+            ]))
+            .unwrap();
+        league.set_autopick_strategy(strategy);
+        league.activate().unwrap();
+        league
+    }
+
+    #[test]
+    fn best_available_by_cost_breaks_ties_by_rank() {
+        let mut league = league(Box::new(BestAvailableByCost));
+        let picks = league.autopick().unwrap();
+        assert_eq!(picks[0].item, "Mahomes");
+        assert_eq!(picks[0].source, PickSource::Autopick);
+    }
+
+    #[test]
+    fn queue_then_random_prefers_queue() {
+        let mut league = league(Box::new(QueueThenRandom::with_seed(7)));
+        league
+            .add_to_player_queue(serenity::UserId(1), item("Diggs", 30))
+            .unwrap();
+        let picks = league.autopick().unwrap();
+        assert_eq!(picks[0].user, serenity::UserId(1));
+        assert_eq!(picks[0].item, "Diggs");
+        let picks = league.autopick().unwrap();
+        assert_ne!(picks[0].item, "Diggs");
+        assert_eq!(league.pool().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn empty_pool_leaves_player_on_clock() {
+        let mut league = new_league(1, DraftType::Linear, 2);
+        league.activate().unwrap();
+        match league.autopick() {
+            Err(LeagueError::NoAutopickAvailableError) => {}
+            _ => panic!("nothing to pick from"),
+        }
+    }
+
+    #[test]
+    fn leagues_not_picking_in_turn_cannot_autopick() {
+        for draft_type in [
+            DraftType::Auction,
+            DraftType::Winston,
+            DraftType::Grid,
+            DraftType::Solomon,
+        ] {
+            let mut league = new_league(2, draft_type, 2);
+            league
+                .set_pool(DraftPool::new(vec![item("Kelce", 40), item("Mahomes", 60)]))
+                .unwrap();
+            league.activate().unwrap();
+            match league.autopick() {
+                Err(LeagueError::WrongDraftTypeError) => {}
+                _ => panic!("{draft_type:?} drafts are not picked in turn"),
+            }
+            assert_eq!(league.pick_number(), 1);
+        }
+    }
+}
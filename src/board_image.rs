@@ -0,0 +1,200 @@
+use crate::League;
+use ab_glyph::{Font, FontRef, PxScale, ScaleFont};
+
+const CELL_WIDTH: u32 = 180;
+const CELL_HEIGHT: u32 = 36;
+const ROUND_WIDTH: u32 = 48;
+const PADDING: f32 = 8.0;
+const TEXT_SIZE: f32 = 16.0;
+
+const BACKGROUND: [u8; 3] = [0x31, 0x33, 0x38];
+const HEADER: [u8; 3] = [0x1e, 0x1f, 0x22];
+const ROWS: [[u8; 3]; 2] = [[0x2b, 0x2d, 0x31], [0x38, 0x3a, 0x40]];
+const TEXT: [u8; 3] = [0xf2, 0xf3, 0xf5];
+
+#[derive(Debug)]
+pub enum BoardImageError {
+    /// The font given could not be read.
+    InvalidFontError,
+    /// The image could not be written as a PNG.
+    EncodingError(png::EncodingError),
+}
+
+// an RGB image drawn cell by cell
+struct Canvas {
+    width: u32,
+    height: u32,
+    pixels: Vec<u8>,
+}
+
+impl Canvas {
+    fn new(width: u32, height: u32) -> Canvas {
+        Canvas {
+            width,
+            height,
+            pixels: BACKGROUND.repeat((width * height) as usize),
+        }
+    }
+    fn fill(&mut self, x: u32, y: u32, width: u32, height: u32, color: [u8; 3]) {
+        for row in y..(y + height).min(self.height) {
+            for col in x..(x + width).min(self.width) {
+                let idx = ((row * self.width + col) * 3) as usize;
+                self.pixels[idx..idx + 3].copy_from_slice(&color);
+            }
+        }
+    }
+    fn blend(&mut self, x: u32, y: u32, color: [u8; 3], coverage: f32) {
+        if x >= self.width || y >= self.height {
+            return;
+        }
+        let idx = ((y * self.width + x) * 3) as usize;
+        for (channel, target) in self.pixels[idx..idx + 3].iter_mut().zip(color) {
+            let mixed =
+                f32::from(*channel) + (f32::from(target) - f32::from(*channel)) * coverage.min(1.0);
+            *channel = mixed.round() as u8;
+        }
+    }
+    // writes text left-aligned in the box, cutting it short with an ellipsis if it does not fit
+    fn text(&mut self, font: &FontRef, text: &str, x: u32, y: u32, width: u32, height: u32) {
+        let font = font.as_scaled(PxScale::from(TEXT_SIZE));
+        let max_width = width as f32 - 2.0 * PADDING;
+        let text_width = |s: &str| {
+            s.chars()
+                .map(|c| font.h_advance(font.glyph_id(c)))
+                .sum::<f32>()
+        };
+        let mut shown: String = text.to_string();
+        if text_width(&shown) > max_width {
+            while !shown.is_empty() && text_width(&shown) + text_width("…") > max_width {
+                shown.pop();
+            }
+            shown.push('…');
+        }
+        let baseline = y as f32 + (height as f32 + font.ascent() + font.descent()) / 2.0;
+        let mut caret = x as f32 + PADDING;
+        for c in shown.chars() {
+            let mut glyph = font.scaled_glyph(c);
+            glyph.position = ab_glyph::point(caret, baseline);
+            caret += font.h_advance(glyph.id);
+            if let Some(outlined) = font.outline_glyph(glyph) {
+                let bounds = outlined.px_bounds();
+                outlined.draw(|gx, gy, coverage| {
+                    let px = bounds.min.x + gx as f32;
+                    let py = bounds.min.y + gy as f32;
+                    if px >= 0.0 && py >= 0.0 {
+                        self.blend(px as u32, py as u32, TEXT, coverage);
+                    }
+                });
+            }
+        }
+    }
+    fn encode(self) -> Result<Vec<u8>, BoardImageError> {
+        let mut png = Vec::new();
+        let mut encoder = png::Encoder::new(&mut png, self.width, self.height);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+        encoder
+            .write_header()
+            .and_then(|mut writer| writer.write_image_data(&self.pixels))
+            .map_err(BoardImageError::EncodingError)?;
+        Ok(png)
+    }
+}
+
+impl League {
+    /// Renders the draft board as a PNG, ready to upload as a Discord attachment: a column for each seat, headed by its
+    /// [`Team`](crate::Team) name in the team's color (or "Seat 1", "Seat 2"...), and a row for each round.
+    /// Picks are read from [`League::events`] the same way as [`League::round_picks`], and skipped picks leave their cell blank.
+    ///
+    /// font is the contents of a TrueType or OpenType font file, which DRFTR does not bundle; pick one that covers the
+    /// characters in your items' names.
+    ///
+    /// Requires the `image` feature.
+    ///
+    /// # Errors
+    ///
+    /// If the font cannot be read, returns [`BoardImageError::InvalidFontError`].
+    ///
+    /// If the PNG cannot be written, returns [`BoardImageError::EncodingError`].
+    pub fn board_png(&self, font: &[u8]) -> Result<Vec<u8>, BoardImageError> {
+        let font = FontRef::try_from_slice(font).map_err(|_| BoardImageError::InvalidFontError)?;
+        let seats = self.players.len() as u32;
+        let (made, _) = self.pick_history();
+        let rounds = (made.len() as u32).div_ceil(seats.max(1));
+        let mut canvas = Canvas::new(ROUND_WIDTH + seats * CELL_WIDTH, (rounds + 1) * CELL_HEIGHT);
+
+        for (seat, player) in self.players.iter().enumerate() {
+            let x = ROUND_WIDTH + seat as u32 * CELL_WIDTH;
+            let color = player
+                .team()
+                .and_then(|t| t.color())
+                .map_or(HEADER, |c| [c.r(), c.g(), c.b()]);
+            canvas.fill(x, 0, CELL_WIDTH, CELL_HEIGHT, color);
+            let name = self.seat_heading(seat, player);
+            canvas.text(&font, &name, x, 0, CELL_WIDTH, CELL_HEIGHT);
+        }
+        for round in 0..rounds {
+            let y = (round + 1) * CELL_HEIGHT;
+            canvas.fill(0, y, canvas.width, CELL_HEIGHT, ROWS[(round % 2) as usize]);
+            canvas.text(
+                &font,
+                &self.round_label(round as usize),
+                0,
+                y,
+                ROUND_WIDTH,
+                CELL_HEIGHT,
+            );
+        }
+        for (pick, made) in made.into_iter().enumerate() {
+            let Some((user, item)) = made else {
+                continue;
+            };
+            // picks by a seat removed since have nowhere to go
+            let Some(seat) = self.players.iter().position(|p| p.id == user) else {
+                continue;
+            };
+            let x = ROUND_WIDTH + seat as u32 * CELL_WIDTH;
+            let y = (pick as u32 / seats + 1) * CELL_HEIGHT;
+            canvas.text(&font, item, x, y, CELL_WIDTH, CELL_HEIGHT);
+        }
+        canvas.encode()
+    }
+}
+
+#[cfg(test)]
+mod board_image_tests {
+    use super::*;
+    use crate::test_support::{new_league, pokemon};
+    use crate::{DraftType, LeagueError};
+
+    fn league() -> Result<League, LeagueError> {
+        let mut league = new_league(2, DraftType::Snake, 2);
+        league.activate()?;
+        league.lock(pokemon("Pikachu"))?;
+        league.lock(pokemon("Charizard"))?;
+        league.lock(pokemon("Fletchinder the Unreasonably Long Named"))?;
+        Ok(league)
+    }
+
+    #[test]
+    fn fonts_must_be_readable() {
+        let league = league().unwrap();
+        match league.board_png(b"not a font") {
+            Err(BoardImageError::InvalidFontError) => {}
+            _ => panic!("that is not a font"),
+        }
+    }
+
+    #[test]
+    #[ignore = "DRFTR does not bundle a font, so this borrows DejaVu Sans from the system: run it with --ignored where it is installed"]
+    fn board_is_a_grid_of_rounds_and_seats() {
+        let font = std::fs::read("/usr/share/fonts/truetype/dejavu/DejaVuSans.ttf")
+            .expect("DejaVu Sans is installed");
+        let png = league().unwrap().board_png(&font).unwrap();
+        let decoder = png::Decoder::new(png.as_slice());
+        let reader = decoder.read_info().unwrap();
+        let info = reader.info();
+        assert_eq!(info.width, ROUND_WIDTH + 2 * CELL_WIDTH);
+        assert_eq!(info.height, 3 * CELL_HEIGHT);
+    }
+}
@@ -0,0 +1,139 @@
+use crate::{League, LeagueError};
+use poise::serenity_prelude as serenity;
+
+/// A seat's budget in an auction, from [`League::budget`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Budget {
+    /// What every seat started the auction with (see [`League::set_auction_budget`]).
+    pub starting: u32,
+    /// What the seat has paid for the items it has won.
+    pub spent: u32,
+    /// What the seat has bid on the lot up for auction, which it pays at most if it wins.
+    pub committed: u32,
+    /// The most the seat may bid on the lot up for auction, keeping 1 back for every other roster slot it still has to fill.
+    /// 0 once its roster is full.
+    pub max_bid: u32,
+}
+
+impl Budget {
+    /// Returns what the seat has left to spend, not counting its bid on the open lot.
+    pub fn remaining(&self) -> u32 {
+        self.starting.saturating_sub(self.spent)
+    }
+    /// Returns what the seat would have left to spend if it won the open lot at its own bid.
+    pub fn available(&self) -> u32 {
+        self.remaining().saturating_sub(self.committed)
+    }
+}
+
+impl League {
+    /// Gives every seat in an auction the same budget to bid from, or lifts the limit with None (the default).
+    /// See [`League::budget`] for where each seat stands.
+    ///
+    /// # Errors
+    ///
+    /// If the draft has started, returns [`LeagueError::LeagueActiveError`], or [`LeagueError::WrongPhaseError`] once it is over.
+    pub fn set_auction_budget(&mut self, budget: Option<u32>) -> Result<(), LeagueError> {
+        self.require_pre_draft()?;
+        self.auction.budget = budget;
+        Ok(())
+    }
+    /// Returns the budget every seat starts the auction with, if there is one.
+    pub fn auction_budget(&self) -> Option<u32> {
+        self.auction.budget
+    }
+    /// Returns a seat's budget ledger, e.g. to show a "max bid" next to the lot, or None if the League has no budget
+    /// or the user has no seat.
+    pub fn budget(&self, id: serenity::UserId) -> Option<Budget> {
+        let starting = self.auction.budget?;
+        let player = self.get_player(id)?;
+        let spent = self
+            .auction
+            .sold
+            .iter()
+            .filter(|(winner, _, _)| *winner == player.id)
+            .map(|(_, _, price)| *price)
+            .sum::<u32>();
+        let slots = player
+            .team_size
+            .unwrap_or(self.team_size)
+            .saturating_sub(player.picks.len() as u32);
+        let max_bid = match slots {
+            0 => 0,
+            slots => starting.saturating_sub(spent).saturating_sub(slots - 1),
+        };
+        Some(Budget {
+            starting,
+            spent,
+            committed: self.bid_of(player.id).unwrap_or(0),
+            max_bid,
+        })
+    }
+    /// Returns every seat's budget ledger in seat order, by their seat's original owner. Empty if the League has no budget.
+    pub fn budgets(&self) -> Vec<(serenity::UserId, Budget)> {
+        self.players
+            .iter()
+            .filter_map(|p| Some((p.id, self.budget(p.id)?)))
+            .collect()
+    }
+    // bids over the seat's max bid would leave it unable to fill its roster
+    pub(crate) fn require_affordable(
+        &self,
+        id: serenity::UserId,
+        amount: u32,
+    ) -> Result<(), LeagueError> {
+        match self.budget(id) {
+            Some(budget) if amount > budget.max_bid => {
+                Err(LeagueError::BidTooHighError(budget.max_bid))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod budget_tests {
+    use super::*;
+    use crate::test_support::{new_league, pokemon};
+    use crate::{AuctionPricing, DraftPool, DraftType};
+    use chrono::{Duration, Utc};
+
+    #[test]
+    fn budgets_track_spending_and_cap_bids() {
+        let mut league = new_league(2, DraftType::Auction, 3);
+        let pool = ["Mewtwo", "Pikachu", "Eevee"]
+            .iter()
+            .map(|n| pokemon(n))
+            .collect();
+        league.set_pool(DraftPool::new(pool)).unwrap();
+        league.set_auction_budget(Some(100)).unwrap();
+        league
+            .set_auction_pricing(AuctionPricing::SecondPrice)
+            .unwrap();
+        league.activate().unwrap();
+        let now = Utc::now();
+
+        let budget = league.budget(serenity::UserId(1)).unwrap();
+        assert_eq!(budget.max_bid, 98, "1 kept back for each of 2 more slots");
+        league
+            .nominate(serenity::UserId(1), "Mewtwo", now + Duration::hours(1))
+            .unwrap();
+        match league.bid(serenity::UserId(1), 99, now) {
+            Err(LeagueError::BidTooHighError(98)) => {}
+            _ => panic!("seat 1 must keep 2 back"),
+        }
+        league.bid(serenity::UserId(1), 90, now).unwrap();
+        league.bid(serenity::UserId(2), 60, now).unwrap();
+        let budget = league.budget(serenity::UserId(1)).unwrap();
+        assert_eq!(budget.committed, 90);
+        assert_eq!(budget.available(), 10);
+        league.resolve_lot(now).unwrap();
+
+        let budget = league.budget(serenity::UserId(1)).unwrap();
+        assert_eq!(budget.spent, 60);
+        assert_eq!(budget.committed, 0);
+        assert_eq!(budget.remaining(), 40);
+        assert_eq!(budget.max_bid, 39);
+        assert_eq!(league.budgets()[1].1.max_bid, 98);
+    }
+}
@@ -0,0 +1,263 @@
+use crate::{DraftType, League, LeagueTemplate};
+use chrono::Duration;
+use poise::serenity_prelude as serenity;
+use std::collections::{HashMap, HashSet};
+
+/// A validated alternative to [`League::new`].
+///
+/// Start from [`League::builder`], set whatever you need, and call [`LeagueBuilder::build`]. Options you do not set
+/// fall back to the same defaults as [`League::new`]: a snake draft, no output channel and untimed picks.
+pub struct LeagueBuilder {
+    id: u64,
+    name: String,
+    players: Vec<serenity::UserId>,
+    draft_type: DraftType,
+    team_size: Option<u32>,
+    output: Option<serenity::ChannelId>,
+    pick_time_limit: Option<Duration>,
+    locale: Option<String>,
+    queue_limit: Option<u32>,
+    // k: category, v: the most items of it each team may hold
+    roster_slots: HashMap<String, u32>,
+    salary_cap: Option<u32>,
+    // the rest of a template's settings, which only a template sets
+    template: Option<LeagueTemplate>,
+}
+
+impl LeagueBuilder {
+    pub(crate) fn new(id: u64, name: String) -> LeagueBuilder {
+        LeagueBuilder {
+            id,
+            name,
+            players: Vec::new(),
+            draft_type: DraftType::Snake,
+            team_size: None,
+            output: None,
+            pick_time_limit: None,
+            locale: None,
+            queue_limit: None,
+            roster_slots: HashMap::new(),
+            salary_cap: None,
+            template: None,
+        }
+    }
+    /// Sets the players in seat order, replacing any set previously.
+    pub fn players(mut self, users: &[serenity::UserId]) -> LeagueBuilder {
+        self.players = users.to_vec();
+        self
+    }
+    /// Adds a player in the next seat.
+    pub fn player(mut self, user: serenity::UserId) -> LeagueBuilder {
+        self.players.push(user);
+        self
+    }
+    /// Sets the [`DraftType`]. Defaults to [`DraftType::Snake`].
+    pub fn draft_type(mut self, draft_type: DraftType) -> LeagueBuilder {
+        self.draft_type = draft_type;
+        self
+    }
+    /// Sets the number of picks each player makes. Required.
+    pub fn team_size(mut self, team_size: u32) -> LeagueBuilder {
+        self.team_size = Some(team_size);
+        self
+    }
+    /// Sets the channel announcements for this League go to, instead of the [`DraftGuild`](crate::DraftGuild)'s default output.
+    pub fn output(mut self, output: serenity::ChannelId) -> LeagueBuilder {
+        self.output = Some(output);
+        self
+    }
+    /// Sets the time each player has to make a pick. See [`League::set_pick_time_limit`].
+    pub fn pick_time_limit(mut self, limit: Duration) -> LeagueBuilder {
+        self.pick_time_limit = Some(limit);
+        self
+    }
+    /// Sets the locale the League's messages are written in. See [`League::set_locale`].
+    pub fn locale(mut self, locale: String) -> LeagueBuilder {
+        self.locale = Some(locale);
+        self
+    }
+    /// Caps how many items each player can queue. See [`League::set_queue_limit`].
+    pub fn queue_limit(mut self, limit: u32) -> LeagueBuilder {
+        self.queue_limit = Some(limit);
+        self
+    }
+    /// Limits how many items of a category each team may hold. See [`League::set_roster_slot`].
+    pub fn roster_slot(mut self, category: &str, count: u32) -> LeagueBuilder {
+        self.roster_slots.insert(category.to_string(), count);
+        self
+    }
+    /// Caps the total cost of each team's picks. See [`League::set_salary_cap`].
+    pub fn salary_cap(mut self, cap: u32) -> LeagueBuilder {
+        self.salary_cap = Some(cap);
+        self
+    }
+    /// Sets everything a [`LeagueTemplate`] holds, replacing anything set before. Options set afterwards override the template's.
+    pub fn template(mut self, template: &LeagueTemplate) -> LeagueBuilder {
+        self.draft_type = template.draft_type();
+        self.team_size = Some(template.team_size());
+        self.pick_time_limit = template.pick_time_limit();
+        self.locale = template.locale().map(String::from);
+        self.queue_limit = template.queue_limit();
+        self.roster_slots = template.roster_slots().iter().cloned().collect();
+        self.salary_cap = template.salary_cap();
+        self.template = Some(template.clone());
+        self
+    }
+    /// Validates the configuration and creates the [`League`].
+    ///
+    /// # Errors
+    ///
+    /// If the name is empty or only whitespace, returns [`LeagueConfigError::EmptyNameError`].
+    ///
+    /// If no players were added, returns [`LeagueConfigError::NoPlayersError`].
+    ///
+    /// If a player was added more than once, returns [`LeagueConfigError::DuplicatePlayerError`] with that player's ID.
+    ///
+    /// If the team size was never set, returns [`LeagueConfigError::MissingTeamSizeError`], and if it is 0, [`LeagueConfigError::ZeroTeamSizeError`].
+    ///
+    /// If the pick time limit is not positive, returns [`LeagueConfigError::InvalidTimeLimitError`].
+    ///
+    /// If a template was set, also returns the errors of [`LeagueTemplate::validate`], and if its nomination order names a seat
+    /// that does not exist, [`LeagueConfigError::InvalidNominationOrderError`].
+    pub fn build(self) -> Result<League, LeagueConfigError> {
+        if self.name.trim().is_empty() {
+            return Err(LeagueConfigError::EmptyNameError);
+        }
+        if self.players.is_empty() {
+            return Err(LeagueConfigError::NoPlayersError);
+        }
+        let mut seen = HashSet::new();
+        if let Some(duplicate) = self.players.iter().find(|id| !seen.insert(**id)) {
+            return Err(LeagueConfigError::DuplicatePlayerError(*duplicate));
+        }
+        let Some(team_size) = self.team_size else {
+            return Err(LeagueConfigError::MissingTeamSizeError);
+        };
+        if team_size == 0 {
+            return Err(LeagueConfigError::ZeroTeamSizeError);
+        }
+        if let Some(limit) = self.pick_time_limit {
+            if limit <= Duration::zero() {
+                return Err(LeagueConfigError::InvalidTimeLimitError);
+            }
+        }
+        if let Some(template) = &self.template {
+            template.validate()?;
+        }
+        let mut league = League::new(
+            &self.players,
+            self.id,
+            self.name,
+            self.output,
+            self.draft_type,
+            team_size,
+        );
+        league.set_pick_time_limit(self.pick_time_limit);
+        league.set_locale(self.locale);
+        league.set_queue_limit(self.queue_limit);
+        league.roster_slots = self.roster_slots;
+        league.salary_cap = self.salary_cap;
+        if let Some(template) = self.template {
+            league.set_reminder_interval(template.reminder_interval());
+            league.set_max_duration(template.max_duration());
+            league.set_queue_edits_off_turn(template.queue_edits_off_turn());
+            league.timezone = template.timezone().and_then(|name| name.parse().ok());
+            league.round_categories = template.round_categories().iter().cloned().collect();
+            league.auction.pricing = template.auction_pricing();
+            league.auction.budget = template.auction_budget();
+            league.set_skip_policy(template.skip_policy());
+            league
+                .set_nomination_order(template.nomination_order().clone())
+                .map_err(|_| LeagueConfigError::InvalidNominationOrderError)?;
+        }
+        Ok(league)
+    }
+}
+
+#[derive(Debug)]
+pub enum LeagueConfigError {
+    EmptyNameError,
+    NoPlayersError,
+    DuplicatePlayerError(serenity::UserId),
+    MissingTeamSizeError,
+    ZeroTeamSizeError,
+    InvalidTimeLimitError,
+    MalformedTemplateError(String),
+    InvalidPhaseOrderError,
+    InvalidTimezoneError(String),
+    InvalidRoundError,
+    InvalidNominationOrderError,
+}
+
+#[cfg(test)]
+mod builder_tests {
+    use super::*;
+
+    #[test]
+    fn builds_valid_league() {
+        let league = League::builder(69420, "Creenis".to_string())
+            .player(serenity::UserId(1))
+            .player(serenity::UserId(2))
+            .draft_type(DraftType::Linear)
+            .team_size(3)
+            .pick_time_limit(Duration::minutes(2))
+            .build()
+            .expect("valid config");
+        assert_eq!(league.players.len(), 2);
+        assert_eq!(league.final_pick, 5);
+        assert_eq!(league.clock().limit(), Some(Duration::minutes(2)));
+    }
+
+    #[test]
+    fn builds_roster_limits() {
+        let league = League::builder(69420, "Creenis".to_string())
+            .player(serenity::UserId(1))
+            .team_size(3)
+            .roster_slot("GK", 1)
+            .salary_cap(100)
+            .build()
+            .unwrap();
+        assert_eq!(league.roster_slot("GK"), Some(1));
+        assert_eq!(league.roster_slot("FW"), None);
+        assert_eq!(league.salary_cap(), Some(100));
+    }
+
+    #[test]
+    fn empty_players_errors_instead_of_panicking() {
+        match League::builder(69420, "Creenis".to_string())
+            .team_size(3)
+            .build()
+        {
+            Err(LeagueConfigError::NoPlayersError) => {}
+            _ => panic!("a league needs players"),
+        }
+    }
+
+    #[test]
+    fn duplicate_player_is_reported() {
+        match League::builder(69420, "Creenis".to_string())
+            .players(&[
+                serenity::UserId(1),
+                serenity::UserId(2),
+                serenity::UserId(1),
+            ])
+            .team_size(3)
+            .build()
+        {
+            Err(LeagueConfigError::DuplicatePlayerError(id)) => assert_eq!(id.0, 1),
+            _ => panic!("player 1 was added twice"),
+        }
+    }
+
+    #[test]
+    fn zero_team_size_errors() {
+        match League::builder(69420, "Creenis".to_string())
+            .player(serenity::UserId(1))
+            .team_size(0)
+            .build()
+        {
+            Err(LeagueConfigError::ZeroTeamSizeError) => {}
+            _ => panic!("teams need at least one pick"),
+        }
+    }
+}
@@ -0,0 +1,461 @@
+use crate::{
+    DraftEvent, DraftPool, DraftType, Draftable, League, LeagueError, PickResult, PickSource,
+};
+use poise::serenity_prelude as serenity;
+use std::fmt;
+
+/// The errors from [`League::bulk_add_to_picks`] or [`League::import_state`], which changed nothing, with every item handed back
+/// in the order it was given.
+///
+/// Each error comes with the position of the item it belongs to, so a whole import can be fixed in one go. Errors about
+/// the League rather than any one item are given for the first.
+pub struct BulkRejected {
+    pub errors: Vec<(usize, LeagueError)>,
+    pub items: Vec<(serenity::UserId, Draftable)>,
+}
+
+// each error with the position of the item it belongs to
+type ItemErrors = Vec<(usize, LeagueError)>;
+
+impl fmt::Debug for BulkRejected {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BulkRejected")
+            .field("errors", &self.errors)
+            .field(
+                "items",
+                &self
+                    .items
+                    .iter()
+                    .map(|(user, item)| (user, item.name()))
+                    .collect::<Vec<_>>(),
+            )
+            .finish()
+    }
+}
+
+impl DraftPool {
+    /// Removes a copy of each named item from the pool and returns them in the order they were named, e.g. to take out
+    /// everything picked in a draft run elsewhere. Naming an item twice removes two copies.
+    ///
+    /// # Errors
+    ///
+    /// If any item is not in the pool, or is named more times than it has copies, returns every such name's position with a
+    /// [`LeagueError::DraftableNotFoundError`] holding the pool's closest names, and leaves the pool unchanged.
+    pub fn bulk_remove(
+        &mut self,
+        names: &[&str],
+    ) -> Result<Vec<Draftable>, Vec<(usize, LeagueError)>> {
+        let mut errors = Vec::new();
+        for (idx, name) in names.iter().enumerate() {
+            let earlier = names[..idx].iter().filter(|n| *n == name).count() as u32;
+            if self.copies(name) <= earlier {
+                errors.push((idx, crate::fuzzy::not_found(name, self.iter())));
+            }
+        }
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+        Ok(names.iter().filter_map(|name| self.take(name)).collect())
+    }
+}
+
+impl League {
+    /// Adds many Draftables directly to players' picks at once, e.g. to carry on a draft partly run elsewhere.
+    /// Either every item is added, in the order given, or none are.
+    ///
+    /// Items are checked as if each earlier one had already been added, so the same item cannot go to two seats unless the
+    /// [`DraftPool`] has copies for both. Like [`League::add_to_player_picks`], this does not take a turn.
+    ///
+    /// # Errors
+    ///
+    /// If any item could not be added, returns a [`BulkRejected`] with every item and the errors [`League::add_to_player_picks`]
+    /// would have given, each with its item's position. The League is left unchanged.
+    pub fn bulk_add_to_picks(
+        &mut self,
+        items: Vec<(serenity::UserId, Draftable)>,
+    ) -> Result<(), BulkRejected> {
+        let mut errors = Vec::new();
+        let mut checked: Vec<(u32, String)> = Vec::new();
+        for (idx, (user, item)) in items.iter().enumerate() {
+            let name = self.resolve_name(item.name()).to_string();
+            match self.seat_of(*user).and_then(|seat| {
+                self.require_available_after(seat, &name, &checked)
+                    .map(|_| seat)
+            }) {
+                Ok(seat) => checked.push((seat, name)),
+                Err(error) => errors.push((idx, error)),
+            }
+        }
+        if errors.is_empty() {
+            if let Some(shared) = &self.shared_pool {
+                let names: Vec<&str> = checked.iter().map(|(_, name)| name.as_str()).collect();
+                if let Err(taken) = shared.claim_all(&names, self.id) {
+                    errors.extend(
+                        taken
+                            .into_iter()
+                            .map(|idx| (idx, LeagueError::PickConflictError)),
+                    );
+                }
+            }
+        }
+        if !errors.is_empty() {
+            return Err(BulkRejected { errors, items });
+        }
+        for ((seat, _), (_, item)) in checked.into_iter().zip(items) {
+            self.add_checked(seat, item);
+        }
+        Ok(())
+    }
+    /// Carries on a draft begun on another platform by making its picks so far, in the order they were made, e.g. when a League
+    /// moves to Discord mid-draft. Either every pick is made or none are.
+    ///
+    /// Each pick goes through the draft order like [`League::lock`]: the user must own (or co-own) the seat on the clock for that
+    /// pick, and the item must be available. Turns of forfeited seats (see [`League::forfeit_player`]) are skipped, as they would be
+    /// during the draft, and get no imported pick. Imported picks are not timed, and queues and autopick only come into play once the
+    /// last one is in, for the seat then on the clock. Returns every pick made, imported first, in the same form as [`League::lock`].
+    /// Importing no picks changes nothing.
+    ///
+    /// # Errors
+    ///
+    /// If any pick could not be made, returns a [`BulkRejected`] with every pick and each error with its pick's position.
+    /// The League is left unchanged.
+    ///
+    /// If the League is not drafting, returns the error [`League::lock`] would, against the first pick.
+    ///
+    /// If a user does not own the seat on the clock for their pick, that pick gets a [`LeagueError::NotYourTurnError`].
+    ///
+    /// If there are more picks than are left in the draft, the extra ones get a [`LeagueError::InvalidPickNumberError`].
+    ///
+    /// Rochester drafts pick from packs that are not imported, and auctions and head-to-head drafts do not pick in turn,
+    /// so all of them return [`LeagueError::WrongDraftTypeError`] against the first pick.
+    ///
+    /// Otherwise, picks get the same errors as [`League::lock`].
+    pub fn import_state(
+        &mut self,
+        picks_in_order: Vec<(serenity::UserId, Draftable)>,
+    ) -> Result<Vec<PickResult>, BulkRejected> {
+        if picks_in_order.is_empty() {
+            return Ok(Vec::new());
+        }
+        let checked = match self.require_drafting() {
+            Ok(()) if self.draft_type == DraftType::Rochester || self.require_turns().is_err() => {
+                Err(vec![(0, LeagueError::WrongDraftTypeError)])
+            }
+            Ok(()) => self.check_import(&picks_in_order),
+            Err(error) => Err(vec![(0, error)]),
+        };
+        let checked = match checked {
+            Ok(checked) => checked,
+            Err(errors) => {
+                return Err(BulkRejected {
+                    errors,
+                    items: picks_in_order,
+                })
+            }
+        };
+        if let Some(shared) = &self.shared_pool {
+            let names: Vec<&str> = checked.iter().map(|(_, name)| name.as_str()).collect();
+            if let Err(taken) = shared.claim_all(&names, self.id) {
+                return Err(BulkRejected {
+                    errors: taken
+                        .into_iter()
+                        .map(|idx| (idx, LeagueError::PickConflictError))
+                        .collect(),
+                    items: picks_in_order,
+                });
+            }
+        }
+        let numbers: Vec<u32> = self
+            .turns_from(self.total_picks)
+            .map(|(number, _)| number)
+            .collect();
+        let mut picks = Vec::new();
+        let mut drafting = true;
+        for (number, (_, pick)) in numbers.into_iter().zip(picks_in_order) {
+            while self.total_picks < number {
+                self.record(DraftEvent::Skipped);
+                self.advance_seat();
+            }
+            // an imported pick took however long it took elsewhere
+            self.clock.stop();
+            let pick = self.resolve_item(pick);
+            picks.push(self.place_pick(pick, PickSource::Manual));
+            drafting = self.advance_seat();
+        }
+        if drafting {
+            picks = self.settle_private(picks).0;
+        }
+        crate::metrics::picks_made(picks.len());
+        Ok(picks)
+    }
+    // the seat and real name of every pick to import, or the errors of those that cannot be made
+    fn check_import(
+        &self,
+        picks: &[(serenity::UserId, Draftable)],
+    ) -> Result<Vec<(u32, String)>, ItemErrors> {
+        let mut errors = Vec::new();
+        let mut checked: Vec<(u32, String)> = Vec::new();
+        // each checked pick's seat and item, for the roster slots and salary cap
+        let mut placed: Vec<(u32, &Draftable)> = Vec::new();
+        let mut turns = self.turns_from(self.total_picks);
+        for (idx, (user, item)) in picks.iter().enumerate() {
+            let Some((number, seat)) = turns.next() else {
+                errors.push((idx, LeagueError::InvalidPickNumberError));
+                continue;
+            };
+            let name = self.resolve_name(item.name()).to_string();
+            let earlier: Vec<&Draftable> = placed
+                .iter()
+                .filter(|(s, _)| *s == seat)
+                .map(|(_, item)| *item)
+                .collect();
+            let checked_pick = if self.players[seat as usize].is_owner(*user) {
+                self.require_round_category(item, number)
+                    .and_then(|_| self.require_roster_room(seat, item, &earlier))
+                    .and_then(|_| self.require_available_after(seat, &name, &checked))
+            } else {
+                Err(LeagueError::NotYourTurnError)
+            };
+            match checked_pick {
+                Ok(()) => {
+                    checked.push((seat, name));
+                    placed.push((seat, item));
+                }
+                Err(error) => errors.push((idx, error)),
+            }
+        }
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+        Ok(checked)
+    }
+    // the number and seat of every turn from the given pick on, passing over the turns of forfeited seats the way
+    // settling the draft does
+    fn turns_from(&self, pick: u32) -> impl Iterator<Item = (u32, u32)> + '_ {
+        (pick..=self.final_pick)
+            .map(|number| (number, self.seat_at(number)))
+            .filter(|(_, seat)| !self.players[*seat as usize].forfeited)
+    }
+    // require_available, with the items already checked for the same batch counted as taken
+    fn require_available_after(
+        &self,
+        seat: u32,
+        name: &str,
+        checked: &[(u32, String)],
+    ) -> Result<(), LeagueError> {
+        let earlier: Vec<u32> = checked
+            .iter()
+            .filter(|(_, n)| n == name)
+            .map(|(s, _)| *s)
+            .collect();
+        if earlier.contains(&seat) {
+            return Err(LeagueError::DraftableInUseError);
+        }
+        self.require_available(seat, name)?;
+        let left = match &self.pool {
+            Some(pool) => pool.copies(name),
+            None => 1,
+        };
+        if earlier.len() as u32 >= left {
+            return Err(LeagueError::DraftableInUseError);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod bulk_tests {
+    use super::*;
+    use crate::test_support::{new_league, pokemon};
+    use crate::{DraftType, ForfeitPolicy, SharedPool};
+
+    fn league(id: u64) -> League {
+        let mut league = new_league(2, DraftType::Snake, 2);
+        league.id = id;
+        let pool = (1..=4).map(|n| pokemon(&format!("Pokemon {n}"))).collect();
+        league.set_pool(DraftPool::new(pool)).unwrap();
+        league
+    }
+
+    #[test]
+    fn bulk_adds_are_all_or_nothing() {
+        let mut league = league(69420);
+        let rejected = league
+            .bulk_add_to_picks(vec![
+                (serenity::UserId(1), pokemon("Pokemon 1")),
+                (serenity::UserId(3), pokemon("Pokemon 2")),
+                (serenity::UserId(2), pokemon("Pokemon 1")),
+                (serenity::UserId(2), pokemon("Pokemon 3")),
+            ])
+            .unwrap_err();
+        assert_eq!(rejected.items.len(), 4);
+        assert_eq!(rejected.items[1].1.name(), "Pokemon 2");
+        match rejected.errors.as_slice() {
+            [(1, LeagueError::PlayerNotFoundError), (2, LeagueError::DraftableInUseError)] => {}
+            _ => panic!("the second and third items cannot be added"),
+        }
+        assert_eq!(league.pool().unwrap().len(), 4);
+        assert!(league
+            .events()
+            .iter()
+            .all(|e| !matches!(e, DraftEvent::AddedToPicks { .. })));
+
+        league
+            .bulk_add_to_picks(vec![
+                (serenity::UserId(1), pokemon("Pokemon 1")),
+                (serenity::UserId(2), pokemon("Pokemon 3")),
+            ])
+            .unwrap();
+        assert_eq!(league.pool().unwrap().len(), 2);
+        assert_eq!(
+            league.get_player(serenity::UserId(2)).unwrap().picks[0].name(),
+            "Pokemon 3"
+        );
+    }
+
+    #[test]
+    fn bulk_adds_claim_shared_items_together() {
+        let shared = SharedPool::new();
+        let mut east = league(1);
+        east.share_pool(shared.clone()).unwrap();
+        let mut west = league(2);
+        west.share_pool(shared).unwrap();
+        west.add_to_player_picks(serenity::UserId(1), pokemon("Pokemon 2"))
+            .unwrap();
+        let rejected = east
+            .bulk_add_to_picks(vec![
+                (serenity::UserId(1), pokemon("Pokemon 1")),
+                (serenity::UserId(2), pokemon("Pokemon 2")),
+            ])
+            .unwrap_err();
+        match rejected.errors.as_slice() {
+            [(1, LeagueError::PickConflictError)] => {}
+            _ => panic!("west took Pokemon 2"),
+        }
+        assert_eq!(east.shared_pool().unwrap().taken_by("Pokemon 1"), None);
+    }
+
+    #[test]
+    fn imports_follow_the_draft_order() {
+        let mut league = league(69420);
+        league.activate().unwrap();
+        let rejected = league
+            .import_state(vec![
+                (serenity::UserId(1), pokemon("Pokemon 1")),
+                (serenity::UserId(1), pokemon("Pokemon 2")),
+                (serenity::UserId(2), pokemon("Pokemon 1")),
+            ])
+            .unwrap_err();
+        match rejected.errors.as_slice() {
+            [(1, LeagueError::NotYourTurnError), (2, LeagueError::DraftableInUseError)] => {}
+            _ => panic!("seat 2 picks second, and Pokemon 1 is gone"),
+        }
+        assert_eq!(league.pick_number(), 1);
+        assert_eq!(league.pool().unwrap().len(), 4);
+
+        league
+            .add_to_player_queue(serenity::UserId(1), pokemon("Pokemon 4"))
+            .unwrap();
+        let picks = league
+            .import_state(vec![
+                (serenity::UserId(1), pokemon("Pokemon 1")),
+                (serenity::UserId(2), pokemon("Pokemon 2")),
+                (serenity::UserId(2), pokemon("Pokemon 3")),
+            ])
+            .unwrap();
+        assert_eq!(
+            picks.len(),
+            4,
+            "seat 1 picks from its queue once the import is in"
+        );
+        assert_eq!(picks[3].source, PickSource::Queue);
+        assert_eq!(league.phase(), crate::LeaguePhase::PostDraft);
+        assert!(
+            league.pick_times.iter().all(|(pick, _)| *pick == 4),
+            "only the queued pick was timed"
+        );
+
+        let mut replayed = new_league(2, DraftType::Snake, 2);
+        replayed
+            .replay(league.events(), |name| Some(pokemon(name)))
+            .unwrap();
+        assert_eq!(replayed.events(), league.events());
+    }
+
+    #[test]
+    fn imports_stop_at_the_end_of_the_draft() {
+        let mut league = league(69420);
+        league.activate().unwrap();
+        let picks = (1..=4)
+            .map(|n| (serenity::UserId(1), pokemon(&format!("Pokemon {n}"))))
+            .chain(std::iter::once((serenity::UserId(2), pokemon("Pikachu"))))
+            .collect();
+        match league.import_state(picks).unwrap_err().errors.last() {
+            Some((4, LeagueError::InvalidPickNumberError)) => {}
+            _ => panic!("there are only four picks"),
+        }
+    }
+
+    #[test]
+    fn imports_pass_over_forfeited_seats() {
+        let mut league = new_league(3, DraftType::Snake, 2);
+        let pool = (1..=6).map(|n| pokemon(&format!("Pokemon {n}"))).collect();
+        league.set_pool(DraftPool::new(pool)).unwrap();
+        league.activate().unwrap();
+        league
+            .forfeit_player(serenity::UserId(2), ForfeitPolicy::Skip)
+            .unwrap();
+        let events = league.events().len();
+        assert!(league.import_state(Vec::new()).unwrap().is_empty());
+        assert_eq!(
+            league.events().len(),
+            events,
+            "importing nothing changes nothing"
+        );
+
+        let rejected = league
+            .import_state(vec![
+                (serenity::UserId(1), pokemon("Pokemon 1")),
+                (serenity::UserId(2), pokemon("Pokemon 2")),
+            ])
+            .unwrap_err();
+        match rejected.errors.as_slice() {
+            [(1, LeagueError::NotYourTurnError)] => {}
+            _ => panic!("seat 2 forfeited, so seat 3 picks second"),
+        }
+        // seats pick 1 2 3 3 2 1, and seat 2's turns are skipped
+        let picks = league
+            .import_state(vec![
+                (serenity::UserId(1), pokemon("Pokemon 1")),
+                (serenity::UserId(3), pokemon("Pokemon 2")),
+                (serenity::UserId(3), pokemon("Pokemon 3")),
+                (serenity::UserId(1), pokemon("Pokemon 4")),
+            ])
+            .unwrap();
+        assert_eq!(picks.len(), 4);
+        assert_eq!(league.phase(), crate::LeaguePhase::PostDraft);
+        let skips = league.events()[events..]
+            .iter()
+            .filter(|e| **e == DraftEvent::Skipped)
+            .count();
+        assert_eq!(skips, 2);
+    }
+
+    #[test]
+    fn pools_remove_in_bulk() {
+        let mut pool =
+            DraftPool::with_copies(vec![(pokemon("Pikachu"), 2), (pokemon("Raichu"), 1)]);
+        match pool.bulk_remove(&["Pikachu", "Raichu", "Raichu", "Pichu"]) {
+            Err(errors) => match errors.as_slice() {
+                [(2, LeagueError::DraftableNotFoundError(_)), (3, LeagueError::DraftableNotFoundError(_))] =>
+                    {}
+                _ => panic!("there is one Raichu and no Pichu"),
+            },
+            Ok(_) => panic!("nothing should be removed"),
+        }
+        assert_eq!(pool.copies("Raichu"), 1);
+        let removed = pool.bulk_remove(&["Pikachu", "Raichu", "Pikachu"]).unwrap();
+        assert_eq!(removed.len(), 3);
+        assert!(pool.is_empty());
+    }
+}
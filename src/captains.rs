@@ -0,0 +1,157 @@
+use crate::{DraftItem, Draftable, League, LeagueError};
+use poise::serenity_prelude as serenity;
+
+/// A Discord user to be drafted, for captains drafts where the seats are team captains picking their players,
+/// e.g. for an in-house 5v5.
+///
+/// Its name is the user's mention (`<@id>`), which is unique, renders as the user in Discord messages and embeds, and is
+/// what [`UserDraftItem::user_of`] reads the user back from. Show [`UserDraftItem::display_name`] where mentions do not render,
+/// e.g. in select menus.
+pub struct UserDraftItem {
+    id: serenity::UserId,
+    mention: String,
+    display_name: String,
+    position: Option<String>,
+    rating: Option<u32>,
+}
+
+impl UserDraftItem {
+    /// Creates an item for a user, shown as display_name where mentions do not render.
+    pub fn new(id: serenity::UserId, display_name: impl Into<String>) -> UserDraftItem {
+        UserDraftItem {
+            id,
+            mention: format!("<@{}>", id.0),
+            display_name: display_name.into(),
+            position: None,
+            rating: None,
+        }
+    }
+    /// Sets the position the user plays (tank, support...), which is the item's category.
+    pub fn position(mut self, position: impl Into<String>) -> UserDraftItem {
+        self.position = Some(position.into());
+        self
+    }
+    /// Sets a skill rating for the user, which is the item's cost, e.g. to cap each team's total with a budget.
+    pub fn rating(mut self, rating: u32) -> UserDraftItem {
+        self.rating = Some(rating);
+        self
+    }
+    /// Returns the user.
+    pub fn id(&self) -> serenity::UserId {
+        self.id
+    }
+    /// Returns the name to show for the user where mentions do not render.
+    pub fn display_name(&self) -> &str {
+        &self.display_name
+    }
+    /// Returns the user an item drafted as a [`UserDraftItem`] stands for, read from its name, or None for any other item.
+    pub fn user_of(item: &Draftable) -> Option<serenity::UserId> {
+        let id = item.name().strip_prefix("<@")?.strip_suffix('>')?;
+        id.trim_start_matches('!')
+            .parse()
+            .ok()
+            .map(serenity::UserId)
+    }
+}
+
+impl DraftItem for UserDraftItem {
+    fn name(&self) -> &str {
+        &self.mention
+    }
+    fn category(&self) -> Option<&str> {
+        self.position.as_deref()
+    }
+    fn cost(&self) -> Option<u32> {
+        self.rating
+    }
+}
+
+impl League {
+    /// Returns the users a captain has drafted as [`UserDraftItem`]s, in the order they were picked, as their roster stands now.
+    /// Any other items the seat holds are left out.
+    ///
+    /// # Errors
+    ///
+    /// If the user does not own a seat, returns [`LeagueError::PlayerNotFoundError`].
+    pub fn drafted_users(
+        &self,
+        captain: serenity::UserId,
+    ) -> Result<Vec<serenity::UserId>, LeagueError> {
+        let player = self
+            .get_player(captain)
+            .ok_or(LeagueError::PlayerNotFoundError)?;
+        Ok(player
+            .picks
+            .iter()
+            .filter_map(UserDraftItem::user_of)
+            .collect())
+    }
+    /// Returns every seat's drafted users like [`League::drafted_users`], in seat order, by their seat's original owner,
+    /// e.g. to give each team its Discord role or voice channel once the draft is over.
+    pub fn user_rosters(&self) -> Vec<(serenity::UserId, Vec<serenity::UserId>)> {
+        self.players
+            .iter()
+            .map(|p| {
+                (
+                    p.id,
+                    p.picks.iter().filter_map(UserDraftItem::user_of).collect(),
+                )
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod captains_tests {
+    use super::*;
+    use crate::test_support::new_league;
+    use crate::{DraftPool, DraftType};
+
+    #[test]
+    fn captains_draft_users_onto_teams() {
+        let mut league = new_league(2, DraftType::Snake, 2);
+        let pool = [
+            (10, "Creenis", "Tank"),
+            (11, "Ash", "Support"),
+            (12, "Misty", "DPS"),
+        ]
+        .into_iter()
+        .map(|(id, name, position)| {
+            Box::new(UserDraftItem::new(serenity::UserId(id), name).position(position)) as Draftable
+        })
+        .collect();
+        league.set_pool(DraftPool::new(pool)).unwrap();
+        let item = league.pool().unwrap().get("<@11>").unwrap();
+        assert_eq!(item.category(), Some("Support"));
+        league.activate().unwrap();
+        league
+            .lock(Box::new(UserDraftItem::new(serenity::UserId(11), "Ash")))
+            .unwrap();
+        league
+            .lock(Box::new(UserDraftItem::new(
+                serenity::UserId(10),
+                "Creenis",
+            )))
+            .unwrap();
+        league
+            .lock(Box::new(UserDraftItem::new(serenity::UserId(12), "Misty")))
+            .unwrap();
+        assert_eq!(
+            league.drafted_users(serenity::UserId(2)).unwrap(),
+            vec![serenity::UserId(10), serenity::UserId(12)]
+        );
+        assert_eq!(
+            UserDraftItem::user_of(&(Box::new("Pikachu") as Draftable)),
+            None
+        );
+        let rosters = league.user_rosters();
+        assert_eq!(
+            rosters[0],
+            (serenity::UserId(1), vec![serenity::UserId(11)])
+        );
+        match league.drafted_users(serenity::UserId(3)) {
+            Err(LeagueError::PlayerNotFoundError) => {}
+            _ => panic!("user 3 is not a captain"),
+        }
+    }
+}
@@ -0,0 +1,144 @@
+use chrono::{DateTime, Duration, Utc};
+
+/// Tracks how long the current seat in a [`League`](crate::League) has been on the clock.
+///
+/// Time spent while the clock is paused does not count against the player, so a paused draft
+/// can be resumed without anyone being skipped for time they could not use.
+pub struct PickClock {
+    pub(crate) limit: Option<Duration>,
+    // start of the current running segment, None while stopped or paused
+    pub(crate) started: Option<DateTime<Utc>>,
+    // time accumulated in earlier segments of the same pick
+    pub(crate) banked: Duration,
+    pub(crate) paused: bool,
+    // when the current pick started, pauses and all
+    pub(crate) since: Option<DateTime<Utc>>,
+}
+
+impl PickClock {
+    /// Creates a stopped clock. A limit of None means picks are never timed out.
+    pub fn new(limit: Option<Duration>) -> PickClock {
+        PickClock {
+            limit,
+            started: None,
+            banked: Duration::zero(),
+            paused: false,
+            since: None,
+        }
+    }
+    /// Restarts the clock from zero for a new pick.
+    pub fn start(&mut self, now: DateTime<Utc>) {
+        self.banked = Duration::zero();
+        self.paused = false;
+        self.started = Some(now);
+        self.since = Some(now);
+    }
+    /// Stops the clock entirely, e.g. when the draft ends.
+    pub fn stop(&mut self) {
+        self.banked = Duration::zero();
+        self.paused = false;
+        self.started = None;
+        self.since = None;
+    }
+    /// Freezes the clock, banking the time used so far. Does nothing if the clock is not running.
+    pub fn pause(&mut self, now: DateTime<Utc>) {
+        if let Some(started) = self.started.take() {
+            // a pause dated before the segment started (e.g. a stale heartbeat) banks nothing
+            if now > started {
+                self.banked += now - started;
+            }
+            self.paused = true;
+        }
+    }
+    /// Unfreezes a paused clock. Does nothing if the clock is not paused.
+    pub fn resume(&mut self, now: DateTime<Utc>) {
+        if self.paused {
+            self.paused = false;
+            self.started = Some(now);
+        }
+    }
+    /// Returns true if the clock is counting down.
+    pub fn running(&self) -> bool {
+        self.started.is_some()
+    }
+    /// Returns true if the clock is frozen mid-pick.
+    pub fn paused(&self) -> bool {
+        self.paused
+    }
+    /// Returns when the current pick started, including any time since spent paused, or None while the clock is stopped.
+    pub fn since(&self) -> Option<DateTime<Utc>> {
+        self.since
+    }
+    /// Returns the time limit for each pick, if there is one.
+    pub fn limit(&self) -> Option<Duration> {
+        self.limit
+    }
+    /// Sets the time limit for each pick. Takes effect on the pick currently on the clock.
+    pub fn set_limit(&mut self, limit: Option<Duration>) {
+        self.limit = limit;
+    }
+    /// Returns how much time the current pick has used, not counting paused time.
+    pub fn elapsed(&self, now: DateTime<Utc>) -> Duration {
+        match self.started {
+            Some(started) if now > started => self.banked + (now - started),
+            _ => self.banked,
+        }
+    }
+    /// Returns how much time the current pick has left, or None if picks are untimed.
+    pub fn remaining(&self, now: DateTime<Utc>) -> Option<Duration> {
+        let limit = self.limit?;
+        let remaining = limit - self.elapsed(now);
+        if remaining < Duration::zero() {
+            return Some(Duration::zero());
+        }
+        Some(remaining)
+    }
+    /// Returns true if the current pick has run out of time. Paused and stopped clocks never expire.
+    pub fn expired(&self, now: DateTime<Utc>) -> bool {
+        if !self.running() {
+            return false;
+        }
+        match self.limit {
+            Some(limit) => self.elapsed(now) >= limit,
+            None => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod clock_tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn at(seconds: i64) -> DateTime<Utc> {
+        Utc.timestamp_opt(seconds, 0).unwrap()
+    }
+
+    #[test]
+    fn paused_time_does_not_count() {
+        let mut clock = PickClock::new(Some(Duration::seconds(60)));
+        clock.start(at(0));
+        clock.pause(at(20));
+        assert_eq!(clock.elapsed(at(500)), Duration::seconds(20));
+        assert!(!clock.expired(at(500)));
+        clock.resume(at(500));
+        assert_eq!(clock.remaining(at(510)), Some(Duration::seconds(30)));
+        assert!(clock.expired(at(540)));
+    }
+
+    #[test]
+    fn untimed_clock_never_expires() {
+        let mut clock = PickClock::new(None);
+        clock.start(at(0));
+        assert_eq!(clock.remaining(at(1_000_000)), None);
+        assert!(!clock.expired(at(1_000_000)));
+    }
+
+    #[test]
+    fn pause_before_segment_start_banks_nothing() {
+        let mut clock = PickClock::new(Some(Duration::seconds(60)));
+        clock.start(at(100));
+        clock.pause(at(50));
+        assert_eq!(clock.elapsed(at(200)), Duration::zero());
+    }
+}
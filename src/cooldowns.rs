@@ -0,0 +1,211 @@
+use crate::{League, LeagueError};
+use chrono::{DateTime, Duration, Utc};
+use poise::serenity_prelude as serenity;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+
+/// The things a [`League`] can rate limit, for each user on their own, with [`League::set_rate_limit`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum RateLimitedAction {
+    /// Adding to, removing from or clearing a queue, counted against the queue's owner.
+    QueueEdit,
+    /// A [`League::trade`], counted against user1, who is taken to have asked for it.
+    Trade,
+    /// A [`League::waiver`].
+    Waiver,
+}
+
+/// How often a user may do a [`RateLimitedAction`]: at most max times in any stretch of period, e.g. 5 queue edits a minute.
+/// A max of 0 refuses the action outright.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimit {
+    /// How many times a user may do the action within a period.
+    pub max: u32,
+    /// How far back the limit looks.
+    pub period: Duration,
+}
+
+impl RateLimit {
+    /// Creates a limit of max times per period.
+    pub fn new(max: u32, period: Duration) -> RateLimit {
+        RateLimit { max, period }
+    }
+}
+
+// each action's limit, and when each user did each limited action within its period
+#[derive(Default)]
+pub(crate) struct RateLimits {
+    pub(crate) limits: HashMap<RateLimitedAction, RateLimit>,
+    pub(crate) used: HashMap<(serenity::UserId, RateLimitedAction), VecDeque<DateTime<Utc>>>,
+}
+
+impl League {
+    /// Limits how often each user may do an action, or lifts the limit with None, and returns the limit it had. Actions past the
+    /// limit are refused with [`LeagueError::RateLimitedError`], so bots can shrug off spam without middleware of their own.
+    /// Nothing is limited by default.
+    ///
+    /// Only actions that go through count towards the limit. Events applied with [`League::replay`] are never limited.
+    pub fn set_rate_limit(
+        &mut self,
+        action: RateLimitedAction,
+        limit: Option<RateLimit>,
+    ) -> Option<RateLimit> {
+        match limit {
+            Some(limit) => self.rate_limits.limits.insert(action, limit),
+            None => self.rate_limits.limits.remove(&action),
+        }
+    }
+    /// Returns how often each user may do an action, if it is limited.
+    pub fn rate_limit(&self, action: RateLimitedAction) -> Option<RateLimit> {
+        self.rate_limits.limits.get(&action).copied()
+    }
+    /// Returns how long a user has to wait before they may do an action again, or None if they may do it now.
+    pub fn retry_after(
+        &self,
+        id: serenity::UserId,
+        action: RateLimitedAction,
+        now: DateTime<Utc>,
+    ) -> Option<Duration> {
+        let limit = self.rate_limits.limits.get(&action)?;
+        if limit.max == 0 {
+            return Some(limit.period);
+        }
+        let used = self.rate_limits.used.get(&(id, action))?;
+        let recent: Vec<&DateTime<Utc>> =
+            used.iter().filter(|at| now - **at < limit.period).collect();
+        if recent.len() < limit.max as usize {
+            return None;
+        }
+        // the user may go again once enough of their recent uses have aged out of the period
+        let freed = recent[recent.len() - limit.max as usize];
+        // periods too long to add to a date never free up
+        let wait = freed
+            .checked_add_signed(limit.period)
+            .map_or(limit.period, |at| at - now);
+        Some(wait)
+    }
+    // refuses an action the user has done too often lately
+    pub(crate) fn require_rate(
+        &self,
+        id: serenity::UserId,
+        action: RateLimitedAction,
+        now: DateTime<Utc>,
+    ) -> Result<(), LeagueError> {
+        match self.retry_after(id, action, now) {
+            Some(wait) => Err(LeagueError::RateLimitedError(wait)),
+            None => Ok(()),
+        }
+    }
+    // counts an action that went through towards the user's limit
+    pub(crate) fn count_use(
+        &mut self,
+        id: serenity::UserId,
+        action: RateLimitedAction,
+        now: DateTime<Utc>,
+    ) {
+        let Some(limit) = self.rate_limits.limits.get(&action) else {
+            return;
+        };
+        let used = self.rate_limits.used.entry((id, action)).or_default();
+        used.retain(|at| now - *at < limit.period);
+        used.push_back(now);
+    }
+}
+
+#[cfg(test)]
+mod cooldowns_tests {
+    use super::*;
+    use crate::test_support::new_league;
+    use crate::DraftType;
+
+    fn league() -> League {
+        new_league(2, DraftType::Snake, 2)
+    }
+
+    #[test]
+    fn spammed_queue_edits_are_refused() {
+        let mut league = league();
+        league.set_rate_limit(
+            RateLimitedAction::QueueEdit,
+            Some(RateLimit::new(2, Duration::minutes(1))),
+        );
+        let user = serenity::UserId(1);
+        league
+            .add_to_player_queue(user, Box::new("Pikachu"))
+            .unwrap();
+        league
+            .add_to_player_queue(user, Box::new("Squirtle"))
+            .unwrap();
+        match league.delete_from_player_queue(user, "Pikachu") {
+            Err(LeagueError::RateLimitedError(wait)) => {
+                assert!(wait > Duration::seconds(55) && wait <= Duration::minutes(1))
+            }
+            _ => panic!("user 1 has edited their queue twice this minute"),
+        }
+        league
+            .add_to_player_queue(serenity::UserId(2), Box::new("Pikachu"))
+            .unwrap();
+
+        let now = Utc::now();
+        assert!(league
+            .retry_after(user, RateLimitedAction::QueueEdit, now)
+            .is_some());
+        assert_eq!(
+            league.retry_after(
+                user,
+                RateLimitedAction::QueueEdit,
+                now + Duration::minutes(1)
+            ),
+            None
+        );
+        assert_eq!(
+            league.retry_after(user, RateLimitedAction::Trade, now),
+            None,
+            "trades are not limited"
+        );
+
+        let mut replayed = self::league();
+        replayed.set_rate_limit(
+            RateLimitedAction::QueueEdit,
+            Some(RateLimit::new(1, Duration::minutes(1))),
+        );
+        replayed
+            .replay(league.events(), |name| Some(Box::new(name.to_string())))
+            .unwrap();
+        assert_eq!(replayed.player_queue(user).unwrap().len(), 2);
+        assert_eq!(
+            replayed.rate_limit(RateLimitedAction::QueueEdit),
+            Some(RateLimit::new(1, Duration::minutes(1)))
+        );
+    }
+
+    #[test]
+    fn zero_limits_refuse_outright() {
+        let mut league = new_league(2, DraftType::Snake, 2);
+        league.set_rate_limit(
+            RateLimitedAction::QueueEdit,
+            Some(RateLimit::new(0, Duration::minutes(1))),
+        );
+        for _ in 0..2 {
+            match league.add_to_player_queue(serenity::UserId(1), Box::new("Pikachu")) {
+                Err(LeagueError::RateLimitedError(wait)) => assert_eq!(wait, Duration::minutes(1)),
+                _ => panic!("no queue edits are allowed"),
+            }
+        }
+
+        league.set_rate_limit(
+            RateLimitedAction::QueueEdit,
+            Some(RateLimit::new(1, Duration::minutes(1))),
+        );
+        let snapshot = league.snapshot();
+        assert!(snapshot.contains(r#"["QueueEdit",1,60]"#));
+        let corrupt = snapshot.replace(
+            r#"["QueueEdit",1,60]"#,
+            &format!(r#"["QueueEdit",1,{}]"#, i64::MAX),
+        );
+        match League::restore(&corrupt, |name| Some(Box::new(name.to_string()))) {
+            Err(crate::SnapshotError::MalformedSnapshotError(_)) => {}
+            _ => panic!("no period lasts that long"),
+        }
+    }
+}
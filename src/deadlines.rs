@@ -0,0 +1,245 @@
+use crate::{ActivePlayer, DraftEvent, League, LeagueError, LeaguePhase, PickResult, PickSource};
+use chrono::{DateTime, Duration, Utc};
+use std::collections::HashMap;
+
+/// When a [`League`]'s draft is scheduled to start, any pick-specific deadlines, and how long the whole draft may run.
+pub(crate) struct Deadlines {
+    pub(crate) start: Option<DateTime<Utc>>,
+    // k: overall pick number, starting at 1
+    pub(crate) picks: HashMap<u32, DateTime<Utc>>,
+    pub(crate) max_duration: Option<Duration>,
+}
+
+impl Deadlines {
+    pub(crate) fn new() -> Deadlines {
+        Deadlines {
+            start: None,
+            picks: HashMap::new(),
+            max_duration: None,
+        }
+    }
+}
+
+impl League {
+    /// Schedules the draft to start at the given time, or clears the schedule with None. See [`League::start_if_due`].
+    ///
+    /// # Errors
+    ///
+    /// If the draft has already started, returns [`LeagueError::LeagueActiveError`], or [`LeagueError::WrongPhaseError`] if it is over.
+    pub fn set_start_time(&mut self, start: Option<DateTime<Utc>>) -> Result<(), LeagueError> {
+        self.require_pre_draft()?;
+        self.deadlines.start = start;
+        Ok(())
+    }
+    /// Returns when the draft is scheduled to start, if it is.
+    pub fn start_time(&self) -> Option<DateTime<Utc>> {
+        self.deadlines.start
+    }
+    /// Sets a fixed deadline for one pick, e.g. so the first round of a slow draft closes by the weekend.
+    ///
+    /// Pick numbers count every pick in the draft, starting at 1. A pick's deadline applies alongside the
+    /// League's pick time limit - whichever comes first is the one that counts.
+    ///
+    /// # Errors
+    ///
+    /// If the pick does not exist or has already been made, returns [`LeagueError::InvalidPickNumberError`].
+    pub fn set_pick_deadline(&mut self, pick: u32, at: DateTime<Utc>) -> Result<(), LeagueError> {
+        if pick <= self.total_picks || pick > self.final_pick + 1 {
+            return Err(LeagueError::InvalidPickNumberError);
+        }
+        self.deadlines.picks.insert(pick, at);
+        Ok(())
+    }
+    /// Removes a pick's fixed deadline, returning it if there was one.
+    pub fn clear_pick_deadline(&mut self, pick: u32) -> Option<DateTime<Utc>> {
+        self.deadlines.picks.remove(&pick)
+    }
+    /// Caps how long the draft may run from its start, pauses included, or lifts the cap with None (the default).
+    /// Once it is up, [`League::finish_if_expired`] autopicks the rest of the draft, so abandoned drafts do not stay active forever.
+    pub fn set_max_duration(&mut self, max: Option<Duration>) {
+        self.deadlines.max_duration = max;
+    }
+    /// Returns how long the draft may run from its start, if it is capped.
+    pub fn max_duration(&self) -> Option<Duration> {
+        self.deadlines.max_duration
+    }
+    /// Returns when the draft's time is up (see [`League::set_max_duration`]), or None if it is uncapped or has not started.
+    pub fn draft_deadline(&self) -> Option<DateTime<Utc>> {
+        Some(self.started_at? + self.deadlines.max_duration?)
+    }
+    /// Finishes the draft if its time is up (see [`League::set_max_duration`]), and returns every pick made to finish it.
+    /// Call this from the same background task as [`League::start_if_due`].
+    ///
+    /// A paused draft is resumed first. Every turn left is picked by the League's [`AutopickStrategy`](crate::AutopickStrategy),
+    /// from the seat's queue first, and turns with nothing to autopick are skipped. Drafts that are not picked in turn - auctions
+    /// and the head-to-head drafts - simply end, with whatever is left undrafted.
+    ///
+    /// Returns no picks, and changes nothing, if the draft is not underway or its time is not up.
+    pub fn finish_if_expired(&mut self, now: DateTime<Utc>) -> Vec<PickResult> {
+        match self.draft_deadline() {
+            Some(deadline) if deadline <= now && self.phase.is_drafting() => {}
+            _ => return Vec::new(),
+        }
+        self.resume(now);
+        if self.require_turns().is_err() {
+            self.set_phase(LeaguePhase::PostDraft)
+                .expect("a drafting League can always finish");
+            return Vec::new();
+        }
+        let mut picks = Vec::new();
+        while self.phase == LeaguePhase::Drafting {
+            let pick = match self.queued_pick() {
+                Some(pick) => Some((pick, PickSource::Queue)),
+                None => self
+                    .strategy_pick(None)
+                    .map(|pick| (pick, PickSource::Autopick)),
+            };
+            (picks, _) = match pick {
+                Some((pick, source)) => self.lock_private(pick, source, picks),
+                None => {
+                    self.record(DraftEvent::Skipped);
+                    self.advance_private(picks)
+                }
+            };
+        }
+        crate::metrics::picks_made(picks.len());
+        picks
+    }
+    /// Returns the next moment a background task should act on: the scheduled start or the ready check's timeout before the draft,
+    /// or the deadline of the pick on the clock during it, or the draft's own deadline (see [`League::set_max_duration`]) if that comes first.
+    ///
+    /// Returns None if there is nothing scheduled, picks are untimed and the draft uncapped, or the draft is over.
+    /// A paused draft only has its own deadline.
+    pub fn next_deadline(&self, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        match self.phase {
+            phase if phase.is_pre_draft() => {
+                match (self.deadlines.start, self.lobby.check.timeout) {
+                    (Some(start), Some(timeout)) => Some(start.min(timeout)),
+                    (start, timeout) => start.or(timeout),
+                }
+            }
+            LeaguePhase::Drafting => match (self.pick_deadline(now), self.draft_deadline()) {
+                (Some(pick), Some(draft)) => Some(pick.min(draft)),
+                (pick, draft) => pick.or(draft),
+            },
+            LeaguePhase::Paused => self.draft_deadline(),
+            _ => None,
+        }
+    }
+    // the deadline of the pick on the clock: its fixed deadline or the pick clock running out, whichever comes first
+    fn pick_deadline(&self, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        let fixed = self.deadlines.picks.get(&(self.total_picks + 1)).copied();
+        let timed = self.clock.remaining(now).map(|remaining| now + remaining);
+        match (fixed, timed) {
+            (Some(fixed), Some(timed)) => Some(fixed.min(timed)),
+            (fixed, timed) => fixed.or(timed),
+        }
+    }
+    /// Returns the players who have missed their deadline, so they can be pinged or skipped.
+    ///
+    /// Only one seat is on the clock at a time, so this holds at most one player, and it is always empty unless the draft is underway and unpaused.
+    pub fn overdue_players(&self, now: DateTime<Utc>) -> Vec<&ActivePlayer> {
+        if self.phase != LeaguePhase::Drafting {
+            return Vec::new();
+        }
+        match self.pick_deadline(now) {
+            Some(deadline) if deadline <= now => vec![&self.players[self.current_seat as usize]],
+            _ => Vec::new(),
+        }
+    }
+    /// Starts the draft if its scheduled start time has arrived. Call this from a background task.
+    ///
+    /// # Returns
+    ///
+    /// Returns true if this call started the draft, so the bot knows to announce the first pick.
+    pub fn start_if_due(&mut self, now: DateTime<Utc>) -> bool {
+        match self.deadlines.start {
+            Some(start) if start <= now && self.phase.is_pre_draft() => {
+                self.deadlines.start = None;
+                self.activate().is_ok()
+            }
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod deadline_tests {
+    use super::*;
+    use crate::test_support::new_league;
+    use crate::DraftType;
+    use chrono::{Duration, TimeZone};
+    use poise::serenity_prelude as serenity;
+
+    fn at(secs: i64) -> DateTime<Utc> {
+        Utc.timestamp_opt(1_700_000_000 + secs, 0).unwrap()
+    }
+
+    fn league() -> League {
+        new_league(2, DraftType::Snake, 2)
+    }
+
+    #[test]
+    fn scheduled_start_is_the_first_deadline() {
+        let mut league = league();
+        league.set_start_time(Some(at(60))).unwrap();
+        assert_eq!(league.next_deadline(at(0)), Some(at(60)));
+        assert!(!league.start_if_due(at(59)));
+        assert!(league.start_if_due(at(60)));
+        assert!(league.active());
+        assert!(!league.start_if_due(at(61)));
+    }
+
+    #[test]
+    fn earliest_pick_deadline_wins() {
+        let mut league = league();
+        league.set_pick_deadline(1, at(30)).unwrap();
+        league.activate().unwrap();
+        league.set_pick_time_limit(Some(Duration::hours(1)));
+        let now = Utc::now();
+        assert_eq!(league.next_deadline(now), Some(at(30)));
+        assert_eq!(league.overdue_players(now)[0].id, serenity::UserId(1));
+        league.clear_pick_deadline(1);
+        assert!(league.next_deadline(now).unwrap() > now);
+        assert!(league.overdue_players(now).is_empty());
+        match league.set_pick_deadline(5, at(0)) {
+            Err(LeagueError::InvalidPickNumberError) => {}
+            _ => panic!("there are only 4 picks"),
+        }
+    }
+
+    #[test]
+    fn expired_drafts_autopick_to_the_end() {
+        let mut league = league();
+        let pool = ["Pikachu", "Bulbasaur", "Charmander"]
+            .iter()
+            .map(|name| Box::new(*name) as crate::Draftable)
+            .collect();
+        league.set_pool(crate::DraftPool::new(pool)).unwrap();
+        league.set_max_duration(Some(Duration::hours(1)));
+        assert_eq!(league.draft_deadline(), None, "the draft has not started");
+        league.activate().unwrap();
+        league
+            .add_to_player_queue(serenity::UserId(2), Box::new("Charmander"))
+            .unwrap();
+        league.lock(Box::new("Bulbasaur")).unwrap();
+        // seat 1 picks its queued item straight away, and is back on the clock with nothing queued
+        let now = Utc::now();
+        league.pause(now);
+        let deadline = league.draft_deadline().unwrap();
+        assert_eq!(league.next_deadline(now), Some(deadline));
+        assert!(league.finish_if_expired(now).is_empty());
+        assert!(league.paused());
+
+        let picks = league.finish_if_expired(deadline);
+        let picked: Vec<(&str, PickSource)> =
+            picks.iter().map(|p| (p.item.as_str(), p.source)).collect();
+        assert_eq!(picked, vec![("Pikachu", PickSource::Autopick)]);
+        assert_eq!(league.phase(), LeaguePhase::PostDraft);
+        assert_eq!(
+            league.draft_summary().unwrap().seats[0].skipped,
+            1,
+            "nothing was left for seat 0"
+        );
+    }
+}
@@ -0,0 +1,272 @@
+use crate::schedule::round_robin;
+use crate::{ActivePlayer, Draftable, League, LeagueError, Matchup, Standing};
+use poise::serenity_prelude as serenity;
+
+impl ActivePlayer {
+    /// Returns the name of the seat's division, if it is in one (see [`League::set_division`]).
+    pub fn division(&self) -> Option<&str> {
+        self.division.as_deref()
+    }
+}
+
+impl League {
+    /// Puts a player's seat in a named division (or conference), creating the division if no other seat is in it yet.
+    /// Division names are matched ignoring case.
+    ///
+    /// # Errors
+    ///
+    /// If the player is not in the league, returns [`LeagueError::PlayerNotFoundError`].
+    ///
+    /// If the division name is empty, returns [`LeagueError::InvalidDivisionNameError`].
+    pub fn set_division(
+        &mut self,
+        id: serenity::UserId,
+        division: &str,
+    ) -> Result<(), LeagueError> {
+        let seat = self.seat_of(id)? as usize;
+        if division.trim().is_empty() {
+            return Err(LeagueError::InvalidDivisionNameError);
+        }
+        // seats joining an existing division take on its spelling
+        let name = self
+            .divisions()
+            .into_iter()
+            .find(|d| d.eq_ignore_ascii_case(division))
+            .unwrap_or(division)
+            .to_string();
+        self.players[seat].division = Some(name);
+        Ok(())
+    }
+    /// Takes a player's seat out of its division, returning the division's name if it was in one.
+    ///
+    /// # Errors
+    ///
+    /// If the player is not in the league, returns [`LeagueError::PlayerNotFoundError`].
+    pub fn clear_division(&mut self, id: serenity::UserId) -> Result<Option<String>, LeagueError> {
+        let Some(player) = self.get_player_mut(id) else {
+            return Err(LeagueError::PlayerNotFoundError);
+        };
+        Ok(player.division.take())
+    }
+    /// Returns the name of every division, in the order of the first seat in each.
+    pub fn divisions(&self) -> Vec<&str> {
+        let mut divisions: Vec<&str> = Vec::new();
+        for division in self.players.iter().filter_map(|p| p.division()) {
+            if !divisions.contains(&division) {
+                divisions.push(division);
+            }
+        }
+        divisions
+    }
+    /// Returns the seats in a division, in draft order.
+    ///
+    /// # Errors
+    ///
+    /// If no seat is in the division, returns [`LeagueError::DivisionNotFoundError`].
+    pub fn division_members(&self, division: &str) -> Result<Vec<&ActivePlayer>, LeagueError> {
+        let members: Vec<&ActivePlayer> = self
+            .players
+            .iter()
+            .filter(|p| matches!(p.division(), Some(d) if d.eq_ignore_ascii_case(division)))
+            .collect();
+        if members.is_empty() {
+            return Err(LeagueError::DivisionNotFoundError);
+        }
+        Ok(members)
+    }
+    /// Returns every pick made by the seats in a division.
+    ///
+    /// # Errors
+    ///
+    /// If no seat is in the division, returns [`LeagueError::DivisionNotFoundError`].
+    pub fn division_picks(&self, division: &str) -> Result<Vec<&Draftable>, LeagueError> {
+        Ok(self
+            .division_members(division)?
+            .into_iter()
+            .flat_map(|p| p.picks.iter())
+            .collect())
+    }
+    /// Returns the [`Standing`] of every seat in a division, best first, counting all of their results.
+    ///
+    /// # Errors
+    ///
+    /// If no seat is in the division, returns [`LeagueError::DivisionNotFoundError`].
+    pub fn division_standings(&self, division: &str) -> Result<Vec<Standing>, LeagueError> {
+        let members: Vec<serenity::UserId> = self
+            .division_members(division)?
+            .iter()
+            .map(|p| p.id)
+            .collect();
+        Ok(self
+            .standings()
+            .into_iter()
+            .filter(|s| members.contains(&s.user))
+            .collect())
+    }
+    /// Generates a season schedule over the given number of weeks weighted towards division rivals, replacing any schedule the League had, and returns it.
+    ///
+    /// The season runs in blocks: a full round robin of the League, then a round robin within each division, so division rivals meet
+    /// twice for every once against the rest of the League. Seats without a division are scheduled together as if they were one.
+    ///
+    /// # Errors
+    ///
+    /// If the League has fewer than two players, returns [`LeagueError::NotEnoughPlayersError`].
+    pub fn generate_divisional_schedule(&mut self, weeks: u32) -> Result<&[Matchup], LeagueError> {
+        if self.players.len() < 2 {
+            return Err(LeagueError::NotEnoughPlayersError);
+        }
+        let users: Vec<serenity::UserId> = self.players.iter().map(|p| p.id).collect();
+        let mut groups: Vec<Vec<serenity::UserId>> = Vec::new();
+        for division in self.divisions() {
+            let members = self
+                .players
+                .iter()
+                .filter(|p| p.division() == Some(division));
+            groups.push(members.map(|p| p.id).collect());
+        }
+        groups.push(
+            self.players
+                .iter()
+                .filter(|p| p.division.is_none())
+                .map(|p| p.id)
+                .collect(),
+        );
+        // a round robin takes one round fewer than it has seats, after rounding up to an even number
+        let rounds = |seats: usize| (seats + seats % 2).saturating_sub(1);
+        let league_weeks = rounds(users.len());
+        let division_weeks = groups.iter().map(|g| rounds(g.len())).max().unwrap_or(0);
+        let block = league_weeks + division_weeks;
+        let mut schedule = Vec::new();
+        for week in 1..=weeks {
+            let (blocks_done, into_block) =
+                ((week as usize - 1) / block, (week as usize - 1) % block);
+            let games = if into_block < league_weeks {
+                round_robin(&users, blocks_done * league_weeks + into_block)
+            } else {
+                let round = blocks_done * division_weeks + into_block - league_weeks;
+                groups.iter().flat_map(|g| round_robin(g, round)).collect()
+            };
+            for (home, away) in games {
+                schedule.push(Matchup { week, home, away });
+            }
+        }
+        self.schedule = schedule;
+        Ok(&self.schedule)
+    }
+    /// Reorders the seats before the draft so divisions take turns down the draft order (the first seat of each division,
+    /// then the second of each, and so on), so no division gets all the early picks. Seats without a division go last.
+    ///
+    /// Seats claimed in the lobby (see [`League::claim_seat`]) still take their claimed places once everyone is ready.
+    ///
+    /// # Errors
+    ///
+    /// If the league is active, returns [`LeagueError::LeagueActiveError`].
+    ///
+    /// If the draft is already over, returns [`LeagueError::WrongPhaseError`].
+    pub fn interleave_divisions(&mut self) -> Result<(), LeagueError> {
+        self.require_pre_draft()?;
+        let divisions: Vec<String> = self.divisions().into_iter().map(String::from).collect();
+        let mut groups: Vec<Vec<ActivePlayer>> = divisions.iter().map(|_| Vec::new()).collect();
+        let mut undivided = Vec::new();
+        for player in self.players.drain(..) {
+            match divisions
+                .iter()
+                .position(|d| Some(d.as_str()) == player.division())
+            {
+                Some(idx) => groups[idx].push(player),
+                None => undivided.push(player),
+            }
+        }
+        let mut groups: Vec<_> = groups.into_iter().map(|g| g.into_iter()).collect();
+        loop {
+            let mut seated = false;
+            for group in &mut groups {
+                if let Some(player) = group.next() {
+                    self.players.push(player);
+                    seated = true;
+                }
+            }
+            if !seated {
+                break;
+            }
+        }
+        self.players.extend(undivided);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod divisions_tests {
+    use super::*;
+    use crate::test_support::{new_league, Pokemon};
+    use crate::DraftType;
+    use std::collections::HashMap;
+
+    // seats 1 and 2 are East, 3 and 4 West
+    fn league() -> League {
+        let mut league = new_league(4, DraftType::Snake, 1);
+        for (user, division) in [(1, "East"), (2, "east"), (3, "West"), (4, "West")] {
+            league
+                .set_division(serenity::UserId(user), division)
+                .unwrap();
+        }
+        league
+    }
+
+    #[test]
+    fn divisions_group_seats() {
+        let mut league = league();
+        assert_eq!(league.divisions(), vec!["East", "West"]);
+        let east: Vec<_> = league
+            .division_members("EAST")
+            .unwrap()
+            .iter()
+            .map(|p| p.id)
+            .collect();
+        assert_eq!(east, vec![serenity::UserId(1), serenity::UserId(2)]);
+        match league.division_members("North") {
+            Err(LeagueError::DivisionNotFoundError) => {}
+            _ => panic!("there is no North"),
+        }
+        match league.set_division(serenity::UserId(1), " ") {
+            Err(LeagueError::InvalidDivisionNameError) => {}
+            _ => panic!("divisions need names"),
+        }
+        league.activate().unwrap();
+        league
+            .lock(Box::new(Pokemon {
+                name: "Mew".to_string(),
+            }))
+            .unwrap();
+        assert_eq!(league.division_picks("East").unwrap().len(), 1);
+        assert!(league.division_picks("West").unwrap().is_empty());
+    }
+
+    #[test]
+    fn divisional_schedules_double_up_on_rivals() {
+        let mut league = league();
+        // one block: 3 weeks against the League, then 1 within divisions
+        let schedule = league.generate_divisional_schedule(4).unwrap();
+        let mut meetings: HashMap<(u64, u64), u32> = HashMap::new();
+        for m in schedule {
+            let pair = (m.home.0.min(m.away.0), m.home.0.max(m.away.0));
+            *meetings.entry(pair).or_default() += 1;
+        }
+        assert_eq!(meetings[&(1, 2)], 2);
+        assert_eq!(meetings[&(3, 4)], 2);
+        assert_eq!(meetings[&(1, 3)], 1);
+        assert_eq!(league.matchups_for_week(4).len(), 2);
+    }
+
+    #[test]
+    fn interleaving_alternates_divisions() {
+        let mut league = league();
+        league.set_division(serenity::UserId(2), "West").unwrap();
+        league.clear_division(serenity::UserId(1)).unwrap();
+        // seats: 1 undivided, 2 3 4 West
+        league.set_division(serenity::UserId(4), "East").unwrap();
+        league.interleave_divisions().unwrap();
+        let order: Vec<u64> = league.players.iter().map(|p| p.id.0).collect();
+        assert_eq!(order, vec![2, 4, 3, 1]);
+    }
+}
@@ -1,27 +1,62 @@
+use crate::{League, LeagueError};
+use serde::{Deserialize, Serialize};
+
+mod grid;
+mod solomon;
+mod winston;
+pub use grid::{GridDraft, GridLine};
+pub use solomon::{SolomonDraft, SolomonPile, SolomonStep};
+pub use winston::WinstonDraft;
+
+/// The order in which players pick. See [`League::new`](crate::League::new) for a description of each.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum DraftType {
     Snake,
     Linear,
+    Rochester,
+    Auction,
+    Winston,
+    Grid,
+    Solomon,
 }
 
-pub fn snake_draft(total_picks: u32, number_of_drafters: u32) -> u32 {
-    let mut next_seat = 0;
-
-    for i in 0..=(total_picks + 1) {
-        if i % number_of_drafters == 0 {
-            continue;
-        };
-        if i % (2 * number_of_drafters) <= number_of_drafters {
-            next_seat += 1;
-        } else {
-            next_seat -= 1;
+impl League {
+    // Winston, Grid and Solomon drafts are played head to head
+    pub(crate) fn require_head_to_head(&self, draft_type: DraftType) -> Result<(), LeagueError> {
+        if self.draft_type != draft_type {
+            return Err(LeagueError::WrongDraftTypeError);
         }
+        if self.players.len() != 2 {
+            return Err(LeagueError::TwoPlayerDraftError);
+        }
+        Ok(())
+    }
+}
+
+/// Returns the *next* seat in the draft, where total_picks is the zero-based number of the pick just made.
+///
+/// Seats run forward in even rounds and backward in odd ones, so with 5 drafters picks 0-4 go to seats 0-4 and picks 5-9 to seats 4-0.
+/// The seat is worked out from the pick number alone, so it costs the same at pick 400 as at pick 1.
+/// With no drafters there is no seat to go to, and it returns 0.
+pub fn snake_draft(total_picks: u32, number_of_drafters: u32) -> u32 {
+    if number_of_drafters == 0 {
+        return 0;
+    }
+    let next_pick = total_picks + 1;
+    let round = next_pick / number_of_drafters;
+    let position = next_pick % number_of_drafters;
+    if round.is_multiple_of(2) {
+        position
+    } else {
+        number_of_drafters - 1 - position
     }
-    next_seat
 }
 
-/// Returns the *next* seat in the draft
+/// Returns the *next* seat in the draft, or 0 with no drafters.
 pub fn linear_draft(total_picks: u32, number_of_drafters: u32) -> u32 {
-    (total_picks + 1) % number_of_drafters
+    (total_picks + 1)
+        .checked_rem(number_of_drafters)
+        .unwrap_or_default()
 }
 
 #[cfg(test)]
@@ -30,7 +65,55 @@ mod draft_type_tests {
 
     #[test]
     fn snake_draft_returns_correct_next_seat() {
+        // pick 8 is the fourth pick of the second round, which runs 4, 3, 2, 1, 0
         assert_eq!(snake_draft(7, 5), 1);
+        assert_eq!(snake_draft(4, 5), 4);
+        assert_eq!(snake_draft(9, 5), 0);
+    }
+
+    #[test]
+    fn no_drafters_is_seat_0() {
+        assert_eq!(snake_draft(3, 0), 0);
+        assert_eq!(linear_draft(3, 0), 0);
+    }
+
+    #[test]
+    fn snake_draft_matches_walking_the_table() {
+        for drafters in 1..=20 {
+            let mut seat: u32 = 0;
+            let mut forward = true;
+            for pick in 0..drafters * 30 {
+                // walk one seat along, turning around at either end of the table
+                let at_end = if forward {
+                    seat == drafters - 1
+                } else {
+                    seat == 0
+                };
+                if at_end {
+                    forward = !forward;
+                } else if forward {
+                    seat += 1;
+                } else {
+                    seat -= 1;
+                }
+                assert_eq!(
+                    snake_draft(pick, drafters),
+                    seat,
+                    "{drafters} drafters, after pick {pick}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn linear_draft_matches_walking_the_table() {
+        for drafters in 1..=20 {
+            let mut seat = 0;
+            for pick in 0..drafters * 30 {
+                seat = if seat == drafters - 1 { 0 } else { seat + 1 };
+                assert_eq!(linear_draft(pick, drafters), seat);
+            }
+        }
     }
 
     #[test]
@@ -0,0 +1,201 @@
+use crate::{DraftEvent, DraftType, Draftable, League, LeagueError};
+use poise::serenity_prelude as serenity;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+
+/// A row or column of a Grid draft's 3×3 grid, numbered 0 to 2 from the top left.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum GridLine {
+    Row(usize),
+    Column(usize),
+}
+
+/// The table of a Grid draft: a face-down deck and the 3×3 grid of cards dealt from it, from [`League::grid`].
+///
+/// In a Grid draft nine cards are laid out face up, and two players each take a row or column of what is left.
+/// The rest of the grid is thrown away and the next nine are dealt, with the other player taking first.
+pub struct GridDraft {
+    pub(crate) deck: VecDeque<Draftable>,
+    pub(crate) cells: Vec<Option<Draftable>>,
+    pub(crate) grids: u32,
+    pub(crate) taken: u32,
+}
+
+impl GridDraft {
+    pub(crate) fn deal(items: Vec<Draftable>) -> GridDraft {
+        let mut grid = GridDraft {
+            deck: items.into(),
+            cells: Vec::new(),
+            grids: 0,
+            taken: 0,
+        };
+        grid.next_grid();
+        grid
+    }
+    /// Returns the card at a row and column of the grid, if it has not been taken.
+    pub fn cell(&self, row: usize, column: usize) -> Option<&Draftable> {
+        if row > 2 || column > 2 {
+            return None;
+        }
+        self.cells.get(row * 3 + column)?.as_ref()
+    }
+    /// Returns the number of grids dealt so far, starting at 1.
+    pub fn grid_number(&self) -> u32 {
+        self.grids
+    }
+    /// Returns the seat (0 or 1) whose turn it is. Seat 0 takes first from odd-numbered grids, and seat 1 from even ones.
+    pub fn seat(&self) -> u32 {
+        (self.grids + 1 + self.taken) % 2
+    }
+    /// Returns the number of cards left in the deck.
+    pub fn cards_left(&self) -> usize {
+        self.deck.len()
+    }
+    /// Returns true once the deck is empty and nothing is left in the grid to take.
+    pub fn is_done(&self) -> bool {
+        self.deck.is_empty() && self.cells.iter().all(Option::is_none)
+    }
+    // takes every card left in a line, dealing the next grid after the second line
+    pub(crate) fn take(&mut self, line: GridLine) -> Result<Vec<Draftable>, LeagueError> {
+        let cells: Vec<usize> = match line {
+            GridLine::Row(row) if row < 3 => (0..3).map(|column| row * 3 + column).collect(),
+            GridLine::Column(column) if column < 3 => (0..3).map(|row| row * 3 + column).collect(),
+            _ => return Err(LeagueError::InvalidGridLineError),
+        };
+        let taken: Vec<Draftable> = cells
+            .into_iter()
+            .filter_map(|cell| self.cells.get_mut(cell)?.take())
+            .collect();
+        if taken.is_empty() {
+            return Err(LeagueError::InvalidGridLineError);
+        }
+        self.taken += 1;
+        if self.taken == 2 || self.cells.iter().all(Option::is_none) {
+            self.next_grid();
+        }
+        Ok(taken)
+    }
+    // throws away what is left of the grid and deals nine more, if the deck has any
+    fn next_grid(&mut self) {
+        self.cells = (0..9).map(|_| self.deck.pop_front()).collect();
+        self.taken = 0;
+        if self.cells.iter().any(Option::is_some) {
+            self.grids += 1;
+        }
+    }
+}
+
+impl League {
+    /// Deals the deck of a Grid draft from the items given, top card first, and lays out the first grid.
+    /// Shuffle the items first if you want a random deck. Any deck dealt previously is discarded.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`League::set_winston_deck`], for [`DraftType::Grid`].
+    pub fn set_grid_deck(&mut self, items: Vec<Draftable>) -> Result<&GridDraft, LeagueError> {
+        self.require_pre_draft()?;
+        self.require_head_to_head(DraftType::Grid)?;
+        let names = items.iter().map(|i| i.name().to_string()).collect();
+        self.record(DraftEvent::GridDeckSet(names));
+        Ok(self.grid.insert(GridDraft::deal(items)))
+    }
+    /// Returns the table of a Grid draft, e.g. to lay out the grid.
+    ///
+    /// # Errors
+    ///
+    /// If no deck has been dealt, returns [`LeagueError::WrongDraftTypeError`].
+    pub fn grid(&self) -> Result<&GridDraft, LeagueError> {
+        self.grid.as_ref().ok_or(LeagueError::WrongDraftTypeError)
+    }
+    /// Takes what is left of a row or column of the grid into the picks of the player on turn, and returns the names of its cards.
+    ///
+    /// # Errors
+    ///
+    /// If the line is out of the grid or has nothing left in it, returns [`LeagueError::InvalidGridLineError`].
+    ///
+    /// Otherwise, returns the same errors as [`League::winston_take`].
+    pub fn grid_take(
+        &mut self,
+        id: serenity::UserId,
+        line: GridLine,
+    ) -> Result<Vec<String>, LeagueError> {
+        self.require_drafting()?;
+        let seat = self.seat_of(id)?;
+        let grid = self.grid.as_mut().ok_or(LeagueError::WrongDraftTypeError)?;
+        if seat != grid.seat() {
+            return Err(LeagueError::NotYourTurnError);
+        }
+        let taken = grid.take(line)?;
+        let user = self.players[seat as usize].id;
+        self.record(DraftEvent::GridTook { user, line });
+        let names = taken.iter().map(|i| i.name().to_string()).collect();
+        for item in taken {
+            self.give_pick(seat, item);
+        }
+        let grid = self.grid.as_ref().unwrap();
+        self.current_seat = grid.seat();
+        if grid.is_done() {
+            self.finish_draft();
+        }
+        Ok(names)
+    }
+}
+
+#[cfg(test)]
+mod grid_tests {
+    use super::*;
+    use crate::test_support::new_league;
+
+    fn league(cards: u32) -> League {
+        let mut league = new_league(2, DraftType::Grid, 10);
+        let deck = (1..=cards)
+            .map(|n| Box::new(format!("Card {n}")) as Draftable)
+            .collect();
+        league.set_grid_deck(deck).unwrap();
+        league.activate().unwrap();
+        league
+    }
+
+    #[test]
+    fn players_take_a_line_each_from_every_grid() {
+        let mut league = league(18);
+        assert_eq!(league.grid().unwrap().cell(1, 1).unwrap().name(), "Card 5");
+        let taken = league
+            .grid_take(serenity::UserId(1), GridLine::Row(0))
+            .unwrap();
+        assert_eq!(taken, vec!["Card 1", "Card 2", "Card 3"]);
+        match league.grid_take(serenity::UserId(1), GridLine::Column(0)) {
+            Err(LeagueError::NotYourTurnError) => {}
+            _ => panic!("seat 1 takes second"),
+        }
+        let taken = league
+            .grid_take(serenity::UserId(2), GridLine::Column(0))
+            .unwrap();
+        assert_eq!(taken, vec!["Card 4", "Card 7"], "Card 1 is gone");
+
+        // the rest of the grid is thrown away, and seat 1 takes first from the second
+        let grid = league.grid().unwrap();
+        assert_eq!(grid.grid_number(), 2);
+        assert_eq!(grid.cell(0, 0).unwrap().name(), "Card 10");
+        assert_eq!(grid.seat(), 1);
+        match league.grid_take(serenity::UserId(2), GridLine::Row(3)) {
+            Err(LeagueError::InvalidGridLineError) => {}
+            _ => panic!("there is no row 3"),
+        }
+        let mut league =
+            League::restore(&league.snapshot(), |name| Some(Box::new(name.to_string()))).unwrap();
+        assert_eq!(
+            league.grid().unwrap().seat(),
+            1,
+            "the grid survives a snapshot"
+        );
+        league
+            .grid_take(serenity::UserId(2), GridLine::Column(2))
+            .unwrap();
+        league
+            .grid_take(serenity::UserId(1), GridLine::Row(2))
+            .unwrap();
+        assert_eq!(league.phase(), crate::LeaguePhase::PostDraft);
+        assert_eq!(league.player_picks(serenity::UserId(1)).unwrap().len(), 5);
+    }
+}
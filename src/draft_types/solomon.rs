@@ -0,0 +1,292 @@
+use crate::{DraftEvent, DraftType, Draftable, League, LeagueError};
+use poise::serenity_prelude as serenity;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+
+/// One of the two piles a Solomon draft's items are split into.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SolomonPile {
+    First,
+    Second,
+}
+
+/// What a Solomon draft is waiting for, from [`SolomonDraft::step`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SolomonStep {
+    /// The splitter has to split the revealed items into two piles (see [`League::solomon_split`]).
+    Split,
+    /// The chooser has to choose one of the piles (see [`League::solomon_choose`]).
+    Choose,
+}
+
+/// The table of a Solomon draft: a face-down deck, and the items revealed from it or the piles they were split into,
+/// from [`League::solomon`].
+///
+/// In a Solomon draft one player splits the revealed items into two piles however they like, and the other chooses a pile.
+/// The splitter gets the other pile, then the next items are revealed and the players swap roles.
+pub struct SolomonDraft {
+    pub(crate) deck: VecDeque<Draftable>,
+    pub(crate) revealed: Vec<Draftable>,
+    pub(crate) piles: Option<[Vec<Draftable>; 2]>,
+    pub(crate) splitter: u32,
+    pub(crate) reveal: usize,
+}
+
+impl SolomonDraft {
+    pub(crate) fn deal(items: Vec<Draftable>, reveal: usize) -> SolomonDraft {
+        let reveal = if reveal == 0 { items.len() } else { reveal };
+        let mut solomon = SolomonDraft {
+            deck: items.into(),
+            revealed: Vec::new(),
+            piles: None,
+            splitter: 0,
+            reveal,
+        };
+        solomon.reveal_next();
+        solomon
+    }
+    /// Returns the items waiting to be split, or nothing once they have been.
+    pub fn revealed(&self) -> &[Draftable] {
+        &self.revealed
+    }
+    /// Returns the items in one of the piles, or nothing before the revealed items are split.
+    pub fn pile(&self, pile: SolomonPile) -> &[Draftable] {
+        match (&self.piles, pile) {
+            (Some([first, _]), SolomonPile::First) => first,
+            (Some([_, second]), SolomonPile::Second) => second,
+            (None, _) => &[],
+        }
+    }
+    /// Returns whether the draft is waiting for a split or a choice.
+    pub fn step(&self) -> SolomonStep {
+        match self.piles {
+            Some(_) => SolomonStep::Choose,
+            None => SolomonStep::Split,
+        }
+    }
+    /// Returns the seat (0 or 1) splitting the items this time. Seat 0 splits first.
+    pub fn splitter(&self) -> u32 {
+        self.splitter
+    }
+    /// Returns the seat (0 or 1) choosing a pile this time.
+    pub fn chooser(&self) -> u32 {
+        1 - self.splitter
+    }
+    /// Returns the seat (0 or 1) the draft is waiting for: the splitter, then the chooser.
+    pub fn seat(&self) -> u32 {
+        match self.step() {
+            SolomonStep::Split => self.splitter(),
+            SolomonStep::Choose => self.chooser(),
+        }
+    }
+    /// Returns the number of items left in the deck.
+    pub fn cards_left(&self) -> usize {
+        self.deck.len()
+    }
+    /// Returns true once every item has been handed out.
+    pub fn is_done(&self) -> bool {
+        self.deck.is_empty() && self.revealed.is_empty() && self.piles.is_none()
+    }
+    // puts the named items in the first pile and the rest in the second, keeping the order they were revealed in
+    pub(crate) fn split(&mut self, first: &[&str]) -> Result<(), LeagueError> {
+        if let Some(missing) = first
+            .iter()
+            .find(|name| !self.revealed.iter().any(|i| i.name() == **name))
+        {
+            return Err(LeagueError::DraftableNotFoundError(vec![
+                missing.to_string()
+            ]));
+        }
+        let (first, second) = std::mem::take(&mut self.revealed)
+            .into_iter()
+            .partition(|i| first.contains(&i.name()));
+        self.piles = Some([first, second]);
+        Ok(())
+    }
+    // returns the chosen pile and the other, then swaps roles and reveals the next items
+    pub(crate) fn choose(&mut self, pile: SolomonPile) -> (Vec<Draftable>, Vec<Draftable>) {
+        let [first, second] = self.piles.take().unwrap_or_default();
+        self.splitter = self.chooser();
+        self.reveal_next();
+        match pile {
+            SolomonPile::First => (first, second),
+            SolomonPile::Second => (second, first),
+        }
+    }
+    fn reveal_next(&mut self) {
+        let count = self.reveal.min(self.deck.len());
+        self.revealed = self.deck.drain(..count).collect();
+    }
+}
+
+impl League {
+    /// Deals the deck of a Solomon draft from the items given, top card first, and reveals the first reveal items of it
+    /// for seat 0 to split. A reveal of 0 reveals the whole deck at once. Shuffle the items first if you want a random deck.
+    /// Any deck dealt previously is discarded.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`League::set_winston_deck`], for [`DraftType::Solomon`].
+    pub fn set_solomon_deck(
+        &mut self,
+        items: Vec<Draftable>,
+        reveal: usize,
+    ) -> Result<&SolomonDraft, LeagueError> {
+        self.require_pre_draft()?;
+        self.require_head_to_head(DraftType::Solomon)?;
+        let names = items.iter().map(|i| i.name().to_string()).collect();
+        self.record(DraftEvent::SolomonDeckSet {
+            items: names,
+            reveal,
+        });
+        Ok(self.solomon.insert(SolomonDraft::deal(items, reveal)))
+    }
+    /// Returns the table of a Solomon draft, e.g. to show the revealed items or the piles.
+    ///
+    /// # Errors
+    ///
+    /// If no deck has been dealt, returns [`LeagueError::WrongDraftTypeError`].
+    pub fn solomon(&self) -> Result<&SolomonDraft, LeagueError> {
+        self.solomon
+            .as_ref()
+            .ok_or(LeagueError::WrongDraftTypeError)
+    }
+    /// Splits the revealed items into two piles for the splitter: the items named go in the first pile and the rest in the
+    /// second. Either pile may be empty. The chooser then has to choose one with [`League::solomon_choose`].
+    ///
+    /// # Errors
+    ///
+    /// If an item named is not among the revealed items, returns [`LeagueError::DraftableNotFoundError`] with its name.
+    ///
+    /// If the user is not the splitter, or the items have already been split, returns [`LeagueError::NotYourTurnError`].
+    ///
+    /// Otherwise, returns the same errors as [`League::winston_take`].
+    pub fn solomon_split(
+        &mut self,
+        id: serenity::UserId,
+        first: &[&str],
+    ) -> Result<(), LeagueError> {
+        let seat = self.solomon_seat(id)?;
+        let solomon = self.solomon.as_mut().unwrap();
+        if seat != solomon.splitter() || solomon.step() != SolomonStep::Split {
+            return Err(LeagueError::NotYourTurnError);
+        }
+        solomon.split(first)?;
+        self.current_seat = solomon.seat();
+        let user = self.players[seat as usize].id;
+        let pile = first.iter().map(|name| name.to_string()).collect();
+        self.record(DraftEvent::SolomonSplit { user, pile });
+        Ok(())
+    }
+    /// Chooses one of the piles for the chooser, gives the other to the splitter, and reveals the next items for the chooser to split.
+    /// Returns the names of the items in the pile chosen, then those in the pile left to the splitter.
+    ///
+    /// # Errors
+    ///
+    /// If the items have not been split yet, returns [`LeagueError::NotSplitError`].
+    ///
+    /// If the user is not the chooser, returns [`LeagueError::NotYourTurnError`].
+    ///
+    /// Otherwise, returns the same errors as [`League::winston_take`].
+    pub fn solomon_choose(
+        &mut self,
+        id: serenity::UserId,
+        pile: SolomonPile,
+    ) -> Result<(Vec<String>, Vec<String>), LeagueError> {
+        let seat = self.solomon_seat(id)?;
+        let solomon = self.solomon.as_mut().unwrap();
+        if seat != solomon.chooser() {
+            return Err(LeagueError::NotYourTurnError);
+        }
+        if solomon.step() != SolomonStep::Choose {
+            return Err(LeagueError::NotSplitError);
+        }
+        let splitter = solomon.splitter();
+        let (chosen, other) = solomon.choose(pile);
+        let user = self.players[seat as usize].id;
+        self.record(DraftEvent::SolomonChosen { user, pile });
+        let names = |items: &[Draftable]| items.iter().map(|i| i.name().to_string()).collect();
+        let result = (names(&chosen), names(&other));
+        for item in chosen {
+            self.give_pick(seat, item);
+        }
+        for item in other {
+            self.give_pick(splitter, item);
+        }
+        let solomon = self.solomon.as_ref().unwrap();
+        self.current_seat = solomon.seat();
+        if solomon.is_done() {
+            self.finish_draft();
+        }
+        Ok(result)
+    }
+    // the seat acting, once the draft is underway
+    fn solomon_seat(&self, id: serenity::UserId) -> Result<u32, LeagueError> {
+        self.require_drafting()?;
+        self.solomon()?;
+        self.seat_of(id)
+    }
+}
+
+#[cfg(test)]
+mod solomon_tests {
+    use super::*;
+    use crate::test_support::new_league;
+
+    fn league() -> League {
+        let mut league = new_league(2, DraftType::Solomon, 10);
+        let deck = ["Bolt", "Counterspell", "Swords", "Ponder", "Opt"]
+            .iter()
+            .map(|c| Box::new(*c) as Draftable)
+            .collect();
+        league.set_solomon_deck(deck, 3).unwrap();
+        league.activate().unwrap();
+        league
+    }
+
+    #[test]
+    fn one_splits_and_the_other_chooses() {
+        let mut league = league();
+        match league.solomon_choose(serenity::UserId(2), SolomonPile::First) {
+            Err(LeagueError::NotSplitError) => {}
+            _ => panic!("nothing is split yet"),
+        }
+        match league.solomon_split(serenity::UserId(1), &["Ponder"]) {
+            Err(LeagueError::DraftableNotFoundError(names)) => assert_eq!(names, vec!["Ponder"]),
+            _ => panic!("Ponder is still in the deck"),
+        }
+        league
+            .solomon_split(serenity::UserId(1), &["Bolt"])
+            .unwrap();
+        let restored =
+            League::restore(&league.snapshot(), |name| Some(Box::new(name.to_string()))).unwrap();
+        assert_eq!(restored.solomon().unwrap().step(), SolomonStep::Choose);
+        match league.solomon_split(serenity::UserId(1), &[]) {
+            Err(LeagueError::NotYourTurnError) => {}
+            _ => panic!("seat 1 is choosing"),
+        }
+        let (chosen, other) = league
+            .solomon_choose(serenity::UserId(2), SolomonPile::Second)
+            .unwrap();
+        assert_eq!(chosen, vec!["Counterspell", "Swords"]);
+        assert_eq!(other, vec!["Bolt"]);
+
+        // the roles swap for the last two items
+        let solomon = league.solomon().unwrap();
+        assert_eq!(solomon.splitter(), 1);
+        assert_eq!(solomon.revealed().len(), 2);
+        league.solomon_split(serenity::UserId(2), &[]).unwrap();
+        league
+            .solomon_choose(serenity::UserId(1), SolomonPile::Second)
+            .unwrap();
+        assert_eq!(league.phase(), crate::LeaguePhase::PostDraft);
+        let picks = league.player_picks(serenity::UserId(1)).unwrap();
+        assert_eq!(picks.len(), 3);
+
+        let mut replayed = new_league(2, DraftType::Solomon, 10);
+        replayed
+            .replay(league.events(), |name| Some(Box::new(name.to_string())))
+            .unwrap();
+        assert_eq!(replayed.player_picks(serenity::UserId(2)).unwrap().len(), 2);
+    }
+}
@@ -0,0 +1,263 @@
+use crate::{DraftEvent, DraftType, Draftable, League, LeagueError};
+use poise::serenity_prelude as serenity;
+use std::collections::VecDeque;
+
+/// The table of a Winston draft: a face-down deck and three piles dealt from it, from [`League::winston`].
+///
+/// In a Winston draft two players take turns looking at the piles in order. The player on turn takes the pile they are
+/// looking at, which is replaced with a card from the deck, or passes it, adding a card from the deck to it, and looks
+/// at the next pile. Passing the last pile draws the top card of the deck instead. Either way, taking ends the turn.
+pub struct WinstonDraft {
+    pub(crate) deck: VecDeque<Draftable>,
+    pub(crate) piles: [Vec<Draftable>; 3],
+    pub(crate) pile: usize,
+    pub(crate) seat: u32,
+}
+
+impl WinstonDraft {
+    // deals a card to each pile, and puts seat 0 on turn looking at the first pile
+    pub(crate) fn deal(items: Vec<Draftable>) -> WinstonDraft {
+        let mut deck: VecDeque<Draftable> = items.into();
+        let piles = [(); 3].map(|_| deck.pop_front().into_iter().collect());
+        let mut winston = WinstonDraft {
+            deck,
+            piles,
+            pile: 0,
+            seat: 0,
+        };
+        winston.pile = winston.first_pile();
+        winston
+    }
+    /// Returns the pile at the given index (0 to 2), in the order its cards were added. Empty piles are passed over.
+    pub fn pile(&self, index: usize) -> &[Draftable] {
+        self.piles.get(index).map_or(&[], |pile| pile.as_slice())
+    }
+    /// Returns the index of the pile the player on turn is looking at.
+    pub fn current_pile(&self) -> usize {
+        self.pile
+    }
+    /// Returns the seat (0 or 1) whose turn it is.
+    pub fn seat(&self) -> u32 {
+        self.seat
+    }
+    /// Returns the number of cards left in the deck.
+    pub fn cards_left(&self) -> usize {
+        self.deck.len()
+    }
+    /// Returns true once the deck and every pile are empty.
+    pub fn is_done(&self) -> bool {
+        self.deck.is_empty() && self.piles.iter().all(Vec::is_empty)
+    }
+    // takes the current pile and replaces it from the deck
+    pub(crate) fn take(&mut self) -> Vec<Draftable> {
+        let taken = std::mem::take(&mut self.piles[self.pile]);
+        self.piles[self.pile].extend(self.deck.pop_front());
+        self.end_turn();
+        taken
+    }
+    // passes the current pile, returning the card drawn blind if it was the last pile
+    pub(crate) fn pass(&mut self) -> Result<Option<Draftable>, LeagueError> {
+        let next = (self.pile + 1..3).find(|p| !self.piles[*p].is_empty());
+        if next.is_none() && self.deck.is_empty() {
+            return Err(LeagueError::MustTakePileError);
+        }
+        // the last pile only grows if the deck has a card to spare for the blind draw
+        if next.is_some() || self.deck.len() > 1 {
+            self.piles[self.pile].extend(self.deck.pop_front());
+        }
+        match next {
+            Some(next) => {
+                self.pile = next;
+                Ok(None)
+            }
+            None => {
+                let drawn = self.deck.pop_front();
+                self.end_turn();
+                Ok(drawn)
+            }
+        }
+    }
+    fn end_turn(&mut self) {
+        self.seat = 1 - self.seat;
+        self.pile = self.first_pile();
+    }
+    fn first_pile(&self) -> usize {
+        (0..3).find(|p| !self.piles[*p].is_empty()).unwrap_or(0)
+    }
+}
+
+impl League {
+    /// Deals the deck of a Winston draft from the items given, top card first, and lays out its three piles.
+    /// Shuffle the items first if you want a random deck. Any deck dealt previously is discarded.
+    ///
+    /// # Errors
+    ///
+    /// If the League's draft type is not [`DraftType::Winston`], returns [`LeagueError::WrongDraftTypeError`].
+    ///
+    /// If the League does not have exactly two players, returns [`LeagueError::TwoPlayerDraftError`].
+    ///
+    /// If the draft has started, returns [`LeagueError::LeagueActiveError`], or [`LeagueError::WrongPhaseError`] once it is over.
+    pub fn set_winston_deck(
+        &mut self,
+        items: Vec<Draftable>,
+    ) -> Result<&WinstonDraft, LeagueError> {
+        self.require_pre_draft()?;
+        self.require_head_to_head(DraftType::Winston)?;
+        let names = items.iter().map(|i| i.name().to_string()).collect();
+        self.record(DraftEvent::WinstonDeckSet(names));
+        Ok(self.winston.insert(WinstonDraft::deal(items)))
+    }
+    /// Returns the table of a Winston draft, e.g. to show the player on turn the pile they are looking at.
+    ///
+    /// # Errors
+    ///
+    /// If no deck has been dealt, returns [`LeagueError::WrongDraftTypeError`].
+    pub fn winston(&self) -> Result<&WinstonDraft, LeagueError> {
+        self.winston
+            .as_ref()
+            .ok_or(LeagueError::WrongDraftTypeError)
+    }
+    /// Takes the pile the player on turn is looking at into their picks, and returns the names of its cards.
+    /// The other player is then on turn.
+    ///
+    /// # Errors
+    ///
+    /// If no deck has been dealt, returns [`LeagueError::WrongDraftTypeError`].
+    ///
+    /// If the user has no seat, returns [`LeagueError::PlayerNotFoundError`], or [`LeagueError::NotYourTurnError`] if it is not their turn.
+    ///
+    /// If the League is not drafting, returns [`LeagueError::LeagueInactiveError`], or [`LeagueError::LeaguePausedError`] while paused.
+    pub fn winston_take(&mut self, id: serenity::UserId) -> Result<Vec<String>, LeagueError> {
+        let seat = self.winston_turn(id)?;
+        let user = self.players[seat as usize].id;
+        self.record(DraftEvent::WinstonTook { user });
+        let taken = self.winston.as_mut().unwrap().take();
+        let names = taken.iter().map(|i| i.name().to_string()).collect();
+        for item in taken {
+            self.give_pick(seat, item);
+        }
+        self.end_winston_turn();
+        Ok(names)
+    }
+    /// Passes the pile the player on turn is looking at, adding a card from the deck to it. If it was the last pile, the
+    /// player draws the top card of the deck into their picks instead, which is returned by name, and the other player is on turn.
+    ///
+    /// # Errors
+    ///
+    /// If the pile is the last and the deck is empty, returns [`LeagueError::MustTakePileError`].
+    ///
+    /// Otherwise, returns the same errors as [`League::winston_take`].
+    pub fn winston_pass(&mut self, id: serenity::UserId) -> Result<Option<String>, LeagueError> {
+        let seat = self.winston_turn(id)?;
+        let user = self.players[seat as usize].id;
+        let drawn = self.winston.as_mut().unwrap().pass()?;
+        self.record(DraftEvent::WinstonPassed { user });
+        let name = drawn.as_ref().map(|i| i.name().to_string());
+        if let Some(item) = drawn {
+            self.give_pick(seat, item);
+        }
+        self.end_winston_turn();
+        Ok(name)
+    }
+    // the seat acting, if it is on turn
+    fn winston_turn(&self, id: serenity::UserId) -> Result<u32, LeagueError> {
+        self.require_drafting()?;
+        let winston = self.winston()?;
+        let seat = self.seat_of(id)?;
+        if seat != winston.seat() {
+            return Err(LeagueError::NotYourTurnError);
+        }
+        Ok(seat)
+    }
+    fn end_winston_turn(&mut self) {
+        let winston = self.winston.as_ref().unwrap();
+        self.current_seat = winston.seat();
+        if winston.is_done() {
+            self.finish_draft();
+        }
+    }
+}
+
+#[cfg(test)]
+mod winston_tests {
+    use super::*;
+    use crate::test_support::new_league;
+
+    fn league(cards: &[&'static str]) -> League {
+        let mut league = new_league(2, DraftType::Winston, 10);
+        let deck = cards.iter().map(|c| Box::new(*c) as Draftable).collect();
+        league.set_winston_deck(deck).unwrap();
+        league.activate().unwrap();
+        league
+    }
+
+    fn names(pile: &[Draftable]) -> Vec<&str> {
+        pile.iter().map(|i| i.name()).collect()
+    }
+
+    #[test]
+    fn piles_grow_when_passed() {
+        let mut league = league(&[
+            "Bolt",
+            "Counterspell",
+            "Swords",
+            "Ponder",
+            "Brainstorm",
+            "Opt",
+            "Thoughtseize",
+            "Duress",
+        ]);
+        match league.winston_take(serenity::UserId(2)) {
+            Err(LeagueError::NotYourTurnError) => {}
+            _ => panic!("seat 0 goes first"),
+        }
+        assert_eq!(league.winston_pass(serenity::UserId(1)).unwrap(), None);
+        assert_eq!(
+            names(league.winston().unwrap().pile(0)),
+            vec!["Bolt", "Ponder"]
+        );
+        assert_eq!(league.winston().unwrap().current_pile(), 1);
+        assert_eq!(
+            league.winston_take(serenity::UserId(1)).unwrap(),
+            vec!["Counterspell"]
+        );
+        assert_eq!(names(league.winston().unwrap().pile(1)), vec!["Brainstorm"]);
+
+        // seat 1 passes every pile and draws blind
+        league.winston_pass(serenity::UserId(2)).unwrap();
+        league.winston_pass(serenity::UserId(2)).unwrap();
+        assert_eq!(
+            league.winston_pass(serenity::UserId(2)).unwrap().as_deref(),
+            Some("Duress")
+        );
+        let winston = league.winston().unwrap();
+        assert_eq!(names(winston.pile(0)), vec!["Bolt", "Ponder", "Opt"]);
+        assert_eq!(names(winston.pile(2)), vec!["Swords"], "no card to spare");
+        assert_eq!(winston.seat(), 0);
+    }
+
+    #[test]
+    fn the_draft_ends_when_every_card_is_taken() {
+        let mut league = league(&["Bolt", "Counterspell", "Swords", "Ponder"]);
+        league.winston_pass(serenity::UserId(1)).unwrap();
+        league.winston_pass(serenity::UserId(1)).unwrap();
+        match league.winston_pass(serenity::UserId(1)) {
+            Err(LeagueError::MustTakePileError) => {}
+            _ => panic!("there is nothing left to draw"),
+        }
+        league.winston_take(serenity::UserId(1)).unwrap();
+        league.winston_take(serenity::UserId(2)).unwrap();
+        league.winston_take(serenity::UserId(1)).unwrap();
+        assert_eq!(league.phase(), crate::LeaguePhase::PostDraft);
+        let picks = league.player_picks(serenity::UserId(1)).unwrap();
+        assert_eq!(picks[0].name(), "Swords");
+        assert_eq!(picks.len(), 2);
+
+        let mut replayed = new_league(2, DraftType::Winston, 10);
+        replayed
+            .replay(league.events(), |name| Some(Box::new(name.to_string())))
+            .unwrap();
+        assert_eq!(replayed.player_picks(serenity::UserId(2)).unwrap().len(), 2);
+        assert_eq!(replayed.phase(), crate::LeaguePhase::PostDraft);
+    }
+}
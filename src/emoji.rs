@@ -0,0 +1,173 @@
+use crate::{DraftItem, League, LeagueError};
+use poise::serenity_prelude as serenity;
+use std::collections::HashMap;
+
+const CATEGORY_PREFIX: &str = "category:";
+
+/// Emoji registered for a [`League`]'s items, either for a single item or for every item in a category.
+#[derive(Clone)]
+pub(crate) struct EmojiMap {
+    // k: item name
+    pub(crate) items: HashMap<String, String>,
+    // k: category name
+    pub(crate) categories: HashMap<String, String>,
+}
+
+impl EmojiMap {
+    pub(crate) fn new() -> EmojiMap {
+        EmojiMap {
+            items: HashMap::new(),
+            categories: HashMap::new(),
+        }
+    }
+}
+
+impl League {
+    /// Registers an emoji for a single item, by the item's name. Item emoji take precedence over category emoji.
+    ///
+    /// Emoji can be unicode (`"🔥"`) or custom emoji in Discord's `<:name:id>` format.
+    pub fn set_item_emoji(&mut self, name: String, emoji: String) {
+        let name = self.resolve_name(&name).to_string();
+        self.emoji.items.insert(name, emoji);
+    }
+    /// Registers an emoji for every item whose [`DraftItem::category`] matches.
+    pub fn set_category_emoji(&mut self, category: String, emoji: String) {
+        self.emoji.categories.insert(category, emoji);
+    }
+    /// Removes an item's emoji, returning it if there was one.
+    pub fn remove_item_emoji(&mut self, name: &str) -> Option<String> {
+        let name = self.resolve_name(name).to_string();
+        self.emoji.items.remove(&name)
+    }
+    /// Removes a category's emoji, returning it if there was one.
+    pub fn remove_category_emoji(&mut self, category: &str) -> Option<String> {
+        self.emoji.categories.remove(category)
+    }
+    /// Registers many emoji at once from text, one mapping per line, e.g. a file uploaded alongside your pool:
+    ///
+    /// ```text
+    /// # comments and blank lines are ignored
+    /// Charizard = 🔥
+    /// category:Water = <:water:123456789012345678>
+    /// ```
+    ///
+    /// Lines starting with `category:` map a category, and every other line maps a single item.
+    ///
+    /// # Returns
+    ///
+    /// Returns the number of mappings registered.
+    ///
+    /// # Errors
+    ///
+    /// If a line is not in `key = emoji` form, returns [`LeagueError::InvalidEmojiMappingError`] with its line number (starting at 1).
+    /// Nothing is registered unless every line is valid.
+    pub fn import_emoji_map(&mut self, mapping: &str) -> Result<usize, LeagueError> {
+        let mut parsed = Vec::new();
+        for (idx, line) in mapping.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let Some((key, emoji)) = line.split_once('=') else {
+                return Err(LeagueError::InvalidEmojiMappingError(idx + 1));
+            };
+            let (key, emoji) = (key.trim(), emoji.trim());
+            if key.is_empty() || emoji.is_empty() {
+                return Err(LeagueError::InvalidEmojiMappingError(idx + 1));
+            }
+            parsed.push((key.to_string(), emoji.to_string()));
+        }
+        let registered = parsed.len();
+        for (key, emoji) in parsed {
+            match key.strip_prefix(CATEGORY_PREFIX) {
+                Some(category) => self.set_category_emoji(category.trim().to_string(), emoji),
+                None => self.set_item_emoji(key, emoji),
+            }
+        }
+        Ok(registered)
+    }
+    /// Returns the emoji for an item: its own if it has one, otherwise its category's.
+    pub fn emoji_for(&self, item: &dyn DraftItem) -> Option<&str> {
+        if let Some(emoji) = self.emoji.items.get(item.name()) {
+            return Some(emoji);
+        }
+        item.category()
+            .and_then(|category| self.emoji.categories.get(category))
+            .map(String::as_str)
+    }
+    /// Returns an item's name prefixed with its emoji, if it has one - the form boards and announcements should use.
+    pub fn item_label(&self, item: &dyn DraftItem) -> String {
+        match self.emoji_for(item) {
+            Some(emoji) => format!("{emoji} {}", item.name()),
+            None => item.name().to_string(),
+        }
+    }
+    /// Builds a select menu option for an item, with the item's name as its value and its emoji (if Discord can parse it) as its icon.
+    pub fn item_select_option(&self, item: &dyn DraftItem) -> serenity::CreateSelectMenuOption {
+        let mut option = serenity::CreateSelectMenuOption::new(item.name(), item.name());
+        if let Some(emoji) = self.emoji_for(item) {
+            if let Ok(emoji) = serenity::ReactionType::try_from(emoji) {
+                option.emoji(emoji);
+            }
+        }
+        option
+    }
+}
+
+#[cfg(test)]
+mod emoji_tests {
+    use super::*;
+    use crate::test_support::new_league;
+    use crate::DraftType;
+
+    struct Pokemon {
+        name: String,
+        category: String,
+    }
+    impl DraftItem for Pokemon {
+        fn name(&self) -> &str {
+            &self.name
+        }
+        fn category(&self) -> Option<&str> {
+            Some(&self.category)
+        }
+    }
+
+    fn league() -> League {
+        new_league(1, DraftType::Snake, 2)
+    }
+
+    #[test]
+    fn item_emoji_beats_category_emoji() {
+        let mut league = league();
+        let imported = league
+            .import_emoji_map("# starters\nCharizard = 🐉\n\ncategory:Fire = 🔥\n")
+            .unwrap();
+        assert_eq!(imported, 2);
+        let charizard = Pokemon {
+            name: "Charizard".to_string(),
+            category: "Fire".to_string(),
+        };
+        let arcanine = Pokemon {
+            name: "Arcanine".to_string(),
+            category: "Fire".to_string(),
+        };
+        let quaxly = Pokemon {
+            name: "Quaxly".to_string(),
+            category: "Water".to_string(),
+        };
+        assert_eq!(league.item_label(&charizard), "🐉 Charizard");
+        assert_eq!(league.item_label(&arcanine), "🔥 Arcanine");
+        assert_eq!(league.item_label(&quaxly), "Quaxly");
+    }
+
+    #[test]
+    fn bad_import_registers_nothing() {
+        let mut league = league();
+        match league.import_emoji_map("Charizard = 🐉\nArcanine 🔥") {
+            Err(LeagueError::InvalidEmojiMappingError(2)) => {}
+            _ => panic!("line 2 is missing its ="),
+        }
+        assert!(league.emoji.items.is_empty());
+    }
+}
@@ -0,0 +1,664 @@
+use crate::{
+    DraftItem, DraftPool, ForfeitPolicy, GridLine, League, LeagueError, LeaguePhase, PickSource,
+    SolomonPile,
+};
+use chrono::{DateTime, Utc};
+use poise::serenity_prelude as serenity;
+use serde::{Deserialize, Serialize};
+use tokio::sync::broadcast;
+
+// how many events a subscriber can fall behind before it misses some
+const STREAM_CAPACITY: usize = 256;
+
+/// Something that changed a [`League`]'s draft. Every League keeps a log of these (see [`League::events`]),
+/// and [`League::replay`] rebuilds a League from one.
+///
+/// Events record what happened rather than what was asked for: a pick made from a queue or by autopick is a [`DraftEvent::Picked`]
+/// like any other, so replaying never depends on queues being in the same order or on a random autopick strategy.
+/// Players are recorded by their seat's original owner. Events serialize with serde, so a storage backend can simply append them.
+///
+/// Only changes to the draft itself are events. Lobby seating, teams, permissions, emoji and templates are League configuration,
+/// and should be set up again on the League replayed into.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DraftEvent {
+    /// The League moved to a new phase, including pausing and resuming.
+    PhaseChanged(LeaguePhase),
+    /// The draft was kicked off with [`League::start`], with this player first on the clock.
+    /// The phase change before it already starts the draft, so replaying this event changes nothing.
+    Started { user: serenity::UserId },
+    /// The seat on the clock picked an item, and the draft moved on to the next seat.
+    Picked {
+        user: serenity::UserId,
+        item: String,
+    },
+    /// The seat on the clock was skipped.
+    Skipped,
+    /// An item was added directly to a seat's picks (see [`League::add_to_player_picks`]).
+    AddedToPicks {
+        user: serenity::UserId,
+        item: String,
+    },
+    /// An item was added to the end of a seat's queue.
+    Queued {
+        user: serenity::UserId,
+        item: String,
+    },
+    /// An item was removed from a seat's queue.
+    Unqueued {
+        user: serenity::UserId,
+        item: String,
+    },
+    /// A seat's queue was emptied.
+    QueueCleared { user: serenity::UserId },
+    /// The draft ended with these items still in a seat's queue, and they were thrown away.
+    /// Replaying the end of the draft discards them again, so replaying this event changes nothing.
+    QueueDiscarded {
+        user: serenity::UserId,
+        items: Vec<String>,
+    },
+    /// A seat was put on or taken off autopick.
+    AutopickSet {
+        user: serenity::UserId,
+        autopick: bool,
+    },
+    /// A seat exchanged one of its picks for an item from the pool.
+    Waivered {
+        user: serenity::UserId,
+        dropped: String,
+        claimed: String,
+    },
+    /// Two seats exchanged picks.
+    Traded {
+        user1: serenity::UserId,
+        item1: String,
+        user2: serenity::UserId,
+        item2: String,
+    },
+    /// The League was given a [`DraftPool`] holding these items, in ranking order.
+    PoolSet(Vec<String>),
+    /// The League was given a [`DraftPool`] holding these items with their copy counts (see [`DraftPool::with_copies`]).
+    PoolWithCopiesSet(Vec<(String, u32)>),
+    /// A Rochester draft's pool was partitioned into packs.
+    PacksSet {
+        items: Vec<String>,
+        pack_size: usize,
+    },
+    /// The next Rochester pack was turned face up.
+    PackRevealed,
+    /// Every pick after this overall pick number was reverted (see [`League::rewind_to_pick`]).
+    Rewound(u32),
+    /// A seat was forfeited (see [`League::forfeit_player`]).
+    Forfeited {
+        user: serenity::UserId,
+        policy: ForfeitPolicy,
+    },
+    /// An expansion draft started with these entrants (see [`League::start_expansion`]).
+    ExpansionStarted {
+        entrants: Vec<serenity::UserId>,
+        protect: u32,
+        picks: u32,
+    },
+    /// A seat submitted its protection list for the expansion draft.
+    Protected {
+        user: serenity::UserId,
+        items: Vec<String>,
+    },
+    /// Every protection list was in, and the entrants started picking.
+    ExpansionOpened,
+    /// An entrant picked an unprotected item from whichever seat held it.
+    ExpansionPicked {
+        user: serenity::UserId,
+        item: String,
+    },
+    /// An item was put up for auction (see [`League::nominate`]).
+    Nominated {
+        user: serenity::UserId,
+        item: String,
+        closes_at: DateTime<Utc>,
+    },
+    /// A seat bid on the item up for auction, replacing any earlier bid.
+    BidPlaced { user: serenity::UserId, amount: u32 },
+    /// The seat whose turn it was to nominate was passed over (see [`League::skip_nominator`]).
+    NominationSkipped,
+    /// The item up for auction went to the highest bidder, or back to the pool if nobody bid (see [`League::resolve_lot`]).
+    LotResolved,
+    /// A Winston draft's deck was dealt from these items, top card first (see [`League::set_winston_deck`]).
+    WinstonDeckSet(Vec<String>),
+    /// A seat took the Winston pile it was looking at.
+    WinstonTook { user: serenity::UserId },
+    /// A seat passed the Winston pile it was looking at, drawing blind if it was the last.
+    WinstonPassed { user: serenity::UserId },
+    /// A Grid draft's deck was dealt from these items, top card first (see [`League::set_grid_deck`]).
+    GridDeckSet(Vec<String>),
+    /// A seat took a row or column of the grid.
+    GridTook {
+        user: serenity::UserId,
+        line: GridLine,
+    },
+    /// A Solomon draft's deck was dealt from these items, top card first, to be revealed reveal at a time (see [`League::set_solomon_deck`]).
+    SolomonDeckSet { items: Vec<String>, reveal: usize },
+    /// A seat split the revealed items, putting these in the first pile and the rest in the second.
+    SolomonSplit {
+        user: serenity::UserId,
+        pile: Vec<String>,
+    },
+    /// A seat chose a pile, leaving the other to the seat that split them.
+    SolomonChosen {
+        user: serenity::UserId,
+        pile: SolomonPile,
+    },
+    /// The seat on the clock was skipped but owes the pick, to make later (see [`SkipPolicy::PickLaterAnyTime`](crate::SkipPolicy::PickLaterAnyTime)).
+    /// The [`DraftEvent::Skipped`] that moves the draft on comes next.
+    PickOwed { user: serenity::UserId },
+    /// A seat made a pick it owed, out of turn (see [`League::lock_owed`]).
+    OwedPicked {
+        user: serenity::UserId,
+        item: String,
+    },
+}
+
+impl League {
+    /// Returns every [`DraftEvent`] the League has recorded, oldest first.
+    pub fn events(&self) -> &[DraftEvent] {
+        &self.events
+    }
+    /// Returns a live feed of the League's [`DraftEvent`]s, each sent as it is recorded, e.g. for a web draft board that updates
+    /// as picks are locked in on Discord. Subscribe as many times as you like; every receiver gets every event from then on.
+    ///
+    /// Receivers that fall more than 256 events behind miss the oldest and get
+    /// [`RecvError::Lagged`](tokio::sync::broadcast::error::RecvError::Lagged), and can catch up with [`League::changes_since`].
+    /// A League restored from a snapshot or rolled over has no subscribers, so subscribe to it again.
+    pub fn subscribe(&mut self) -> broadcast::Receiver<DraftEvent> {
+        self.stream
+            .get_or_insert_with(|| broadcast::channel(STREAM_CAPACITY).0)
+            .subscribe()
+    }
+    // logs an event and sends it to any subscribers
+    pub(crate) fn record(&mut self, event: DraftEvent) {
+        if let Some(stream) = &self.stream {
+            // nobody may be listening right now, which is fine
+            let _ = stream.send(event.clone());
+        }
+        self.events.push(event);
+        self.last_activity = Utc::now();
+    }
+    // sends events recorded while the stream was held back to any subscribers, e.g. once a LeagueService has saved them
+    pub(crate) fn broadcast(&self, events: &[DraftEvent]) {
+        if let Some(stream) = &self.stream {
+            for event in events {
+                let _ = stream.send(event.clone());
+            }
+        }
+    }
+    // moves on with every event recorded, so a change can tell whether anything has happened to the League since
+    pub(crate) fn revision(&self) -> (usize, DateTime<Utc>) {
+        (self.events.len(), self.last_activity)
+    }
+    /// Returns every [`DraftEvent`] recorded after overall pick number pick was made, oldest first - e.g. for a bot that went offline
+    /// after announcing pick 12 to catch its announcement channel up from pick 13 without repeating anything. `changes_since(0)`
+    /// returns every event.
+    ///
+    /// If the pick was reverted with [`League::rewind_to_pick`] and made again, the events since it was made again are returned.
+    ///
+    /// # Errors
+    ///
+    /// If pick has not been made yet, returns [`LeagueError::InvalidPickNumberError`]. Picks are counted from [`League::events`],
+    /// so a League restored from a snapshot only knows the picks made since.
+    pub fn changes_since(&self, pick: u32) -> Result<&[DraftEvent], LeagueError> {
+        if pick == 0 {
+            return Ok(&self.events);
+        }
+        let mut made = 0;
+        let mut since = None;
+        for (idx, event) in self.events.iter().enumerate() {
+            match event {
+                DraftEvent::Picked { .. } | DraftEvent::Skipped => {
+                    made += 1;
+                    if made == pick {
+                        since = Some(idx + 1);
+                    }
+                }
+                DraftEvent::Rewound(to) => made = made.min(*to),
+                _ => {}
+            }
+        }
+        match since {
+            Some(idx) if made >= pick => Ok(&self.events[idx..]),
+            _ => Err(LeagueError::InvalidPickNumberError),
+        }
+    }
+    /// Applies events to the League in order, e.g. to rebuild a League from an event store after a restart,
+    /// or to a fresh copy of a League to see how it stood at any earlier point.
+    ///
+    /// Start from a League set up as the original was before its first event - same players in the same seats,
+    /// same draft type and team size. resolver turns each item name back into your [`DraftItem`] type.
+    ///
+    /// # Errors
+    ///
+    /// If resolver does not recognize an item, returns [`LeagueError::DraftableNotFoundError`].
+    ///
+    /// If an event does not fit the League as it stands - a pick by a seat that is not on the clock, a phase change that is not allowed, a trade
+    /// of items nobody has - returns [`LeagueError::InvalidEventError`] with the index of the event. Events before it stay applied.
+    pub fn replay(
+        &mut self,
+        events: &[DraftEvent],
+        resolver: impl FnMut(&str) -> Option<Box<dyn DraftItem>>,
+    ) -> Result<(), LeagueError> {
+        // the events got past any rate limits when they were first made
+        let limits = std::mem::take(&mut self.rate_limits.limits);
+        let replayed = self.replay_events(events, resolver);
+        self.rate_limits.limits = limits;
+        replayed
+    }
+    // applies each event in turn, for League::replay
+    fn replay_events(
+        &mut self,
+        events: &[DraftEvent],
+        mut resolver: impl FnMut(&str) -> Option<Box<dyn DraftItem>>,
+    ) -> Result<(), LeagueError> {
+        let mut resolve =
+            |name: &str| resolver(name).ok_or(LeagueError::DraftableNotFoundError(Vec::new()));
+        for (idx, event) in events.iter().enumerate() {
+            let invalid = |_| LeagueError::InvalidEventError(idx);
+            match event {
+                DraftEvent::PhaseChanged(phase) => {
+                    self.set_phase(*phase).map(|_| ()).map_err(invalid)?
+                }
+                DraftEvent::Picked { user, item } => {
+                    let on_the_clock = self.phase == LeaguePhase::Drafting
+                        && self.players[self.current_seat as usize].id == *user;
+                    if !on_the_clock {
+                        return Err(LeagueError::InvalidEventError(idx));
+                    }
+                    let item = resolve(item)?;
+                    self.place_pick(item, PickSource::Manual);
+                    self.advance_seat();
+                }
+                DraftEvent::Skipped => {
+                    self.record(DraftEvent::Skipped);
+                    self.advance_seat();
+                }
+                DraftEvent::AddedToPicks { user, item } => {
+                    self.add_to_player_picks(*user, resolve(item)?)
+                        .map_err(|rejected| invalid(rejected.error))?;
+                }
+                DraftEvent::Queued { user, item } => {
+                    self.add_to_player_queue(*user, resolve(item)?)
+                        .map_err(invalid)?;
+                }
+                DraftEvent::Unqueued { user, item } => {
+                    self.delete_from_player_queue(*user, item)
+                        .map_err(invalid)?;
+                }
+                DraftEvent::QueueCleared { user } => {
+                    self.clear_player_queue(*user).map_err(invalid)?;
+                }
+                DraftEvent::QueueDiscarded { .. } | DraftEvent::Started { .. } => {}
+                DraftEvent::AutopickSet { user, autopick } => {
+                    self.set_autopick(*user, *autopick).map_err(invalid)?;
+                }
+                DraftEvent::Waivered {
+                    user,
+                    dropped,
+                    claimed,
+                } => {
+                    self.waiver(*user, dropped, resolve(claimed)?)
+                        .map_err(|rejected| invalid(rejected.error))?;
+                }
+                DraftEvent::Traded {
+                    user1,
+                    item1,
+                    user2,
+                    item2,
+                } => {
+                    self.trade(*user1, item1, *user2, item2).map_err(invalid)?;
+                }
+                DraftEvent::PoolSet(items) => {
+                    let items = items.iter().map(|i| resolve(i)).collect::<Result<_, _>>()?;
+                    self.set_pool(DraftPool::new(items)).map_err(invalid)?;
+                }
+                DraftEvent::PoolWithCopiesSet(items) => {
+                    let items = items
+                        .iter()
+                        .map(|(i, copies)| resolve(i).map(|i| (i, *copies)))
+                        .collect::<Result<_, _>>()?;
+                    self.set_pool(DraftPool::with_copies(items))
+                        .map_err(invalid)?;
+                }
+                DraftEvent::PacksSet { items, pack_size } => {
+                    let items = items.iter().map(|i| resolve(i)).collect::<Result<_, _>>()?;
+                    self.set_packs(items, *pack_size).map_err(invalid)?;
+                }
+                DraftEvent::PackRevealed => {
+                    self.reveal_pack().map_err(invalid)?;
+                }
+                DraftEvent::Rewound(pick) => {
+                    self.rewind_to_pick(*pick).map_err(invalid)?;
+                }
+                // the picks and skips that followed the forfeit are events of their own
+                DraftEvent::Forfeited { user, policy } => {
+                    let seat = self.seat_of(*user).map_err(invalid)?;
+                    self.forfeit_seat(seat, *policy).map_err(invalid)?;
+                }
+                DraftEvent::ExpansionStarted {
+                    entrants,
+                    protect,
+                    picks,
+                } => {
+                    self.start_expansion(entrants, *protect, *picks)
+                        .map_err(invalid)?;
+                }
+                DraftEvent::Protected { user, items } => {
+                    let items: Vec<&str> = items.iter().map(String::as_str).collect();
+                    self.protect_items(*user, &items).map_err(invalid)?;
+                }
+                DraftEvent::ExpansionOpened => {
+                    self.open_expansion().map_err(invalid)?;
+                }
+                DraftEvent::ExpansionPicked { user, item } => {
+                    self.expansion_pick(*user, item).map_err(invalid)?;
+                }
+                DraftEvent::Nominated {
+                    user,
+                    item,
+                    closes_at,
+                } => {
+                    self.nominate(*user, item, *closes_at).map_err(invalid)?;
+                }
+                // bids and results do not depend on when they are replayed
+                DraftEvent::BidPlaced { user, amount } => {
+                    self.place_bid(*user, *amount).map_err(invalid)?;
+                }
+                DraftEvent::NominationSkipped => {
+                    self.skip_nominator().map_err(invalid)?;
+                }
+                DraftEvent::LotResolved => {
+                    if self.settle_lot().is_none() {
+                        return Err(LeagueError::InvalidEventError(idx));
+                    }
+                }
+                DraftEvent::WinstonDeckSet(items) => {
+                    let items = items.iter().map(|i| resolve(i)).collect::<Result<_, _>>()?;
+                    self.set_winston_deck(items).map_err(invalid)?;
+                }
+                DraftEvent::WinstonTook { user } => {
+                    self.winston_take(*user).map_err(invalid)?;
+                }
+                DraftEvent::WinstonPassed { user } => {
+                    self.winston_pass(*user).map_err(invalid)?;
+                }
+                DraftEvent::GridDeckSet(items) => {
+                    let items = items.iter().map(|i| resolve(i)).collect::<Result<_, _>>()?;
+                    self.set_grid_deck(items).map_err(invalid)?;
+                }
+                DraftEvent::GridTook { user, line } => {
+                    self.grid_take(*user, *line).map_err(invalid)?;
+                }
+                DraftEvent::SolomonDeckSet { items, reveal } => {
+                    let items = items.iter().map(|i| resolve(i)).collect::<Result<_, _>>()?;
+                    self.set_solomon_deck(items, *reveal).map_err(invalid)?;
+                }
+                DraftEvent::SolomonSplit { user, pile } => {
+                    let pile: Vec<&str> = pile.iter().map(String::as_str).collect();
+                    self.solomon_split(*user, &pile).map_err(invalid)?;
+                }
+                DraftEvent::SolomonChosen { user, pile } => {
+                    self.solomon_choose(*user, *pile).map_err(invalid)?;
+                }
+                DraftEvent::PickOwed { user } => {
+                    self.owe_pick(*user).map_err(invalid)?;
+                }
+                DraftEvent::OwedPicked { user, item } => {
+                    self.lock_owed(*user, resolve(item)?)
+                        .map_err(|rejected| invalid(rejected.error))?;
+                }
+            }
+        }
+        Ok(())
+    }
+    /// Reverts every pick after overall pick number pick, e.g. when a dispute is discovered several picks later,
+    /// and puts the seat that made pick + 1 back on the clock. Pick numbers count every pick in the draft, starting at 1,
+    /// so `rewind_to_pick(0)` restarts the draft from the first pick.
+    ///
+    /// Reverted items are taken from whoever holds them now and go back to the [`DraftPool`] (at the bottom of the rankings)
+    /// or, in a Rochester draft, the face-up pack. Nothing is put back in anyone's queue. Skipped picks are reverted too,
+    /// along with any pick they left owed (see [`SkipPolicy::PickLaterAnyTime`](crate::SkipPolicy::PickLaterAnyTime)).
+    /// A finished draft starts drafting again.
+    ///
+    /// Returns the reverted picks, in the order they were made, in the same form as [`League::lock`].
+    ///
+    /// # Errors
+    ///
+    /// If the draft has not started, or the League is archived, returns [`LeagueError::WrongPhaseError`].
+    ///
+    /// If pick has not been made yet, or was made before a seat was removed (see [`ForfeitPolicy::RemoveSeat`]),
+    /// returns [`LeagueError::InvalidPickNumberError`]. Picks are counted from [`League::events`],
+    /// so a League restored from a snapshot can only rewind to picks made since.
+    pub fn rewind_to_pick(
+        &mut self,
+        pick: u32,
+    ) -> Result<Vec<(serenity::UserId, String)>, LeagueError> {
+        match self.phase {
+            LeaguePhase::Drafting | LeaguePhase::Paused | LeaguePhase::PostDraft => {}
+            _ => return Err(LeagueError::WrongPhaseError),
+        }
+        let (mut made, earliest) = self.pick_history();
+        if (pick as usize) < earliest || pick as usize > made.len() {
+            return Err(LeagueError::InvalidPickNumberError);
+        }
+        if pick as usize == made.len() {
+            return Ok(Vec::new());
+        }
+        let reverted: Vec<(serenity::UserId, String)> = made
+            .split_off(pick as usize)
+            .into_iter()
+            .flatten()
+            .map(|(user, item)| (user, item.to_string()))
+            .collect();
+        for (_, name) in &reverted {
+            let holder = self.holders(name).first().copied();
+            let Some(item) = holder
+                .and_then(|holder| self.seat_of(holder).ok())
+                .and_then(|seat| self.take_pick(seat, name))
+            else {
+                // traded or waivered back into the pool since
+                continue;
+            };
+            if let Some(pool) = &mut self.pool {
+                pool.restore(item);
+            } else if let Some(packs) = &mut self.packs {
+                packs.revealed.push(item);
+            }
+            self.release_shared(name);
+        }
+        self.total_picks = pick;
+        self.current_seat = self.seat_at(pick);
+        self.pick_times.retain(|(number, _)| *number <= pick);
+        if self.phase == LeaguePhase::PostDraft {
+            self.phase = LeaguePhase::Drafting;
+            self.summary = None;
+            self.track_phase();
+        }
+        if self.phase == LeaguePhase::Drafting {
+            self.clock.start(Utc::now());
+        }
+        self.forgive_owed_since(pick);
+        self.record(DraftEvent::Rewound(pick));
+        Ok(reverted)
+    }
+    // every pick still standing in the order they were made, None for skips, and the first pick number that can be
+    // rewound to, since the order before a seat was removed no longer exists
+    pub(crate) fn pick_history(&self) -> (Vec<Option<(serenity::UserId, &str)>>, usize) {
+        let mut made = Vec::new();
+        let mut earliest = 0;
+        for event in &self.events {
+            match event {
+                DraftEvent::Picked { user, item } => made.push(Some((*user, item.as_str()))),
+                DraftEvent::Skipped => made.push(None),
+                DraftEvent::Rewound(pick) => made.truncate(*pick as usize),
+                DraftEvent::Forfeited {
+                    policy: ForfeitPolicy::RemoveSeat,
+                    ..
+                } => earliest = made.len(),
+                _ => {}
+            }
+        }
+        (made, earliest)
+    }
+    // the original owner of the seat a user acts for, which is how events name players
+    pub(crate) fn seat_owner(&self, id: serenity::UserId) -> serenity::UserId {
+        self.get_player(id).map(|p| p.id).unwrap_or(id)
+    }
+}
+
+#[cfg(test)]
+mod event_tests {
+    use super::*;
+    use crate::test_support::{new_league, pokemon, resolve};
+    use crate::DraftType;
+
+    fn league() -> League {
+        new_league(2, DraftType::Snake, 2)
+    }
+
+    #[test]
+    fn subscribers_hear_every_event() {
+        let mut league = league();
+        let mut first = league.subscribe();
+        league.activate().unwrap();
+        let mut second = league.subscribe();
+        league.lock(pokemon("Charizard")).unwrap();
+        assert_eq!(
+            first.try_recv().unwrap(),
+            DraftEvent::PhaseChanged(LeaguePhase::Drafting)
+        );
+        for receiver in [&mut first, &mut second] {
+            assert_eq!(
+                receiver.try_recv().unwrap(),
+                DraftEvent::Picked {
+                    user: serenity::UserId(1),
+                    item: "Charizard".to_string()
+                }
+            );
+            assert!(receiver.try_recv().is_err());
+        }
+    }
+
+    #[test]
+    fn changes_since_catches_up_after_a_pick() {
+        let mut league = league();
+        league.activate().unwrap();
+        league.lock(pokemon("Charizard")).unwrap();
+        league.lock(pokemon("Blastoise")).unwrap();
+        let since = league.changes_since(1).unwrap();
+        assert_eq!(
+            since,
+            &[DraftEvent::Picked {
+                user: serenity::UserId(2),
+                item: "Blastoise".to_string()
+            }]
+        );
+        assert_eq!(league.changes_since(0).unwrap(), league.events());
+        match league.changes_since(3) {
+            Err(LeagueError::InvalidPickNumberError) => {}
+            _ => panic!("pick 3 has not been made"),
+        }
+        league.rewind_to_pick(0).unwrap();
+        league.lock(pokemon("Pikachu")).unwrap();
+        assert_eq!(league.changes_since(1).unwrap(), &[]);
+        match league.changes_since(2) {
+            Err(LeagueError::InvalidPickNumberError) => {}
+            _ => panic!("pick 2 was reverted"),
+        }
+    }
+
+    #[test]
+    fn replay_rebuilds_the_board() {
+        let mut original = league();
+        let pool = ["Charizard", "Blastoise", "Venusaur", "Pikachu"]
+            .iter()
+            .map(|n| pokemon(n))
+            .collect();
+        original.set_pool(DraftPool::new(pool)).unwrap();
+        original.activate().unwrap();
+        original
+            .add_to_player_queue(serenity::UserId(2), pokemon("Venusaur"))
+            .unwrap();
+        original.set_autopick(serenity::UserId(1), true).unwrap();
+        original.skip().unwrap();
+        original.lock(pokemon("Blastoise")).unwrap();
+
+        let mut replayed = league();
+        replayed.replay(original.events(), resolve).unwrap();
+        assert_eq!(replayed.events(), original.events());
+        assert_eq!(replayed.phase(), LeaguePhase::PostDraft);
+        for id in [serenity::UserId(1), serenity::UserId(2)] {
+            let names = |l: &mut League| -> Vec<String> {
+                l.player_picks(id)
+                    .unwrap()
+                    .iter()
+                    .map(|p| p.name().to_string())
+                    .collect()
+            };
+            assert_eq!(names(&mut replayed), names(&mut original));
+        }
+    }
+
+    #[test]
+    fn picks_out_of_turn_are_invalid() {
+        let mut league = league();
+        let events = vec![
+            DraftEvent::PhaseChanged(LeaguePhase::Drafting),
+            DraftEvent::Picked {
+                user: serenity::UserId(2),
+                item: "Mew".to_string(),
+            },
+        ];
+        match league.replay(&events, resolve) {
+            Err(LeagueError::InvalidEventError(1)) => {}
+            _ => panic!("seat 1 is on the clock"),
+        }
+    }
+
+    #[test]
+    fn rewind_returns_picks_to_the_pool() {
+        let mut original = league();
+        let pool = ["Charizard", "Blastoise", "Venusaur", "Pikachu"]
+            .iter()
+            .map(|n| pokemon(n))
+            .collect();
+        original.set_pool(DraftPool::new(pool)).unwrap();
+        original.activate().unwrap();
+        for name in ["Charizard", "Blastoise", "Venusaur", "Pikachu"] {
+            original.lock(pokemon(name)).unwrap();
+        }
+        assert_eq!(original.phase(), LeaguePhase::PostDraft);
+        match original.rewind_to_pick(5) {
+            Err(LeagueError::InvalidPickNumberError) => {}
+            _ => panic!("there are only 4 picks"),
+        }
+
+        let reverted = original.rewind_to_pick(1).unwrap();
+        assert_eq!(
+            reverted,
+            vec![
+                (serenity::UserId(2), "Blastoise".to_string()),
+                (serenity::UserId(2), "Venusaur".to_string()),
+                (serenity::UserId(1), "Pikachu".to_string()),
+            ]
+        );
+        assert_eq!(original.phase(), LeaguePhase::Drafting);
+        assert_eq!(original.current_player().unwrap().id, serenity::UserId(2));
+        assert_eq!(original.pool().unwrap().len(), 3);
+        match original.player_picks(serenity::UserId(2)) {
+            Err(LeagueError::PlayerPicksEmptyError) => {}
+            _ => panic!("seat 2's picks were reverted"),
+        }
+
+        original.lock(pokemon("Pikachu")).unwrap();
+        let mut replayed = league();
+        replayed.replay(original.events(), resolve).unwrap();
+        assert_eq!(replayed.current_player().unwrap().id, serenity::UserId(2));
+        assert_eq!(replayed.pool().unwrap().len(), 2);
+    }
+}
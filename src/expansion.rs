@@ -0,0 +1,364 @@
+use crate::{fuzzy, ActivePlayer, DraftEvent, Draftable, League, LeagueError, LeaguePhase};
+use poise::serenity_prelude as serenity;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// An expansion draft underway in a League, started with [`League::start_expansion`].
+#[derive(Clone, Serialize, Deserialize)]
+pub(crate) struct Expansion {
+    entrants: Vec<serenity::UserId>,
+    // how many items each existing seat may protect, and how many each entrant picks
+    protect: u32,
+    picks: u32,
+    // k: existing seat's owner, v: the items it protected
+    lists: HashMap<serenity::UserId, Vec<String>>,
+    open: bool,
+    made: u32,
+}
+
+impl Expansion {
+    fn on_the_clock(&self) -> Option<serenity::UserId> {
+        if !self.open || self.made >= self.picks * self.entrants.len() as u32 {
+            return None;
+        }
+        Some(self.entrants[(self.made % self.entrants.len() as u32) as usize])
+    }
+}
+
+impl League {
+    /// Starts an expansion draft between seasons: the entrants join the League with empty teams, every existing seat protects
+    /// up to protect of its items (see [`League::protect_items`]), and once [`League::open_expansion`] checks the lists,
+    /// the entrants take turns picking picks items each from what was left unprotected.
+    ///
+    /// # Errors
+    ///
+    /// If the draft is not over, returns [`LeagueError::WrongPhaseError`].
+    ///
+    /// If an expansion draft is already underway, returns [`LeagueError::ExpansionUnderwayError`].
+    ///
+    /// If there are no entrants, returns [`LeagueError::NotEnoughPlayersError`].
+    ///
+    /// If an entrant is already in the league, returns [`LeagueError::PlayerAlreadyInLeagueError`].
+    pub fn start_expansion(
+        &mut self,
+        entrants: &[serenity::UserId],
+        protect: u32,
+        picks: u32,
+    ) -> Result<(), LeagueError> {
+        if self.phase != LeaguePhase::PostDraft {
+            return Err(LeagueError::WrongPhaseError);
+        }
+        if self.expansion.is_some() {
+            return Err(LeagueError::ExpansionUnderwayError);
+        }
+        if entrants.is_empty() {
+            return Err(LeagueError::NotEnoughPlayersError);
+        }
+        for (idx, entrant) in entrants.iter().enumerate() {
+            if self.get_player(*entrant).is_some() || entrants[..idx].contains(entrant) {
+                return Err(LeagueError::PlayerAlreadyInLeagueError);
+            }
+        }
+        self.players
+            .extend(entrants.iter().map(|id| ActivePlayer::new(*id)));
+        self.expansion = Some(Expansion {
+            entrants: entrants.to_vec(),
+            protect,
+            picks,
+            lists: HashMap::new(),
+            open: false,
+            made: 0,
+        });
+        self.record(DraftEvent::ExpansionStarted {
+            entrants: entrants.to_vec(),
+            protect,
+            picks,
+        });
+        Ok(())
+    }
+    /// Submits a seat's protection list for the expansion draft, replacing any it submitted before.
+    /// An empty list is a valid submission that protects nothing.
+    ///
+    /// # Errors
+    ///
+    /// If no expansion draft is underway, or its picking has started, returns [`LeagueError::WrongPhaseError`].
+    ///
+    /// If the player is not in the league, returns [`LeagueError::PlayerNotFoundError`].
+    ///
+    /// If the player is one of the entrants, returns [`LeagueError::PermissionDeniedError`].
+    ///
+    /// If the list is longer than the expansion allows, returns [`LeagueError::TooManyProtectedError`].
+    ///
+    /// If the seat does not hold one of the items, returns [`LeagueError::DraftableNotFoundError`] with the closest names it does hold.
+    pub fn protect_items(
+        &mut self,
+        id: serenity::UserId,
+        items: &[&str],
+    ) -> Result<(), LeagueError> {
+        let expansion = match &self.expansion {
+            Some(expansion) if !expansion.open => expansion,
+            _ => return Err(LeagueError::WrongPhaseError),
+        };
+        let player = self
+            .get_player(id)
+            .ok_or(LeagueError::PlayerNotFoundError)?;
+        if expansion.entrants.contains(&player.id) {
+            return Err(LeagueError::PermissionDeniedError);
+        }
+        let mut list: Vec<String> = Vec::new();
+        for name in items {
+            let name = &self.resolve_name(name);
+            if !self.holders(name).contains(&player.id) {
+                return Err(fuzzy::not_found(name, &player.picks));
+            }
+            if !list.iter().any(|l| l == name) {
+                list.push(name.to_string());
+            }
+        }
+        if list.len() as u32 > expansion.protect {
+            return Err(LeagueError::TooManyProtectedError);
+        }
+        let user = player.id;
+        self.record(DraftEvent::Protected {
+            user,
+            items: list.clone(),
+        });
+        if let Some(expansion) = &mut self.expansion {
+            expansion.lists.insert(user, list);
+        }
+        Ok(())
+    }
+    /// Returns the items a seat has protected, or None if it has not submitted a list yet.
+    ///
+    /// # Errors
+    ///
+    /// If no expansion draft is underway, returns [`LeagueError::WrongPhaseError`].
+    ///
+    /// If the player is not in the league, returns [`LeagueError::PlayerNotFoundError`].
+    pub fn protected_items(&self, id: serenity::UserId) -> Result<Option<&[String]>, LeagueError> {
+        let expansion = self
+            .expansion
+            .as_ref()
+            .ok_or(LeagueError::WrongPhaseError)?;
+        let player = self
+            .get_player(id)
+            .ok_or(LeagueError::PlayerNotFoundError)?;
+        Ok(expansion.lists.get(&player.id).map(Vec::as_slice))
+    }
+    /// Returns the existing seats that have not submitted a protection list yet, by their original owner.
+    /// Empty when no expansion draft is underway.
+    pub fn missing_protection_lists(&self) -> Vec<serenity::UserId> {
+        let Some(expansion) = &self.expansion else {
+            return Vec::new();
+        };
+        self.players
+            .iter()
+            .map(|p| p.id)
+            .filter(|id| !expansion.entrants.contains(id) && !expansion.lists.contains_key(id))
+            .collect()
+    }
+    /// Returns every item the entrants may pick, with the seat that holds it, in seat order.
+    /// Empty when no expansion draft is underway.
+    pub fn unprotected_items(&self) -> Vec<(serenity::UserId, &Draftable)> {
+        let Some(expansion) = &self.expansion else {
+            return Vec::new();
+        };
+        self.players
+            .iter()
+            .filter(|p| !expansion.entrants.contains(&p.id))
+            .flat_map(|p| {
+                let protected = expansion.lists.get(&p.id);
+                p.picks
+                    .iter()
+                    .filter(move |i| protected.is_none_or(|l| !l.iter().any(|n| n == i.name())))
+                    .map(move |i| (p.id, i))
+            })
+            .collect()
+    }
+    /// Checks every protection list is in and opens the expansion draft for picking, with the first entrant on the clock.
+    ///
+    /// # Errors
+    ///
+    /// If no expansion draft is underway, or it is already open, returns [`LeagueError::WrongPhaseError`].
+    ///
+    /// If a seat has not submitted its protection list, returns [`LeagueError::ProtectionListMissingError`] with its owner.
+    ///
+    /// If too few items are unprotected for every entrant to make all their picks, returns [`LeagueError::NotEnoughItemsError`].
+    pub fn open_expansion(&mut self) -> Result<(), LeagueError> {
+        let expansion = match &self.expansion {
+            Some(expansion) if !expansion.open => expansion,
+            _ => return Err(LeagueError::WrongPhaseError),
+        };
+        if let Some(missing) = self.missing_protection_lists().first() {
+            return Err(LeagueError::ProtectionListMissingError(*missing));
+        }
+        let needed = (expansion.picks * expansion.entrants.len() as u32) as usize;
+        if self.unprotected_items().len() < needed {
+            return Err(LeagueError::NotEnoughItemsError);
+        }
+        if let Some(expansion) = &mut self.expansion {
+            expansion.open = true;
+        }
+        self.record(DraftEvent::ExpansionOpened);
+        self.finish_expansion();
+        Ok(())
+    }
+    /// Returns the entrant whose turn it is to pick, or None if the expansion draft is not open or is over.
+    pub fn expansion_on_the_clock(&self) -> Option<serenity::UserId> {
+        self.expansion.as_ref()?.on_the_clock()
+    }
+    /// Picks an unprotected item for the entrant on the clock, taking it from the seat that held it,
+    /// and returns that seat's owner with the item's name. Entrants pick in turn, in the order they were given to
+    /// [`League::start_expansion`]; the expansion draft ends once every entrant has made their picks.
+    ///
+    /// # Errors
+    ///
+    /// If the expansion draft is not open, returns [`LeagueError::WrongPhaseError`].
+    ///
+    /// If the user does not own the seat on the clock, returns [`LeagueError::NotYourTurnError`].
+    ///
+    /// If the item is protected, returns [`LeagueError::DraftableProtectedError`].
+    ///
+    /// If no existing seat holds the item, returns [`LeagueError::DraftableNotFoundError`] with the closest unprotected names.
+    pub fn expansion_pick(
+        &mut self,
+        id: serenity::UserId,
+        item: &str,
+    ) -> Result<(serenity::UserId, String), LeagueError> {
+        let item = &self.resolve_name(item).to_string();
+        let on_the_clock = self
+            .expansion_on_the_clock()
+            .ok_or(LeagueError::WrongPhaseError)?;
+        let picker = self.seat_of(id)?;
+        if self.players[picker as usize].id != on_the_clock {
+            return Err(LeagueError::NotYourTurnError);
+        }
+        let Some(from) = self
+            .unprotected_items()
+            .iter()
+            .find(|(_, i)| i.name() == item)
+            .map(|(owner, _)| *owner)
+        else {
+            let protected = self
+                .expansion
+                .iter()
+                .flat_map(|e| e.lists.values())
+                .any(|l| l.iter().any(|n| n == item));
+            if protected {
+                return Err(LeagueError::DraftableProtectedError);
+            }
+            let unprotected = self.unprotected_items();
+            return Err(fuzzy::not_found(item, unprotected.iter().map(|(_, i)| *i)));
+        };
+        let taken = self
+            .seat_of(from)
+            .ok()
+            .and_then(|seat| self.take_pick(seat, item))
+            .ok_or(LeagueError::DraftableNotFoundError(Vec::new()))?;
+        self.give_pick(picker, taken);
+        if let Some(expansion) = &mut self.expansion {
+            expansion.made += 1;
+        }
+        self.record(DraftEvent::ExpansionPicked {
+            user: on_the_clock,
+            item: item.to_string(),
+        });
+        self.finish_expansion();
+        Ok((from, item.to_string()))
+    }
+    // an expansion with every pick made is over
+    fn finish_expansion(&mut self) {
+        if self
+            .expansion
+            .as_ref()
+            .is_some_and(|e| e.made >= e.picks * e.entrants.len() as u32)
+        {
+            self.expansion = None;
+        }
+    }
+}
+
+#[cfg(test)]
+mod expansion_tests {
+    use super::*;
+    use crate::test_support::{new_league, pokemon, resolve};
+    use crate::DraftType;
+
+    // seat 1 has Pikachu and Eevee, seat 2 has Charizard and Mew
+    fn league() -> League {
+        let mut league = new_league(2, DraftType::Snake, 2);
+        league.activate().unwrap();
+        for name in ["Pikachu", "Charizard", "Mew", "Eevee"] {
+            league.lock(pokemon(name)).unwrap();
+        }
+        league
+    }
+
+    #[test]
+    fn entrants_pick_unprotected_items() {
+        let mut league = league();
+        league
+            .start_expansion(&[serenity::UserId(3)], 1, 2)
+            .unwrap();
+        match league.protect_items(serenity::UserId(1), &["Pikachu", "Eevee"]) {
+            Err(LeagueError::TooManyProtectedError) => {}
+            _ => panic!("seats protect one item"),
+        }
+        league
+            .protect_items(serenity::UserId(1), &["Pikachu"])
+            .unwrap();
+        match league.open_expansion() {
+            Err(LeagueError::ProtectionListMissingError(serenity::UserId(2))) => {}
+            _ => panic!("seat 2 has not protected anything"),
+        }
+        league
+            .protect_items(serenity::UserId(2), &["Charizard"])
+            .unwrap();
+        assert_eq!(league.unprotected_items().len(), 2);
+        league.open_expansion().unwrap();
+        match league.expansion_pick(serenity::UserId(3), "Pikachu") {
+            Err(LeagueError::DraftableProtectedError) => {}
+            _ => panic!("Pikachu is protected"),
+        }
+        match league.expansion_pick(serenity::UserId(1), "Eevee") {
+            Err(LeagueError::NotYourTurnError) => {}
+            _ => panic!("only entrants pick"),
+        }
+        assert_eq!(
+            league.expansion_pick(serenity::UserId(3), "Mew").unwrap(),
+            (serenity::UserId(2), "Mew".to_string())
+        );
+        league.expansion_pick(serenity::UserId(3), "Eevee").unwrap();
+        assert_eq!(league.expansion_on_the_clock(), None);
+        let picks: Vec<String> = league
+            .player_picks(serenity::UserId(3))
+            .unwrap()
+            .iter()
+            .map(|p| p.name().to_string())
+            .collect();
+        assert_eq!(picks, vec!["Mew", "Eevee"]);
+
+        let mut replayed = new_league(2, DraftType::Snake, 2);
+        replayed.replay(league.events(), resolve).unwrap();
+        assert_eq!(replayed.events(), league.events());
+        assert_eq!(replayed.player_picks(serenity::UserId(3)).unwrap().len(), 2);
+    }
+
+    #[test]
+    fn expansions_need_enough_unprotected_items() {
+        let mut league = league();
+        league
+            .start_expansion(&[serenity::UserId(3)], 2, 1)
+            .unwrap();
+        league
+            .protect_items(serenity::UserId(1), &["Pikachu", "Eevee"])
+            .unwrap();
+        league
+            .protect_items(serenity::UserId(2), &["Charizard", "Mew"])
+            .unwrap();
+        match league.open_expansion() {
+            Err(LeagueError::NotEnoughItemsError) => {}
+            _ => panic!("everything is protected"),
+        }
+    }
+}
@@ -0,0 +1,243 @@
+use crate::{DraftEvent, League, LeagueError, LeaguePhase, PickResult};
+use chrono::Utc;
+use poise::serenity_prelude as serenity;
+use serde::{Deserialize, Serialize};
+
+/// What happens to a seat whose player quits mid-draft, given to [`League::forfeit_player`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ForfeitPolicy {
+    /// The seat leaves the table. Its picks go back to the [`DraftPool`](crate::DraftPool), and the rest of the draft
+    /// runs in the same order without its turns.
+    RemoveSeat,
+    /// The seat stays at the table with the picks it has, and its remaining turns are skipped.
+    Skip,
+    /// The seat stays at the table, and its remaining turns are picked from its queue, then by the League's
+    /// [`AutopickStrategy`](crate::AutopickStrategy). Turns with nothing to autopick are skipped.
+    Autopick,
+}
+
+// stands in the draft order for the picks made by seats removed since
+pub(crate) const REMOVED_SEAT: u32 = u32::MAX;
+
+impl League {
+    /// Deals with a player who quits mid-draft, so the draft does not stall forever on their turns.
+    ///
+    /// If the seat is on the clock while the League is drafting, its turn is dealt with straight away, and any picks locked in
+    /// as the draft moves on are returned in the same form as [`League::lock`]. While the League is paused, a forfeited seat
+    /// left on the clock keeps it until [`League::skip`].
+    ///
+    /// # Errors
+    ///
+    /// If the League is not drafting or paused, returns [`LeagueError::WrongPhaseError`].
+    ///
+    /// If the player is not in the league, returns [`LeagueError::PlayerNotFoundError`].
+    ///
+    /// If the player has the only seat left and the policy is [`ForfeitPolicy::RemoveSeat`], returns [`LeagueError::LastSeatError`].
+    pub fn forfeit_player(
+        &mut self,
+        id: serenity::UserId,
+        policy: ForfeitPolicy,
+    ) -> Result<Vec<PickResult>, LeagueError> {
+        match self.phase {
+            LeaguePhase::Drafting | LeaguePhase::Paused => {}
+            _ => return Err(LeagueError::WrongPhaseError),
+        }
+        let seat = self.seat_of(id)?;
+        let on_the_clock = seat == self.current_seat;
+        self.forfeit_seat(seat, policy)?;
+        if !on_the_clock || self.phase != LeaguePhase::Drafting {
+            return Ok(Vec::new());
+        }
+        let (picks, _) = self.settle_private(Vec::new());
+        crate::metrics::picks_made(picks.len());
+        Ok(picks)
+    }
+    // changes the seat without dealing with the turn on the clock, so replays can take the picks that followed from the log
+    pub(crate) fn forfeit_seat(
+        &mut self,
+        seat: u32,
+        policy: ForfeitPolicy,
+    ) -> Result<(), LeagueError> {
+        let user = self.players[seat as usize].id;
+        match policy {
+            ForfeitPolicy::Skip => {
+                let player = &mut self.players[seat as usize];
+                player.forfeited = true;
+                player.autopick = false;
+                self.drain_queue(seat);
+            }
+            ForfeitPolicy::Autopick => {
+                let player = &mut self.players[seat as usize];
+                player.forfeited = true;
+                player.autopick = true;
+            }
+            ForfeitPolicy::RemoveSeat => self.remove_seat(seat)?,
+        }
+        self.record(DraftEvent::Forfeited { user, policy });
+        Ok(())
+    }
+    fn remove_seat(&mut self, seat: u32) -> Result<(), LeagueError> {
+        if self.players.len() == 1 {
+            return Err(LeagueError::LastSeatError);
+        }
+        let shift = |s: u32| match s {
+            REMOVED_SEAT => REMOVED_SEAT,
+            s if s == seat => REMOVED_SEAT,
+            s if s > seat => s - 1,
+            s => s,
+        };
+        // picks already made keep their numbers, and the seat's turns still to come drop out
+        let mut order: Vec<u32> = (0..self.total_picks)
+            .map(|pick| shift(self.seat_at(pick)))
+            .collect();
+        order.extend(
+            (self.total_picks..=self.final_pick)
+                .map(|pick| self.seat_at(pick))
+                .filter(|s| *s != seat)
+                .map(shift),
+        );
+        let removed = self.players.remove(seat as usize);
+        let returned: Vec<String> = removed.picks.iter().map(|i| i.name().to_string()).collect();
+        if let Some(pool) = &mut self.pool {
+            for item in removed.picks {
+                pool.restore(item);
+            }
+        }
+        self.reindex();
+        for name in &returned {
+            self.release_shared(name);
+        }
+        self.final_pick = (order.len() as u32).saturating_sub(1);
+        if order.len() as u32 <= self.total_picks {
+            // nobody else had picks left
+            self.order = Some(order);
+            self.finish_draft();
+            return Ok(());
+        }
+        let was_on_the_clock = seat == self.current_seat;
+        self.current_seat = order[self.total_picks as usize];
+        self.order = Some(order);
+        if was_on_the_clock && self.phase == LeaguePhase::Drafting {
+            self.clock.start(Utc::now());
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod forfeit_tests {
+    use super::*;
+    use crate::test_support::{new_league, pokemon};
+    use crate::{DraftPool, DraftType};
+
+    // seats pick A B C C B A
+    fn league() -> League {
+        let mut league = new_league(3, DraftType::Snake, 2);
+        let pool = (1..=6).map(|n| pokemon(&format!("Pokemon {n}"))).collect();
+        league.set_pool(DraftPool::new(pool)).unwrap();
+        league.activate().unwrap();
+        league
+    }
+
+    fn on_the_clock(league: &League) -> serenity::UserId {
+        league.current_player().unwrap().id
+    }
+
+    #[test]
+    fn skipped_seats_never_stall_the_draft() {
+        let mut league = league();
+        league.lock(pokemon("Pokemon 1")).unwrap();
+        let picks = league
+            .forfeit_player(serenity::UserId(2), ForfeitPolicy::Skip)
+            .unwrap();
+        assert!(picks.is_empty());
+        assert_eq!(on_the_clock(&league), serenity::UserId(3));
+        league.lock(pokemon("Pokemon 2")).unwrap();
+        league.lock(pokemon("Pokemon 3")).unwrap();
+        assert_eq!(on_the_clock(&league), serenity::UserId(1));
+        assert_eq!(
+            league.events().last(),
+            Some(&DraftEvent::Skipped),
+            "seat 2's second turn was skipped"
+        );
+    }
+
+    #[test]
+    fn autopicked_seats_pick_from_the_pool() {
+        let mut league = league();
+        league.lock(pokemon("Pokemon 1")).unwrap();
+        let picks = league
+            .forfeit_player(serenity::UserId(2), ForfeitPolicy::Autopick)
+            .unwrap();
+        assert_eq!(picks.len(), 1);
+        assert_eq!(picks[0].user, serenity::UserId(2));
+        assert_eq!(picks[0].item, "Pokemon 2");
+        assert_eq!(on_the_clock(&league), serenity::UserId(3));
+    }
+
+    #[test]
+    fn removed_seats_leave_the_order() {
+        let mut league = league();
+        league.lock(pokemon("Pokemon 1")).unwrap();
+        league.lock(pokemon("Pokemon 2")).unwrap();
+        league
+            .forfeit_player(serenity::UserId(2), ForfeitPolicy::RemoveSeat)
+            .unwrap();
+        assert!(league.pool().unwrap().contains("Pokemon 2"));
+        match league.current_player() {
+            Ok(player) => assert_eq!(player.id, serenity::UserId(3)),
+            Err(_) => panic!("seat 3 is next"),
+        }
+        league.lock(pokemon("Pokemon 3")).unwrap();
+        league.lock(pokemon("Pokemon 4")).unwrap();
+        assert_eq!(on_the_clock(&league), serenity::UserId(1));
+        league.lock(pokemon("Pokemon 5")).unwrap();
+        assert_eq!(league.phase(), LeaguePhase::PostDraft);
+        match league.rewind_to_pick(1) {
+            Err(LeagueError::InvalidPickNumberError) => {}
+            _ => panic!("the order before the forfeit is gone"),
+        }
+
+        let mut replayed = new_league(3, DraftType::Snake, 2);
+        replayed
+            .replay(league.events(), |name| Some(pokemon(name)))
+            .unwrap();
+        assert_eq!(replayed.events(), league.events());
+        assert_eq!(replayed.phase(), LeaguePhase::PostDraft);
+    }
+
+    #[test]
+    fn long_runs_of_forfeited_turns_fit_on_a_worker_stack() {
+        // the stack of a tokio worker thread
+        let finished = std::thread::Builder::new()
+            .stack_size(2 * 1024 * 1024)
+            .spawn(|| {
+                let mut league = new_league(2, DraftType::Snake, 2000);
+                let pool = (0..2000)
+                    .map(|n| pokemon(&format!("Pokemon {n}")))
+                    .collect();
+                league.set_pool(DraftPool::new(pool)).unwrap();
+                league.activate().unwrap();
+                league.lock(pokemon("Pokemon 0")).unwrap();
+                league.set_autopick(serenity::UserId(1), true).unwrap();
+                let picks = league
+                    .forfeit_player(serenity::UserId(2), ForfeitPolicy::Skip)
+                    .unwrap();
+                (picks.len(), league.phase())
+            })
+            .unwrap()
+            .join()
+            .unwrap();
+        assert_eq!(finished, (1999, LeaguePhase::PostDraft));
+    }
+
+    #[test]
+    fn last_seat_cannot_be_removed() {
+        let mut league = new_league(1, DraftType::Snake, 2);
+        league.activate().unwrap();
+        match league.forfeit_player(serenity::UserId(1), ForfeitPolicy::RemoveSeat) {
+            Err(LeagueError::LastSeatError) => {}
+            _ => panic!("someone has to draft"),
+        }
+    }
+}
@@ -0,0 +1,334 @@
+use crate::{ActivePlayer, League, LeagueError};
+use poise::serenity_prelude as serenity;
+use std::collections::HashMap;
+
+/// The most characters Discord allows in one message.
+pub const MESSAGE_LIMIT: usize = 2000;
+
+// widest a column of the board gets before names are cut short
+const COLUMN_WIDTH: usize = 18;
+
+/// Splits text into messages Discord will accept, breaking between lines wherever possible.
+/// A code block cut in two is closed at the end of one message and reopened at the start of the next,
+/// so each message renders on its own. Lines too long for a message by themselves are cut.
+pub fn split_message(text: &str) -> Vec<String> {
+    split_text(text, MESSAGE_LIMIT)
+}
+
+// split_message with any limit, e.g. for pages that leave room for a footer
+pub(crate) fn split_text(text: &str, limit: usize) -> Vec<String> {
+    let mut messages = Vec::new();
+    let mut current = String::new();
+    // the line that opened the code block we are in, if any
+    let mut fence: Option<&str> = None;
+    for line in text.lines() {
+        let room = limit - fence.map_or(0, |f| f.len() + 5);
+        for piece in cut(line, room) {
+            let closing = if fence.is_some() { 4 } else { 0 };
+            if !current.is_empty() && current.len() + 1 + piece.len() + closing > limit {
+                if fence.is_some() {
+                    current.push_str("\n```");
+                }
+                messages.push(std::mem::take(&mut current));
+                if let Some(fence) = fence {
+                    current.push_str(fence);
+                }
+            }
+            if !current.is_empty() {
+                current.push('\n');
+            }
+            current.push_str(piece);
+        }
+        if line.trim_start().starts_with("```") {
+            fence = match fence {
+                Some(_) => None,
+                None => Some(line.trim()),
+            };
+        }
+    }
+    if !current.is_empty() {
+        messages.push(current);
+    }
+    messages
+}
+
+// cuts a line into pieces of at most max bytes, on character boundaries
+fn cut(line: &str, max: usize) -> Vec<&str> {
+    let mut pieces = Vec::new();
+    let mut rest = line;
+    while rest.len() > max {
+        let mut end = max;
+        while !rest.is_char_boundary(end) {
+            end -= 1;
+        }
+        pieces.push(&rest[..end]);
+        rest = &rest[end..];
+    }
+    pieces.push(rest);
+    pieces
+}
+
+// pads text to a column's width, cutting it short with an ellipsis if it is too wide
+fn pad(text: &str, width: usize) -> String {
+    let length = text.chars().count();
+    if length > width {
+        let cut: String = text.chars().take(width - 1).collect();
+        return format!("{cut}…");
+    }
+    format!("{text}{}", " ".repeat(width - length))
+}
+
+impl League {
+    // each round's picks in seat order, None where a pick was skipped
+    pub(crate) fn board_grid(&self) -> Vec<Vec<Option<&str>>> {
+        let seats = self.players.len();
+        // a League whose lobby has emptied has no columns to put picks in
+        if seats == 0 {
+            return Vec::new();
+        }
+        let (made, _) = self.pick_history();
+        let mut board: Vec<Vec<Option<&str>>> = Vec::new();
+        // k: a seat in the draft order, v: the picks it has had so far, which is the round of its next one
+        let mut turns: HashMap<u32, usize> = HashMap::new();
+        for (pick, made) in made.into_iter().enumerate() {
+            let seat = self.seat_at(pick as u32);
+            // picks by a seat removed since have nowhere to go
+            if seat == crate::forfeit::REMOVED_SEAT {
+                continue;
+            }
+            let turn = turns.entry(seat).or_default();
+            let round = *turn;
+            *turn += 1;
+            if board.len() <= round {
+                board.resize(round + 1, vec![None; seats]);
+            }
+            let Some((user, item)) = made else {
+                continue;
+            };
+            if let Some(seat) = self.players.iter().position(|p| p.id == user) {
+                board[round][seat] = Some(item);
+            }
+        }
+        board
+    }
+    // how a seat is headed where mentions do not render
+    pub(crate) fn seat_heading(&self, seat: usize, player: &ActivePlayer) -> String {
+        match player.team() {
+            Some(team) => team.name().to_string(),
+            None => self.seat_label(seat),
+        }
+    }
+    pub(crate) fn seat_label(&self, seat: usize) -> String {
+        self.text("seat", &[("seat", (seat + 1).to_string())])
+    }
+    pub(crate) fn round_label(&self, round: usize) -> String {
+        self.text("board.round", &[("round", (round + 1).to_string())])
+    }
+    /// Builds the draft board as a code block, a column for each seat and a row for each round, split into as many
+    /// messages as it needs (see [`split_message`]). Seats are headed by their [`Team`](crate::Team) name, or "Seat 1",
+    /// "Seat 2"... and long names are cut short so columns line up.
+    ///
+    /// Picks are read from [`League::events`] the same way as [`League::round_picks`], and skipped picks leave their cell blank.
+    pub fn board_messages(&self) -> Vec<String> {
+        let seats = self.players.len();
+        let board: Vec<Vec<String>> = self
+            .board_grid()
+            .into_iter()
+            .map(|round| {
+                round
+                    .into_iter()
+                    .map(|i| i.unwrap_or_default().to_string())
+                    .collect()
+            })
+            .collect();
+        let rounds = board.len();
+        let headings: Vec<String> = self
+            .players
+            .iter()
+            .enumerate()
+            .map(|(seat, player)| self.seat_heading(seat, player))
+            .collect();
+        let widths: Vec<usize> = (0..seats)
+            .map(|seat| {
+                board
+                    .iter()
+                    .map(|row| row[seat].chars().count())
+                    .chain(std::iter::once(headings[seat].chars().count()))
+                    .max()
+                    .unwrap_or(0)
+                    .min(COLUMN_WIDTH)
+            })
+            .collect();
+        let rounds_width = self.round_label(rounds.saturating_sub(1)).chars().count();
+        let row = |label: String, cells: &[String]| {
+            let cells: Vec<String> = cells
+                .iter()
+                .zip(&widths)
+                .map(|(cell, width)| pad(cell, *width))
+                .collect();
+            format!("{} | {}", pad(&label, rounds_width), cells.join(" | "))
+                .trim_end()
+                .to_string()
+        };
+        let mut lines = vec![format!("**{}**", self.name), "```".to_string()];
+        lines.push(row(String::new(), &headings));
+        for (round, picks) in board.iter().enumerate() {
+            lines.push(row(self.round_label(round), picks));
+        }
+        lines.push("```".to_string());
+        split_message(&lines.join("\n"))
+    }
+    /// Builds a table of a player's picks in the order they were made, with each item's category and cost where it has one,
+    /// split into as many messages as it needs (see [`split_message`]).
+    ///
+    /// # Errors
+    ///
+    /// If there is no player with the given ID, returns a [`LeagueError::PlayerNotFoundError`].
+    pub fn roster_messages(&self, id: serenity::UserId) -> Result<Vec<String>, LeagueError> {
+        let player = self
+            .get_player(id)
+            .ok_or(LeagueError::PlayerNotFoundError)?;
+        let mut lines = vec![format!("**{}**", player.display_name())];
+        if player.picks.is_empty() {
+            lines.push(self.text("summary.no_picks", &[]));
+            return Ok(split_message(&lines.join("\n")));
+        }
+        let rows: Vec<[String; 4]> = player
+            .picks
+            .iter()
+            .enumerate()
+            .map(|(idx, item)| {
+                [
+                    (idx + 1).to_string(),
+                    item.name().to_string(),
+                    item.category().unwrap_or_default().to_string(),
+                    item.cost().map(|c| c.to_string()).unwrap_or_default(),
+                ]
+            })
+            .collect();
+        let headings = [
+            "#".to_string(),
+            self.text("roster.item", &[]),
+            self.text("roster.category", &[]),
+            self.text("roster.cost", &[]),
+        ];
+        // leave out category and cost columns nobody has anything in
+        let shown: Vec<usize> = (0..4)
+            .filter(|col| *col < 2 || rows.iter().any(|row| !row[*col].is_empty()))
+            .collect();
+        let widths: Vec<usize> = (0..4)
+            .map(|col| {
+                rows.iter()
+                    .chain(std::iter::once(&headings))
+                    .map(|row| row[col].chars().count())
+                    .max()
+                    .unwrap_or(0)
+            })
+            .collect();
+        let line = |row: &[String; 4]| {
+            let cells: Vec<String> = shown
+                .iter()
+                .map(|col| pad(&row[*col], widths[*col]))
+                .collect();
+            cells.join(" | ").trim_end().to_string()
+        };
+        lines.push("```".to_string());
+        lines.push(line(&headings));
+        lines.extend(rows.iter().map(line));
+        lines.push("```".to_string());
+        Ok(split_message(&lines.join("\n")))
+    }
+    /// Builds a numbered list of a player's queue, next up first, split into as many messages as it needs
+    /// (see [`split_message`]). Items are shown with their emoji, if any (see [`League::item_label`]).
+    ///
+    /// # Errors
+    ///
+    /// If there is no player with the given ID, returns a [`LeagueError::PlayerNotFoundError`].
+    pub fn queue_messages(&self, id: serenity::UserId) -> Result<Vec<String>, LeagueError> {
+        let player = self
+            .get_player(id)
+            .ok_or(LeagueError::PlayerNotFoundError)?;
+        let mut lines = vec![format!("**{}**", player.display_name())];
+        if player.queue.is_empty() {
+            lines.push(self.text("queue.empty", &[]));
+        }
+        lines.extend(
+            player
+                .queue
+                .iter()
+                .enumerate()
+                .map(|(idx, item)| format!("{}. {}", idx + 1, self.item_label(item.as_ref()))),
+        );
+        Ok(split_message(&lines.join("\n")))
+    }
+}
+
+#[cfg(test)]
+mod format_tests {
+    use super::*;
+    use crate::test_support::{new_league, pokemon};
+    use crate::DraftType;
+
+    #[test]
+    fn split_messages_reopen_code_blocks() {
+        let mut text = "**Board**\n```".to_string();
+        for n in 0..300 {
+            text.push_str(&format!("\nPokemon number {n}"));
+        }
+        text.push_str("\n```\ndone");
+        let messages = split_message(&text);
+        assert!(messages.len() > 1);
+        for message in &messages {
+            assert!(message.len() <= MESSAGE_LIMIT);
+            assert_eq!(message.matches("```").count() % 2, 0, "{message}");
+        }
+        assert!(messages[1].starts_with("```\nPokemon number"));
+        assert!(messages.last().unwrap().ends_with("```\ndone"));
+
+        let long = "a".repeat(MESSAGE_LIMIT * 2 + 1);
+        assert_eq!(split_message(&long).len(), 3);
+    }
+
+    #[test]
+    fn boards_line_up() {
+        let mut league = new_league(2, DraftType::Snake, 2);
+        league.activate().unwrap();
+        league.lock(pokemon("Pikachu")).unwrap();
+        league.lock(pokemon("Charizard")).unwrap();
+        league
+            .lock(pokemon("Fletchinder the Unreasonably Long Named"))
+            .unwrap();
+        let board = league.board_messages();
+        assert_eq!(
+            board,
+            vec![[
+                "**Creenis**",
+                "```",
+                "   | Seat 1  | Seat 2",
+                "R1 | Pikachu | Charizard",
+                "R2 |         | Fletchinder the U…",
+                "```",
+            ]
+            .join("\n")]
+        );
+        league
+            .add_to_player_queue(serenity::UserId(1), pokemon("Mew"))
+            .unwrap();
+        assert_eq!(
+            league.queue_messages(serenity::UserId(1)).unwrap(),
+            vec!["**<@1>**\n1. Mew".to_string()]
+        );
+        assert_eq!(
+            league.roster_messages(serenity::UserId(1)).unwrap(),
+            vec!["**<@1>**\n```\n# | Item\n1 | Pikachu\n```".to_string()]
+        );
+    }
+    #[test]
+    fn empty_lobbies_have_an_empty_board() {
+        let league = new_league(1, DraftType::Snake, 2).duplicate("Bofa".to_string(), 2, false);
+        assert!(league.board_grid().is_empty());
+        assert_eq!(league.rounds(), 0);
+        assert_eq!(league.latest_round_complete(), None);
+        league.board_messages();
+    }
+}
@@ -0,0 +1,112 @@
+// "did you mean" suggestions for names that matched nothing
+use crate::{Draftable, LeagueError};
+
+// the most names suggested at once
+const SUGGESTIONS: usize = 3;
+// how similar a name must be to be suggested, from 0 (nothing alike) to 1 (the same)
+const THRESHOLD: f64 = 0.8;
+
+// the candidates closest to name, best first, ignoring case
+pub(crate) fn closest<'a>(name: &str, candidates: impl Iterator<Item = &'a str>) -> Vec<String> {
+    let name: Vec<char> = name.to_lowercase().chars().collect();
+    let mut scored: Vec<(f64, &str)> = candidates
+        .map(|candidate| {
+            let lower: Vec<char> = candidate.to_lowercase().chars().collect();
+            (jaro_winkler(&name, &lower), candidate)
+        })
+        .filter(|(score, _)| *score >= THRESHOLD)
+        .collect();
+    // stable, so equally close names keep their order (e.g. pool rankings)
+    scored.sort_by(|a, b| b.0.total_cmp(&a.0));
+    let mut names: Vec<String> = Vec::new();
+    for (_, candidate) in scored {
+        if names.len() == SUGGESTIONS {
+            break;
+        }
+        if !names.iter().any(|n| n == candidate) {
+            names.push(candidate.to_string());
+        }
+    }
+    names
+}
+
+// the error for a name that is not among the items it was looked for in
+pub(crate) fn not_found<'a>(
+    name: &str,
+    items: impl IntoIterator<Item = &'a Draftable>,
+) -> LeagueError {
+    LeagueError::DraftableNotFoundError(closest(name, items.into_iter().map(|i| i.name())))
+}
+
+fn jaro_winkler(a: &[char], b: &[char]) -> f64 {
+    let jaro = jaro(a, b);
+    let prefix = a.iter().zip(b).take(4).take_while(|(x, y)| x == y).count();
+    jaro + prefix as f64 * 0.1 * (1.0 - jaro)
+}
+
+fn jaro(a: &[char], b: &[char]) -> f64 {
+    if a.is_empty() || b.is_empty() {
+        return if a.len() == b.len() { 1.0 } else { 0.0 };
+    }
+    let window = (a.len().max(b.len()) / 2).saturating_sub(1);
+    let mut b_matched = vec![false; b.len()];
+    let mut a_matches = Vec::new();
+    for (i, x) in a.iter().enumerate() {
+        let start = i.saturating_sub(window);
+        let end = (i + window + 1).min(b.len());
+        for j in start..end {
+            if !b_matched[j] && b[j] == *x {
+                b_matched[j] = true;
+                a_matches.push(*x);
+                break;
+            }
+        }
+    }
+    if a_matches.is_empty() {
+        return 0.0;
+    }
+    let b_matches = b
+        .iter()
+        .zip(&b_matched)
+        .filter(|(_, m)| **m)
+        .map(|(c, _)| *c);
+    let transpositions = a_matches
+        .iter()
+        .zip(b_matches)
+        .filter(|(x, y)| **x != *y)
+        .count();
+    let m = a_matches.len() as f64;
+    (m / a.len() as f64 + m / b.len() as f64 + (m - transpositions as f64 / 2.0) / m) / 3.0
+}
+
+#[cfg(test)]
+mod fuzzy_tests {
+    use super::*;
+    use crate::test_support::new_league;
+    use crate::{DraftPool, DraftType};
+
+    #[test]
+    fn typos_find_their_item() {
+        let pool = ["Eldegoss", "Eelektross", "Pikachu", "Excadrill"];
+        assert_eq!(closest("eldgoss", pool.into_iter()), ["Eldegoss"]);
+        assert_eq!(closest("Pikachu", pool.into_iter())[0], "Pikachu");
+        assert!(closest("Zzz", pool.into_iter()).is_empty());
+    }
+
+    #[test]
+    fn picks_suggest_the_pool() {
+        let mut league = new_league(1, DraftType::Snake, 1);
+        let pool = ["Eldegoss", "Gossifleur", "Pikachu"];
+        let pool: Vec<Draftable> = pool.iter().map(|n| Box::new(*n) as Draftable).collect();
+        league.set_pool(DraftPool::new(pool)).unwrap();
+        league.activate().unwrap();
+        let error = match league.lock(Box::new("Eldgoss")) {
+            Err(error @ LeagueError::DraftableNotFoundError(_)) => error,
+            _ => panic!("Eldgoss is a typo"),
+        };
+        assert_eq!(
+            league.error_text(&error),
+            "That item is not available. Did you mean Eldegoss?"
+        );
+    }
+}
@@ -0,0 +1,530 @@
+use crate::{League, LeagueError, MessageTemplate};
+use std::collections::HashMap;
+use std::sync::{Arc, OnceLock, PoisonError, RwLock};
+
+// every string DRFTR shows to users, by key, in English. Text in {braces} is filled in when the string is used.
+const DEFAULTS: &[(&str, &str)] = &[
+    ("template.pick", "{player} picked **{item}**{source}"),
+    ("template.on_the_clock", "{next_player} is on the clock."),
+    (
+        "template.turn_ping",
+        "{mentions} you're on the clock in **{league}** (round {round}, pick {pick}).",
+    ),
+    ("template.complete", "The **{league}** draft is complete!"),
+    ("source.manual", ""),
+    ("source.queue", " (auto-drafted from queue)"),
+    ("source.autopick", " (autopicked)"),
+    ("source.admin", " (picked by a commissioner)"),
+    ("summary.title", "{league} - Draft Results"),
+    ("summary.no_picks", "No picks"),
+    ("lobby.title", "{league} lobby"),
+    (
+        "lobby.description",
+        "{ready}/{players} players ready. The draft starts when everyone is ready.",
+    ),
+    ("lobby.ready", "ready"),
+    ("lobby.not_ready", "not ready"),
+    ("lobby.join_button", "Join"),
+    ("lobby.leave_button", "Leave"),
+    ("lobby.ready_button", "Ready"),
+    ("lobby.unready_button", "Not ready"),
+    ("lobby.seat_placeholder", "Pick your seat"),
+    ("seat", "Seat {seat}"),
+    ("board.round", "R{round}"),
+    ("roster.item", "Item"),
+    ("roster.category", "Category"),
+    ("roster.cost", "Cost"),
+    ("queue.empty", "Nothing queued"),
+    (
+        "trade.announcement",
+        "{user1} traded **{item1}** to {user2} for **{item2}**.",
+    ),
+    (
+        "waiver.announcement",
+        "{player} dropped **{dropped}** for **{claimed}**.",
+    ),
+    (
+        "error.PlayerNotFoundError",
+        "That player is not in this league.",
+    ),
+    (
+        "error.DraftableNotFoundError",
+        "That item is not available.",
+    ),
+    ("error.did_you_mean", "Did you mean {names}?"),
+    (
+        "error.DraftableInUseError",
+        "That item has already been picked.",
+    ),
+    ("error.PlayerPicksEmptyError", "No picks yet."),
+    ("error.PlayerQueueEmptyError", "Your queue is empty."),
+    ("error.LeagueActiveError", "The draft has already started."),
+    ("error.LeagueInactiveError", "The draft is not running."),
+    ("error.LeaguePausedError", "The draft is paused."),
+    ("error.NoPicksError", "Nobody has picked yet."),
+    (
+        "error.WrongDraftTypeError",
+        "This league's draft type does not allow that.",
+    ),
+    (
+        "error.DraftableNotInPackError",
+        "That item is not in the current pack.",
+    ),
+    (
+        "error.PackNotExhaustedError",
+        "The current pack still has items in it.",
+    ),
+    ("error.NoPacksRemainingError", "There are no packs left."),
+    (
+        "error.PlayerAlreadyInLeagueError",
+        "That player is already in this league.",
+    ),
+    ("error.InvalidSeatError", "That seat does not exist."),
+    (
+        "error.SeatTakenError",
+        "Someone else has already taken that seat.",
+    ),
+    (
+        "error.PermissionDeniedError",
+        "You are not allowed to do that.",
+    ),
+    (
+        "error.InvalidTeamNameError",
+        "That team name is not allowed.",
+    ),
+    (
+        "error.TeamNameTakenError",
+        "Another team already has that name.",
+    ),
+    (
+        "error.TeamNotFoundError",
+        "There is no team with that name.",
+    ),
+    (
+        "error.InvalidEmojiMappingError",
+        "Line {line} of the emoji map could not be read.",
+    ),
+    (
+        "error.InvalidPickNumberError",
+        "That pick number is not valid.",
+    ),
+    (
+        "error.InvalidTeamSizeError",
+        "Teams need at least one pick.",
+    ),
+    ("error.NoPoolError", "This league has no pool."),
+    (
+        "error.NoAutopickAvailableError",
+        "There is nothing to autopick.",
+    ),
+    (
+        "error.InvalidPhaseTransitionError",
+        "The league cannot go from {from} to {to}.",
+    ),
+    (
+        "error.WrongPhaseError",
+        "That cannot be done at this stage of the league.",
+    ),
+    (
+        "error.UnknownPlaceholderError",
+        "There is no {{placeholder}} placeholder.",
+    ),
+    (
+        "error.InvalidEventError",
+        "Event {event} does not fit the league.",
+    ),
+    ("error.LastSeatError", "The last seat cannot be removed."),
+    ("error.InvalidRoundError", "That round does not exist."),
+    ("error.SameSeatError", "A seat cannot play itself."),
+    (
+        "error.NotEnoughPlayersError",
+        "There are not enough players.",
+    ),
+    ("error.DraftableLockedError", "That item is locked."),
+    (
+        "error.InvalidDivisionNameError",
+        "That division name is not allowed.",
+    ),
+    (
+        "error.DivisionNotFoundError",
+        "There is no division with that name.",
+    ),
+    (
+        "error.PickConflictError",
+        "Another league picked that item first.",
+    ),
+    (
+        "error.StalePickError",
+        "The draft moved on before your pick arrived.",
+    ),
+    (
+        "error.ExpansionUnderwayError",
+        "An expansion draft is already underway.",
+    ),
+    (
+        "error.TooManyProtectedError",
+        "That is more items than you can protect.",
+    ),
+    (
+        "error.ProtectionListMissingError",
+        "{user} has not submitted a protection list.",
+    ),
+    (
+        "error.NotEnoughItemsError",
+        "There are not enough items left.",
+    ),
+    ("error.NotYourTurnError", "It is not your turn."),
+    ("error.DraftableProtectedError", "That item is protected."),
+    (
+        "error.InvalidTimezoneError",
+        "That timezone does not exist.",
+    ),
+    (
+        "error.UnknownTextKeyError",
+        "There is no text called {key}.",
+    ),
+    ("error.InvalidAliasError", "That alias is not allowed."),
+    (
+        "error.InvalidAliasMappingError",
+        "Line {line} of the alias list could not be read.",
+    ),
+    (
+        "error.QueueFullError",
+        "Your queue is full (the limit is {limit}).",
+    ),
+    (
+        "error.WrongCategoryError",
+        "This round is for {category} picks only.",
+    ),
+    ("error.NoLotError", "Nothing is up for auction."),
+    (
+        "error.LotOpenError",
+        "Bidding is still open on the item up for auction.",
+    ),
+    ("error.LotClosedError", "Bidding has closed."),
+    ("error.InvalidBidError", "Bids must be at least 1."),
+    ("error.RosterFullError", "Your roster is full."),
+    (
+        "error.BidTooHighError",
+        "You can bid at most {max} and still fill your roster.",
+    ),
+    (
+        "error.MustTakePileError",
+        "The deck is empty, so you have to take the last pile.",
+    ),
+    (
+        "error.InvalidGridLineError",
+        "That row or column is not in the grid, or has nothing left in it.",
+    ),
+    (
+        "error.TwoPlayerDraftError",
+        "This draft needs exactly two players.",
+    ),
+    (
+        "error.NotSplitError",
+        "The items have to be split into piles before one is chosen.",
+    ),
+    ("error.NoOwedPicksError", "You do not owe any picks."),
+    (
+        "error.RosterSlotsFullError",
+        "Your team has no {category} slots left.",
+    ),
+    (
+        "error.SalaryCapError",
+        "That pick would take your team over the salary cap of {cap}.",
+    ),
+    (
+        "error.RateLimitedError",
+        "You are doing that too often. Try again in {seconds} seconds.",
+    ),
+    (
+        "error.NoPlayersError",
+        "The draft cannot start without any players.",
+    ),
+    (
+        "error.LeagueIdInUseError",
+        "Another league sharing this pool has the same ID.",
+    ),
+];
+
+/// The strings DRFTR shows to users, translated for one locale: message templates, embeds, buttons, board headings
+/// and error messages (see [`League::error_text`]).
+///
+/// Register a bundle with [`register_translations`], then give a server its locale with [`DraftGuild::set_locale`](crate::DraftGuild::set_locale),
+/// or a League outside any server with [`League::set_locale`]. Strings a bundle leaves out stay in English.
+///
+/// Keys are listed by [`Translations::keys`]. Text in `{braces}` is filled in when the string is used, and translations
+/// should keep the same placeholders as the English.
+#[derive(Debug, Clone)]
+pub struct Translations {
+    locale: String,
+    texts: HashMap<String, String>,
+}
+
+impl Translations {
+    /// Creates an empty bundle for a locale, e.g. `"es"` or `"pt-BR"`.
+    pub fn new(locale: &str) -> Translations {
+        Translations {
+            locale: locale.to_string(),
+            texts: HashMap::new(),
+        }
+    }
+    /// Returns the bundle's locale.
+    pub fn locale(&self) -> &str {
+        &self.locale
+    }
+    /// Translates one string, replacing any translation it had.
+    ///
+    /// # Errors
+    ///
+    /// If DRFTR has no string with that key, returns [`LeagueError::UnknownTextKeyError`] with the key.
+    ///
+    /// If a message template uses a placeholder that does not exist, returns [`LeagueError::UnknownPlaceholderError`] with its name.
+    pub fn set(&mut self, key: &str, text: &str) -> Result<(), LeagueError> {
+        if english(key).is_none() {
+            return Err(LeagueError::UnknownTextKeyError(key.to_string()));
+        }
+        if key.starts_with("template.") {
+            MessageTemplate::new(text.to_string())?;
+        }
+        self.texts.insert(key.to_string(), text.to_string());
+        Ok(())
+    }
+    /// Returns the translation of a string, if the bundle has one.
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.texts.get(key).map(String::as_str)
+    }
+    /// Returns the key of every string DRFTR shows to users, with its English text.
+    pub fn keys() -> impl Iterator<Item = (&'static str, &'static str)> {
+        DEFAULTS.iter().copied()
+    }
+    /// Returns the keys the bundle has no translation for, so translators know what is left.
+    pub fn missing(&self) -> Vec<&'static str> {
+        DEFAULTS
+            .iter()
+            .map(|(key, _)| *key)
+            .filter(|key| !self.texts.contains_key(*key))
+            .collect()
+    }
+}
+
+fn registry() -> &'static RwLock<HashMap<String, Arc<Translations>>> {
+    static REGISTRY: OnceLock<RwLock<HashMap<String, Arc<Translations>>>> = OnceLock::new();
+    REGISTRY.get_or_init(|| RwLock::new(HashMap::new()))
+}
+
+/// Makes a [`Translations`] bundle available to every League whose locale matches, replacing any bundle registered for the same locale.
+/// Register bundles once at startup, before Leagues start rendering messages.
+pub fn register_translations(translations: Translations) {
+    let mut registry = registry().write().unwrap_or_else(PoisonError::into_inner);
+    registry.insert(translations.locale.to_lowercase(), Arc::new(translations));
+}
+
+// the bundle for a locale, falling back from a regional locale ("es-MX") to its language ("es")
+fn bundle_for(locale: &str) -> Option<Arc<Translations>> {
+    let registry = registry().read().unwrap_or_else(PoisonError::into_inner);
+    let locale = locale.to_lowercase();
+    registry.get(&locale).cloned().or_else(|| {
+        let (language, _) = locale.split_once('-')?;
+        registry.get(language).cloned()
+    })
+}
+
+pub(crate) fn english(key: &str) -> Option<&'static str> {
+    DEFAULTS
+        .iter()
+        .find(|(k, _)| *k == key)
+        .map(|(_, text)| *text)
+}
+
+// fills in {name} placeholders, leaving ones without a value as they are
+fn fill(text: &str, values: &[(&str, String)]) -> String {
+    fill_placeholders(text, |name| {
+        values
+            .iter()
+            .find(|(key, _)| *key == name)
+            .map(|(_, value)| value.as_str())
+    })
+}
+
+// fills in {name} placeholders with whatever value gives for the name, leaving those it gives None for as they are.
+// One pass over the text, so a value that looks like a placeholder (a team called "{item}") is never filled in itself
+pub(crate) fn fill_placeholders<'a>(text: &str, value: impl Fn(&str) -> Option<&'a str>) -> String {
+    let mut filled = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('{') {
+        filled.push_str(&rest[..start]);
+        let after = &rest[start + 1..];
+        let placeholder = after
+            .find('}')
+            .and_then(|end| Some((end, value(&after[..end])?)));
+        match placeholder {
+            Some((end, value)) => {
+                filled.push_str(value);
+                rest = &after[end + 1..];
+            }
+            None => {
+                filled.push('{');
+                rest = after;
+            }
+        }
+    }
+    filled.push_str(rest);
+    filled
+}
+
+impl League {
+    /// Sets the locale the League's messages are written in (see [`Translations`]), or goes back to English with None.
+    ///
+    /// The locale belongs to the server: a League added to a [`DraftGuild`](crate::DraftGuild) with a locale takes the
+    /// server's, and [`DraftGuild::set_locale`](crate::DraftGuild::set_locale) changes every League in it.
+    pub fn set_locale(&mut self, locale: Option<String>) {
+        self.locale = locale;
+    }
+    /// Returns the League's locale, if it has one.
+    pub fn locale(&self) -> Option<&str> {
+        self.locale.as_deref()
+    }
+    /// Returns an error as a message for users, in the League's locale.
+    pub fn error_text(&self, error: &LeagueError) -> String {
+        let values = match error {
+            LeagueError::InvalidEmojiMappingError(line)
+            | LeagueError::InvalidAliasMappingError(line) => vec![("line", line.to_string())],
+            LeagueError::InvalidPhaseTransitionError(from, to) => {
+                vec![("from", format!("{from:?}")), ("to", format!("{to:?}"))]
+            }
+            LeagueError::UnknownPlaceholderError(name) => vec![("placeholder", name.clone())],
+            LeagueError::InvalidEventError(event) => vec![("event", event.to_string())],
+            LeagueError::ProtectionListMissingError(user) => {
+                vec![("user", format!("<@{}>", user.0))]
+            }
+            LeagueError::UnknownTextKeyError(key) => vec![("key", key.clone())],
+            LeagueError::QueueFullError(limit) => vec![("limit", limit.to_string())],
+            LeagueError::BidTooHighError(max) => vec![("max", max.to_string())],
+            LeagueError::WrongCategoryError(category)
+            | LeagueError::RosterSlotsFullError(category) => vec![("category", category.clone())],
+            LeagueError::SalaryCapError(cap) => vec![("cap", cap.to_string())],
+            LeagueError::RateLimitedError(wait) => {
+                // rounded up, so users who wait as long as they are told are let through
+                let seconds = (wait.num_milliseconds() + 999) / 1000;
+                vec![("seconds", seconds.to_string())]
+            }
+            _ => Vec::new(),
+        };
+        let text = self.text(&format!("error.{}", error.kind()), &values);
+        match error {
+            LeagueError::DraftableNotFoundError(names) if !names.is_empty() => {
+                let names = names.join(", ");
+                let suggestion = self.text("error.did_you_mean", &[("names", names)]);
+                format!("{text} {suggestion}")
+            }
+            _ => text,
+        }
+    }
+    // a string in the League's locale, with its placeholders filled in
+    pub(crate) fn text(&self, key: &str, values: &[(&str, String)]) -> String {
+        let translated = self.locale.as_deref().and_then(bundle_for);
+        let text = translated
+            .as_ref()
+            .and_then(|bundle| bundle.get(key))
+            .or_else(|| english(key))
+            .unwrap_or(key);
+        fill(text, values)
+    }
+}
+
+#[cfg(test)]
+mod i18n_tests {
+    use super::*;
+    use crate::test_support::new_league;
+    use crate::{DraftType, MessageKind};
+
+    #[test]
+    fn leagues_speak_their_locale() {
+        let mut spanish = Translations::new("es-test");
+        spanish
+            .set(
+                "template.complete",
+                "¡El draft de **{league}** ha terminado!",
+            )
+            .unwrap();
+        spanish
+            .set("error.NotYourTurnError", "No es tu turno.")
+            .unwrap();
+        match spanish.set("template.pick", "{jugador} eligió **{item}**") {
+            Err(LeagueError::UnknownPlaceholderError(name)) => assert_eq!(name, "jugador"),
+            _ => panic!("placeholders stay in English"),
+        }
+        match spanish.set("greeting", "Hola") {
+            Err(LeagueError::UnknownTextKeyError(_)) => {}
+            _ => panic!("DRFTR never says hello"),
+        }
+        assert!(spanish.missing().contains(&"template.pick"));
+        register_translations(spanish);
+
+        let mut league = new_league(1, DraftType::Snake, 1);
+        assert_eq!(
+            league.error_text(&LeagueError::NotYourTurnError),
+            "It is not your turn."
+        );
+        league.set_locale(Some("es-TEST-MX".to_string()));
+        assert_eq!(
+            league.error_text(&LeagueError::NotYourTurnError),
+            "It is not your turn."
+        );
+        league.set_locale(Some("es-TEST".to_string()));
+        assert_eq!(
+            league.error_text(&LeagueError::NotYourTurnError),
+            "No es tu turno."
+        );
+        assert_eq!(
+            league.error_text(&LeagueError::InvalidEventError(3)),
+            "Event 3 does not fit the league."
+        );
+        assert_eq!(
+            league.template(MessageKind::Complete).text(),
+            "¡El draft de **{league}** ha terminado!"
+        );
+        assert_eq!(
+            league.template(MessageKind::Pick).text(),
+            "{player} picked **{item}**{source}"
+        );
+    }
+
+    #[test]
+    fn servers_share_a_locale() {
+        let mut french = Translations::new("fr-test");
+        french
+            .set("error.NotYourTurnError", "Ce n'est pas ton tour.")
+            .unwrap();
+        register_translations(french);
+        let mut guild = crate::DraftGuild::new(1, poise::serenity_prelude::ChannelId(1));
+        guild
+            .add_league(new_league(1, DraftType::Snake, 1))
+            .unwrap();
+        guild.set_locale(Some("fr-test".to_string()));
+        let mut added = new_league(1, DraftType::Snake, 1);
+        added.name = "Added".to_string();
+        guild.add_league(added).unwrap();
+        for league in guild.leagues() {
+            assert_eq!(
+                league.error_text(&LeagueError::NotYourTurnError),
+                "Ce n'est pas ton tour."
+            );
+        }
+        guild.set_locale(None);
+        assert!(guild.leagues().all(|league| league.locale().is_none()));
+    }
+
+    #[test]
+    fn values_are_filled_in_once() {
+        let values = [
+            ("player", "{item}".to_string()),
+            ("item", "Pikachu".to_string()),
+        ];
+        assert_eq!(
+            fill("{player} picked {item} {unknown} {", &values),
+            "{item} picked Pikachu {unknown} {"
+        );
+    }
+}
@@ -0,0 +1,115 @@
+use crate::League;
+use chrono::{DateTime, Duration, Utc};
+use std::any::Any;
+use std::collections::{HashMap, HashSet};
+
+/// How long a [`League`] remembers idempotency keys by default: the 15 minutes a Discord interaction can be answered in.
+pub const DEFAULT_IDEMPOTENCY_WINDOW_MINUTES: i64 = 15;
+
+// the results of calls made through League::once, by key, for as long as the window lasts
+pub(crate) struct Idempotency {
+    pub(crate) window: Duration,
+    seen: HashMap<u64, (DateTime<Utc>, Box<dyn Any + Send + Sync>)>,
+}
+
+impl Idempotency {
+    pub(crate) fn new() -> Idempotency {
+        Idempotency {
+            window: Duration::minutes(DEFAULT_IDEMPOTENCY_WINDOW_MINUTES),
+            seen: HashMap::new(),
+        }
+    }
+    // the keys remembered right now
+    pub(crate) fn keys(&self) -> HashSet<u64> {
+        self.seen.keys().copied().collect()
+    }
+    // forgets every key but those given, e.g. the keys of a change that was rolled back
+    pub(crate) fn keep_only(&mut self, keys: &HashSet<u64>) {
+        self.seen.retain(|key, _| keys.contains(key));
+    }
+}
+
+impl League {
+    /// Runs f on the League once per idempotency key, e.g. the id of the interaction that asked for it, and returns its result.
+    /// Discord retries interactions it did not hear back about in time, so a retry of a /pick must not pick twice: calling this
+    /// again with the same key within the League's [`League::idempotency_window`] does not run f, and returns a copy of the
+    /// first call's result, errors included.
+    ///
+    /// Works with any mutating method whose result can be kept. Map away results that borrow the League:
+    ///
+    /// ```ignore
+    /// let picks = league.once(ctx.id(), Utc::now(), |league| league.lock_as(ctx.author().id, item))?;
+    /// league.once(ctx.id(), Utc::now(), |league| league.trade(user1, &item1, user2, &item2).map(|_| ()))?;
+    /// ```
+    ///
+    /// A key reused for a call with a different result type runs f again, and remembers the new result instead.
+    pub fn once<R: Clone + Send + Sync + 'static>(
+        &mut self,
+        key: u64,
+        now: DateTime<Utc>,
+        f: impl FnOnce(&mut League) -> R,
+    ) -> R {
+        let window = self.idempotency.window;
+        self.idempotency
+            .seen
+            .retain(|_, (at, _)| now - *at < window);
+        let seen = self
+            .idempotency
+            .seen
+            .get(&key)
+            .and_then(|(_, result)| result.downcast_ref::<R>());
+        if let Some(result) = seen {
+            return result.clone();
+        }
+        let result = f(self);
+        self.idempotency
+            .seen
+            .insert(key, (now, Box::new(result.clone())));
+        result
+    }
+    /// Sets how long [`League::once`] remembers a key. Defaults to [`DEFAULT_IDEMPOTENCY_WINDOW_MINUTES`].
+    pub fn set_idempotency_window(&mut self, window: Duration) {
+        self.idempotency.window = window;
+    }
+    /// Returns how long [`League::once`] remembers a key.
+    pub fn idempotency_window(&self) -> Duration {
+        self.idempotency.window
+    }
+}
+
+#[cfg(test)]
+mod idempotency_tests {
+    use super::*;
+    use crate::test_support::new_league;
+    use crate::{DraftType, LeagueError, PickResult};
+    use poise::serenity_prelude as serenity;
+
+    #[test]
+    fn retried_interactions_are_only_applied_once() {
+        let mut league = new_league(2, DraftType::Snake, 2);
+        league.activate().unwrap();
+        let now = Utc::now();
+        let pick = |league: &mut League| league.lock_as(serenity::UserId(1), Box::new("Pikachu"));
+        let first: Result<Vec<PickResult>, LeagueError> = league.once(100, now, pick);
+        let retry = league.once(100, now + Duration::seconds(3), pick);
+        assert_eq!(first.unwrap(), retry.unwrap());
+        assert_eq!(league.pick_number(), 2, "the retry did not pick again");
+
+        let refused = league.once(101, now, pick);
+        let retry = league.once(101, now, |league| {
+            league.lock_as(serenity::UserId(2), Box::new("Squirtle"))
+        });
+        match (refused, retry) {
+            (Err(LeagueError::NotYourTurnError), Err(LeagueError::NotYourTurnError)) => {}
+            _ => panic!("the retry gets the first call's error"),
+        }
+        assert_eq!(league.pick_number(), 2);
+
+        league.set_idempotency_window(Duration::minutes(1));
+        let later = now + Duration::minutes(2);
+        match league.once(100, later, pick) {
+            Err(LeagueError::NotYourTurnError) => {}
+            _ => panic!("key 100 was forgotten, so the pick ran again"),
+        }
+    }
+}
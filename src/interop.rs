@@ -0,0 +1,245 @@
+use crate::{BulkRejected, Draftable, League};
+use poise::serenity_prelude as serenity;
+use serde::Deserialize;
+use std::collections::HashMap;
+
+/// One pick from a draft run on another platform, read by [`parse_sleeper_picks`] or [`parse_espn_picks`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExternalPick {
+    /// The overall pick number, from 1.
+    pub pick: u32,
+    /// The round of the pick, from 1.
+    pub round: u32,
+    /// The platform's id for the team that made the pick: the Sleeper user id (or roster id in offline drafts), or the ESPN team id.
+    pub team: String,
+    /// The platform's id for the player picked.
+    pub player_id: String,
+    /// The name of the player picked, if the export has it. Sleeper exports do, ESPN exports do not.
+    pub name: Option<String>,
+}
+
+/// Errors from reading and importing drafts run on other platforms.
+#[derive(Debug)]
+pub enum InteropError {
+    /// Carries the parser's message.
+    MalformedExportError(String),
+    /// Carries the platform's id for a team with no entry in the mapping table.
+    UnmappedTeamError(String),
+    /// Carries a pick the resolver did not recognize.
+    UnknownItemError(ExternalPick),
+    /// The League refused the picks, and changed nothing.
+    RejectedError(BulkRejected),
+}
+
+// Sleeper and ESPN write some ids as numbers and others as strings
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Id {
+    Number(u64),
+    Text(String),
+}
+
+impl Id {
+    fn into_string(self) -> String {
+        match self {
+            Id::Number(id) => id.to_string(),
+            Id::Text(id) => id,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct SleeperPick {
+    pick_no: u32,
+    round: u32,
+    #[serde(default)]
+    picked_by: Option<String>,
+    roster_id: Option<Id>,
+    player_id: Id,
+    #[serde(default)]
+    metadata: HashMap<String, serde_json::Value>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct EspnPick {
+    overall_pick_number: u32,
+    round_id: u32,
+    team_id: Id,
+    player_id: Id,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct EspnDraftDetail {
+    picks: Vec<EspnPick>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct EspnLeague {
+    draft_detail: EspnDraftDetail,
+}
+
+/// Reads the picks of a Sleeper draft, as returned by Sleeper's `GET /v1/draft/<draft_id>/picks`, in pick order.
+///
+/// # Errors
+///
+/// If the text is not a Sleeper pick list, returns [`InteropError::MalformedExportError`] with the parser's message.
+pub fn parse_sleeper_picks(json: &str) -> Result<Vec<ExternalPick>, InteropError> {
+    let picks: Vec<SleeperPick> = serde_json::from_str(json)
+        .map_err(|e| InteropError::MalformedExportError(e.to_string()))?;
+    let mut picks: Vec<ExternalPick> = picks
+        .into_iter()
+        .map(|pick| {
+            let meta = |key: &str| pick.metadata.get(key).and_then(|v| v.as_str());
+            let name = match (meta("first_name"), meta("last_name")) {
+                (Some(first), Some(last)) => Some(format!("{first} {last}")),
+                (first, last) => first.or(last).map(String::from),
+            };
+            // offline drafts have nobody's user id, only the roster
+            let team = match (pick.picked_by, pick.roster_id) {
+                (Some(user), _) if !user.is_empty() => user,
+                (_, Some(roster)) => roster.into_string(),
+                _ => String::new(),
+            };
+            ExternalPick {
+                pick: pick.pick_no,
+                round: pick.round,
+                team,
+                player_id: pick.player_id.into_string(),
+                name,
+            }
+        })
+        .collect();
+    picks.sort_by_key(|pick| pick.pick);
+    Ok(picks)
+}
+
+/// Reads the picks of an ESPN fantasy draft, from ESPN's league endpoint with `?view=mDraftDetail`, in pick order.
+/// ESPN only gives player ids, so the resolver given to [`League::import_external`] has to look up each player.
+///
+/// # Errors
+///
+/// If the text is not an ESPN league with draft details, returns [`InteropError::MalformedExportError`] with the parser's message.
+pub fn parse_espn_picks(json: &str) -> Result<Vec<ExternalPick>, InteropError> {
+    let league: EspnLeague = serde_json::from_str(json)
+        .map_err(|e| InteropError::MalformedExportError(e.to_string()))?;
+    let mut picks: Vec<ExternalPick> = league
+        .draft_detail
+        .picks
+        .into_iter()
+        .map(|pick| ExternalPick {
+            pick: pick.overall_pick_number,
+            round: pick.round_id,
+            team: pick.team_id.into_string(),
+            player_id: pick.player_id.into_string(),
+            name: None,
+        })
+        .collect();
+    picks.sort_by_key(|pick| pick.pick);
+    Ok(picks)
+}
+
+impl League {
+    /// Adds the picks of a draft run on Sleeper, ESPN or elsewhere to the League's rosters, so a League that drafted elsewhere
+    /// can run waivers and trades in Discord. Either every pick is added or none are.
+    ///
+    /// teams maps each platform team id (see [`ExternalPick::team`]) to the Discord user whose seat it is, and resolver turns
+    /// each pick into your [`DraftItem`](crate::DraftItem) type, by [`ExternalPick::name`] or by looking up [`ExternalPick::player_id`].
+    /// Picks are added like [`League::bulk_add_to_picks`], without taking turns, and the League's phase does not change.
+    ///
+    /// # Errors
+    ///
+    /// If a pick's team is not in teams, returns [`InteropError::UnmappedTeamError`] with the team id.
+    ///
+    /// If resolver does not recognize a pick, returns [`InteropError::UnknownItemError`] with the pick.
+    ///
+    /// If the League refuses any pick, returns [`InteropError::RejectedError`] with the errors [`League::bulk_add_to_picks`] gives.
+    pub fn import_external(
+        &mut self,
+        picks: &[ExternalPick],
+        teams: &HashMap<String, serenity::UserId>,
+        mut resolver: impl FnMut(&ExternalPick) -> Option<Draftable>,
+    ) -> Result<(), InteropError> {
+        let mut items = Vec::new();
+        for pick in picks {
+            let user = teams
+                .get(&pick.team)
+                .ok_or_else(|| InteropError::UnmappedTeamError(pick.team.clone()))?;
+            let item =
+                resolver(pick).ok_or_else(|| InteropError::UnknownItemError(pick.clone()))?;
+            items.push((*user, item));
+        }
+        self.bulk_add_to_picks(items)
+            .map_err(InteropError::RejectedError)
+    }
+}
+
+#[cfg(test)]
+mod interop_tests {
+    use super::*;
+    use crate::test_support::new_league;
+    use crate::{DraftType, LeagueError};
+
+    const SLEEPER: &str = r#"[
+        {"pick_no": 2, "round": 1, "picked_by": "", "roster_id": 2, "player_id": "4046",
+         "metadata": {"first_name": "Patrick", "last_name": "Mahomes", "position": "QB"}},
+        {"pick_no": 1, "round": 1, "picked_by": "7331", "roster_id": 1, "player_id": "4034",
+         "metadata": {"first_name": "Christian", "last_name": "McCaffrey", "position": "RB"}}
+    ]"#;
+
+    const ESPN: &str = r#"{"id": 1, "draftDetail": {"drafted": true, "picks": [
+        {"overallPickNumber": 1, "roundId": 1, "roundPickNumber": 1, "teamId": 3, "playerId": 3916387},
+        {"overallPickNumber": 2, "roundId": 1, "roundPickNumber": 2, "teamId": 3, "playerId": 3139477}
+    ]}}"#;
+
+    fn league() -> League {
+        new_league(2, DraftType::Snake, 2)
+    }
+
+    #[test]
+    fn sleeper_drafts_import_by_name() {
+        let picks = parse_sleeper_picks(SLEEPER).unwrap();
+        assert_eq!(picks[0].team, "7331");
+        assert_eq!(picks[1].team, "2", "offline picks fall back to the roster");
+        assert_eq!(picks[0].name.as_deref(), Some("Christian McCaffrey"));
+        let teams = HashMap::from([
+            ("7331".to_string(), serenity::UserId(1)),
+            ("2".to_string(), serenity::UserId(2)),
+        ]);
+        let mut league = league();
+        league
+            .import_external(&picks, &teams, |pick| {
+                Some(Box::new(pick.name.clone()?) as Draftable)
+            })
+            .unwrap();
+        let roster = league.player_picks(serenity::UserId(2)).unwrap();
+        assert_eq!(roster[0].name(), "Patrick Mahomes");
+    }
+
+    #[test]
+    fn espn_drafts_need_every_team_mapped() {
+        let picks = parse_espn_picks(ESPN).unwrap();
+        assert_eq!(picks.len(), 2);
+        assert_eq!(picks[1].player_id, "3139477");
+        let mut league = league();
+        let resolver = |pick: &ExternalPick| Some(Box::new(pick.player_id.clone()) as Draftable);
+        match league.import_external(&picks, &HashMap::new(), resolver) {
+            Err(InteropError::UnmappedTeamError(team)) => assert_eq!(team, "3"),
+            _ => panic!("team 3 is not mapped"),
+        }
+        let teams = HashMap::from([("3".to_string(), serenity::UserId(3))]);
+        match league.import_external(&picks, &teams, resolver) {
+            Err(InteropError::RejectedError(rejected)) => match rejected.errors[0] {
+                (0, LeagueError::PlayerNotFoundError) => {}
+                _ => panic!("user 3 has no seat"),
+            },
+            _ => panic!("user 3 has no seat"),
+        }
+        match parse_espn_picks("[]") {
+            Err(InteropError::MalformedExportError(_)) => {}
+            _ => panic!("not an ESPN league"),
+        }
+    }
+}
@@ -0,0 +1,139 @@
+use crate::League;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::borrow::Borrow;
+use std::fmt;
+use std::ops::Deref;
+use std::sync::Arc;
+
+/// The name of a [`DraftItem`](crate::DraftItem), shared rather than copied: cloning one is a reference count bump, and every
+/// [`PickResult`](crate::PickResult) a League hands out for the same item shares the one allocation.
+///
+/// It derefs to `str` and compares with string types, so it can mostly be used as one:
+///
+/// ```
+/// # use drftr::ItemName;
+/// let name = ItemName::from("Pikachu");
+/// assert_eq!(name, "Pikachu");
+/// assert!(name.starts_with("Pika"));
+/// ```
+#[derive(Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ItemName(Arc<str>);
+
+impl ItemName {
+    /// Returns the name as a string slice.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Deref for ItemName {
+    type Target = str;
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl AsRef<str> for ItemName {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Borrow<str> for ItemName {
+    fn borrow(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for ItemName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl fmt::Debug for ItemName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&*self.0, f)
+    }
+}
+
+impl From<&str> for ItemName {
+    fn from(name: &str) -> ItemName {
+        ItemName(Arc::from(name))
+    }
+}
+
+impl From<String> for ItemName {
+    fn from(name: String) -> ItemName {
+        ItemName(Arc::from(name))
+    }
+}
+
+impl From<ItemName> for String {
+    fn from(name: ItemName) -> String {
+        name.0.to_string()
+    }
+}
+
+impl PartialEq<str> for ItemName {
+    fn eq(&self, other: &str) -> bool {
+        &*self.0 == other
+    }
+}
+
+impl PartialEq<&str> for ItemName {
+    fn eq(&self, other: &&str) -> bool {
+        &*self.0 == *other
+    }
+}
+
+impl PartialEq<String> for ItemName {
+    fn eq(&self, other: &String) -> bool {
+        *self.0 == **other
+    }
+}
+
+impl Serialize for ItemName {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for ItemName {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<ItemName, D::Error> {
+        String::deserialize(deserializer).map(ItemName::from)
+    }
+}
+
+impl League {
+    // the League's shared copy of a name, made on first use
+    pub(crate) fn intern(&mut self, name: &str) -> ItemName {
+        if let Some(interned) = self.names.get(name) {
+            return interned.clone();
+        }
+        let interned = ItemName::from(name);
+        self.names.insert(interned.clone());
+        interned
+    }
+}
+
+#[cfg(test)]
+mod item_name_tests {
+    use super::*;
+    use crate::test_support::new_league;
+    use crate::DraftType;
+
+    #[test]
+    fn picks_share_one_name() {
+        let mut league = new_league(2, DraftType::Snake, 1);
+        let interned = league.intern("Pikachu");
+        league.activate().unwrap();
+        let picks = league.lock(Box::new("Pikachu")).unwrap();
+        assert_eq!(picks[0].item, "Pikachu");
+        assert!(Arc::ptr_eq(&picks[0].item.0, &interned.0));
+        assert_eq!(
+            serde_json::to_string(&picks[0].item).unwrap(),
+            "\"Pikachu\""
+        );
+    }
+}
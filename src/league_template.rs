@@ -0,0 +1,500 @@
+use crate::{
+    AuctionPricing, DraftEvent, DraftType, League, LeagueConfigError, LeaguePhase, NominationOrder,
+    SkipPolicy,
+};
+use chrono::Duration;
+use serde::{Deserialize, Serialize};
+
+/// A League's settings without its players, pool or picks - "our standard settings" - for a commissioner to save and reuse
+/// season after season, or share with another server.
+///
+/// Take one from an existing League with [`League::to_template`], or start from [`LeagueTemplate::new`]. Save it with
+/// [`LeagueTemplate::to_json`] (or `to_toml` with the `toml` feature), and start Leagues from it with
+/// [`LeagueBuilder::template`](crate::LeagueBuilder::template).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LeagueTemplate {
+    draft_type: DraftType,
+    team_size: u32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pick_time_limit_secs: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    reminder_interval_secs: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    max_duration_secs: Option<i64>,
+    // the phases between Setup and Drafting, in order
+    #[serde(default)]
+    phases: Vec<LeaguePhase>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    queue_limit: Option<u32>,
+    #[serde(default)]
+    queues_locked_off_turn: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    locale: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    timezone: Option<String>,
+    // (round, category), ordered by round
+    #[serde(default)]
+    round_categories: Vec<(u32, String)>,
+    // (category, the most items of it each team may hold), ordered by category
+    #[serde(default)]
+    roster_slots: Vec<(String, u32)>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    salary_cap: Option<u32>,
+    #[serde(default)]
+    auction_pricing: AuctionPricing,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    auction_budget: Option<u32>,
+    #[serde(default)]
+    nomination_order: NominationOrder,
+    #[serde(default)]
+    skip_policy: SkipPolicy,
+}
+
+impl LeagueTemplate {
+    /// Creates a template with a draft type and team size, and everything else left at [`League::new`]'s defaults.
+    pub fn new(draft_type: DraftType, team_size: u32) -> LeagueTemplate {
+        LeagueTemplate {
+            draft_type,
+            team_size,
+            pick_time_limit_secs: None,
+            reminder_interval_secs: None,
+            max_duration_secs: None,
+            phases: Vec::new(),
+            queue_limit: None,
+            queues_locked_off_turn: false,
+            locale: None,
+            timezone: None,
+            round_categories: Vec::new(),
+            roster_slots: Vec::new(),
+            salary_cap: None,
+            auction_pricing: AuctionPricing::FirstPrice,
+            auction_budget: None,
+            nomination_order: NominationOrder::Linear,
+            skip_policy: SkipPolicy::LoseThePick,
+        }
+    }
+    /// Reads a template written by [`LeagueTemplate::to_json`].
+    ///
+    /// # Errors
+    ///
+    /// If the text is not a template, returns [`LeagueConfigError::MalformedTemplateError`] with the parser's message.
+    ///
+    /// Otherwise, returns the same errors as [`LeagueTemplate::validate`].
+    pub fn from_json(json: &str) -> Result<LeagueTemplate, LeagueConfigError> {
+        let template: LeagueTemplate = serde_json::from_str(json)
+            .map_err(|e| LeagueConfigError::MalformedTemplateError(e.to_string()))?;
+        template.validate()?;
+        Ok(template)
+    }
+    /// Writes the template as JSON.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("templates only hold serializable data")
+    }
+    /// Reads a template written by [`LeagueTemplate::to_toml`], or by hand.
+    ///
+    /// # Errors
+    ///
+    /// If the text is not a template, returns [`LeagueConfigError::MalformedTemplateError`] with the parser's message.
+    ///
+    /// Otherwise, returns the same errors as [`LeagueTemplate::validate`].
+    #[cfg(feature = "toml")]
+    pub fn from_toml(toml: &str) -> Result<LeagueTemplate, LeagueConfigError> {
+        let template: LeagueTemplate = toml::from_str(toml)
+            .map_err(|e| LeagueConfigError::MalformedTemplateError(e.to_string()))?;
+        template.validate()?;
+        Ok(template)
+    }
+    /// Writes the template as TOML, for commissioners who would rather edit it by hand.
+    #[cfg(feature = "toml")]
+    pub fn to_toml(&self) -> String {
+        toml::to_string(self).expect("templates only hold serializable data")
+    }
+    /// Checks the template could start a League. Templates read with [`LeagueTemplate::from_json`] or
+    /// `from_toml` have already been checked.
+    ///
+    /// # Errors
+    ///
+    /// If the team size is 0, returns [`LeagueConfigError::ZeroTeamSizeError`].
+    ///
+    /// If the pick time limit, reminder interval or max duration is not positive, or too long to be a [`Duration`], returns [`LeagueConfigError::InvalidTimeLimitError`].
+    ///
+    /// If the phases are not pre-draft phases a League can go through in order (see [`LeaguePhase::can_transition_to`]),
+    /// returns [`LeagueConfigError::InvalidPhaseOrderError`].
+    ///
+    /// If the timezone is not an IANA timezone name, returns [`LeagueConfigError::InvalidTimezoneError`] with the name.
+    ///
+    /// If a round category is for round 0, returns [`LeagueConfigError::InvalidRoundError`].
+    ///
+    /// If the nomination order is an empty custom order, returns [`LeagueConfigError::InvalidNominationOrderError`].
+    pub fn validate(&self) -> Result<(), LeagueConfigError> {
+        if self.team_size == 0 {
+            return Err(LeagueConfigError::ZeroTeamSizeError);
+        }
+        let positive = |secs: Option<i64>| {
+            secs.is_none_or(|secs| secs > 0 && Duration::try_seconds(secs).is_some())
+        };
+        if !positive(self.pick_time_limit_secs)
+            || !positive(self.reminder_interval_secs)
+            || !positive(self.max_duration_secs)
+        {
+            return Err(LeagueConfigError::InvalidTimeLimitError);
+        }
+        let mut previous = LeaguePhase::Setup;
+        for phase in &self.phases {
+            if !phase.is_pre_draft() || !previous.can_transition_to(*phase) {
+                return Err(LeagueConfigError::InvalidPhaseOrderError);
+            }
+            previous = *phase;
+        }
+        if let Some(name) = &self.timezone {
+            if name.parse::<chrono_tz::Tz>().is_err() {
+                return Err(LeagueConfigError::InvalidTimezoneError(name.clone()));
+            }
+        }
+        if self.round_categories.iter().any(|(round, _)| *round == 0) {
+            return Err(LeagueConfigError::InvalidRoundError);
+        }
+        if self.nomination_order == NominationOrder::Custom(Vec::new()) {
+            return Err(LeagueConfigError::InvalidNominationOrderError);
+        }
+        Ok(())
+    }
+    /// The [`DraftType`] Leagues from this template use.
+    pub fn draft_type(&self) -> DraftType {
+        self.draft_type
+    }
+    /// Sets the [`DraftType`] Leagues from this template use.
+    pub fn set_draft_type(&mut self, draft_type: DraftType) {
+        self.draft_type = draft_type;
+    }
+    /// The number of picks each player makes.
+    pub fn team_size(&self) -> u32 {
+        self.team_size
+    }
+    /// Sets the number of picks each player makes.
+    pub fn set_team_size(&mut self, team_size: u32) {
+        self.team_size = team_size;
+    }
+    /// The time each player has to pick, if picks are timed. See [`League::set_pick_time_limit`].
+    pub fn pick_time_limit(&self) -> Option<Duration> {
+        self.pick_time_limit_secs.and_then(Duration::try_seconds)
+    }
+    /// Sets the time each player has to pick, to the second.
+    pub fn set_pick_time_limit(&mut self, limit: Option<Duration>) {
+        self.pick_time_limit_secs = limit.map(|limit| limit.num_seconds());
+    }
+    /// How long the seat on the clock may go between reminders, if the League sends them. See [`League::set_reminder_interval`].
+    pub fn reminder_interval(&self) -> Option<Duration> {
+        self.reminder_interval_secs.and_then(Duration::try_seconds)
+    }
+    /// Sets how long the seat on the clock may go between reminders, to the second.
+    pub fn set_reminder_interval(&mut self, every: Option<Duration>) {
+        self.reminder_interval_secs = every.map(|every| every.num_seconds());
+    }
+    /// How long the whole draft may run, if it is capped. See [`League::set_max_duration`].
+    pub fn max_duration(&self) -> Option<Duration> {
+        self.max_duration_secs.and_then(Duration::try_seconds)
+    }
+    /// Sets how long the whole draft may run, to the second.
+    pub fn set_max_duration(&mut self, max: Option<Duration>) {
+        self.max_duration_secs = max.map(|max| max.num_seconds());
+    }
+    /// The phases Leagues from this template go through between [`LeaguePhase::Setup`] and [`LeaguePhase::Drafting`], in order.
+    pub fn phases(&self) -> &[LeaguePhase] {
+        &self.phases
+    }
+    /// Sets the phases Leagues from this template go through before drafting, in order. See [`LeagueTemplate::validate`]
+    /// for which lists are allowed.
+    pub fn set_phases(&mut self, phases: Vec<LeaguePhase>) {
+        self.phases = phases;
+    }
+    /// Returns the phase a League following this template moves to after the one it is in, for a bot's "next phase" command
+    /// to pass to [`League::set_phase`]. Returns None once the draft is over.
+    pub fn next_phase(&self, current: LeaguePhase) -> Option<LeaguePhase> {
+        match current {
+            // phases are in order, so the next one is the first the League can still move to
+            LeaguePhase::Setup
+            | LeaguePhase::OrderLottery
+            | LeaguePhase::Keepers
+            | LeaguePhase::Banning => Some(
+                self.phases
+                    .iter()
+                    .copied()
+                    .find(|phase| current.can_transition_to(*phase))
+                    .unwrap_or(LeaguePhase::Drafting),
+            ),
+            LeaguePhase::Drafting | LeaguePhase::Paused => Some(LeaguePhase::PostDraft),
+            LeaguePhase::PostDraft => Some(LeaguePhase::Archived),
+            LeaguePhase::Archived => None,
+        }
+    }
+    /// The most items each player can queue, if there is a limit. See [`League::set_queue_limit`].
+    pub fn queue_limit(&self) -> Option<u32> {
+        self.queue_limit
+    }
+    /// Sets the most items each player can queue.
+    pub fn set_queue_limit(&mut self, limit: Option<u32>) {
+        self.queue_limit = limit;
+    }
+    /// Returns true if queues can be edited while it is not the player's turn. See [`League::set_queue_edits_off_turn`].
+    pub fn queue_edits_off_turn(&self) -> bool {
+        !self.queues_locked_off_turn
+    }
+    /// Sets whether queues can be edited while it is not the player's turn.
+    pub fn set_queue_edits_off_turn(&mut self, allowed: bool) {
+        self.queues_locked_off_turn = !allowed;
+    }
+    /// The locale messages are written in, if not English. See [`League::set_locale`].
+    pub fn locale(&self) -> Option<&str> {
+        self.locale.as_deref()
+    }
+    /// Sets the locale messages are written in.
+    pub fn set_locale(&mut self, locale: Option<String>) {
+        self.locale = locale;
+    }
+    /// The name of the timezone times are written in, if not UTC. See [`League::set_timezone`].
+    pub fn timezone(&self) -> Option<&str> {
+        self.timezone.as_deref()
+    }
+    /// Sets the timezone times are written in, by its IANA name (e.g. `"America/New_York"`).
+    ///
+    /// # Errors
+    ///
+    /// If the name is not a timezone, returns [`LeagueConfigError::InvalidTimezoneError`] with the name.
+    pub fn set_timezone(&mut self, name: Option<&str>) -> Result<(), LeagueConfigError> {
+        if let Some(name) = name {
+            if name.parse::<chrono_tz::Tz>().is_err() {
+                return Err(LeagueConfigError::InvalidTimezoneError(name.to_string()));
+            }
+        }
+        self.timezone = name.map(String::from);
+        Ok(())
+    }
+    /// The category every pick in a round must be, if it has one. See [`League::set_round_category`].
+    pub fn round_category(&self, round: u32) -> Option<&str> {
+        self.round_categories
+            .iter()
+            .find(|(r, _)| *r == round)
+            .map(|(_, category)| category.as_str())
+    }
+    /// Restricts every pick in a round to one category, or lifts the restriction with None.
+    ///
+    /// # Errors
+    ///
+    /// If round is 0, returns [`LeagueConfigError::InvalidRoundError`].
+    pub fn set_round_category(
+        &mut self,
+        round: u32,
+        category: Option<String>,
+    ) -> Result<(), LeagueConfigError> {
+        if round == 0 {
+            return Err(LeagueConfigError::InvalidRoundError);
+        }
+        self.round_categories.retain(|(r, _)| *r != round);
+        if let Some(category) = category {
+            self.round_categories.push((round, category));
+            self.round_categories.sort();
+        }
+        Ok(())
+    }
+    pub(crate) fn round_categories(&self) -> &[(u32, String)] {
+        &self.round_categories
+    }
+    /// The most items of a category each team may hold, if there is a limit. See [`League::set_roster_slot`].
+    pub fn roster_slot(&self, category: &str) -> Option<u32> {
+        self.roster_slots
+            .iter()
+            .find(|(c, _)| c == category)
+            .map(|(_, count)| *count)
+    }
+    /// Limits how many items of a category each team may hold, or lifts the limit with None.
+    pub fn set_roster_slot(&mut self, category: String, count: Option<u32>) {
+        self.roster_slots.retain(|(c, _)| *c != category);
+        if let Some(count) = count {
+            self.roster_slots.push((category, count));
+            self.roster_slots.sort();
+        }
+    }
+    pub(crate) fn roster_slots(&self) -> &[(String, u32)] {
+        &self.roster_slots
+    }
+    /// The most each team's picks may cost in total, if there is a cap. See [`League::set_salary_cap`].
+    pub fn salary_cap(&self) -> Option<u32> {
+        self.salary_cap
+    }
+    /// Sets the most each team's picks may cost in total.
+    pub fn set_salary_cap(&mut self, cap: Option<u32>) {
+        self.salary_cap = cap;
+    }
+    /// What the winners of sealed-bid lots pay. See [`League::set_auction_pricing`].
+    pub fn auction_pricing(&self) -> AuctionPricing {
+        self.auction_pricing
+    }
+    /// Sets what the winners of sealed-bid lots pay.
+    pub fn set_auction_pricing(&mut self, pricing: AuctionPricing) {
+        self.auction_pricing = pricing;
+    }
+    /// The budget every seat starts an auction with, if there is one. See [`League::set_auction_budget`].
+    pub fn auction_budget(&self) -> Option<u32> {
+        self.auction_budget
+    }
+    /// Sets the budget every seat starts an auction with.
+    pub fn set_auction_budget(&mut self, budget: Option<u32>) {
+        self.auction_budget = budget;
+    }
+    /// The order seats nominate items in an auction. See [`League::set_nomination_order`].
+    pub fn nomination_order(&self) -> &NominationOrder {
+        &self.nomination_order
+    }
+    /// Sets the order seats nominate items in an auction. Custom orders are checked against the League's seats when it is built.
+    pub fn set_nomination_order(&mut self, order: NominationOrder) {
+        self.nomination_order = order;
+    }
+    /// What happens to a seat that is skipped or runs out of time. See [`League::set_skip_policy`].
+    pub fn skip_policy(&self) -> SkipPolicy {
+        self.skip_policy
+    }
+    /// Sets what happens to a seat that is skipped or runs out of time.
+    pub fn set_skip_policy(&mut self, policy: SkipPolicy) {
+        self.skip_policy = policy;
+    }
+}
+
+impl League {
+    /// Returns the League's settings as a [`LeagueTemplate`], to start future Leagues the same way.
+    ///
+    /// The template's phases are the ones this League has gone through between Setup and Drafting so far.
+    pub fn to_template(&self) -> LeagueTemplate {
+        let phases = self
+            .events
+            .iter()
+            .filter_map(|event| match event {
+                DraftEvent::PhaseChanged(phase) if phase.is_pre_draft() => Some(*phase),
+                _ => None,
+            })
+            .collect();
+        LeagueTemplate {
+            draft_type: self.draft_type,
+            team_size: self.team_size,
+            pick_time_limit_secs: self.clock.limit().map(|limit| limit.num_seconds()),
+            reminder_interval_secs: self.reminders.every.map(|every| every.num_seconds()),
+            max_duration_secs: self.deadlines.max_duration.map(|max| max.num_seconds()),
+            phases,
+            queue_limit: self.queue_limit,
+            queues_locked_off_turn: self.queues_locked_off_turn,
+            locale: self.locale.clone(),
+            timezone: self.timezone.map(|tz| tz.name().to_string()),
+            round_categories: {
+                let mut categories: Vec<(u32, String)> = self
+                    .round_categories
+                    .iter()
+                    .map(|(round, category)| (*round, category.clone()))
+                    .collect();
+                categories.sort();
+                categories
+            },
+            roster_slots: {
+                let mut slots: Vec<(String, u32)> = self
+                    .roster_slots
+                    .iter()
+                    .map(|(category, count)| (category.clone(), *count))
+                    .collect();
+                slots.sort();
+                slots
+            },
+            salary_cap: self.salary_cap,
+            auction_pricing: self.auction.pricing,
+            auction_budget: self.auction.budget,
+            nomination_order: self.auction.order.clone(),
+            skip_policy: self.skip_policy,
+        }
+    }
+}
+
+#[cfg(test)]
+mod league_template_tests {
+    use super::*;
+    use poise::serenity_prelude as serenity;
+
+    fn template() -> LeagueTemplate {
+        let mut template = LeagueTemplate::new(DraftType::Linear, 3);
+        template.set_pick_time_limit(Some(Duration::minutes(5)));
+        template.set_phases(vec![LeaguePhase::OrderLottery, LeaguePhase::Keepers]);
+        template.set_queue_limit(Some(10));
+        template.set_queue_edits_off_turn(false);
+        template.set_timezone(Some("America/New_York")).unwrap();
+        template
+            .set_round_category(2, Some("GK".to_string()))
+            .unwrap();
+        template.set_roster_slot("GK".to_string(), Some(1));
+        template.set_salary_cap(Some(100));
+        template
+    }
+
+    #[test]
+    fn templates_round_trip_through_leagues() {
+        let template = template();
+        let mut league = League::builder(69420, "Creenis".to_string())
+            .template(&template)
+            .players(&[serenity::UserId(1), serenity::UserId(2)])
+            .build()
+            .unwrap();
+        assert_eq!(league.draft_type(), DraftType::Linear);
+        assert_eq!(league.final_pick, 5);
+        assert!(!league.queue_edits_off_turn());
+        assert_eq!(league.roster_slot("GK"), Some(1));
+        assert_eq!(league.salary_cap(), Some(100));
+        assert_eq!(
+            template.next_phase(LeaguePhase::Setup),
+            Some(LeaguePhase::OrderLottery)
+        );
+        assert_eq!(
+            template.next_phase(LeaguePhase::Keepers),
+            Some(LeaguePhase::Drafting)
+        );
+        for phase in template.phases() {
+            league.set_phase(*phase).unwrap();
+        }
+        assert_eq!(league.to_template(), template);
+    }
+
+    #[test]
+    fn templates_read_back_what_they_write() {
+        let template = template();
+        assert_eq!(
+            LeagueTemplate::from_json(&template.to_json()).unwrap(),
+            template
+        );
+        match LeagueTemplate::from_json(
+            r#"{"draft_type":"Snake","team_size":2,"phases":["Drafting"]}"#,
+        ) {
+            Err(LeagueConfigError::InvalidPhaseOrderError) => {}
+            _ => panic!("Drafting is not a pre-draft phase"),
+        }
+        match LeagueTemplate::from_json(r#"{"draft_type":"Snake"}"#) {
+            Err(LeagueConfigError::MalformedTemplateError(_)) => {}
+            _ => panic!("templates need a team size"),
+        }
+        match LeagueTemplate::from_json(
+            r#"{"draft_type":"Snake","team_size":2,"max_duration_secs":9223372036854775807}"#,
+        ) {
+            Err(LeagueConfigError::InvalidTimeLimitError) => {}
+            _ => panic!("that is longer than any Duration"),
+        }
+    }
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn templates_read_toml() {
+        let template = template();
+        assert_eq!(
+            LeagueTemplate::from_toml(&template.to_toml()).unwrap(),
+            template
+        );
+        let written = "draft_type = \"Snake\"\nteam_size = 6\ntimezone = \"Mars/Olympus\"\n";
+        match LeagueTemplate::from_toml(written) {
+            Err(LeagueConfigError::InvalidTimezoneError(name)) => assert_eq!(name, "Mars/Olympus"),
+            _ => panic!("there is no such timezone"),
+        }
+    }
+}
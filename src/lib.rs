@@ -2,21 +2,204 @@
 //!
 //! This library is designed to allow only one player to lock in their pick at a time, and for the draft pool to be a single shared pool.
 //! In other words, it does not yet support things like Magic: the Gathering drafts, though that is a feature I intend to build.
+//!
+//! With the `metrics` feature, DRFTR reports what it is doing through the [metrics](https://docs.rs/metrics) crate,
+//! to whichever exporter (Prometheus, StatsD...) your bot installs:
+//!
+//! - `drftr_picks_total` (counter): picks locked in, queued picks and autopicks included. Chart its rate for picks per second.
+//! - `drftr_trades_total` (counter): trades made.
+//! - `drftr_errors_total` (counter, labelled `kind`): picks, skips, autopicks, waivers and trades that failed, by [`LeagueError`] variant.
+//! - `drftr_active_leagues` (gauge): Leagues drafting or paused.
+//!
+//! With the `toml` feature, [`LeagueTemplate`]s can also be written and read as TOML.
+//!
+//! With the `mtg` feature, `Cube` reads Magic: the Gathering cube lists into pools, with card details from Scryfall.
+//!
+//! With the `web` feature, `web_router` serves Leagues as JSON over HTTP with [axum](https://docs.rs/axum), for a companion website.
 #![allow(dead_code)]
+// lets #[derive(DraftItem)] name the trait as ::drftr::DraftItem inside this crate too
+extern crate self as drftr;
+mod aliases;
+mod archive;
+mod auction;
+mod autopick;
+#[cfg(feature = "image")]
+mod board_image;
+mod budget;
+mod builder;
+mod bulk;
+mod captains;
+mod clock;
+mod cooldowns;
+mod deadlines;
+mod divisions;
 mod draft_types;
+mod emoji;
+mod events;
+mod expansion;
+mod forfeit;
+mod format;
+mod fuzzy;
+mod i18n;
+mod idempotency;
+mod interop;
+mod item_name;
+mod league_template;
+mod lobby;
+mod locks;
+mod manager;
+mod metrics;
+#[cfg(feature = "mtg")]
+mod mtg;
+mod nomination;
+mod notify;
+mod packs;
+mod paginate;
+mod permissions;
+mod phase;
+mod pick_result;
+mod picked;
+mod pool;
+mod profile;
+mod queues;
+mod ready_check;
+mod recycle_bin;
+mod registry;
+mod rejected;
+mod rochester;
+mod rollover;
+mod roster;
+mod round_categories;
+mod rounds;
+mod schedule;
+mod search;
+mod service;
+mod settings;
+mod shared_guild;
+mod shared_pool;
+mod sheets;
+mod showdown;
+mod simulate;
+mod skip_policy;
+mod slow;
+mod snapshot;
+mod stale;
+mod standings;
+mod stats;
+mod suggest;
+mod summary;
+mod team;
+mod team_roles;
+mod team_size;
+mod template;
+#[cfg(test)]
+mod test_support;
+mod threads;
+mod timezone;
+mod turn;
+mod view;
+#[cfg(feature = "web")]
+mod web;
+pub use archive::ArchivedLeague;
+pub use auction::{AuctionPricing, Lot, LotResult};
+pub use autopick::{
+    AutopickStrategy, BestAvailable, BestAvailableByCost, QueueThenRandom, RandomAvailable,
+};
+#[cfg(feature = "image")]
+pub use board_image::BoardImageError;
+pub use budget::Budget;
+pub use builder::{LeagueBuilder, LeagueConfigError};
+pub use bulk::BulkRejected;
+pub use captains::UserDraftItem;
+use chrono::{DateTime, Duration, Utc};
+pub use clock::PickClock;
+pub use cooldowns::{RateLimit, RateLimitedAction};
+pub use draft_types::{
+    DraftType, GridDraft, GridLine, SolomonDraft, SolomonPile, SolomonStep, WinstonDraft,
+};
+/// Implements [`DraftItem`] from fields marked `#[name]`, and optionally `#[category]`, `#[cost]` and `#[tier]`.
+/// Needs the `derive` feature.
+///
+/// ```ignore
+/// #[derive(drftr::DraftItem)]
+/// struct Pokemon {
+///     #[name]
+///     name: String,
+///     #[category]
+///     kind: Option<String>,
+///     #[cost]
+///     points: u32,
+/// }
+/// ```
+#[cfg(feature = "derive")]
+pub use drftr_derive::DraftItem;
+pub use events::DraftEvent;
+pub use forfeit::ForfeitPolicy;
+pub use format::{split_message, MESSAGE_LIMIT};
+pub use i18n::{register_translations, Translations};
+pub use idempotency::DEFAULT_IDEMPOTENCY_WINDOW_MINUTES;
+pub use interop::{parse_espn_picks, parse_sleeper_picks, ExternalPick, InteropError};
+pub use item_name::ItemName;
+pub use league_template::LeagueTemplate;
+pub use lobby::{LobbyAction, LobbyEntry};
+pub use manager::GuildManager;
+#[cfg(feature = "mtg")]
+pub use mtg::{Card, Cube};
+pub use nomination::NominationOrder;
+pub use packs::{PackError, PackGenerator};
+pub use paginate::Paginator;
+pub use permissions::{LeagueAction, Role};
+pub use phase::LeaguePhase;
+pub use pick_result::{PickResult, PickSource};
 use poise::serenity_prelude as serenity;
-use std::collections::{HashMap, VecDeque};
+pub use pool::DraftPool;
+pub use profile::PlayerProfile;
+pub use ready_check::{NoShowPolicy, ReadyStatus};
+pub use recycle_bin::DeletedLeague;
+pub use registry::DraftRegistry;
+pub use rejected::Rejected;
+pub use rochester::PackSet;
+pub use round_categories::UpcomingPick;
+pub use schedule::Matchup;
+pub use search::{PoolPage, PoolQuery};
+pub use service::{Announcer, DraftStore, LeagueService, ServiceError, ServiceFuture, StoreError};
+pub use settings::GuildSettings;
+pub use shared_guild::SharedGuild;
+pub use shared_pool::SharedPool;
+pub use sheets::{SheetRange, SheetsUpdate};
+pub use showdown::{parse_points_sheet, parse_showdown_tiers, PoolImportError, TieredPokemon};
+pub use simulate::{Scripted, SimulatedPick, Simulation, SimulationResult};
+pub use skip_policy::SkipPolicy;
+pub use slow::Reminder;
+pub use snapshot::{SnapshotError, SNAPSHOT_VERSION};
+pub use standings::{MatchResult, Standing};
+pub use stats::{DraftStats, DraftedPick, PlayerStats};
+use std::collections::{HashMap, HashSet, VecDeque};
+pub use summary::{DraftSummary, SeatSummary};
+pub use team::Team;
+pub use template::{MessageKind, MessageTemplate};
+pub use timezone::{discord_timestamp, TimestampStyle};
+pub use turn::{Direction, TurnInfo};
+pub use view::{LeagueView, SeatView};
+#[cfg(feature = "web")]
+pub use web::{web_router, PickSubmission, WebError};
 type Draftable = Box<dyn DraftItem + 'static>;
 
 /// A container for any number of draft [`League`]s in a single Discord server.
 ///
 /// Each server your draft bot is in needs to have an associated DraftGuild.
 /// Have your users run a /setup or /config command to set the default output channel and initialize an associated DraftGuild.
+/// Bots handling commands on several threads at once should use a [`SharedGuild`] instead, which locks each League separately.
 pub struct DraftGuild {
     id: u64,
     // k: name provided on League initialization
     leagues: HashMap<String, League>,
     default_output: serenity::ChannelId,
+    settings: GuildSettings,
+    archives: Vec<ArchivedLeague>,
+    recycle_bin: Vec<DeletedLeague>,
+    // set when the id is a custom one rather than the Discord guild's
+    discord_guild: Option<serenity::GuildId>,
 }
 
 impl DraftGuild {
@@ -29,19 +212,50 @@ impl DraftGuild {
             id,
             leagues: HashMap::new(),
             default_output,
+            settings: GuildSettings::new(),
+            archives: Vec::new(),
+            recycle_bin: Vec::new(),
+            discord_guild: None,
         }
     }
+    /// Returns the DraftGuild's id, as given to [`DraftGuild::new`].
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+    /// Returns the Discord server the DraftGuild belongs to: the one set with [`DraftGuild::set_discord_guild`],
+    /// or if none was, the one whose ID is the DraftGuild's id.
+    pub fn discord_guild(&self) -> serenity::GuildId {
+        self.discord_guild.unwrap_or(serenity::GuildId(self.id))
+    }
+    /// Links a DraftGuild with a custom id to its Discord server, so it can be found from the server's GuildId
+    /// (see [`DraftRegistry::guild_for`]).
+    pub fn set_discord_guild(&mut self, guild: serenity::GuildId) {
+        self.discord_guild = Some(guild);
+    }
+    /// Returns the channel Leagues without an output channel of their own post to (see [`League::output_channel`]).
+    pub fn default_output(&self) -> serenity::ChannelId {
+        self.default_output
+    }
+    /// Changes the channel Leagues without an output channel of their own post to, and returns the old one.
+    /// Leagues with their own channel (see [`League::set_output`]) are unaffected.
+    pub fn set_default_output(&mut self, channel: serenity::ChannelId) -> serenity::ChannelId {
+        std::mem::replace(&mut self.default_output, channel)
+    }
     /// Adds a [`League`] to the DraftGuild.
     ///
     /// Leagues are inserted into a HashMap whose keys are the Leagues' names.
     /// No more than one league with the same name can exist in a DraftGuild at any given time.
+    /// If the DraftGuild has a locale (see [`DraftGuild::set_locale`]), the League writes its messages in it.
     pub fn add_league(
         &mut self,
-        league: League,
+        mut league: League,
     ) -> Result<&HashMap<String, League>, DraftGuildError> {
         if self.leagues.contains_key(&league.name) {
             return Err(DraftGuildError::LeagueNameAlreadyInUseError);
         }
+        if let Some(locale) = self.settings.locale() {
+            league.set_locale(Some(locale.to_string()));
+        }
         self.leagues.insert(league.name.clone(), league);
         Ok(&self.leagues)
     }
@@ -61,19 +275,87 @@ impl DraftGuild {
         }
         Err(DraftGuildError::LeagueNotFoundError)
     }
-    /// Deletes a [`League`] by name, if it exists.
-    pub fn delete_league(&mut self, key: String) -> Result<League, DraftGuildError> {
+    /// Returns a [`League`] by name without mutable access, so read-only commands can share the DraftGuild.
+    pub fn league(&self, name: &str) -> Result<&League, DraftGuildError> {
+        self.leagues
+            .get(name)
+            .ok_or(DraftGuildError::LeagueNotFoundError)
+    }
+    /// Returns a [`League`] by ID without mutable access.
+    pub fn league_with_id(&self, id: u64) -> Result<&League, DraftGuildError> {
+        self.leagues
+            .values()
+            .find(|league| league.id == id)
+            .ok_or(DraftGuildError::LeagueNotFoundError)
+    }
+    /// Returns every [`League`] in the DraftGuild, in no particular order, e.g. for a /list-drafts command.
+    pub fn leagues(&self) -> impl Iterator<Item = &League> {
+        self.leagues.values()
+    }
+    /// Returns every [`League`] in the DraftGuild mutably, in no particular order.
+    pub fn leagues_mut(&mut self) -> impl Iterator<Item = &mut League> {
+        self.leagues.values_mut()
+    }
+    /// Returns the names of every League in the DraftGuild, in no particular order.
+    pub fn league_names(&self) -> impl Iterator<Item = &str> {
+        self.leagues.keys().map(String::as_str)
+    }
+    /// Returns how many Leagues the DraftGuild holds, not counting archived ones.
+    pub fn len(&self) -> usize {
+        self.leagues.len()
+    }
+    /// Returns true if the DraftGuild holds no Leagues.
+    pub fn is_empty(&self) -> bool {
+        self.leagues.is_empty()
+    }
+    /// Returns true if a League with this name exists in the DraftGuild.
+    pub fn contains(&self, name: &str) -> bool {
+        self.leagues.contains_key(name)
+    }
+    /// Renames a [`League`], keeping its key in the DraftGuild and its own name in step, and returns it.
+    ///
+    /// # Errors
+    ///
+    /// If there is no League named old, returns [`DraftGuildError::LeagueNotFoundError`].
+    ///
+    /// If another League is already named new, returns [`DraftGuildError::LeagueNameAlreadyInUseError`].
+    pub fn rename_league(
+        &mut self,
+        old: &str,
+        new: String,
+    ) -> Result<&mut League, DraftGuildError> {
+        if !self.leagues.contains_key(old) {
+            return Err(DraftGuildError::LeagueNotFoundError);
+        }
+        if old != new && self.leagues.contains_key(&new) {
+            return Err(DraftGuildError::LeagueNameAlreadyInUseError);
+        }
+        let mut league = self.leagues.remove(old).unwrap();
+        league.name = new.clone();
+        Ok(self.leagues.entry(new).or_insert(league))
+    }
+    /// Deletes a [`League`] by name, if it exists, moving it to the guild's recycle bin with now as its deletion date.
+    /// It can be brought back with [`DraftGuild::restore_league`] until [`DraftGuild::purge_deleted`] drops it.
+    pub fn delete_league(
+        &mut self,
+        key: String,
+        now: DateTime<Utc>,
+    ) -> Result<&DeletedLeague, DraftGuildError> {
         if let Some(league) = self.leagues.remove(&key) {
-            return Ok(league);
+            self.recycle_bin.push(DeletedLeague::new(league, now));
+            return Ok(self.recycle_bin.last().unwrap());
         }
         Err(DraftGuildError::LeagueNotFoundError)
     }
-    /// Deletes a [`League`] by ID, if it exists.
-    pub fn delete_league_by_id(&mut self, id: u64) -> Result<League, DraftGuildError> {
+    /// Deletes a [`League`] by ID, if it exists, moving it to the guild's recycle bin like [`DraftGuild::delete_league`].
+    pub fn delete_league_by_id(
+        &mut self,
+        id: u64,
+        now: DateTime<Utc>,
+    ) -> Result<&DeletedLeague, DraftGuildError> {
         if let Some(league) = self.leagues.values().find(|league| league.id == id) {
             let league_name = league.name.clone();
-            let league = self.leagues.remove(&league_name).unwrap();
-            return Ok(league);
+            return self.delete_league(league_name, now);
         }
         Err(DraftGuildError::LeagueNotFoundError)
     }
@@ -92,6 +374,8 @@ impl DraftGuild {
 pub enum DraftGuildError {
     LeagueNotFoundError,
     LeagueNameAlreadyInUseError,
+    LeagueNotFinishedError,
+    LeagueInUseError,
 }
 
 /// A specific ongoing draft league.
@@ -103,11 +387,69 @@ pub struct League {
     players: Vec<ActivePlayer>,
     output: Option<serenity::ChannelId>,
     name: String,
-    active: bool,
+    phase: LeaguePhase,
     current_seat: u32,
     total_picks: u32,
     draft_type: draft_types::DraftType,
     final_pick: u32,
+    // the seat for every pick, once seats drop out of the rotation (removed mid-draft, or done with a smaller team)
+    // and the order no longer follows the draft type
+    order: Option<Vec<u32>>,
+    clock: PickClock,
+    packs: Option<PackSet>,
+    winston: Option<WinstonDraft>,
+    grid: Option<GridDraft>,
+    solomon: Option<SolomonDraft>,
+    team_size: u32,
+    lobby: lobby::Lobby,
+    permissions: permissions::Permissions,
+    emoji: emoji::EmojiMap,
+    pool: Option<DraftPool>,
+    autopick_strategy: Box<dyn AutopickStrategy>,
+    deadlines: deadlines::Deadlines,
+    templates: template::Templates,
+    events: Vec<DraftEvent>,
+    // sends every event as it is recorded, once something has subscribed
+    stream: Option<tokio::sync::broadcast::Sender<DraftEvent>>,
+    results: Vec<MatchResult>,
+    schedule: Vec<Matchup>,
+    locked_items: HashSet<String>,
+    shared_pool: Option<SharedPool>,
+    // how long each pick made on a running clock took, by pick number
+    pick_times: Vec<(u32, Duration)>,
+    expansion: Option<expansion::Expansion>,
+    reminders: slow::Reminders,
+    timezone: Option<chrono_tz::Tz>,
+    locale: Option<String>,
+    active_gauge: metrics::ActiveGauge,
+    // k: lowercased alias, v: the item's name
+    aliases: HashMap<String, String>,
+    // (thread, the channel it was opened in), while the League posts to a thread of its own
+    thread: Option<(serenity::ChannelId, serenity::ChannelId)>,
+    queue_limit: Option<u32>,
+    queues_locked_off_turn: bool,
+    // k: round, v: the category every pick in it must be
+    round_categories: HashMap<u32, String>,
+    // k: category, v: the most items of it each team may hold
+    roster_slots: HashMap<String, u32>,
+    salary_cap: Option<u32>,
+    auction: auction::Auction,
+    // k: a seat's original owner, v: the Discord role of their team
+    team_roles: HashMap<serenity::UserId, serenity::RoleId>,
+    skip_policy: SkipPolicy,
+    // when the draft left the pre-draft phases
+    started_at: Option<DateTime<Utc>>,
+    summary: Option<DraftSummary>,
+    // when the League last recorded an event, or was created
+    last_activity: DateTime<Utc>,
+    // the seats holding each item
+    picked: picked::NameIndex,
+    // the seats with each item in their queue
+    queued: picked::NameIndex,
+    // every item name the League has handed out, shared by its picks
+    names: HashSet<ItemName>,
+    idempotency: idempotency::Idempotency,
+    rate_limits: cooldowns::RateLimits,
 }
 
 impl League {
@@ -117,22 +459,46 @@ impl League {
     ///
     /// team_size should be the number of [DraftItem]s on each team, e.g. 15 (11 starters + 4 substitutes) for fantasy football.
     ///
+    /// Players do not all need to be known up front - others can join, choose seats and ready up through the lobby
+    /// (see [`League::join_lobby`] and [`League::lobby_components`]) until the League is activated.
+    ///
     /// To avoid cluttering a single channel with multiple draft announcements, output channels can be set for individual Leagues.
     /// Leagues with a None output should send their messages to the [`DraftGuild`]'s default_output.
     ///
     /// The current options for draft types are:
     ///
     /// * **Snake draft**:
-    /// To snake draft, the pool of possible selections is passed from start to end with each player picking once. When the draft reaches the end of the table, the final player
-    /// selects a second time, then the pool is passed back along the table in reverse order (the placement of starting settlements in Catan is snake draft).
+    ///   To snake draft, the pool of possible selections is passed from start to end with each player picking once. When the draft reaches the end of the table, the final player
+    ///   selects a second time, then the pool is passed back along the table in reverse order (the placement of starting settlements in Catan is snake draft).
     ///
     /// * **Linear draft**:
-    /// A linear draft is more straightforward -- the pool of selections is passed around in a circle. Once the pool reaches the last player, that player passes it back to the first player.
+    ///   A linear draft is more straightforward -- the pool of selections is passed around in a circle. Once the pool reaches the last player, that player passes it back to the first player.
+    ///
+    /// * **Rochester draft**:
+    ///   The pool is split into packs which are revealed face up one at a time (see [`League::set_packs`] and [`League::reveal_pack`]).
+    ///   Players snake through picking from the revealed pack until it is empty, and only then is the next pack revealed.
+    ///
+    /// * **Auction**:
+    ///   Players nominate items from the pool one at a time, and everyone bids for each in secret (see [`League::nominate`] and [`League::bid`]).
+    ///   [`League::resolve_lot`] gives it to the highest bidder. Players do not pick in turn, so [`League::lock`] and [`League::skip`] are not used.
+    ///
+    /// * **Winston draft**:
+    ///   Two players take turns looking through three face-down piles, taking one or passing it to grow it (see [`League::set_winston_deck`],
+    ///   [`League::winston_take`] and [`League::winston_pass`]).
+    ///
+    /// * **Grid draft**:
+    ///   Two players each take a row or column of a 3×3 grid of items, then the next grid is dealt (see [`League::set_grid_deck`] and [`League::grid_take`]).
+    ///
+    /// * **Solomon draft**:
+    ///   One of two players splits the revealed items into two piles, and the other chooses a pile. Then they swap roles for the next items
+    ///   (see [`League::set_solomon_deck`], [`League::solomon_split`] and [`League::solomon_choose`]).
+    ///   None of the head-to-head drafts use [`League::lock`] or [`League::skip`], and each ends once its deck is used up.
     ///
     /// # Panics
     ///
     /// If the users Vec is empty, the program will panic.
     /// Draft organizers should have a method of populating this collection before initializing a new League - e.g. an "Add to Draft" context menu command.
+    /// Use [`League::builder`] to get an error instead.
     pub fn new(
         users: &[serenity::UserId],
         id: u64,
@@ -143,11 +509,7 @@ impl League {
     ) -> League {
         let mut players = Vec::new();
         for id in users.iter() {
-            players.push(ActivePlayer {
-                picks: Vec::new(),
-                queue: VecDeque::new(),
-                id: *id,
-            })
+            players.push(ActivePlayer::new(*id))
         }
         let final_pick = (players.len() as u32 * team_size) - 1;
         League {
@@ -155,127 +517,646 @@ impl League {
             players,
             output,
             name,
-            active: false,
+            phase: LeaguePhase::Setup,
             current_seat: 0,
             total_picks: 0,
             draft_type,
             final_pick,
+            order: None,
+            clock: PickClock::new(None),
+            packs: None,
+            winston: None,
+            grid: None,
+            solomon: None,
+            team_size,
+            lobby: lobby::Lobby::new(),
+            permissions: permissions::Permissions::new(),
+            emoji: emoji::EmojiMap::new(),
+            pool: None,
+            autopick_strategy: Box::new(BestAvailable),
+            deadlines: deadlines::Deadlines::new(),
+            templates: template::Templates::new(),
+            events: Vec::new(),
+            stream: None,
+            results: Vec::new(),
+            schedule: Vec::new(),
+            locked_items: HashSet::new(),
+            shared_pool: None,
+            pick_times: Vec::new(),
+            expansion: None,
+            reminders: slow::Reminders::new(),
+            timezone: None,
+            locale: None,
+            active_gauge: metrics::ActiveGauge::default(),
+            aliases: HashMap::new(),
+            thread: None,
+            queue_limit: None,
+            queues_locked_off_turn: false,
+            round_categories: HashMap::new(),
+            roster_slots: HashMap::new(),
+            salary_cap: None,
+            auction: auction::Auction::default(),
+            team_roles: HashMap::new(),
+            skip_policy: SkipPolicy::default(),
+            started_at: None,
+            summary: None,
+            last_activity: Utc::now(),
+            picked: HashMap::new(),
+            queued: HashMap::new(),
+            names: HashSet::new(),
+            idempotency: idempotency::Idempotency::new(),
+            rate_limits: cooldowns::RateLimits::default(),
         }
     }
+    /// Starts a [`LeagueBuilder`], which validates the League's configuration and returns a [`LeagueConfigError`] instead of panicking.
+    pub fn builder(id: u64, name: String) -> LeagueBuilder {
+        LeagueBuilder::new(id, name)
+    }
     /// Moves the draft one seat forward and returns the [`ActivePlayer`] at that position, or
     /// None if the draft is complete.
     ///
-    ///  If the draft is complete, the League is set to inactive.
+    ///  If the draft is complete, the League moves to [`LeaguePhase::PostDraft`].
     ///
     /// This method is used in [`League::lock`], and does not need to be implemented manually for the normal movement of the draft.
-    /// However, it can be useful in a /skip command, where an absent player can be skipped to prevent a draft from stalling.
-    /// Note that in this case, the draft will end with that player having selected one fewer Draftables than the other players -
-    /// see [`League::add_to_player_picks`].
+    /// It always moves on as [`SkipPolicy::LoseThePick`] does, whatever the League's policy - to skip an absent player,
+    /// use [`League::skip`] or [`League::skip_if_overdue`], which follow the League's [`SkipPolicy`].
+    ///
+    /// Just as after a pick, if the next player has a pick queued, or is on autopick (see [`League::set_autopick`]), their pick is locked in
+    /// immediately and the draft moves on again. The returned player is the one left on the clock - use [`League::skip`] to also find out which picks were made.
     pub fn advance(&mut self) -> Option<&mut ActivePlayer> {
-        if self.total_picks == self.final_pick {
-            self.deactivate();
+        self.record(DraftEvent::Skipped);
+        let (_, ongoing) = self.advance_private(Vec::new());
+        if !ongoing {
             return None;
         }
-        let next = match self.draft_type {
-            draft_types::DraftType::Snake => {
-                draft_types::snake_draft(self.total_picks, self.players.len() as u32)
+        Some(&mut self.players[self.current_seat as usize])
+    }
+    /// Skips the player on the clock, e.g. for a /skip command, dealing with their turn by the League's [`SkipPolicy`]
+    /// (see [`League::set_skip_policy`]). Returns any picks made, for the player skipped and the players after them,
+    /// in the same form as [`League::lock`].
+    ///
+    /// # Errors
+    ///
+    /// If the league is not drafting, returns a [`LeagueError::LeagueInactiveError`].
+    ///
+    /// If the league is paused, returns a [`LeagueError::LeaguePausedError`].
+    ///
+    /// In an auction or a Winston, Grid or Solomon draft, returns [`LeagueError::WrongDraftTypeError`].
+    pub fn skip(&mut self) -> Result<Vec<PickResult>, LeagueError> {
+        metrics::tracked(self.require_drafting())?;
+        metrics::tracked(self.require_turns())?;
+        let picks = self.skip_by_policy();
+        metrics::picks_made(picks.len());
+        Ok(picks)
+    }
+    // returns false if the draft is over
+    fn advance_seat(&mut self) -> bool {
+        if self.total_picks == self.final_pick {
+            self.finish_draft();
+            return false;
+        }
+        self.total_picks += 1;
+        self.current_seat = self.seat_at(self.total_picks);
+        self.clock.start(Utc::now());
+        true
+    }
+    // moves the League on to PostDraft once the last item has been drafted
+    pub(crate) fn finish_draft(&mut self) {
+        self.phase = LeaguePhase::PostDraft;
+        self.clock.stop();
+        self.track_phase();
+        self.discard_queues();
+        self.summarize();
+    }
+    // the seat that makes the pick with the given zero-based index
+    fn seat_at(&self, pick: u32) -> u32 {
+        if let Some(order) = &self.order {
+            return order[pick as usize];
+        }
+        self.rotation_seat(pick)
+    }
+    // the seat for a pick if every seat stays in the rotation
+    fn rotation_seat(&self, pick: u32) -> u32 {
+        if pick == 0 {
+            return 0;
+        }
+        match self.draft_type {
+            draft_types::DraftType::Snake | draft_types::DraftType::Rochester => {
+                draft_types::snake_draft(pick - 1, self.players.len() as u32)
             }
-            draft_types::DraftType::Linear => {
-                draft_types::linear_draft(self.total_picks, self.players.len() as u32)
+            draft_types::DraftType::Linear
+            | draft_types::DraftType::Auction
+            | draft_types::DraftType::Winston
+            | draft_types::DraftType::Grid
+            | draft_types::DraftType::Solomon => {
+                draft_types::linear_draft(pick - 1, self.players.len() as u32)
             }
-        };
-        self.current_seat = next;
-        self.total_picks += 1;
-        Some(&mut self.players[next as usize])
+        }
     }
-    /// Sets the League to active. An active League is one in which the draft portion of the competition is taking place,
-    /// so waivers and trades are disabled.
-    pub fn activate(&mut self) {
-        self.active = true;
+    /// Starts the draft, moving the League to [`LeaguePhase::Drafting`]. While drafting, waivers and trades are disabled.
+    ///
+    /// Starts the [`PickClock`] for the player currently on the clock.
+    ///
+    /// # Errors
+    ///
+    /// If the League is not in one of the phases before the draft, returns [`LeagueError::InvalidPhaseTransitionError`].
+    ///
+    /// If every player has left the lobby, returns [`LeagueError::NoPlayersError`].
+    pub fn activate(&mut self) -> Result<(), LeagueError> {
+        if !self.phase.is_pre_draft() {
+            return Err(LeagueError::InvalidPhaseTransitionError(
+                self.phase,
+                LeaguePhase::Drafting,
+            ));
+        }
+        self.set_phase(LeaguePhase::Drafting).map(|_| ())
+    }
+    /// Kicks off the draft like [`League::activate`], and returns the first player on the clock (the first to nominate in an auction)
+    /// so a bot can announce them. Pick 1 is on the clock as soon as the draft starts - call this instead of [`League::advance`],
+    /// which would move straight on to pick 2.
+    ///
+    /// Records a [`DraftEvent::Started`] after the phase change.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`League::activate`].
+    pub fn start(&mut self) -> Result<&ActivePlayer, LeagueError> {
+        self.activate()?;
+        let seat = match self.draft_type {
+            draft_types::DraftType::Auction => self.nominating_turn().map_or(0, |(_, seat)| seat),
+            _ => self.current_seat,
+        };
+        let user = self.players[seat as usize].id;
+        self.record(DraftEvent::Started { user });
+        Ok(&self.players[seat as usize])
     }
-    /// Sets the League to inactive. Inactive Leagues may stay in their DraftGuild's collection, but users cannot make picks while drafts are inactive.
-    pub fn deactivate(&mut self) {
-        self.active = false;
+    /// Ends the draft, moving the League to [`LeaguePhase::PostDraft`]. This happens on its own once the final pick is made.
+    ///
+    /// # Errors
+    ///
+    /// If the draft is not underway, returns [`LeagueError::InvalidPhaseTransitionError`].
+    pub fn deactivate(&mut self) -> Result<(), LeagueError> {
+        self.set_phase(LeaguePhase::PostDraft).map(|_| ())
     }
-    /// Returns the active status of the League.
+    /// Returns true while the draft is underway, even if it is paused.
     pub fn active(&self) -> bool {
-        self.active
+        self.phase.is_drafting()
+    }
+    /// Freezes an active draft, moving the League to [`LeaguePhase::Paused`]. While paused, picks cannot be locked in and the [`PickClock`] does not run.
+    ///
+    /// Does nothing if the League is not drafting.
+    /// See [`DraftRegistry::pause_all`] to pause every League at once during an outage.
+    pub fn pause(&mut self, now: DateTime<Utc>) {
+        if self.phase != LeaguePhase::Drafting {
+            return;
+        }
+        self.phase = LeaguePhase::Paused;
+        self.clock.pause(now);
+        self.record(DraftEvent::PhaseChanged(LeaguePhase::Paused));
+    }
+    /// Unfreezes a paused draft, restarting the [`PickClock`] where it left off. Does nothing if the League is not paused.
+    pub fn resume(&mut self, now: DateTime<Utc>) {
+        if self.phase != LeaguePhase::Paused {
+            return;
+        }
+        self.phase = LeaguePhase::Drafting;
+        self.clock.resume(now);
+        self.record(DraftEvent::PhaseChanged(LeaguePhase::Drafting));
+    }
+    /// Returns true if the draft is paused.
+    pub fn paused(&self) -> bool {
+        self.phase == LeaguePhase::Paused
+    }
+    /// Returns the League's [`PickClock`], for checking how long the current player has been on the clock.
+    pub fn clock(&self) -> &PickClock {
+        &self.clock
+    }
+    /// Sets the time each player has to make a pick. None (the default) means picks are untimed.
+    ///
+    /// DRFTR does not skip players on its own - poll [`PickClock::expired`] from a background task and decide what to do with slow pickers.
+    pub fn set_pick_time_limit(&mut self, limit: Option<Duration>) {
+        self.clock.set_limit(limit);
     }
-    /// Records the pick argument, then recursively advances the draft, recording any picks that ActivePlayers have queued.
+    /// Records the pick argument, then advances the draft, recording any picks that ActivePlayers have queued.
     ///
     /// Each time a pick is locked in, it is removed from each other ActivePlayer's queue.
     ///
     /// # Returns
     ///
-    /// Returns a Vec of picks as tuples. The first element in the tuple is the UserId of the ActivePlayer who made the pick,
-    /// and the second is the name() of the [`DraftItem`] (as a String, not a &str, for ownership reasons - gimme a break here I'm new to Rust).
+    /// Returns a [`PickResult`] for every pick locked in, in order: this one first, then any made from queues or by autopick,
+    /// each with its [`PickSource`].
+    ///
+    /// # Errors
+    ///
+    /// If the league is not drafting, returns a [`LeagueError::LeagueInactiveError`].
+    ///
+    /// If the league is paused, returns a [`LeagueError::LeaguePausedError`].
+    ///
+    /// In a Rochester draft, if the pick is not in the face-up pack, returns a [`LeagueError::DraftableNotInPackError`].
+    /// In an auction, returns [`LeagueError::WrongDraftTypeError`]; items are won with [`League::bid`] instead.
+    /// Winston, Grid and Solomon drafts return it too, since their items are taken from the table.
+    ///
+    /// If the league has a [`DraftPool`] and the pick was never in it, returns a [`LeagueError::DraftableNotFoundError`]
+    /// with the pool's closest names.
+    /// If it has already been picked (every copy of it, see [`DraftPool::with_copies`]), or the player on the clock already has it,
+    /// returns a [`LeagueError::DraftableInUseError`]. Leagues without a pool check picks against everyone's roster.
+    /// Queued picks that have already been taken are passed over when the draft reaches their seat.
+    ///
+    /// If the League shares its pool (see [`League::share_pool`]) and another League has taken the pick, returns a [`LeagueError::PickConflictError`].
+    pub fn lock(&mut self, pick: Draftable) -> Result<Vec<PickResult>, LeagueError> {
+        let pick = self.resolve_item(pick);
+        let picks = metrics::tracked(self.lock_checked(pick, PickSource::Manual))?;
+        metrics::picks_made(picks.len());
+        Ok(picks)
+    }
+    /// Locks in a pick like [`League::lock`] on behalf of the user who asked for it, e.g. the author of a /pick command,
+    /// checking first that it is their turn.
+    ///
+    /// The seat on the clock's owner, its co-owners and its proxy (see [`League::set_proxy`]) may pick for it. Commissioners
+    /// may pick for any seat, and their picks for seats they do not own are reported as [`PickSource::Admin`].
     ///
     /// # Errors
     ///
-    /// If the league is marked as inactive, returns a [`LeagueError::LeagueInactiveError`].
-    pub fn lock(
+    /// If the user cannot pick for the seat on the clock, returns a [`LeagueError::NotYourTurnError`].
+    ///
+    /// Otherwise, returns the same errors as [`League::lock`].
+    pub fn lock_as(
         &mut self,
+        id: serenity::UserId,
         pick: Draftable,
-    ) -> Result<Vec<(serenity::UserId, String)>, LeagueError> {
-        if !self.active {
-            return Err(LeagueError::LeagueInactiveError);
+    ) -> Result<Vec<PickResult>, LeagueError> {
+        metrics::tracked(self.require_drafting())?;
+        let seat = &self.players[self.current_seat as usize];
+        let source =
+            if seat.is_owner(id) || matches!(self.proxy(seat.id), Ok(Some(proxy)) if proxy == id) {
+                PickSource::Manual
+            } else if self.role(id) == Role::Commissioner {
+                PickSource::Admin
+            } else {
+                return metrics::tracked(Err(LeagueError::NotYourTurnError));
+            };
+        let pick = self.resolve_item(pick);
+        let picks = metrics::tracked(self.lock_checked(pick, source))?;
+        metrics::picks_made(picks.len());
+        Ok(picks)
+    }
+    fn lock_checked(
+        &mut self,
+        pick: Draftable,
+        source: PickSource,
+    ) -> Result<Vec<PickResult>, LeagueError> {
+        self.require_drafting()?;
+        self.require_turns()?;
+        if let Some(packs) = &self.packs {
+            if !packs.contains(pick.name()) {
+                return Err(LeagueError::DraftableNotInPackError);
+            }
+        }
+        self.require_round_category(&pick, self.total_picks)?;
+        self.require_roster_room(self.current_seat, &pick, &[])?;
+        self.require_available(self.current_seat, pick.name())?;
+        self.claim_shared(pick.name())?;
+        Ok(self.lock_private(pick, source, Vec::new()).0)
+    }
+    /// Locks in a pick like [`League::lock`], but only if the draft is still on overall pick number expected_pick (see [`League::pick_number`]).
+    ///
+    /// Read the pick number when a /pick command starts, and pass it here when the command is ready to lock in: if two users pick at once,
+    /// the second finds the draft has moved on rather than taking the next player's turn.
+    ///
+    /// # Errors
+    ///
+    /// If the draft is no longer on expected_pick, returns a [`LeagueError::StalePickError`].
+    ///
+    /// Otherwise, returns the same errors as [`League::lock`].
+    pub fn lock_if_seat(
+        &mut self,
+        expected_pick: u32,
+        pick: Draftable,
+    ) -> Result<Vec<PickResult>, LeagueError> {
+        if expected_pick != self.pick_number() {
+            return metrics::tracked(Err(LeagueError::StalePickError));
         }
-        Ok(self.lock_private(pick, Vec::new()))
+        self.lock(pick)
+    }
+    /// Returns the League's ID.
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+    /// Returns the League's name, which is also its key in its [`DraftGuild`] (see [`DraftGuild::rename_league`]).
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+    /// Returns the League's own output channel, if it has one. See [`League::output_channel`] for where it actually posts.
+    pub fn output(&self) -> Option<serenity::ChannelId> {
+        self.output
+    }
+    /// Returns the League's [`DraftType`].
+    pub fn draft_type(&self) -> draft_types::DraftType {
+        self.draft_type
+    }
+    /// Returns the number of picks each player makes, unless their seat has its own (see [`League::player_team_size`]).
+    pub fn team_size(&self) -> u32 {
+        self.team_size
     }
+    /// Returns the seats in draft order, starting with seat 0. Each [`ActivePlayer`] carries its owner's [`ActivePlayer::id`].
+    pub fn players(&self) -> impl ExactSizeIterator<Item = &ActivePlayer> {
+        self.players.iter()
+    }
+    /// Returns the seat (starting at 0) on the clock. See [`League::current_player`] for its player.
+    pub fn current_seat(&self) -> u32 {
+        self.current_seat
+    }
+    /// Returns how many picks have been made so far, skipped turns included.
+    pub fn total_picks(&self) -> u32 {
+        self.total_picks
+    }
+    /// Returns the overall number of the pick on the clock, counting every pick in the draft from 1.
+    pub fn pick_number(&self) -> u32 {
+        self.total_picks + 1
+    }
+    // the bool is false once the draft is over
     fn lock_private(
         &mut self,
         pick: Draftable,
-        returned_picks: Vec<(serenity::UserId, String)>,
-    ) -> Vec<(serenity::UserId, String)> {
+        source: PickSource,
+        returned_picks: Vec<PickResult>,
+    ) -> (Vec<PickResult>, bool) {
         let mut returned_picks = returned_picks;
-        for player in &mut self.players {
-            player.delete_from_queue(pick.name());
-        }
-        let current_player = &mut self.players[self.current_seat as usize];
-        returned_picks.push((current_player.id, pick.name().to_string()));
-        current_player.lock_in(pick);
-        if let Some(next_player) = self.advance() {
-            if let Some(pick) = next_player.first_in_queue() {
-                returned_picks = self.lock_private(pick, returned_picks);
+        returned_picks.push(self.place_pick(pick, source));
+        self.advance_private(returned_picks)
+    }
+    // gives the seat on the clock its pick, without moving on to the next seat
+    fn place_pick(&mut self, pick: Draftable, source: PickSource) -> PickResult {
+        self.unqueue_everywhere(pick.name());
+        // in a Rochester draft the item comes off the table rather than from the caller
+        let pick = match &mut self.packs {
+            Some(packs) => packs.take(pick.name()).unwrap_or(pick),
+            None => pick,
+        };
+        let pick = match &mut self.pool {
+            Some(pool) => pool.take(pick.name()).unwrap_or(pick),
+            None => pick,
+        };
+        if self.clock.running() {
+            let time = self.clock.elapsed(Utc::now());
+            self.pick_times.push((self.total_picks + 1, time));
+        }
+        let placed = PickResult {
+            pick: self.total_picks + 1,
+            round: self.round_of(self.total_picks),
+            seat: self.current_seat,
+            user: self.players[self.current_seat as usize].id,
+            item: self.intern(pick.name()),
+            source,
+        };
+        if source == PickSource::Autopick {
+            self.players[self.current_seat as usize].autopicks += 1;
+        }
+        self.give_pick(self.current_seat, pick);
+        self.record(DraftEvent::Picked {
+            user: placed.user,
+            item: placed.item.to_string(),
+        });
+        placed
+    }
+    // moves on to the next seat, then keeps locking in picks for as long as the seats on the clock have one queued or are on
+    // autopick, and moves on past forfeited seats. A loop rather than recursion, so long drafts of autopickers cannot run out of stack
+    fn advance_private(&mut self, returned_picks: Vec<PickResult>) -> (Vec<PickResult>, bool) {
+        let mut returned_picks = returned_picks;
+        loop {
+            if !self.advance_seat() {
+                return (returned_picks, false);
+            }
+            match self.next_auto_pick() {
+                Some((pick, source)) => returned_picks.push(self.place_pick(pick, source)),
+                // forfeited seats never stall the draft
+                None if self.players[self.current_seat as usize].forfeited => {
+                    self.record(DraftEvent::Skipped)
+                }
+                None => return (returned_picks, true),
+            }
+        }
+    }
+    // locks in a pick for the seat on the clock if it has one queued or is on autopick, and moves on past forfeited seats
+    fn settle_private(&mut self, returned_picks: Vec<PickResult>) -> (Vec<PickResult>, bool) {
+        match self.next_auto_pick() {
+            Some((pick, source)) => self.lock_private(pick, source, returned_picks),
+            None if self.players[self.current_seat as usize].forfeited => {
+                self.record(DraftEvent::Skipped);
+                self.advance_private(returned_picks)
+            }
+            None => (returned_picks, true),
+        }
+    }
+    // the first queued pick, or for players on autopick, whatever the autopick strategy chooses
+    fn next_auto_pick(&mut self) -> Option<(Draftable, PickSource)> {
+        if let Some(queued) = self.queued_pick() {
+            return Some((queued, PickSource::Queue));
+        }
+        if !self.players[self.current_seat as usize].autopick {
+            return None;
+        }
+        self.strategy_pick(None)
+            .map(|pick| (pick, PickSource::Autopick))
+    }
+    // the first item in the current player's queue they can pick now
+    pub(crate) fn queued_pick(&mut self) -> Option<Draftable> {
+        loop {
+            // Rochester drafts skip over queued items that are not on the table, and items from another category wait for their round
+            let idx = self.players[self.current_seat as usize]
+                .queue
+                .iter()
+                .position(|i| {
+                    self.fits_round(i, self.total_picks)
+                        && self.fits_roster(self.current_seat, i)
+                        && self.packs.as_ref().is_none_or(|p| p.contains(i.name()))
+                })?;
+            let queued = self.dequeue_at(self.current_seat, idx)?;
+            if self
+                .require_available(self.current_seat, queued.name())
+                .is_ok()
+                && self.claim_shared(queued.name()).is_ok()
+            {
+                return Some(queued);
             }
+            // someone already has it, or another League took it, so try the next one in the queue
+        }
+    }
+    /// Gives the League a [`DraftPool`] of the items available to be picked, replacing any it had, and returns it.
+    ///
+    /// Once a League has a pool, picks are checked against it, picked items are removed from it, and players on autopick
+    /// with nothing queued pick from it with the League's [`AutopickStrategy`].
+    ///
+    /// # Errors
+    ///
+    /// If the league is active, returns [`LeagueError::LeagueActiveError`].
+    ///
+    /// If the draft is already over, returns [`LeagueError::WrongPhaseError`].
+    pub fn set_pool(&mut self, pool: DraftPool) -> Result<&DraftPool, LeagueError> {
+        self.require_pre_draft()?;
+        let event = if pool.counts().all(|(_, copies)| copies == 1) {
+            DraftEvent::PoolSet(pool.iter().map(|i| i.name().to_string()).collect())
+        } else {
+            let counts = pool
+                .counts()
+                .map(|(name, copies)| (name.to_string(), copies));
+            DraftEvent::PoolWithCopiesSet(counts.collect())
+        };
+        self.record(event);
+        Ok(self.pool.insert(pool))
+    }
+    /// Returns the League's [`DraftPool`].
+    ///
+    /// # Errors
+    ///
+    /// If the League has no pool, returns [`LeagueError::NoPoolError`].
+    pub fn pool(&self) -> Result<&DraftPool, LeagueError> {
+        self.pool.as_ref().ok_or(LeagueError::NoPoolError)
+    }
+    /// Puts a player on (or takes them off) autopick. Whenever the draft reaches a player on autopick, their first queued pick is locked in,
+    /// or if their queue is empty, whatever the League's [`AutopickStrategy`] chooses from the [`DraftPool`] (or the face-up pack of a Rochester draft).
+    /// The default strategy takes the best item left.
+    ///
+    /// A player on autopick with nothing queued and no pool to pick from stays on the clock as usual.
+    ///
+    /// # Errors
+    ///
+    /// If the player is not in the league, returns [`LeagueError::PlayerNotFoundError`].
+    pub fn set_autopick(
+        &mut self,
+        id: serenity::UserId,
+        autopick: bool,
+    ) -> Result<(), LeagueError> {
+        let Some(player) = self.get_player_mut(id) else {
+            return Err(LeagueError::PlayerNotFoundError);
+        };
+        player.autopick = autopick;
+        let user = player.id;
+        self.record(DraftEvent::AutopickSet { user, autopick });
+        Ok(())
+    }
+    /// Partitions the pool of a Rochester draft into packs of pack_size items, in the order given.
+    ///
+    /// Shuffle the pool before calling this if you want random packs. The last pack may hold fewer than pack_size items,
+    /// and a pack_size of 0 puts the whole pool in a single pack. Any packs set previously are discarded.
+    ///
+    /// # Errors
+    ///
+    /// If the league is active, returns [`LeagueError::LeagueActiveError`].
+    ///
+    /// If the draft is already over, returns [`LeagueError::WrongPhaseError`].
+    ///
+    /// If the league's draft type is not [`DraftType::Rochester`], returns [`LeagueError::WrongDraftTypeError`].
+    pub fn set_packs(
+        &mut self,
+        pool: Vec<Draftable>,
+        pack_size: usize,
+    ) -> Result<&PackSet, LeagueError> {
+        self.require_pre_draft()?;
+        if !matches!(self.draft_type, draft_types::DraftType::Rochester) {
+            return Err(LeagueError::WrongDraftTypeError);
+        }
+        let items = pool.iter().map(|i| i.name().to_string()).collect();
+        self.record(DraftEvent::PacksSet { items, pack_size });
+        Ok(self.packs.insert(PackSet::partition(pool, pack_size)))
+    }
+    /// Reveals the next pack of a Rochester draft and returns its contents, so the bot can lay it out for the table.
+    ///
+    /// Call this once before the first pick, and again each time the face-up pack has been picked clean.
+    ///
+    /// # Errors
+    ///
+    /// If no packs have been set, returns [`LeagueError::WrongDraftTypeError`].
+    ///
+    /// If the face-up pack still has items in it, returns [`LeagueError::PackNotExhaustedError`].
+    ///
+    /// If every pack has already been revealed, returns [`LeagueError::NoPacksRemainingError`].
+    pub fn reveal_pack(&mut self) -> Result<&Vec<Draftable>, LeagueError> {
+        let Some(packs) = &mut self.packs else {
+            return Err(LeagueError::WrongDraftTypeError);
+        };
+        if !packs.revealed().is_empty() {
+            return Err(LeagueError::PackNotExhaustedError);
+        }
+        if packs.reveal().is_none() {
+            return Err(LeagueError::NoPacksRemainingError);
         }
-        returned_picks
+        self.record(DraftEvent::PackRevealed);
+        Ok(self.packs.as_ref().unwrap().revealed())
+    }
+    /// Returns the [`PackSet`] of a Rochester draft, which exposes the face-up pack and the pack round.
+    ///
+    /// # Errors
+    ///
+    /// If no packs have been set, returns [`LeagueError::WrongDraftTypeError`].
+    pub fn packs(&self) -> Result<&PackSet, LeagueError> {
+        self.packs.as_ref().ok_or(LeagueError::WrongDraftTypeError)
+    }
+    fn recalculate_final_pick(&mut self) {
+        let picks: u32 = self
+            .players
+            .iter()
+            .map(|p| p.team_size.unwrap_or(self.team_size))
+            .sum();
+        self.final_pick = picks.saturating_sub(1);
     }
     /// Exchanges a player's [DraftItem] (waivered_from) for a [DraftItem] available in the pool (waivered_for).
     ///
+    /// If the League has a [`DraftPool`], waivered_for is taken out of it and waivered_from is put back in.
+    ///
     /// # Errors
     ///
     /// If the league is active, returns [`LeagueError::LeagueActiveError`].
     ///
-    /// If waivered_for has been picked, it is not in the pool and must be traded for - returns [`LeagueError::DraftableInUseError`].
+    /// If the league is archived, returns [`LeagueError::WrongPhaseError`].
+    ///
+    /// If either item is locked (see [`League::lock_item`]), returns [`LeagueError::DraftableLockedError`].
+    ///
+    /// If waivered_for has been picked (every copy of it, see [`DraftPool::with_copies`]), it is not in the pool and must be traded for,
+    /// and if the player already has it - returns [`LeagueError::DraftableInUseError`].
     ///
-    /// If waivered_from is not in the player's list of picks, returns [`LeagueError::DraftableNotFoundError`].
+    /// If waivered_from is not in the player's list of picks, or the league has a [`DraftPool`] which waivered_for is not in,
+    /// returns [`LeagueError::DraftableNotFoundError`] with the closest names among the player's picks or in the pool.
     ///
     /// If the player is not in this league, returns [`LeagueError::PlayerNotFoundError`].
+    ///
+    /// Every error comes back as a [`Rejected`] holding waivered_for, and leaves the League unchanged.
     pub fn waiver(
         &mut self,
         id: serenity::UserId,
         waivered_from: &str,
         waivered_for: Draftable,
-    ) -> Result<&Vec<Draftable>, LeagueError> {
-        if self.active {
-            return Err(LeagueError::LeagueActiveError);
-        };
-        let all_picks = match self.all_picks() {
-            Ok(picks) => picks,
-            Err(_) => Vec::new(),
+    ) -> Result<&Vec<Draftable>, Rejected> {
+        let waivered_from = self.resolve_name(waivered_from).to_string();
+        let claimed = self.resolve_name(waivered_for.name()).to_string();
+        let seat = match metrics::tracked(self.waiver_checked(id, &waivered_from, &claimed)) {
+            Ok(seat) => seat,
+            Err(error) => {
+                return Err(Rejected {
+                    error,
+                    item: waivered_for,
+                })
+            }
         };
-        if all_picks.iter().any(|p| p.name() == waivered_for.name()) {
-            return Err(LeagueError::DraftableInUseError);
-        }
-        if let Some(player) = self.get_player_mut(id) {
-            if let Some(_) = player.delete_from_picks(waivered_from) {
-                player.lock_in(waivered_for);
-                return Ok(&player.picks);
+        let waivered_for = self.resolve_item(waivered_for);
+        let dropped = self.take_pick(seat, &waivered_from).unwrap();
+        // the dropped item goes back into the pool, and the claimed one comes out of it
+        let waivered_for = match &mut self.pool {
+            Some(pool) => {
+                pool.restore(dropped);
+                pool.take(waivered_for.name()).unwrap_or(waivered_for)
             }
-            return Err(LeagueError::DraftableNotFoundError);
-        }
-        Err(LeagueError::PlayerNotFoundError)
+            None => waivered_for,
+        };
+        self.release_shared(&waivered_from);
+        self.record(DraftEvent::Waivered {
+            user: self.players[seat as usize].id,
+            dropped: waivered_from.to_string(),
+            claimed: waivered_for.name().to_string(),
+        });
+        self.give_pick(seat, waivered_for);
+        self.count_use(id, RateLimitedAction::Waiver, Utc::now());
+        Ok(&self.players[seat as usize].picks)
     }
     /// Trades item1 from user1 to user2 for item2.
     ///
@@ -287,9 +1168,16 @@ impl League {
     ///
     /// If the league is active, returns [`LeagueError::LeagueActiveError`].
     ///
-    /// If user1 does not have item1, or user2 does not have item2, returns [`LeagueError::DraftableNotFoundError`].
+    /// If the league is archived, returns [`LeagueError::WrongPhaseError`].
+    ///
+    /// If either item is locked (see [`League::lock_item`]), returns [`LeagueError::DraftableLockedError`].
+    ///
+    /// If user1 does not have item1, or user2 does not have item2, returns [`LeagueError::DraftableNotFoundError`]
+    /// with the closest names the user does have.
     ///
     /// If either user1 or user2 are not in the draft, returns [`LeagueError::PlayerNotFoundError`].
+    ///
+    /// Both sides are checked before either item moves, so on an error both rosters are left as they were.
     pub fn trade(
         &mut self,
         user1: serenity::UserId,
@@ -297,64 +1185,142 @@ impl League {
         user2: serenity::UserId,
         item2: &str,
     ) -> Result<(&Vec<Draftable>, &Vec<Draftable>), LeagueError> {
-        if self.active {
-            return Err(LeagueError::LeagueActiveError);
-        };
-        let Some(player1) = self.get_player_mut(user1) else {
-            return Err(LeagueError::PlayerNotFoundError)
-        };
-        let Some(item1) = player1.delete_from_picks(item1) else {
-            return Err(LeagueError::DraftableNotFoundError)
-        };
-        let Some(player2) = self.get_player_mut(user2) else {
-            return Err(LeagueError::PlayerNotFoundError)
-        };
-        let Some(item2) = player2.delete_from_picks(item2) else {
-            return Err(LeagueError::DraftableNotFoundError)
+        let item1 = self.resolve_name(item1).to_string();
+        let item2 = self.resolve_name(item2).to_string();
+        metrics::tracked(self.trade_checked(user1, &item1, user2, &item2))
+    }
+    // everything that can stop a waiver, checked before anything changes; returns the player's seat
+    fn waiver_checked(
+        &mut self,
+        id: serenity::UserId,
+        waivered_from: &str,
+        waivered_for: &str,
+    ) -> Result<u32, LeagueError> {
+        self.require_transactions()?;
+        self.require_rate(id, RateLimitedAction::Waiver, Utc::now())?;
+        self.require_unlocked(&[waivered_from, waivered_for])?;
+        let Ok(seat) = self.seat_of(id) else {
+            return Err(LeagueError::PlayerNotFoundError);
         };
-        let p1 = self.get_player_mut(user1).unwrap();
-        p1.lock_in(item2);
-        let p2 = self.get_player_mut(user2).unwrap();
-        p2.lock_in(item1);
+        self.require_available(seat, waivered_for)?;
+        if !self.holds(seat, waivered_from) {
+            return Err(fuzzy::not_found(
+                waivered_from,
+                &self.players[seat as usize].picks,
+            ));
+        }
+        self.claim_shared(waivered_for)?;
+        Ok(seat)
+    }
+    fn trade_checked(
+        &mut self,
+        user1: serenity::UserId,
+        item1: &str,
+        user2: serenity::UserId,
+        item2: &str,
+    ) -> Result<(&Vec<Draftable>, &Vec<Draftable>), LeagueError> {
+        self.require_transactions()?;
+        self.require_rate(user1, RateLimitedAction::Trade, Utc::now())?;
+        self.require_unlocked(&[item1, item2])?;
+        // both sides are checked before either item moves, so a failed trade leaves both rosters as they were
+        let mut seats = [0; 2];
+        for (seat, (user, item)) in seats.iter_mut().zip([(user1, item1), (user2, item2)]) {
+            *seat = self.seat_of(user)?;
+            if !self.holds(*seat, item) {
+                return Err(fuzzy::not_found(item, &self.players[*seat as usize].picks));
+            }
+        }
+        let [seat1, seat2] = seats;
+        let item1 = self.take_pick(seat1, item1).unwrap();
+        let item2 = self.take_pick(seat2, item2).unwrap();
+        self.record(DraftEvent::Traded {
+            user1: self.seat_owner(user1),
+            item1: item1.name().to_string(),
+            user2: self.seat_owner(user2),
+            item2: item2.name().to_string(),
+        });
+        self.give_pick(seat1, item2);
+        self.give_pick(seat2, item1);
+        self.count_use(user1, RateLimitedAction::Trade, Utc::now());
+        metrics::trade_made();
         Ok((
-            &self.get_player(user1).unwrap().picks,
-            &self.get_player(user2).unwrap().picks,
+            &self.players[seat1 as usize].picks,
+            &self.players[seat2 as usize].picks,
         ))
     }
+    /// Caps how many items each player can queue, e.g. to keep autopick cascades sane. None (the default) means queues are unlimited.
+    ///
+    /// Queues already past a new cap keep their items, but nothing more can be added to them until they shrink below it.
+    pub fn set_queue_limit(&mut self, limit: Option<u32>) {
+        self.queue_limit = limit;
+    }
+    /// Returns how many items each player can queue, if queues are capped.
+    pub fn queue_limit(&self) -> Option<u32> {
+        self.queue_limit
+    }
     /// Adds a Draftable to the given user's queue and returns the new queue.
     ///
     /// # Errors
     ///
     /// If there is no player in the league with the given ID, returns a [`LeagueError::PlayerNotFoundError`].
+    ///
+    /// If the player's queue already holds as many items as the League allows (see [`League::set_queue_limit`]),
+    /// returns a [`LeagueError::QueueFullError`] with the cap.
+    ///
+    /// Queues are emptied when the draft ends, so once it is over returns a [`LeagueError::WrongPhaseError`].
+    /// If queues are frozen outside the player's turn (see [`League::set_queue_edits_off_turn`]) and another seat is on the clock,
+    /// returns a [`LeagueError::NotYourTurnError`].
     pub fn add_to_player_queue(
         &mut self,
         id: serenity::UserId,
         item: Draftable,
     ) -> Result<&VecDeque<Draftable>, LeagueError> {
-        if let Some(player) = self.get_player_mut(id) {
-            player.add_to_queue(item);
-            return Ok(&player.queue);
+        self.require_queue_edit(id)?;
+        let item = self.resolve_item(item);
+        let seat = self.seat_of(id)?;
+        let player = &self.players[seat as usize];
+        if let Some(limit) = self.queue_limit {
+            if player.queue.len() >= limit as usize {
+                return Err(LeagueError::QueueFullError(limit));
+            }
         }
-        Err(LeagueError::PlayerNotFoundError)
+        let event = DraftEvent::Queued {
+            user: player.id,
+            item: item.name().to_string(),
+        };
+        self.enqueue(seat, item);
+        self.record(event);
+        self.count_use(id, RateLimitedAction::QueueEdit, Utc::now());
+        Ok(&self.players[seat as usize].queue)
     }
     /// Removes a Draftable from the player's queue and returns the removed item.
     ///
     /// # Errors
     ///
     /// If there is no player with the given ID, returns a [`LeagueError::PlayerNotFoundError`].
-    /// If there is no Draftable with the given name in the player's queue, returns a [`LeagueError::DraftableNotFoundError`].
+    /// If there is no Draftable with the given name in the player's queue, returns a [`LeagueError::DraftableNotFoundError`]
+    /// with the closest names in the queue.
+    ///
+    /// Queues are emptied when the draft ends, so once it is over returns a [`LeagueError::WrongPhaseError`].
+    /// If queues are frozen outside the player's turn (see [`League::set_queue_edits_off_turn`]) and another seat is on the clock,
+    /// returns a [`LeagueError::NotYourTurnError`].
     pub fn delete_from_player_queue(
         &mut self,
         id: serenity::UserId,
         name: &str,
     ) -> Result<Draftable, LeagueError> {
-        if let Some(player) = self.get_player_mut(id) {
-            if let Some(item) = player.delete_from_queue(name) {
-                return Ok(item);
-            }
-            return Err(LeagueError::DraftableNotFoundError);
-        }
-        Err(LeagueError::PlayerNotFoundError)
+        self.require_queue_edit(id)?;
+        let name = self.resolve_name(name).to_string();
+        let seat = self.seat_of(id)?;
+        let Some(item) = self.dequeue(seat, &name) else {
+            return Err(fuzzy::not_found(&name, &self.players[seat as usize].queue));
+        };
+        self.record(DraftEvent::Unqueued {
+            user: self.players[seat as usize].id,
+            item: item.name().to_string(),
+        });
+        self.count_use(id, RateLimitedAction::QueueEdit, Utc::now());
+        Ok(item)
     }
     /// Returns a given player's queue.
     ///
@@ -410,9 +1376,9 @@ impl League {
     ///
     /// # Errors
     ///
-    /// If the league is set as inactive, returns [`LeagueError::LeagueInactiveError`].
+    /// If the league is not drafting, returns [`LeagueError::LeagueInactiveError`].
     pub fn current_player(&self) -> Result<&ActivePlayer, LeagueError> {
-        if !self.active {
+        if !self.phase.is_drafting() {
             return Err(LeagueError::LeagueInactiveError);
         }
         Ok(&self.players[self.current_seat as usize])
@@ -423,81 +1389,299 @@ impl League {
     ///
     /// If there is no player with the given ID, returns a [`LeagueError::PlayerNotFoundError`].
     /// If the player's queue is empty before attempting to clear it, returns a [`LeagueError::PlayerQueueEmptyError`].
+    ///
+    /// Queues are emptied when the draft ends, so once it is over returns a [`LeagueError::WrongPhaseError`].
+    /// If queues are frozen outside the player's turn (see [`League::set_queue_edits_off_turn`]) and another seat is on the clock,
+    /// returns a [`LeagueError::NotYourTurnError`].
     pub fn clear_player_queue(
         &mut self,
         id: serenity::UserId,
     ) -> Result<Vec<Draftable>, LeagueError> {
-        if let Some(player) = self.get_player_mut(id) {
-            if player.queue.is_empty() {
-                return Err(LeagueError::PlayerQueueEmptyError);
-            }
-            let drained = player.queue.drain(..);
-            let mut cleared = Vec::new();
-            for d in drained {
-                cleared.push(d);
-            }
-            return Ok(cleared);
+        self.require_queue_edit(id)?;
+        let seat = self.seat_of(id)?;
+        if self.players[seat as usize].queue.is_empty() {
+            return Err(LeagueError::PlayerQueueEmptyError);
         }
-        Err(LeagueError::PlayerNotFoundError)
+        let cleared = self.drain_queue(seat);
+        let user = self.players[seat as usize].id;
+        self.record(DraftEvent::QueueCleared { user });
+        self.count_use(id, RateLimitedAction::QueueEdit, Utc::now());
+        Ok(cleared)
     }
     /// Adds a Draftable directly to a player's list of picks, and returns that player's picks.
     ///
     /// Use sparingly - it is preferable to use [League::lock]. However, this has some use as part of an admin command allowing the draft
-    /// organizer to pick Draftables for users who were skipped (see [League::advance]).
+    /// organizer to pick Draftables for users who were skipped (see [League::advance]). Players who owe a pick from a skipped turn
+    /// can make it themselves with [`League::lock_owed`].
     ///
     /// # Errors
     ///
     /// If the given player is not in the draft, returns [`LeagueError::PlayerNotFoundError`].
+    ///
+    /// If the league has a [`DraftPool`] and the pick is not in it, returns [`LeagueError::DraftableNotFoundError`] with the pool's closest names.
+    ///
+    /// Every error comes back as a [`Rejected`] holding the pick, and leaves the League unchanged.
     pub fn add_to_player_picks(
         &mut self,
         id: serenity::UserId,
         pick: Draftable,
-    ) -> Result<&Vec<Draftable>, LeagueError> {
-        let all_picks = match self.all_picks() {
-            Ok(picks) => picks,
-            Err(_) => Vec::new(),
+    ) -> Result<&Vec<Draftable>, Rejected> {
+        let name = self.resolve_name(pick.name()).to_string();
+        let checked = self.seat_of(id).and_then(|seat| {
+            self.require_available(seat, &name)?;
+            self.claim_shared(&name)?;
+            Ok(seat)
+        });
+        match checked {
+            Ok(seat) => Ok(self.add_checked(seat, pick)),
+            Err(error) => Err(Rejected { error, item: pick }),
+        }
+    }
+    // adds an item that has been checked and claimed to a seat's picks
+    pub(crate) fn add_checked(&mut self, seat: u32, pick: Draftable) -> &Vec<Draftable> {
+        let pick = self.resolve_item(pick);
+        let pick = match &mut self.pool {
+            Some(pool) => pool.take(pick.name()).unwrap_or(pick),
+            None => pick,
         };
-        if all_picks.iter().any(|p| p.name() == pick.name()) {
-            return Err(LeagueError::DraftableInUseError);
+        self.record(DraftEvent::AddedToPicks {
+            user: self.players[seat as usize].id,
+            item: pick.name().to_string(),
+        });
+        self.give_pick(seat, pick);
+        &self.players[seat as usize].picks
+    }
+    /// Lets another user manage a player's seat alongside them, e.g. a co-manager in a fantasy league.
+    ///
+    /// Every method that takes a player's ID accepts any of the seat's owners, so co-owners can lock, queue, trade and waiver for the seat.
+    /// Picks are still reported under the seat's original owner.
+    ///
+    /// # Errors
+    ///
+    /// If seat_owner is not in the league, returns [`LeagueError::PlayerNotFoundError`].
+    ///
+    /// If co_owner already owns a seat in the league, returns [`LeagueError::PlayerAlreadyInLeagueError`].
+    pub fn add_co_owner(
+        &mut self,
+        seat_owner: serenity::UserId,
+        co_owner: serenity::UserId,
+    ) -> Result<&ActivePlayer, LeagueError> {
+        if self.get_player(co_owner).is_some() {
+            return Err(LeagueError::PlayerAlreadyInLeagueError);
         }
-        if let Some(player) = self.get_player_mut(id) {
-            player.lock_in(pick);
-            return Ok(&player.picks);
+        if let Some(player) = self.get_player_mut(seat_owner) {
+            player.co_owners.push(co_owner);
+            return Ok(player);
         }
         Err(LeagueError::PlayerNotFoundError)
     }
-    fn get_player_mut(&mut self, id: serenity::UserId) -> Option<&mut ActivePlayer> {
-        self.players.iter_mut().find(|p| p.id.0 == id.0)
-    }
-    fn get_player(&self, id: serenity::UserId) -> Option<&ActivePlayer> {
-        self.players.iter().find(|p| p.id == id)
-    }
+    /// Revokes a co-owner's access to the seat they help manage, and returns that seat's player.
+    ///
+    /// # Errors
+    ///
+    /// If the user is not a co-owner of any seat, returns [`LeagueError::PlayerNotFoundError`]. A seat's original owner cannot be removed this way.
+    pub fn remove_co_owner(
+        &mut self,
+        co_owner: serenity::UserId,
+    ) -> Result<&ActivePlayer, LeagueError> {
+        for player in &mut self.players {
+            if let Some(idx) = player.co_owners.iter().position(|id| *id == co_owner) {
+                player.co_owners.remove(idx);
+                return Ok(player);
+            }
+        }
+        Err(LeagueError::PlayerNotFoundError)
+    }
+    /// Returns the seat (starting at 0) owned or co-owned by a user.
+    ///
+    /// # Errors
+    ///
+    /// If the user does not own a seat in the league, returns [`LeagueError::PlayerNotFoundError`].
+    pub fn seat_of(&self, id: serenity::UserId) -> Result<u32, LeagueError> {
+        self.players
+            .iter()
+            .position(|p| p.is_owner(id))
+            .map(|seat| seat as u32)
+            .ok_or(LeagueError::PlayerNotFoundError)
+    }
+    // resolves owners and co-owners alike
+    fn get_player_mut(&mut self, id: serenity::UserId) -> Option<&mut ActivePlayer> {
+        self.players.iter_mut().find(|p| p.is_owner(id))
+    }
+    fn get_player(&self, id: serenity::UserId) -> Option<&ActivePlayer> {
+        self.players.iter().find(|p| p.is_owner(id))
+    }
 }
 
-#[derive(Debug)]
+/// Errors returned by [`League`] methods. [`League::error_text`] turns them into messages for users.
+#[derive(Debug, Clone)]
 pub enum LeagueError {
     PlayerNotFoundError,
-    DraftableNotFoundError,
+    /// Carries the names closest to the one asked for, best first, so bots can ask "Did you mean...?". Often empty.
+    DraftableNotFoundError(Vec<String>),
     DraftableInUseError,
     PlayerPicksEmptyError,
     PlayerQueueEmptyError,
     LeagueActiveError,
     LeagueInactiveError,
+    LeaguePausedError,
     NoPicksError,
+    WrongDraftTypeError,
+    DraftableNotInPackError,
+    PackNotExhaustedError,
+    NoPacksRemainingError,
+    PlayerAlreadyInLeagueError,
+    InvalidSeatError,
+    SeatTakenError,
+    PermissionDeniedError,
+    InvalidTeamNameError,
+    TeamNameTakenError,
+    TeamNotFoundError,
+    InvalidEmojiMappingError(usize),
+    InvalidPickNumberError,
+    InvalidTeamSizeError,
+    NoPoolError,
+    NoAutopickAvailableError,
+    InvalidPhaseTransitionError(LeaguePhase, LeaguePhase),
+    WrongPhaseError,
+    UnknownPlaceholderError(String),
+    InvalidEventError(usize),
+    LastSeatError,
+    InvalidRoundError,
+    SameSeatError,
+    NotEnoughPlayersError,
+    DraftableLockedError,
+    InvalidDivisionNameError,
+    DivisionNotFoundError,
+    PickConflictError,
+    StalePickError,
+    ExpansionUnderwayError,
+    TooManyProtectedError,
+    ProtectionListMissingError(serenity::UserId),
+    NotEnoughItemsError,
+    NotYourTurnError,
+    DraftableProtectedError,
+    InvalidTimezoneError,
+    UnknownTextKeyError(String),
+    InvalidAliasError,
+    InvalidAliasMappingError(usize),
+    QueueFullError(u32),
+    /// Carries the category the round's picks must be.
+    WrongCategoryError(String),
+    NoLotError,
+    LotOpenError,
+    LotClosedError,
+    InvalidBidError,
+    RosterFullError,
+    /// Carries the most the seat may bid.
+    BidTooHighError(u32),
+    MustTakePileError,
+    InvalidGridLineError,
+    TwoPlayerDraftError,
+    NotSplitError,
+    NoOwedPicksError,
+    /// Carries the category whose roster slots are full. See [`League::set_roster_slot`].
+    RosterSlotsFullError(String),
+    /// Carries the salary cap. See [`League::set_salary_cap`].
+    SalaryCapError(u32),
+    /// Carries how long the user has to wait before they may try again. See [`League::set_rate_limit`].
+    RateLimitedError(Duration),
+    NoPlayersError,
+    LeagueIdInUseError,
+}
+
+impl LeagueError {
+    // the variant's name, without any data it carries
+    pub(crate) fn kind(&self) -> String {
+        let debug = format!("{self:?}");
+        match debug.split_once('(') {
+            Some((kind, _)) => kind.to_string(),
+            None => debug,
+        }
+    }
 }
+
 /// A struct to represent a Discord user who is currently part of one or more Leagues.
 ///
 /// All mutation of ActivePlayers can be handled through the [League] that owns them, and they are created automatically when initializing a [League].
+///
+/// An ActivePlayer is really a seat at the draft table: besides the user it was created for, it can be shared with co-owners (see [`League::add_co_owner`]).
 pub struct ActivePlayer {
     picks: Vec<Draftable>,
     queue: VecDeque<Draftable>,
     id: serenity::UserId,
+    co_owners: Vec<serenity::UserId>,
+    team: Option<Team>,
+    // (proxy, last overall pick number they may make)
+    proxy: Option<(serenity::UserId, u32)>,
+    autopick: bool,
+    forfeited: bool,
+    division: Option<String>,
+    // overrides the League's team_size for this seat
+    team_size: Option<u32>,
+    // turns skipped under SkipPolicy::PickLaterAnyTime, still to be made up
+    owed_picks: u32,
+    // picks chosen by the autopick strategy
+    autopicks: u32,
 }
 
 impl ActivePlayer {
+    fn new(id: serenity::UserId) -> ActivePlayer {
+        ActivePlayer {
+            picks: Vec::new(),
+            queue: VecDeque::new(),
+            id,
+            co_owners: Vec::new(),
+            team: None,
+            proxy: None,
+            autopick: false,
+            forfeited: false,
+            division: None,
+            team_size: None,
+            owed_picks: 0,
+            autopicks: 0,
+        }
+    }
+    /// Returns the Discord ID of the user the seat was created for.
+    pub fn id(&self) -> serenity::UserId {
+        self.id
+    }
+    /// Returns the seat's co-owners (see [`League::add_co_owner`]).
+    pub fn co_owners(&self) -> &[serenity::UserId] {
+        &self.co_owners
+    }
+    /// Returns true if the seat's turns are picked automatically (see [`League::set_autopick`]).
+    pub fn autopick(&self) -> bool {
+        self.autopick
+    }
+    /// Returns true if the user owns or co-owns this seat.
+    pub fn is_owner(&self, id: serenity::UserId) -> bool {
+        self.id == id || self.co_owners.contains(&id)
+    }
+    /// Returns every user who can act for this seat, starting with its original owner.
+    pub fn owners(&self) -> impl Iterator<Item = serenity::UserId> + '_ {
+        std::iter::once(self.id).chain(self.co_owners.iter().copied())
+    }
+    /// Returns the seat's queue, next pick first.
+    pub fn queue(&self) -> &VecDeque<Draftable> {
+        &self.queue
+    }
+    /// Returns the seat's picks, in the order they were made.
+    pub fn picks(&self) -> &[Draftable] {
+        &self.picks
+    }
+    /// Returns true if the seat's player has quit the draft (see [`League::forfeit_player`]).
+    pub fn forfeited(&self) -> bool {
+        self.forfeited
+    }
+    /// Returns how many picks the seat owes from turns skipped under [`SkipPolicy::PickLaterAnyTime`], to make with [`League::lock_owed`].
+    pub fn owed_picks(&self) -> u32 {
+        self.owed_picks
+    }
     fn add_to_queue(&mut self, item: Draftable) {
         self.queue.push_back(item);
     }
+    // go through League::give_pick and League::take_pick instead, which keep the League's pick index in step
     fn lock_in(&mut self, item: Draftable) {
         self.picks.push(item);
     }
@@ -520,22 +1704,85 @@ impl ActivePlayer {
 }
 
 /// Trait to implement on any type you make to represent the things being drafted.
-pub trait DraftItem {
+///
+/// DraftItems must be Send and Sync so Leagues can be shared between threads, e.g. in a [`DraftRegistry`].
+pub trait DraftItem: Send + Sync {
     /// Use this to expose the name, or any other *unique* identifier, for your DraftItem. Each DraftItem **must** return a *unique* name.
     fn name(&self) -> &str;
+    /// Optionally expose a category (position, type, color...) that groups similar DraftItems, e.g. so they can share an emoji
+    /// (see [`League::set_category_emoji`]). Defaults to None.
+    fn category(&self) -> Option<&str> {
+        None
+    }
+    /// Optionally expose what the DraftItem costs (a salary, points value, ADP...), used by [`BestAvailableByCost`]. Defaults to None.
+    fn cost(&self) -> Option<u32> {
+        None
+    }
+    /// Optionally expose a tier (1 being the best) that ranks DraftItems in broad bands, e.g. so players can search the
+    /// pool for what is left in a tier (see [`DraftPool::search`]). Defaults to None.
+    fn tier(&self) -> Option<u32> {
+        None
+    }
+}
+
+/// Items that are nothing but a name can be drafted as plain strings, e.g. `Box::new("Pikachu")`.
+impl DraftItem for String {
+    fn name(&self) -> &str {
+        self
+    }
+}
+
+impl DraftItem for &'static str {
+    fn name(&self) -> &str {
+        self
+    }
 }
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
-    #[derive(Debug)]
-    struct Pokemon {
-        name: String,
+    use crate::test_support::{new_league, pokemon_pool, Pokemon};
+
+    fn test_league(
+        players: Vec<ActivePlayer>,
+        active: bool,
+        total_picks: u32,
+        final_pick: u32,
+    ) -> League {
+        let mut league = League::new(
+            &[serenity::UserId(69420)],
+            69420,
+            "Creenis".to_string(),
+            None,
+            draft_types::DraftType::Snake,
+            1,
+        );
+        league.players = players;
+        league.reindex();
+        league.phase = match active {
+            true => LeaguePhase::Drafting,
+            false => LeaguePhase::Setup,
+        };
+        league.total_picks = total_picks;
+        league.final_pick = final_pick;
+        league
     }
-    impl DraftItem for Pokemon {
-        fn name(&self) -> &str {
-            self.name.as_str()
+
+    #[test]
+    fn start_puts_the_first_pick_on_the_clock() {
+        let mut league = new_league(2, draft_types::DraftType::Snake, 1);
+        assert_eq!(league.start().unwrap().id, serenity::UserId(1));
+        assert_eq!(league.pick_number(), 1);
+        assert_eq!(
+            league.events().last(),
+            Some(&DraftEvent::Started {
+                user: serenity::UserId(1)
+            })
+        );
+        match league.start() {
+            Err(LeagueError::InvalidPhaseTransitionError(..)) => {}
+            _ => panic!("the draft has already started"),
         }
     }
 
@@ -546,11 +1793,7 @@ mod tests {
         };
 
         let boxed_pikachu = Box::new(pikachu);
-        let mut p1 = ActivePlayer {
-            id: serenity::UserId(69420),
-            picks: Vec::new(),
-            queue: VecDeque::new(),
-        };
+        let mut p1 = ActivePlayer::new(serenity::UserId(69420));
         p1.lock_in(boxed_pikachu);
 
         let eldegoss = Pokemon {
@@ -558,23 +1801,9 @@ mod tests {
         };
 
         let boxed_eldegoss = Box::new(eldegoss);
-        let mut p2 = ActivePlayer {
-            id: serenity::UserId(42069),
-            picks: Vec::new(),
-            queue: VecDeque::new(),
-        };
+        let mut p2 = ActivePlayer::new(serenity::UserId(42069));
         p2.lock_in(boxed_eldegoss);
-        let mut league = League {
-            id: 69420,
-            players: Vec::from([p1, p2]),
-            output: None,
-            name: "Creenis".to_string(),
-            active: false,
-            current_seat: 0,
-            total_picks: 3,
-            draft_type: draft_types::DraftType::Snake,
-            final_pick: 5,
-        };
+        let mut league = test_league(Vec::from([p1, p2]), false, 3, 5);
         let (p1picks, p2picks) = league
             .trade(
                 serenity::UserId(69420),
@@ -625,11 +1854,7 @@ mod tests {
         };
         let boxed_pikachu = Box::new(pikachu);
         let boxed_quaxly = Box::new(quaxly);
-        let mut p1 = ActivePlayer {
-            id: serenity::UserId(69420),
-            picks: Vec::new(),
-            queue: VecDeque::new(),
-        };
+        let mut p1 = ActivePlayer::new(serenity::UserId(69420));
         p1.add_to_queue(boxed_pikachu);
         p1.add_to_queue(boxed_quaxly);
 
@@ -641,24 +1866,10 @@ mod tests {
         };
         let boxed_pikachu = Box::new(pikachu);
         let boxed_raichu = Box::new(raichu);
-        let mut p2 = ActivePlayer {
-            id: serenity::UserId(42069),
-            picks: Vec::new(),
-            queue: VecDeque::new(),
-        };
+        let mut p2 = ActivePlayer::new(serenity::UserId(42069));
         p2.add_to_queue(boxed_pikachu);
         p2.add_to_queue(boxed_raichu);
-        let mut league = League {
-            id: 69420,
-            players: Vec::from([p1, p2]),
-            output: None,
-            name: "Creenis".to_string(),
-            active: true,
-            current_seat: 0,
-            total_picks: 3,
-            draft_type: draft_types::DraftType::Snake,
-            final_pick: 5,
-        };
+        let mut league = test_league(Vec::from([p1, p2]), true, 3, 5);
         league
             .lock(Box::new(Pokemon {
                 name: "Pikachu".to_string(),
@@ -679,11 +1890,7 @@ mod tests {
         };
         let boxed_pikachu = Box::new(pikachu);
         let boxed_quaxly = Box::new(quaxly);
-        let mut p1 = ActivePlayer {
-            id: serenity::UserId(69420),
-            picks: Vec::new(),
-            queue: VecDeque::new(),
-        };
+        let mut p1 = ActivePlayer::new(serenity::UserId(69420));
         p1.add_to_queue(boxed_pikachu);
         p1.add_to_queue(boxed_quaxly);
 
@@ -695,37 +1902,23 @@ mod tests {
         };
         let boxed_pikachu = Box::new(pikachu);
         let boxed_raichu = Box::new(raichu);
-        let mut p2 = ActivePlayer {
-            id: serenity::UserId(42069),
-            picks: Vec::new(),
-            queue: VecDeque::new(),
-        };
+        let mut p2 = ActivePlayer::new(serenity::UserId(42069));
         p2.add_to_queue(boxed_pikachu);
         p2.add_to_queue(boxed_raichu);
-        let mut league = League {
-            id: 69420,
-            players: Vec::from([p1, p2]),
-            output: None,
-            name: "Creenis".to_string(),
-            active: true,
-            current_seat: 0,
-            total_picks: 3,
-            draft_type: draft_types::DraftType::Snake,
-            final_pick: 5,
-        };
+        let mut league = test_league(Vec::from([p1, p2]), true, 3, 5);
         let picks = league
             .lock(Box::new(Pokemon {
                 name: "Pikachu".to_string(),
             }))
             .expect("this is fine");
-        let (u1, pokemon1) = &picks[0];
-        let (u2, pokemon2) = &picks[1];
-        let (u3, pokemon3) = &picks[2];
-        assert_eq!(u1, u2);
-        assert_ne!(u1, u3);
-        assert_eq!(pokemon1.to_owned(), "Pikachu".to_string());
-        assert_eq!(pokemon2.to_owned(), "Quaxly".to_string());
-        assert_eq!(pokemon3.to_owned(), "Raichu".to_string());
+        assert_eq!(picks[0].user, picks[1].user);
+        assert_ne!(picks[0].user, picks[2].user);
+        assert_eq!(picks[0].item, "Pikachu");
+        assert_eq!(picks[1].item, "Quaxly");
+        assert_eq!(picks[2].item, "Raichu");
+        assert_eq!(picks[0].source, PickSource::Manual);
+        assert_eq!(picks[1].source, PickSource::Queue);
+        assert_eq!((picks[2].pick, picks[2].round, picks[2].seat), (6, 3, 1));
     }
 
     #[test]
@@ -734,17 +1927,7 @@ mod tests {
         let pikachu = Pokemon {
             name: "Pikachu".to_string(),
         };
-        let mut league = League {
-            id: 69420,
-            players: Vec::new(),
-            output: None,
-            name: "Cheenis".into(),
-            active: true,
-            current_seat: 0,
-            total_picks: 0,
-            draft_type: draft_types::DraftType::Snake,
-            final_pick: 255,
-        };
+        let mut league = test_league(Vec::new(), true, 0, 255);
         league
             .waiver(serenity::UserId(69420), "pikachu", Box::new(pikachu))
             .expect("no waivers in active drafts");
@@ -759,30 +1942,19 @@ mod tests {
         };
         let boxed_pikachu = Box::new(pikachu);
         let boxed_quaxly = Box::new(quaxly);
-        let mut p1 = ActivePlayer {
-            id: serenity::UserId(69420),
-            picks: Vec::new(),
-            queue: VecDeque::new(),
-        };
+        let mut p1 = ActivePlayer::new(serenity::UserId(69420));
         p1.lock_in(boxed_pikachu);
         p1.lock_in(boxed_quaxly);
-        let mut league = League {
-            id: 69420,
-            players: Vec::from([p1]),
-            output: None,
-            name: "Creenis".to_string(),
-            active: false,
-            current_seat: 0,
-            total_picks: 3,
-            draft_type: draft_types::DraftType::Snake,
-            final_pick: 5,
-        };
+        let mut league = test_league(Vec::from([p1]), false, 3, 5);
         let pikachu = Pokemon {
             name: "Pikachu".to_string(),
         };
         let boxed_pikachu = Box::new(pikachu);
         match league.waiver(serenity::UserId(69420), "Pikachu", boxed_pikachu) {
-            Err(LeagueError::DraftableInUseError) => {}
+            Err(Rejected {
+                error: LeagueError::DraftableInUseError,
+                ..
+            }) => {}
             _ => panic!("wronge"),
         }
     }
@@ -796,32 +1968,127 @@ mod tests {
         };
         let boxed_pikachu = Box::new(pikachu);
         let boxed_quaxly = Box::new(quaxly);
-        let mut p1 = ActivePlayer {
-            id: serenity::UserId(69420),
-            picks: Vec::new(),
-            queue: VecDeque::new(),
-        };
+        let mut p1 = ActivePlayer::new(serenity::UserId(69420));
         p1.lock_in(boxed_pikachu);
         p1.lock_in(boxed_quaxly);
-        let mut league = League {
-            id: 69420,
-            players: Vec::from([p1]),
-            output: None,
-            name: "Creenis".to_string(),
-            active: false,
-            current_seat: 0,
-            total_picks: 3,
-            draft_type: draft_types::DraftType::Snake,
-            final_pick: 5,
-        };
+        let mut league = test_league(Vec::from([p1]), false, 3, 5);
         let amoonguss = Pokemon {
             name: "Amoonguss".to_string(),
         };
         let boxed_amoonguss = Box::new(amoonguss);
         match league.waiver(serenity::UserId(69420), "Raichu", boxed_amoonguss) {
-            Err(LeagueError::DraftableNotFoundError) => {}
+            Err(Rejected {
+                error: LeagueError::DraftableNotFoundError(_),
+                item,
+            }) => assert_eq!(item.name(), "Amoonguss"),
             _ => panic!("wronge"),
         }
+        assert_eq!(league.players[0].picks.len(), 2);
+    }
+    #[test]
+    fn only_the_seat_on_the_clock_can_pick() {
+        let mut league = new_league(2, draft_types::DraftType::Snake, 2);
+        league
+            .add_co_owner(serenity::UserId(1), serenity::UserId(3))
+            .unwrap();
+        league
+            .set_proxy(serenity::UserId(2), serenity::UserId(4), 2)
+            .unwrap();
+        league.add_commissioner(serenity::UserId(5));
+        league.activate().unwrap();
+        match league.lock_as(serenity::UserId(2), Box::new("Pikachu")) {
+            Err(LeagueError::NotYourTurnError) => {}
+            _ => panic!("seat 1 is on the clock"),
+        }
+        assert_eq!(league.pick_number(), 1);
+        let picks = league
+            .lock_as(serenity::UserId(3), Box::new("Pikachu"))
+            .unwrap();
+        assert_eq!(picks[0].user, serenity::UserId(1));
+        let picks = league
+            .lock_as(serenity::UserId(4), Box::new("Quaxly"))
+            .unwrap();
+        assert_eq!(picks[0].source, PickSource::Manual);
+        let picks = league
+            .lock_as(serenity::UserId(5), Box::new("Raichu"))
+            .unwrap();
+        assert_eq!(picks[0].source, PickSource::Admin);
+        match league.lock_as(serenity::UserId(4), Box::new("Eldegoss")) {
+            Err(LeagueError::NotYourTurnError) => {}
+            _ => panic!("the proxy ran out after pick 2"),
+        }
+    }
+    #[test]
+    fn picked_items_cannot_be_picked_again() {
+        let mut league = new_league(3, draft_types::DraftType::Linear, 1);
+        league.activate().unwrap();
+        league.lock(Box::new("Pikachu")).unwrap();
+        match league.lock(Box::new("Pikachu")) {
+            Err(LeagueError::DraftableInUseError) => {}
+            _ => panic!("seat 1 has Pikachu"),
+        }
+        assert_eq!(league.pick_number(), 2);
+        league
+            .add_to_player_queue(serenity::UserId(3), Box::new("Pikachu"))
+            .unwrap();
+        league
+            .add_to_player_queue(serenity::UserId(3), Box::new("Quaxly"))
+            .unwrap();
+        let picks = league.lock(Box::new("Raichu")).unwrap();
+        assert_eq!(picks[1].item, "Quaxly");
+        assert_eq!(league.players[0].picks.len(), 1);
+    }
+    #[test]
+    fn failed_trades_keep_both_rosters() {
+        let mut league = League::new(
+            &[serenity::UserId(69420), serenity::UserId(42069)],
+            69420,
+            "Creenis".to_string(),
+            None,
+            draft_types::DraftType::Snake,
+            1,
+        );
+        league.activate().unwrap();
+        league.lock(Box::new("Pikachu")).unwrap();
+        league.lock(Box::new("Quaxly")).unwrap();
+        match league.trade(
+            serenity::UserId(69420),
+            "Pikachu",
+            serenity::UserId(42069),
+            "Raichu",
+        ) {
+            Err(LeagueError::DraftableNotFoundError(_)) => {}
+            _ => panic!("nobody has Raichu"),
+        }
+        match league.trade(
+            serenity::UserId(69420),
+            "Pikachu",
+            serenity::UserId(1),
+            "Quaxly",
+        ) {
+            Err(LeagueError::PlayerNotFoundError) => {}
+            _ => panic!("there is no player 1"),
+        }
+        assert_eq!(league.players[0].picks[0].name(), "Pikachu");
+        assert_eq!(league.players[1].picks[0].name(), "Quaxly");
+    }
+    #[test]
+    fn failed_admin_picks_hand_the_item_back() {
+        let mut league = League::new(
+            &[serenity::UserId(69420), serenity::UserId(42069)],
+            69420,
+            "Creenis".to_string(),
+            None,
+            draft_types::DraftType::Snake,
+            1,
+        );
+        match league.add_to_player_picks(serenity::UserId(1), Box::new("Raichu")) {
+            Err(Rejected {
+                error: LeagueError::PlayerNotFoundError,
+                item,
+            }) => assert_eq!(item.name(), "Raichu"),
+            _ => panic!("there is no player 1"),
+        }
     }
     #[test]
     #[should_panic]
@@ -830,6 +2097,10 @@ mod tests {
             id: 69420,
             leagues: HashMap::new(),
             default_output: serenity::ChannelId(69420),
+            settings: GuildSettings::new(),
+            archives: Vec::new(),
+            recycle_bin: Vec::new(),
+            discord_guild: None,
         };
         guild
             .league_by_name("key".to_string())
@@ -841,6 +2112,10 @@ mod tests {
             id: 69420,
             leagues: HashMap::new(),
             default_output: serenity::ChannelId(69420),
+            settings: GuildSettings::new(),
+            archives: Vec::new(),
+            recycle_bin: Vec::new(),
+            discord_guild: None,
         };
         let users = Vec::from([serenity::UserId(69420), serenity::UserId(42069)]);
         let league = League::new(
@@ -857,6 +2132,121 @@ mod tests {
             .expect("You had better not ever see this message");
         assert_eq!("Creenis".to_string(), got_league.name);
     }
+    #[test]
+    fn renamed_leagues_keep_their_key_in_step() {
+        let mut guild = DraftGuild::new(69420, serenity::ChannelId(69420));
+        for name in ["Creenis", "Bingus"] {
+            let league = League::new(
+                &[serenity::UserId(69420)],
+                69420,
+                name.to_string(),
+                None,
+                draft_types::DraftType::Snake,
+                3,
+            );
+            guild.add_league(league).unwrap();
+        }
+        match guild.rename_league("Creenis", "Bingus".to_string()) {
+            Err(DraftGuildError::LeagueNameAlreadyInUseError) => {}
+            _ => panic!("Bingus is taken"),
+        }
+        assert!(guild.contains("Creenis"));
+        let league = guild
+            .rename_league("Creenis", "Creenis II".to_string())
+            .unwrap();
+        assert_eq!(league.name, "Creenis II");
+        assert!(!guild.contains("Creenis"));
+        assert_eq!(guild.league("Creenis II").unwrap().name, "Creenis II");
+        match guild.rename_league("Creenis", "Creenis III".to_string()) {
+            Err(DraftGuildError::LeagueNotFoundError) => {}
+            _ => panic!("Creenis was renamed"),
+        }
+    }
+    #[test]
+    fn queues_stop_at_the_limit() {
+        let mut league = League::builder(69420, "Creenis".to_string())
+            .player(serenity::UserId(69420))
+            .team_size(3)
+            .queue_limit(1)
+            .build()
+            .unwrap();
+        league
+            .add_to_player_queue(serenity::UserId(69420), Box::new("Pikachu"))
+            .unwrap();
+        match league.add_to_player_queue(serenity::UserId(69420), Box::new("Quaxly")) {
+            Err(LeagueError::QueueFullError(1)) => {}
+            _ => panic!("one item is the limit"),
+        }
+        assert_eq!(
+            league.error_text(&LeagueError::QueueFullError(1)),
+            "Your queue is full (the limit is 1)."
+        );
+        league.set_queue_limit(None);
+        league
+            .add_to_player_queue(serenity::UserId(69420), Box::new("Quaxly"))
+            .unwrap();
+        assert_eq!(
+            league
+                .get_player(serenity::UserId(69420))
+                .unwrap()
+                .queue
+                .len(),
+            2
+        );
+    }
+    #[test]
+    fn league_internals_are_readable() {
+        let mut league = League::new(
+            &[serenity::UserId(69420), serenity::UserId(42069)],
+            69420,
+            "Creenis".to_string(),
+            Some(serenity::ChannelId(1)),
+            draft_types::DraftType::Linear,
+            2,
+        );
+        league.activate().unwrap();
+        league.lock(Box::new("Pikachu")).unwrap();
+        assert_eq!(league.id(), 69420);
+        assert_eq!(league.name(), "Creenis");
+        assert_eq!(league.output(), Some(serenity::ChannelId(1)));
+        assert_eq!(league.draft_type(), draft_types::DraftType::Linear);
+        assert_eq!(league.team_size(), 2);
+        assert_eq!(league.current_seat(), 1);
+        assert_eq!(league.total_picks(), 1);
+        let ids: Vec<serenity::UserId> = league.players().map(ActivePlayer::id).collect();
+        assert_eq!(ids, [serenity::UserId(69420), serenity::UserId(42069)]);
+        assert_eq!(league.players().next().unwrap().picks().len(), 1);
+    }
+    #[test]
+    fn leagues_are_readable_without_mut() {
+        let mut guild = DraftGuild::new(69420, serenity::ChannelId(69420));
+        assert!(guild.is_empty());
+        for (id, name) in [(1, "Creenis"), (2, "Creenis II")] {
+            let league = League::new(
+                &[serenity::UserId(69420)],
+                id,
+                name.to_string(),
+                None,
+                draft_types::DraftType::Snake,
+                3,
+            );
+            guild.add_league(league).unwrap();
+        }
+        let guild = &guild;
+        assert_eq!(guild.len(), 2);
+        assert!(guild.contains("Creenis II"));
+        assert!(!guild.contains("creenis"));
+        assert_eq!(guild.league_with_id(2).unwrap().name, "Creenis II");
+        assert_eq!(guild.league("Creenis").unwrap().id, 1);
+        let mut names: Vec<&str> = guild.league_names().collect();
+        names.sort();
+        assert_eq!(names, ["Creenis", "Creenis II"]);
+        assert_eq!(guild.leagues().count(), 2);
+        match guild.league("Bingus") {
+            Err(DraftGuildError::LeagueNotFoundError) => {}
+            _ => panic!("there is no Bingus"),
+        }
+    }
 
     #[test]
     fn returns_next_player() {
@@ -894,11 +2284,7 @@ mod tests {
         let pikachu = Pokemon {
             name: "Pikachu".to_string(),
         };
-        let mut player = ActivePlayer {
-            picks: Vec::new(),
-            queue: VecDeque::new(),
-            id: serenity::UserId(69420),
-        };
+        let mut player = ActivePlayer::new(serenity::UserId(69420));
         player.add_to_queue(Box::new(pikachu));
         assert_eq!(player.queue.len(), 1);
         let removed = player.delete_from_queue("Pikachu").unwrap();
@@ -910,11 +2296,7 @@ mod tests {
     #[test]
     #[should_panic]
     fn try_delete_item_not_in_queue() {
-        let mut player = ActivePlayer {
-            picks: Vec::new(),
-            queue: VecDeque::new(),
-            id: serenity::UserId(69420),
-        };
+        let mut player = ActivePlayer::new(serenity::UserId(69420));
         let _removed = player.delete_from_queue("Pikachu").unwrap();
     }
 
@@ -926,14 +2308,301 @@ mod tests {
         let quaxly = Pokemon {
             name: "Quaxly".to_string(),
         };
-        let mut player = ActivePlayer {
-            picks: Vec::new(),
-            queue: VecDeque::new(),
-            id: serenity::UserId(69420),
-        };
+        let mut player = ActivePlayer::new(serenity::UserId(69420));
         player.add_to_queue(Box::new(pikachu));
         player.add_to_queue(Box::new(quaxly));
         let pikachu = player.first_in_queue().unwrap();
         assert_eq!(pikachu.name(), "Pikachu");
     }
+
+    #[test]
+    fn no_picks_while_paused() {
+        let mut league = test_league(
+            Vec::from([ActivePlayer::new(serenity::UserId(69420))]),
+            true,
+            0,
+            5,
+        );
+        league.pause(Utc::now());
+        match league.lock(Box::new(Pokemon {
+            name: "Pikachu".to_string(),
+        })) {
+            Err(LeagueError::LeaguePausedError) => {}
+            _ => panic!("paused leagues should not accept picks"),
+        }
+        league.resume(Utc::now());
+        league
+            .lock(Box::new(Pokemon {
+                name: "Pikachu".to_string(),
+            }))
+            .expect("resumed leagues accept picks");
+    }
+
+    #[test]
+    fn rochester_picks_only_from_revealed_pack() {
+        let users = Vec::from([serenity::UserId(69420), serenity::UserId(42069)]);
+        let mut league = League::new(
+            &users,
+            69420,
+            "Creenis".to_string(),
+            None,
+            draft_types::DraftType::Rochester,
+            2,
+        );
+        let pool: Vec<Draftable> = ["Pikachu", "Quaxly", "Raichu", "Eldegoss"]
+            .iter()
+            .map(|name| {
+                Box::new(Pokemon {
+                    name: name.to_string(),
+                }) as Draftable
+            })
+            .collect();
+        league
+            .set_packs(pool, 2)
+            .expect("rochester leagues take packs");
+        let pack = league.reveal_pack().expect("first pack");
+        assert_eq!(pack.len(), 2);
+        league.activate().unwrap();
+        match league.lock(Box::new(Pokemon {
+            name: "Raichu".to_string(),
+        })) {
+            Err(LeagueError::DraftableNotInPackError) => {}
+            _ => panic!("Raichu is still sealed"),
+        }
+        league
+            .add_to_player_queue(
+                serenity::UserId(42069),
+                Box::new(Pokemon {
+                    name: "Raichu".to_string(),
+                }),
+            )
+            .unwrap();
+        league
+            .add_to_player_queue(
+                serenity::UserId(42069),
+                Box::new(Pokemon {
+                    name: "Quaxly".to_string(),
+                }),
+            )
+            .unwrap();
+        let picks = league
+            .lock(Box::new(Pokemon {
+                name: "Pikachu".to_string(),
+            }))
+            .unwrap();
+        assert_eq!(picks.len(), 2);
+        assert_eq!(picks[1].item, "Quaxly");
+        assert_eq!(league.packs().unwrap().pack_round(), 1);
+        let pack = league.reveal_pack().expect("second pack");
+        assert_eq!(pack[0].name(), "Raichu");
+    }
+
+    #[test]
+    fn co_owner_resolves_to_seat() {
+        let users = Vec::from([serenity::UserId(69420), serenity::UserId(42069)]);
+        let mut league = League::new(
+            &users,
+            69420,
+            "Creenis".to_string(),
+            None,
+            draft_types::DraftType::Snake,
+            3,
+        );
+        league
+            .add_co_owner(serenity::UserId(42069), serenity::UserId(1))
+            .expect("seat exists");
+        assert_eq!(league.seat_of(serenity::UserId(1)).unwrap(), 1);
+        league
+            .add_to_player_queue(
+                serenity::UserId(1),
+                Box::new(Pokemon {
+                    name: "Pikachu".to_string(),
+                }),
+            )
+            .expect("co-owners can queue");
+        assert_eq!(league.players[1].queue.len(), 1);
+        match league.add_co_owner(serenity::UserId(69420), serenity::UserId(1)) {
+            Err(LeagueError::PlayerAlreadyInLeagueError) => {}
+            _ => panic!("a user can only own one seat"),
+        }
+    }
+
+    #[test]
+    fn stale_picks_are_rejected() {
+        let users = Vec::from([serenity::UserId(69420), serenity::UserId(42069)]);
+        let mut league = League::new(
+            &users,
+            69420,
+            "Creenis".to_string(),
+            None,
+            draft_types::DraftType::Snake,
+            2,
+        );
+        league.activate().unwrap();
+        let started_at = league.pick_number();
+        let pikachu = Box::new(Pokemon {
+            name: "Pikachu".to_string(),
+        });
+        let quaxly = Box::new(Pokemon {
+            name: "Quaxly".to_string(),
+        });
+        league.lock_if_seat(started_at, pikachu).unwrap();
+        match league.lock_if_seat(started_at, quaxly) {
+            Err(LeagueError::StalePickError) => {}
+            _ => panic!("pick 1 is already in"),
+        }
+        assert_eq!(league.pick_number(), 2);
+        assert_eq!(league.current_player().unwrap().id, serenity::UserId(42069));
+    }
+
+    #[test]
+    fn autopick_takes_best_available_from_pool() {
+        let users = Vec::from([serenity::UserId(69420), serenity::UserId(42069)]);
+        let mut league = League::new(
+            &users,
+            69420,
+            "Creenis".to_string(),
+            None,
+            draft_types::DraftType::Linear,
+            2,
+        );
+        league
+            .set_pool(pokemon_pool(&["Pikachu", "Quaxly", "Raichu", "Eldegoss"]))
+            .unwrap();
+        league.set_autopick(serenity::UserId(42069), true).unwrap();
+        league.activate().unwrap();
+        let picks = league
+            .lock(Box::new(Pokemon {
+                name: "Quaxly".to_string(),
+            }))
+            .unwrap();
+        assert_eq!(picks.len(), 2);
+        assert_eq!(picks[1].user, serenity::UserId(42069));
+        assert_eq!(picks[1].item, "Pikachu");
+        assert_eq!(league.current_player().unwrap().id, serenity::UserId(69420));
+        assert_eq!(league.pool().unwrap().len(), 2);
+        match league.lock(Box::new(Pokemon {
+            name: "Pikachu".to_string(),
+        })) {
+            Err(LeagueError::DraftableInUseError) => {}
+            _ => panic!("Pikachu has been picked"),
+        }
+    }
+
+    #[test]
+    fn long_autopick_drafts_fit_on_a_worker_stack() {
+        // the stack of a tokio worker thread
+        let drafted = std::thread::Builder::new()
+            .stack_size(2 * 1024 * 1024)
+            .spawn(|| {
+                let mut league = new_league(2, draft_types::DraftType::Snake, 2000);
+                let names: Vec<String> = (0..4000).map(|n| format!("Pokemon {n}")).collect();
+                let names: Vec<&str> = names.iter().map(String::as_str).collect();
+                league.set_pool(pokemon_pool(&names)).unwrap();
+                league.set_autopick(serenity::UserId(1), true).unwrap();
+                league.set_autopick(serenity::UserId(2), true).unwrap();
+                league.activate().unwrap();
+                let picks = league.autopick().unwrap();
+                (picks.len(), league.phase())
+            })
+            .unwrap()
+            .join()
+            .unwrap();
+        assert_eq!(drafted, (4000, LeaguePhase::PostDraft));
+    }
+
+    #[test]
+    fn skip_reports_autopicks() {
+        let users = Vec::from([serenity::UserId(69420), serenity::UserId(42069)]);
+        let mut league = League::new(
+            &users,
+            69420,
+            "Creenis".to_string(),
+            None,
+            draft_types::DraftType::Linear,
+            2,
+        );
+        league
+            .set_pool(pokemon_pool(&["Pikachu", "Quaxly"]))
+            .unwrap();
+        league.set_autopick(serenity::UserId(42069), true).unwrap();
+        league.activate().unwrap();
+        let picks = league.skip().unwrap();
+        assert_eq!(
+            picks,
+            vec![PickResult {
+                pick: 2,
+                round: 1,
+                seat: 1,
+                user: serenity::UserId(42069),
+                item: "Pikachu".into(),
+                source: PickSource::Autopick,
+            }]
+        );
+    }
+
+    #[test]
+    fn waiver_swaps_with_pool() {
+        let mut p1 = ActivePlayer::new(serenity::UserId(69420));
+        p1.lock_in(Box::new(Pokemon {
+            name: "Pikachu".to_string(),
+        }));
+        let mut league = test_league(Vec::from([p1]), false, 0, 5);
+        league.set_pool(pokemon_pool(&["Quaxly"])).unwrap();
+        league
+            .waiver(
+                serenity::UserId(69420),
+                "Pikachu",
+                Box::new(Pokemon {
+                    name: "Quaxly".to_string(),
+                }),
+            )
+            .unwrap();
+        let pool = league.pool().unwrap();
+        assert!(pool.contains("Pikachu"));
+        assert!(!pool.contains("Quaxly"));
+    }
+
+    #[test]
+    fn strings_are_draft_items() {
+        let mut league = test_league(
+            Vec::from([ActivePlayer::new(serenity::UserId(69420))]),
+            true,
+            0,
+            1,
+        );
+        league.lock(Box::new("Pikachu")).unwrap();
+        league.lock(Box::new("Quaxly".to_string())).unwrap();
+        let picks: Vec<&str> = league.players[0].picks.iter().map(|p| p.name()).collect();
+        assert_eq!(picks, ["Pikachu", "Quaxly"]);
+    }
+
+    #[cfg(feature = "derive")]
+    #[test]
+    fn draft_items_derive() {
+        #[derive(DraftItem)]
+        struct Card {
+            #[name]
+            name: &'static str,
+            #[category]
+            color: Option<String>,
+            #[cost]
+            mana: u8,
+        }
+        #[derive(DraftItem)]
+        struct Tiered(#[name] String, #[tier] Option<u32>);
+
+        let card = Card {
+            name: "Black Lotus",
+            color: None,
+            mana: 0,
+        };
+        assert_eq!(card.name(), "Black Lotus");
+        assert_eq!(card.category(), None);
+        assert_eq!(card.cost(), Some(0));
+        assert_eq!(card.tier(), None);
+        let tiered = Tiered("Mewtwo".to_string(), Some(1));
+        assert_eq!(tiered.name(), "Mewtwo");
+        assert_eq!(tiered.tier(), Some(1));
+    }
 }
@@ -0,0 +1,444 @@
+use crate::ready_check::ReadyCheck;
+use crate::{ActivePlayer, League, LeagueError};
+use poise::serenity_prelude as serenity;
+use std::collections::{HashMap, HashSet};
+
+const CUSTOM_ID_PREFIX: &str = "drftr:lobby";
+
+/// The pre-draft lobby of a [`League`], where players join, choose their seats and ready up.
+///
+/// Every player in a League that has not been activated is in its lobby. Once every player is ready (or a quorum of them,
+/// see [`League::set_ready_quorum`]), the League seats everyone and starts itself, so a draft can go from creation to first pick
+/// without a commissioner.
+pub(crate) struct Lobby {
+    // k: player, v: seat index they asked for
+    pub(crate) seat_claims: HashMap<serenity::UserId, u32>,
+    pub(crate) ready: HashSet<serenity::UserId>,
+    pub(crate) check: ReadyCheck,
+}
+
+impl Lobby {
+    pub(crate) fn new() -> Lobby {
+        Lobby {
+            seat_claims: HashMap::new(),
+            ready: HashSet::new(),
+            check: ReadyCheck::default(),
+        }
+    }
+    pub(crate) fn remove(&mut self, id: serenity::UserId) {
+        self.seat_claims.remove(&id);
+        self.ready.remove(&id);
+    }
+}
+
+/// A player's entry in the lobby, as returned by [`League::lobby`].
+pub struct LobbyEntry {
+    id: serenity::UserId,
+    seat: u32,
+    claimed: bool,
+    ready: bool,
+}
+
+impl LobbyEntry {
+    /// The player's Discord ID.
+    pub fn id(&self) -> serenity::UserId {
+        self.id
+    }
+    /// The seat (starting at 0) the player will draft from if the League activates now.
+    pub fn seat(&self) -> u32 {
+        self.seat
+    }
+    /// True if the player picked this seat, false if they were placed in it.
+    pub fn claimed(&self) -> bool {
+        self.claimed
+    }
+    /// True if the player has readied up.
+    pub fn ready(&self) -> bool {
+        self.ready
+    }
+}
+
+/// A button or select menu press on a lobby message built with [`League::lobby_components`].
+#[derive(Debug, PartialEq, Eq)]
+pub enum LobbyAction {
+    Join,
+    Leave,
+    TakeSeat(u32),
+    Ready,
+    Unready,
+}
+
+impl LobbyAction {
+    /// Builds the custom_id used for this action's component. Seat selection shares one id across all seats,
+    /// since the chosen seat arrives in the interaction's values.
+    pub fn custom_id(&self, league_id: u64) -> String {
+        let action = match self {
+            LobbyAction::Join => "join",
+            LobbyAction::Leave => "leave",
+            LobbyAction::TakeSeat(_) => "seat",
+            LobbyAction::Ready => "ready",
+            LobbyAction::Unready => "unready",
+        };
+        format!("{CUSTOM_ID_PREFIX}:{league_id}:{action}")
+    }
+    /// Parses the custom_id and values of a component interaction into the ID of the League it belongs to and the action taken.
+    ///
+    /// Returns None if the interaction did not come from a DRFTR lobby, so your component handler can fall through to its own buttons.
+    pub fn parse(custom_id: &str, values: &[String]) -> Option<(u64, LobbyAction)> {
+        let rest = custom_id
+            .strip_prefix(CUSTOM_ID_PREFIX)?
+            .strip_prefix(':')?;
+        let (league_id, action) = rest.split_once(':')?;
+        let league_id = league_id.parse().ok()?;
+        let action = match action {
+            "join" => LobbyAction::Join,
+            "leave" => LobbyAction::Leave,
+            "seat" => LobbyAction::TakeSeat(values.first()?.parse().ok()?),
+            "ready" => LobbyAction::Ready,
+            "unready" => LobbyAction::Unready,
+            _ => return None,
+        };
+        Some((league_id, action))
+    }
+}
+
+impl League {
+    /// Adds a player to the lobby of a League that has not started yet, and returns the lobby.
+    ///
+    /// # Errors
+    ///
+    /// If the league is active, returns [`LeagueError::LeagueActiveError`].
+    ///
+    /// If the draft is already over, returns [`LeagueError::WrongPhaseError`].
+    ///
+    /// If the player is already in the league, returns [`LeagueError::PlayerAlreadyInLeagueError`].
+    pub fn join_lobby(&mut self, id: serenity::UserId) -> Result<Vec<LobbyEntry>, LeagueError> {
+        self.require_pre_draft()?;
+        if self.get_player(id).is_some() {
+            return Err(LeagueError::PlayerAlreadyInLeagueError);
+        }
+        self.players.push(ActivePlayer::new(id));
+        self.recalculate_final_pick();
+        Ok(self.lobby())
+    }
+    /// Removes a player from the lobby of a League that has not started yet, and returns the lobby.
+    ///
+    /// # Errors
+    ///
+    /// If the league is active, returns [`LeagueError::LeagueActiveError`].
+    ///
+    /// If the draft is already over, returns [`LeagueError::WrongPhaseError`].
+    ///
+    /// If the player is not in the league, returns [`LeagueError::PlayerNotFoundError`].
+    pub fn leave_lobby(&mut self, id: serenity::UserId) -> Result<Vec<LobbyEntry>, LeagueError> {
+        self.require_pre_draft()?;
+        // a co-owner leaving gives up their share of the seat, not the seat itself
+        let Some(idx) = self.players.iter().position(|p| p.id == id) else {
+            self.remove_co_owner(id)?;
+            return Ok(self.lobby());
+        };
+        self.players.remove(idx);
+        self.lobby.remove(id);
+        self.recalculate_final_pick();
+        self.reindex();
+        Ok(self.lobby())
+    }
+    /// Claims a seat (starting at 0) for a player in the lobby, and returns the lobby. Players who never claim a seat
+    /// fill the open seats in the order they joined.
+    ///
+    /// # Errors
+    ///
+    /// If the league is active, returns [`LeagueError::LeagueActiveError`].
+    ///
+    /// If the draft is already over, returns [`LeagueError::WrongPhaseError`].
+    ///
+    /// If the player is not in the league, returns [`LeagueError::PlayerNotFoundError`].
+    ///
+    /// If the seat does not exist, returns [`LeagueError::InvalidSeatError`].
+    ///
+    /// If another player has already claimed the seat, returns [`LeagueError::SeatTakenError`].
+    pub fn claim_seat(
+        &mut self,
+        id: serenity::UserId,
+        seat: u32,
+    ) -> Result<Vec<LobbyEntry>, LeagueError> {
+        self.require_pre_draft()?;
+        let Some(player) = self.get_player(id) else {
+            return Err(LeagueError::PlayerNotFoundError);
+        };
+        let id = player.id;
+        if seat as usize >= self.players.len() {
+            return Err(LeagueError::InvalidSeatError);
+        }
+        if self
+            .lobby
+            .seat_claims
+            .iter()
+            .any(|(user, claimed)| *claimed == seat && *user != id)
+        {
+            return Err(LeagueError::SeatTakenError);
+        }
+        self.lobby.seat_claims.insert(id, seat);
+        Ok(self.lobby())
+    }
+    /// Marks a player as ready (or not ready) to draft.
+    ///
+    /// When the last player readies up, or enough to make the quorum (see [`League::set_ready_quorum`]), everyone is moved
+    /// into their seats and the draft starts as with [`League::start`].
+    ///
+    /// # Returns
+    ///
+    /// Returns true if this call activated the League, so the bot knows to announce the first pick.
+    ///
+    /// # Errors
+    ///
+    /// If the league is active, returns [`LeagueError::LeagueActiveError`].
+    ///
+    /// If the draft is already over, returns [`LeagueError::WrongPhaseError`].
+    ///
+    /// If the player is not in the league, returns [`LeagueError::PlayerNotFoundError`].
+    pub fn set_ready(&mut self, id: serenity::UserId, ready: bool) -> Result<bool, LeagueError> {
+        self.require_pre_draft()?;
+        let Some(player) = self.get_player(id) else {
+            return Err(LeagueError::PlayerNotFoundError);
+        };
+        let id = player.id;
+        if !ready {
+            self.lobby.ready.remove(&id);
+            return Ok(false);
+        }
+        self.lobby.ready.insert(id);
+        let ready = self
+            .players
+            .iter()
+            .filter(|p| self.lobby.ready.contains(&p.id))
+            .count();
+        if ready >= self.ready_needed() {
+            self.start_ready_check()?;
+            return Ok(true);
+        }
+        Ok(false)
+    }
+    /// Applies a [`LobbyAction`] parsed from a component interaction on behalf of the user who pressed it.
+    ///
+    /// # Returns
+    ///
+    /// Returns true if the action activated the League.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as the method each action corresponds to.
+    pub fn handle_lobby_action(
+        &mut self,
+        id: serenity::UserId,
+        action: LobbyAction,
+    ) -> Result<bool, LeagueError> {
+        match action {
+            LobbyAction::Join => self.join_lobby(id).map(|_| false),
+            LobbyAction::Leave => self.leave_lobby(id).map(|_| false),
+            LobbyAction::TakeSeat(seat) => self.claim_seat(id, seat).map(|_| false),
+            LobbyAction::Ready => self.set_ready(id, true),
+            LobbyAction::Unready => self.set_ready(id, false),
+        }
+    }
+    /// Returns every player in the lobby in the seat order the draft would use if it activated now.
+    pub fn lobby(&self) -> Vec<LobbyEntry> {
+        self.seating_order()
+            .into_iter()
+            .enumerate()
+            .map(|(seat, idx)| {
+                let id = self.players[idx].id;
+                LobbyEntry {
+                    id,
+                    seat: seat as u32,
+                    claimed: self.lobby.seat_claims.get(&id) == Some(&(seat as u32)),
+                    ready: self.lobby.ready.contains(&id),
+                }
+            })
+            .collect()
+    }
+    /// Fills in an embed describing the lobby: each seat, who is in it, and who is ready.
+    pub fn lobby_embed<'a>(
+        &self,
+        embed: &'a mut serenity::CreateEmbed,
+    ) -> &'a mut serenity::CreateEmbed {
+        let entries = self.lobby();
+        let ready = entries.iter().filter(|e| e.ready).count();
+        embed
+            .title(self.text("lobby.title", &[("league", self.name.clone())]))
+            .description(self.text(
+                "lobby.description",
+                &[
+                    ("ready", ready.to_string()),
+                    ("players", entries.len().to_string()),
+                ],
+            ));
+        for entry in entries {
+            let status = match entry.ready {
+                true => self.text("lobby.ready", &[]),
+                false => self.text("lobby.not_ready", &[]),
+            };
+            embed.field(
+                self.seat_label(entry.seat as usize),
+                format!(
+                    "{} - {status}",
+                    self.get_player(entry.id).unwrap().display_name()
+                ),
+                true,
+            );
+        }
+        embed
+    }
+    /// Adds the lobby's buttons (join, leave, ready, unready) and seat select menu to a message.
+    ///
+    /// Discord limits select menus to 25 options, so only the first 25 seats can be chosen from the menu.
+    pub fn lobby_components<'a>(
+        &self,
+        components: &'a mut serenity::CreateComponents,
+    ) -> &'a mut serenity::CreateComponents {
+        let buttons = [
+            (
+                LobbyAction::Join,
+                "lobby.join_button",
+                serenity::ButtonStyle::Primary,
+            ),
+            (
+                LobbyAction::Leave,
+                "lobby.leave_button",
+                serenity::ButtonStyle::Secondary,
+            ),
+            (
+                LobbyAction::Ready,
+                "lobby.ready_button",
+                serenity::ButtonStyle::Success,
+            ),
+            (
+                LobbyAction::Unready,
+                "lobby.unready_button",
+                serenity::ButtonStyle::Danger,
+            ),
+        ];
+        components.create_action_row(|row| {
+            for (action, label, style) in buttons {
+                row.create_button(|b| {
+                    b.custom_id(action.custom_id(self.id))
+                        .label(self.text(label, &[]))
+                        .style(style)
+                });
+            }
+            row
+        });
+        if !self.players.is_empty() {
+            components.create_action_row(|row| {
+                row.create_select_menu(|menu| {
+                    menu.custom_id(LobbyAction::TakeSeat(0).custom_id(self.id))
+                        .placeholder(self.text("lobby.seat_placeholder", &[]))
+                        .options(|options| {
+                            for seat in 0..self.players.len().min(25) {
+                                options.create_option(|o| {
+                                    o.label(self.seat_label(seat)).value(seat.to_string())
+                                });
+                            }
+                            options
+                        })
+                })
+            });
+        }
+        components
+    }
+    // indices into self.players, in seat order: claimed seats first, then everyone else in join order
+    fn seating_order(&self) -> Vec<usize> {
+        let mut seats: Vec<Option<usize>> = vec![None; self.players.len()];
+        for (idx, player) in self.players.iter().enumerate() {
+            if let Some(&seat) = self.lobby.seat_claims.get(&player.id) {
+                if let Some(slot @ None) = seats.get_mut(seat as usize) {
+                    *slot = Some(idx);
+                }
+            }
+        }
+        let seated: HashSet<usize> = seats.iter().flatten().copied().collect();
+        let mut unseated = (0..self.players.len()).filter(|idx| !seated.contains(idx));
+        seats
+            .into_iter()
+            .map(|seat| seat.or_else(|| unseated.next()).unwrap())
+            .collect()
+    }
+    pub(crate) fn seat_players(&mut self) {
+        let order = self.seating_order();
+        let mut players: Vec<Option<ActivePlayer>> = self.players.drain(..).map(Some).collect();
+        self.players = order
+            .into_iter()
+            .map(|idx| players[idx].take().unwrap())
+            .collect();
+    }
+}
+
+#[cfg(test)]
+mod lobby_tests {
+    use super::*;
+    use crate::test_support::new_league;
+    use crate::{DraftType, LeaguePhase};
+
+    fn lobby_league() -> League {
+        new_league(1, DraftType::Snake, 2)
+    }
+
+    #[test]
+    fn everyone_ready_activates_in_claimed_seats() {
+        let mut league = lobby_league();
+        league.join_lobby(serenity::UserId(2)).unwrap();
+        league.join_lobby(serenity::UserId(3)).unwrap();
+        league.claim_seat(serenity::UserId(3), 0).unwrap();
+        assert!(!league.set_ready(serenity::UserId(1), true).unwrap());
+        assert!(!league.set_ready(serenity::UserId(2), true).unwrap());
+        assert!(league.set_ready(serenity::UserId(3), true).unwrap());
+        assert!(league.active());
+        let seats: Vec<u64> = league.players.iter().map(|p| p.id.0).collect();
+        assert_eq!(seats, vec![3, 1, 2]);
+        assert_eq!(league.final_pick, 5);
+    }
+
+    #[test]
+    fn empty_lobbies_cannot_start() {
+        let mut league = lobby_league();
+        league.leave_lobby(serenity::UserId(1)).unwrap();
+        match league.start() {
+            Err(LeagueError::NoPlayersError) => {}
+            _ => panic!("everyone left"),
+        }
+        match league.activate() {
+            Err(LeagueError::NoPlayersError) => {}
+            _ => panic!("everyone left"),
+        }
+        assert_eq!(league.phase(), LeaguePhase::Setup);
+        league.join_lobby(serenity::UserId(2)).unwrap();
+        assert_eq!(league.start().unwrap().id, serenity::UserId(2));
+    }
+
+    #[test]
+    fn claimed_seat_cannot_be_taken() {
+        let mut league = lobby_league();
+        league.join_lobby(serenity::UserId(2)).unwrap();
+        league.claim_seat(serenity::UserId(1), 1).unwrap();
+        match league.claim_seat(serenity::UserId(2), 1) {
+            Err(LeagueError::SeatTakenError) => {}
+            _ => panic!("seat 1 is taken"),
+        }
+        match league.claim_seat(serenity::UserId(2), 2) {
+            Err(LeagueError::InvalidSeatError) => {}
+            _ => panic!("there are only two seats"),
+        }
+    }
+
+    #[test]
+    fn lobby_actions_round_trip_through_custom_ids() {
+        let id = LobbyAction::Ready.custom_id(42);
+        assert_eq!(LobbyAction::parse(&id, &[]), Some((42, LobbyAction::Ready)));
+        let id = LobbyAction::TakeSeat(0).custom_id(42);
+        assert_eq!(
+            LobbyAction::parse(&id, &["3".to_string()]),
+            Some((42, LobbyAction::TakeSeat(3)))
+        );
+        assert_eq!(LobbyAction::parse("some:other:button", &[]), None);
+    }
+}
@@ -0,0 +1,74 @@
+use crate::{League, LeagueError};
+
+impl League {
+    /// Freezes an item by name (an injured player, a banned Pokémon), so waivers and trades involving it fail
+    /// until it is unlocked. Picks are not affected. Returns false if the item was already locked.
+    pub fn lock_item(&mut self, name: &str) -> bool {
+        let name = self.resolve_name(name).to_string();
+        self.locked_items.insert(name)
+    }
+    /// Unfreezes an item. Returns false if the item was not locked.
+    pub fn unlock_item(&mut self, name: &str) -> bool {
+        let name = self.resolve_name(name).to_string();
+        self.locked_items.remove(&name)
+    }
+    /// Returns true if an item is locked.
+    pub fn is_item_locked(&self, name: &str) -> bool {
+        self.locked_items.contains(self.resolve_name(name))
+    }
+    /// Iterates over the names of every locked item, in no particular order.
+    pub fn locked_items(&self) -> impl Iterator<Item = &str> {
+        self.locked_items.iter().map(String::as_str)
+    }
+    // waivers and trades check every item they move
+    pub(crate) fn require_unlocked(&self, names: &[&str]) -> Result<(), LeagueError> {
+        if names.iter().any(|name| self.is_item_locked(name)) {
+            return Err(LeagueError::DraftableLockedError);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod locks_tests {
+    use super::*;
+    use crate::test_support::{new_league, pokemon};
+    use crate::{DraftType, Rejected};
+    use poise::serenity_prelude as serenity;
+
+    #[test]
+    fn locked_items_cannot_move() {
+        let mut league = new_league(2, DraftType::Snake, 1);
+        league.activate().unwrap();
+        league.lock(pokemon("Charizard")).unwrap();
+        league.lock(pokemon("Blastoise")).unwrap();
+        assert!(league.lock_item("Charizard"));
+        assert!(!league.lock_item("Charizard"));
+        assert_eq!(league.locked_items().collect::<Vec<_>>(), vec!["Charizard"]);
+        match league.trade(
+            serenity::UserId(1),
+            "Charizard",
+            serenity::UserId(2),
+            "Blastoise",
+        ) {
+            Err(LeagueError::DraftableLockedError) => {}
+            _ => panic!("Charizard is frozen"),
+        }
+        match league.waiver(serenity::UserId(2), "Blastoise", pokemon("Charizard")) {
+            Err(Rejected {
+                error: LeagueError::DraftableLockedError,
+                ..
+            }) => {}
+            _ => panic!("Charizard is frozen"),
+        }
+        assert!(league.unlock_item("Charizard"));
+        assert!(league
+            .trade(
+                serenity::UserId(1),
+                "Charizard",
+                serenity::UserId(2),
+                "Blastoise"
+            )
+            .is_ok());
+    }
+}
@@ -0,0 +1,160 @@
+use crate::DraftRegistry;
+use chrono::{DateTime, Duration, Utc};
+use std::sync::Arc;
+
+/// An operator-facing watchdog that freezes every active draft in a [`DraftRegistry`] when your bot goes down.
+///
+/// If Discord or your host goes down, call [`GuildManager::pause_all`] so that players are not timed out for picks
+/// they could not make, then [`GuildManager::resume_all`] when service is restored.
+///
+/// Pausing can also be automated: feed the manager a heartbeat (e.g. from a timer task, or on every gateway event)
+/// with [`GuildManager::heartbeat`], and call [`GuildManager::check_heartbeat`] periodically from a watchdog.
+/// If the beats stop for longer than the heartbeat timeout, every active clock is frozen as of the last beat.
+pub struct GuildManager {
+    registry: Arc<DraftRegistry>,
+    heartbeat_timeout: Option<Duration>,
+    last_heartbeat: Option<DateTime<Utc>>,
+}
+
+impl GuildManager {
+    /// Creates a GuildManager watching the guilds in a registry, with automatic outage detection disabled.
+    pub fn new(registry: Arc<DraftRegistry>) -> GuildManager {
+        GuildManager {
+            registry,
+            heartbeat_timeout: None,
+            last_heartbeat: None,
+        }
+    }
+    /// Returns the [`DraftRegistry`] the manager watches.
+    pub fn registry(&self) -> &Arc<DraftRegistry> {
+        &self.registry
+    }
+    /// Freezes the clock of every active, unpaused [`League`](crate::League) in every guild, and returns how many were paused.
+    /// See [`DraftRegistry::pause_all`].
+    pub fn pause_all(&self, now: DateTime<Utc>) -> usize {
+        self.registry.pause_all(now)
+    }
+    /// Resumes every [`League`](crate::League) paused by [`GuildManager::pause_all`], and returns how many were resumed.
+    ///
+    /// Leagues that were paused individually before the outage stay paused.
+    pub fn resume_all(&self, now: DateTime<Utc>) -> usize {
+        self.registry.resume_all(now)
+    }
+    /// Returns true if leagues are currently frozen by [`GuildManager::pause_all`].
+    pub fn outage_paused(&self) -> bool {
+        self.registry.is_outage_paused()
+    }
+    /// Sets how long the manager may go without a heartbeat before it pauses every league. None disables outage detection.
+    pub fn set_heartbeat_timeout(&mut self, timeout: Option<Duration>) {
+        self.heartbeat_timeout = timeout;
+    }
+    /// Records that the bot is alive.
+    ///
+    /// If the previous beat is older than the heartbeat timeout, the bot has been down, so every league is paused
+    /// as of the previous beat and true is returned. Leagues stay paused until [`GuildManager::resume_all`] is called.
+    pub fn heartbeat(&mut self, now: DateTime<Utc>) -> bool {
+        let outage = self.check_heartbeat(now);
+        self.last_heartbeat = Some(now);
+        outage
+    }
+    /// Checks for an outage without recording a beat. Call this from a watchdog task that runs independently of the heartbeat.
+    ///
+    /// Returns true if the heartbeat is overdue, in which case every league is paused as of the last beat.
+    pub fn check_heartbeat(&mut self, now: DateTime<Utc>) -> bool {
+        let (Some(timeout), Some(last)) = (self.heartbeat_timeout, self.last_heartbeat) else {
+            return false;
+        };
+        if now - last <= timeout {
+            return false;
+        }
+        self.pause_all(last);
+        true
+    }
+}
+
+#[cfg(test)]
+mod manager_tests {
+    use super::*;
+    use crate::test_support::new_league;
+    use crate::{DraftType, SharedGuild};
+    use chrono::TimeZone;
+    use poise::serenity_prelude as serenity;
+
+    fn at(seconds: i64) -> DateTime<Utc> {
+        Utc.timestamp_opt(seconds, 0).unwrap()
+    }
+
+    fn manager_with_league(active: bool) -> GuildManager {
+        let registry = DraftRegistry::new();
+        let guild = registry.get_or_create_guild(69420, serenity::ChannelId(69420));
+        let mut league = new_league(2, DraftType::Snake, 3);
+        if active {
+            league.activate().unwrap();
+        }
+        guild.add_league(league).expect("fresh guild");
+        GuildManager::new(Arc::new(registry))
+    }
+
+    fn paused(manager: &GuildManager) -> bool {
+        manager
+            .registry()
+            .league(69420, "Creenis", |league| league.paused())
+            .unwrap()
+    }
+
+    #[test]
+    fn pause_all_and_resume_all_toggle_active_leagues() {
+        let manager = manager_with_league(true);
+        assert_eq!(manager.pause_all(at(10)), 1);
+        assert!(paused(&manager));
+        assert_eq!(manager.resume_all(at(20)), 1);
+        assert!(!paused(&manager));
+    }
+
+    #[test]
+    fn pause_all_skips_inactive_leagues() {
+        let manager = manager_with_league(false);
+        assert_eq!(manager.pause_all(at(10)), 0);
+        assert!(!manager.outage_paused());
+    }
+
+    #[test]
+    fn resume_all_leaves_manually_paused_leagues() {
+        let manager = manager_with_league(true);
+        manager
+            .registry()
+            .league(69420, "Creenis", |league| league.pause(at(5)))
+            .unwrap();
+        assert_eq!(manager.pause_all(at(10)), 0);
+        assert_eq!(manager.resume_all(at(20)), 0);
+        assert!(paused(&manager));
+    }
+
+    #[test]
+    fn leagues_are_remembered_by_name() {
+        let manager = manager_with_league(true);
+        let guild = manager.registry().guild(69420).unwrap();
+        // a second League with the same id, paused by its commissioner
+        let mut other = new_league(2, DraftType::Snake, 3);
+        other.name = "Bofa".to_string();
+        other.activate().unwrap();
+        other.pause(at(5));
+        guild.add_league(other).unwrap();
+        assert_eq!(manager.pause_all(at(10)), 1);
+        guild.rename_league("Creenis", "Ash".to_string()).unwrap();
+        assert_eq!(manager.resume_all(at(20)), 1);
+        assert!(!SharedGuild::lock(&guild.league("Ash").unwrap()).paused());
+        assert!(SharedGuild::lock(&guild.league("Bofa").unwrap()).paused());
+    }
+
+    #[test]
+    fn missed_heartbeat_pauses_everything() {
+        let mut manager = manager_with_league(true);
+        manager.set_heartbeat_timeout(Some(Duration::seconds(30)));
+        assert!(!manager.heartbeat(at(0)));
+        assert!(!manager.heartbeat(at(20)));
+        assert!(!manager.check_heartbeat(at(45)));
+        assert!(manager.check_heartbeat(at(100)));
+        assert!(manager.outage_paused());
+    }
+}
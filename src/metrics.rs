@@ -0,0 +1,114 @@
+// Counters and gauges for the `metrics` feature. Without it, everything here does nothing.
+use crate::LeagueError;
+
+#[cfg(feature = "metrics")]
+const PICKS: &str = "drftr_picks_total";
+#[cfg(feature = "metrics")]
+const TRADES: &str = "drftr_trades_total";
+#[cfg(feature = "metrics")]
+const ERRORS: &str = "drftr_errors_total";
+#[cfg(feature = "metrics")]
+const ACTIVE_LEAGUES: &str = "drftr_active_leagues";
+
+// picks locked in during a live draft, not ones rebuilt by replays
+pub(crate) fn picks_made(count: usize) {
+    #[cfg(feature = "metrics")]
+    ::metrics::counter!(PICKS).increment(count as u64);
+    #[cfg(not(feature = "metrics"))]
+    let _ = count;
+}
+
+pub(crate) fn trade_made() {
+    #[cfg(feature = "metrics")]
+    ::metrics::counter!(TRADES).increment(1);
+}
+
+// counts the error a player-facing action failed with, by kind
+pub(crate) fn tracked<T>(result: Result<T, LeagueError>) -> Result<T, LeagueError> {
+    #[cfg(feature = "metrics")]
+    if let Err(error) = &result {
+        ::metrics::counter!(ERRORS, "kind" => error.kind()).increment(1);
+    }
+    result
+}
+
+// whether a League counts towards the active leagues gauge. Dropping a League that counts takes it back off.
+#[derive(Default)]
+pub(crate) struct ActiveGauge {
+    counted: bool,
+}
+
+impl ActiveGauge {
+    pub(crate) fn update(&mut self, active: bool) {
+        if active == self.counted {
+            return;
+        }
+        self.counted = active;
+        #[cfg(feature = "metrics")]
+        match active {
+            true => ::metrics::gauge!(ACTIVE_LEAGUES).increment(1.0),
+            false => ::metrics::gauge!(ACTIVE_LEAGUES).decrement(1.0),
+        }
+    }
+}
+
+impl Drop for ActiveGauge {
+    fn drop(&mut self) {
+        self.update(false);
+    }
+}
+
+#[cfg(all(test, feature = "metrics"))]
+mod metrics_tests {
+    use super::*;
+    use crate::test_support::{new_league, pokemon};
+    use crate::{DraftType, League};
+    use metrics_util::debugging::{DebugValue, DebuggingRecorder};
+    use poise::serenity_prelude as serenity;
+
+    #[test]
+    fn drafts_are_counted() {
+        let recorder = DebuggingRecorder::new();
+        let snapshotter = recorder.snapshotter();
+        let _league = ::metrics::with_local_recorder(&recorder, || {
+            let mut league = new_league(2, DraftType::Snake, 1);
+            match league.lock(pokemon("Pikachu")) {
+                Err(LeagueError::LeagueInactiveError) => {}
+                _ => panic!("the draft has not started"),
+            }
+            league.activate().unwrap();
+            league.lock(pokemon("Pikachu")).unwrap();
+            let mut dropped = League::new(
+                &[serenity::UserId(1)],
+                1,
+                "Creenis".to_string(),
+                None,
+                DraftType::Snake,
+                1,
+            );
+            dropped.activate().unwrap();
+            drop(dropped);
+            league
+        });
+        let values: Vec<(String, Vec<String>, DebugValue)> = snapshotter
+            .snapshot()
+            .into_vec()
+            .into_iter()
+            .map(|(key, _, _, value)| {
+                let key = key.key();
+                let labels = key.labels().map(|l| l.value().to_string()).collect();
+                (key.name().to_string(), labels, value)
+            })
+            .collect();
+        let value = |name: &str| values.iter().find(|(n, _, _)| n == name).unwrap();
+        assert!(matches!(value(PICKS), (_, _, DebugValue::Counter(1))));
+        let (_, labels, errors) = value(ERRORS);
+        assert_eq!(labels, &["LeagueInactiveError".to_string()]);
+        assert!(matches!(errors, DebugValue::Counter(1)));
+        // the first League is still drafting, and the second was dropped mid-draft
+        match value(ACTIVE_LEAGUES) {
+            (_, _, DebugValue::Gauge(active)) => assert_eq!(active.into_inner(), 1.0),
+            _ => panic!("active leagues is a gauge"),
+        }
+    }
+}
@@ -0,0 +1,300 @@
+use crate::{DraftItem, DraftPool, Draftable, PoolImportError};
+use serde::Deserialize;
+
+/// A Magic: the Gathering card in a [`Cube`]. Only the name comes from a plain text cube list: the rest is filled in
+/// from a CSV export's columns or by [`Cube::enrich`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Card {
+    /// The card's name, e.g. "Lightning Bolt".
+    pub name: String,
+    /// The set code of the printing, e.g. "m10".
+    pub set: Option<String>,
+    /// The card's rarity in lowercase, e.g. "rare". This is the card's category, e.g. for pack slots.
+    pub rarity: Option<String>,
+    /// The card's type line, e.g. "Legendary Creature — Human Wizard".
+    pub type_line: Option<String>,
+    /// The card's colors as WUBRG letters, e.g. "UR", or empty for colorless cards.
+    pub colors: Option<String>,
+    /// The card's mana value.
+    pub mana_value: Option<f64>,
+}
+
+impl DraftItem for Card {
+    fn name(&self) -> &str {
+        &self.name
+    }
+    fn category(&self) -> Option<&str> {
+        self.rarity.as_deref()
+    }
+}
+
+/// A cube list: the cards a Magic: the Gathering draft is played with, and how many copies of each.
+pub struct Cube {
+    // each card with its number of copies, in the order listed
+    cards: Vec<(Card, u32)>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum ScryfallList {
+    // a /cards/collection or /cards/search response
+    Page { data: Vec<ScryfallCard> },
+    // a bulk data file
+    Cards(Vec<ScryfallCard>),
+}
+
+#[derive(Deserialize)]
+struct ScryfallCard {
+    name: String,
+    set: Option<String>,
+    rarity: Option<String>,
+    type_line: Option<String>,
+    colors: Option<Vec<String>>,
+    // double-faced cards keep their colors on each face
+    #[serde(default)]
+    card_faces: Vec<ScryfallFace>,
+    cmc: Option<f64>,
+}
+
+#[derive(Deserialize)]
+struct ScryfallFace {
+    colors: Option<Vec<String>>,
+}
+
+impl Cube {
+    /// Reads a cube list, either as plain text or as a CSV export with a `name` column (as CubeCobra exports).
+    ///
+    /// Plain text lists one card per line, optionally with a count ("2 Island" or "2x Island") and an Arena-style printing
+    /// ("Lightning Bolt (M10) 146"). Blank lines and lines starting with `#` or `//` are ignored.
+    ///
+    /// A CSV's `set`, `rarity`, `type`, `color` and `cmc` columns are read when it has them, and its maybeboard is left out.
+    /// A card listed more than once is one card with more copies.
+    ///
+    /// # Errors
+    ///
+    /// If a CSV row cannot be read, returns [`PoolImportError::MalformedPoolError`] with the parser's message.
+    pub fn parse(text: &str) -> Result<Cube, PoolImportError> {
+        let header = text.lines().find(|line| !line.trim().is_empty());
+        let is_csv = header.is_some_and(|line| {
+            line.split(',')
+                .any(|column| column.trim().trim_matches('"').eq_ignore_ascii_case("name"))
+        });
+        let mut cube = Cube { cards: Vec::new() };
+        if is_csv {
+            cube.read_csv(text)?;
+        } else {
+            for line in text.lines().map(str::trim) {
+                if line.is_empty() || line.starts_with('#') || line.starts_with("//") {
+                    continue;
+                }
+                let (copies, card) = read_line(line);
+                cube.add(card, copies);
+            }
+        }
+        Ok(cube)
+    }
+    fn read_csv(&mut self, text: &str) -> Result<(), PoolImportError> {
+        let mut reader = csv::ReaderBuilder::new()
+            .flexible(true)
+            .trim(csv::Trim::All)
+            .from_reader(text.as_bytes());
+        let headers = reader
+            .headers()
+            .map_err(|e| PoolImportError::MalformedPoolError(e.to_string()))?
+            .clone();
+        let column = |name: &str| headers.iter().position(|h| h.eq_ignore_ascii_case(name));
+        let (name, set, rarity) = (column("name"), column("set"), column("rarity"));
+        let (type_line, colors, cmc) = (column("type"), column("color"), column("cmc"));
+        let maybeboard = column("maybeboard");
+        for row in reader.records() {
+            let row = row.map_err(|e| PoolImportError::MalformedPoolError(e.to_string()))?;
+            let cell = |column: Option<usize>| {
+                column
+                    .and_then(|c| row.get(c))
+                    .filter(|value| !value.is_empty())
+                    .map(String::from)
+            };
+            let Some(card_name) = cell(name) else {
+                continue;
+            };
+            if cell(maybeboard).is_some_and(|m| m.eq_ignore_ascii_case("true")) {
+                continue;
+            }
+            let card = Card {
+                name: card_name,
+                set: cell(set).map(|s| s.to_lowercase()),
+                rarity: cell(rarity).map(|r| r.to_lowercase()),
+                type_line: cell(type_line),
+                colors: cell(colors),
+                mana_value: cell(cmc).and_then(|c| c.parse().ok()),
+            };
+            self.add(card, 1);
+        }
+        Ok(())
+    }
+    fn add(&mut self, card: Card, copies: u32) {
+        match self.cards.iter_mut().find(|(c, _)| c.name == card.name) {
+            Some((_, count)) => *count += copies,
+            None => self.cards.push((card, copies)),
+        }
+    }
+    /// Fills in what the cube list left out about its cards from Scryfall card objects, and returns the names of the cards
+    /// Scryfall had nothing on. What the cube list says, e.g. which set a card is from, is kept.
+    ///
+    /// DRFTR does not talk to Scryfall itself: pass the body of a
+    /// [`/cards/collection`](https://scryfall.com/docs/api/cards/collection) response, or a bulk data file such as
+    /// Oracle Cards. Cards are matched by name, ignoring case, and double-faced cards by their front face too.
+    ///
+    /// # Errors
+    ///
+    /// If the text is not a list of Scryfall cards, returns [`PoolImportError::MalformedPoolError`] with the parser's message.
+    pub fn enrich(&mut self, scryfall_json: &str) -> Result<Vec<String>, PoolImportError> {
+        let list: ScryfallList = serde_json::from_str(scryfall_json)
+            .map_err(|e| PoolImportError::MalformedPoolError(e.to_string()))?;
+        let found = match list {
+            ScryfallList::Page { data } => data,
+            ScryfallList::Cards(cards) => cards,
+        };
+        let mut missing = Vec::new();
+        for (card, _) in &mut self.cards {
+            let Some(found) = found.iter().find(|f| {
+                f.name.eq_ignore_ascii_case(&card.name)
+                    || f.name
+                        .split(" // ")
+                        .next()
+                        .is_some_and(|front| front.eq_ignore_ascii_case(&card.name))
+            }) else {
+                missing.push(card.name.clone());
+                continue;
+            };
+            let colors = found
+                .colors
+                .clone()
+                .or_else(|| found.card_faces.first().and_then(|f| f.colors.clone()));
+            card.set = card.set.take().or_else(|| found.set.clone());
+            card.rarity = card.rarity.take().or_else(|| found.rarity.clone());
+            card.type_line = card.type_line.take().or_else(|| found.type_line.clone());
+            card.colors = card.colors.take().or_else(|| colors.map(|c| c.concat()));
+            card.mana_value = card.mana_value.or(found.cmc);
+        }
+        Ok(missing)
+    }
+    /// Returns each card in the cube with its number of copies, in the order listed.
+    pub fn cards(&self) -> &[(Card, u32)] {
+        &self.cards
+    }
+    /// Returns the number of cards in the cube, counting every copy.
+    pub fn len(&self) -> u32 {
+        self.cards.iter().map(|(_, copies)| copies).sum()
+    }
+    /// Returns true if the cube has no cards.
+    pub fn is_empty(&self) -> bool {
+        self.cards.is_empty()
+    }
+    /// Turns the cube into a pool for a [`League`](crate::League), in the order listed, with every copy of each card.
+    pub fn into_pool(self) -> DraftPool {
+        DraftPool::with_copies(
+            self.cards
+                .into_iter()
+                .map(|(card, copies)| (Box::new(card) as Draftable, copies))
+                .collect(),
+        )
+    }
+    /// Turns the cube into items for [`League::set_packs`](crate::League::set_packs), in the order listed. Since every
+    /// item needs a unique name, only one copy of each card is kept.
+    pub fn into_items(self) -> Vec<Draftable> {
+        self.cards
+            .into_iter()
+            .map(|(card, _)| Box::new(card) as Draftable)
+            .collect()
+    }
+}
+
+// reads "2x Lightning Bolt (M10) 146" as 2 copies of Lightning Bolt from m10
+fn read_line(line: &str) -> (u32, Card) {
+    let (copies, rest) = match line.split_once(' ') {
+        Some((count, rest)) => match count.trim_end_matches(['x', 'X']).parse() {
+            Ok(copies) => (copies, rest.trim()),
+            Err(_) => (1, line),
+        },
+        None => (1, line),
+    };
+    let (name, set) = match rest.rsplit_once(" (") {
+        Some((name, printing)) => match printing.split_once(')') {
+            Some((set, number)) if number.trim().chars().all(|c| c.is_ascii_alphanumeric()) => {
+                (name, Some(set.to_lowercase()))
+            }
+            _ => (rest, None),
+        },
+        None => (rest, None),
+    };
+    let card = Card {
+        name: name.trim().to_string(),
+        set,
+        rarity: None,
+        type_line: None,
+        colors: None,
+        mana_value: None,
+    };
+    (copies, card)
+}
+
+#[cfg(test)]
+mod mtg_tests {
+    use super::*;
+
+    const SCRYFALL: &str = r#"{"object": "list", "not_found": [], "data": [
+        {"name": "Lightning Bolt", "set": "lea", "rarity": "common", "type_line": "Instant",
+         "colors": ["R"], "cmc": 1.0},
+        {"name": "Delver of Secrets // Insectile Aberration", "set": "isd", "rarity": "common",
+         "type_line": "Creature — Human Wizard // Creature — Human Insect",
+         "card_faces": [{"colors": ["U"]}, {"colors": ["U"]}], "cmc": 1.0}
+    ]}"#;
+
+    #[test]
+    fn text_cubes_read_counts_and_printings() {
+        let text = "# My Cube\n2x Island\nLightning Bolt (M10) 146\n\n// blue\nDelver of Secrets\nIsland\nBlack Lotus";
+        let mut cube = Cube::parse(text).unwrap();
+        assert_eq!(cube.len(), 6);
+        let (island, copies) = &cube.cards()[0];
+        assert_eq!((island.name.as_str(), *copies), ("Island", 3));
+        let bolt = &cube.cards()[1].0;
+        assert_eq!(bolt.set.as_deref(), Some("m10"));
+
+        let missing = cube.enrich(SCRYFALL).unwrap();
+        assert_eq!(missing, vec!["Island", "Black Lotus"]);
+        let bolt = &cube.cards()[1].0;
+        assert_eq!(
+            bolt.set.as_deref(),
+            Some("m10"),
+            "the cube's printing is kept"
+        );
+        assert_eq!(bolt.category(), Some("common"));
+        assert_eq!(bolt.mana_value, Some(1.0));
+        let delver = &cube.cards()[2].0;
+        assert_eq!(delver.colors.as_deref(), Some("U"));
+        let pool = cube.into_pool();
+        assert_eq!(pool.copies("Island"), 3);
+        match Cube::parse("Island").unwrap().enrich("{}") {
+            Err(PoolImportError::MalformedPoolError(_)) => {}
+            _ => panic!("not a list of cards"),
+        }
+    }
+
+    #[test]
+    fn csv_cubes_read_their_columns() {
+        let csv = "name,CMC,Type,Color,Set,Collector Number,Rarity,maybeboard\n\
+            \"Jace, the Mind Sculptor\",4,Legendary Planeswalker — Jace,U,WWK,31,Mythic,false\n\
+            Counterspell,2,Instant,U,7ED,67,Common,false\n\
+            Ancestral Recall,1,Instant,U,LEA,48,Rare,true\n";
+        let cube = Cube::parse(csv).unwrap();
+        assert_eq!(cube.len(), 2, "the maybeboard is left out");
+        let jace = &cube.cards()[0].0;
+        assert_eq!(jace.name, "Jace, the Mind Sculptor");
+        assert_eq!(jace.rarity.as_deref(), Some("mythic"));
+        assert_eq!(jace.set.as_deref(), Some("wwk"));
+        assert_eq!(jace.mana_value, Some(4.0));
+        let items = cube.into_items();
+        assert_eq!(items[1].name(), "Counterspell");
+    }
+}
@@ -0,0 +1,158 @@
+use crate::{draft_types, DraftEvent, League, LeagueError};
+use poise::serenity_prelude as serenity;
+use serde::{Deserialize, Serialize};
+
+/// The order seats take turns to nominate items in an auction, set with [`League::set_nomination_order`].
+/// It has nothing to do with the order seats would pick in, since nobody picks in turn in an auction.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum NominationOrder {
+    /// Seats nominate in seat order, going back to seat 0 after the last.
+    #[default]
+    Linear,
+    /// Seats nominate in seat order, then in reverse, like a snake draft.
+    Snake,
+    /// Seats nominate in this order of seats (starting at 0), over and over. A seat may appear more than once.
+    Custom(Vec<u32>),
+}
+
+impl League {
+    /// Sets the order seats nominate items in an auction. Defaults to [`NominationOrder::Linear`].
+    ///
+    /// # Errors
+    ///
+    /// If a custom order is empty or names a seat that does not exist, returns [`LeagueError::InvalidSeatError`].
+    ///
+    /// If the draft has started, returns [`LeagueError::LeagueActiveError`], or [`LeagueError::WrongPhaseError`] once it is over.
+    pub fn set_nomination_order(&mut self, order: NominationOrder) -> Result<(), LeagueError> {
+        if let NominationOrder::Custom(seats) = &order {
+            if seats.is_empty() || seats.iter().any(|s| *s as usize >= self.players.len()) {
+                return Err(LeagueError::InvalidSeatError);
+            }
+        }
+        self.require_pre_draft()?;
+        self.auction.order = order;
+        Ok(())
+    }
+    /// Returns the order seats nominate items in an auction.
+    pub fn nomination_order(&self) -> &NominationOrder {
+        &self.auction.order
+    }
+    /// Returns the player whose turn it is to nominate the next item in an auction, by their seat's original owner.
+    /// Seats with full rosters are passed over.
+    ///
+    /// While a lot is open this is the player who nominates after it closes. Returns None unless an auction is drafting.
+    pub fn current_nominator(&self) -> Option<serenity::UserId> {
+        let (_, seat) = self.nominating_turn()?;
+        Some(self.players[seat as usize].id)
+    }
+    /// Passes over the player whose turn it is to nominate, e.g. because they are away, and returns them.
+    ///
+    /// # Errors
+    ///
+    /// If the League is not an auction, returns [`LeagueError::WrongDraftTypeError`].
+    ///
+    /// If the League is not drafting, returns [`LeagueError::LeagueInactiveError`], or [`LeagueError::LeaguePausedError`] while paused.
+    pub fn skip_nominator(&mut self) -> Result<serenity::UserId, LeagueError> {
+        self.require_auction()?;
+        let (turn, seat) = self
+            .nominating_turn()
+            .ok_or(LeagueError::LeagueInactiveError)?;
+        self.auction.nominations = turn + 1;
+        self.record(DraftEvent::NominationSkipped);
+        Ok(self.players[seat as usize].id)
+    }
+    // the nomination turn and seat up next, passing over full rosters
+    pub(crate) fn nominating_turn(&self) -> Option<(u32, u32)> {
+        if self.require_auction().is_err() {
+            return None;
+        }
+        let seats = self.players.len() as u32;
+        let cycle = match &self.auction.order {
+            NominationOrder::Custom(order) => order.len() as u32,
+            _ => seats * 2,
+        };
+        (self.auction.nominations..self.auction.nominations + cycle)
+            .map(|turn| (turn, self.nominating_seat(turn)))
+            .find(|(_, seat)| {
+                let player = &self.players[*seat as usize];
+                (player.picks.len() as u32) < player.team_size.unwrap_or(self.team_size)
+            })
+    }
+    // the seat that nominates on the given zero-based turn
+    fn nominating_seat(&self, turn: u32) -> u32 {
+        let seats = self.players.len() as u32;
+        match &self.auction.order {
+            NominationOrder::Linear => turn % seats,
+            NominationOrder::Snake if turn == 0 => 0,
+            NominationOrder::Snake => draft_types::snake_draft(turn - 1, seats),
+            NominationOrder::Custom(order) => order[(turn % order.len() as u32) as usize],
+        }
+    }
+}
+
+#[cfg(test)]
+mod nomination_tests {
+    use super::*;
+    use crate::test_support::{new_league, Pokemon};
+    use crate::{DraftPool, DraftType, Draftable};
+    use chrono::{Duration, Utc};
+
+    fn league(order: NominationOrder) -> League {
+        let mut league = new_league(3, DraftType::Auction, 2);
+        let pool: Vec<Draftable> = ["Mewtwo", "Pikachu", "Eevee", "Ditto", "Mew", "Abra"]
+            .iter()
+            .map(|n| {
+                Box::new(Pokemon {
+                    name: n.to_string(),
+                }) as Draftable
+            })
+            .collect();
+        league.set_pool(DraftPool::new(pool)).unwrap();
+        league.set_nomination_order(order).unwrap();
+        league.activate().unwrap();
+        league
+    }
+
+    #[test]
+    fn nominators_take_turns_and_can_be_skipped() {
+        let mut league = league(NominationOrder::Snake);
+        let now = Utc::now();
+        assert_eq!(league.current_nominator(), Some(serenity::UserId(1)));
+        match league.nominate(serenity::UserId(2), "Mew", now) {
+            Err(LeagueError::NotYourTurnError) => {}
+            _ => panic!("seat 0 nominates first"),
+        }
+        league.nominate(serenity::UserId(1), "Mew", now).unwrap();
+        league.bid(serenity::UserId(3), 5, now).unwrap();
+        assert_eq!(league.current_nominator(), Some(serenity::UserId(2)));
+        assert_eq!(league.skip_nominator().unwrap(), serenity::UserId(2));
+        league.resolve_lot(now).unwrap();
+        // the snake turns at seat 2, which nominates twice
+        league.nominate(serenity::UserId(3), "Abra", now).unwrap();
+        league.bid(serenity::UserId(3), 5, now).unwrap();
+        league.resolve_lot(now).unwrap();
+        assert_eq!(
+            league.current_nominator(),
+            Some(serenity::UserId(2)),
+            "seat 2 is full, so the snake moves straight back to seat 1"
+        );
+        league
+            .nominate(serenity::UserId(2), "Ditto", now + Duration::hours(1))
+            .unwrap();
+    }
+
+    #[test]
+    fn custom_orders_must_name_real_seats() {
+        let mut league = league(NominationOrder::Custom(vec![2, 0]));
+        assert_eq!(league.current_nominator(), Some(serenity::UserId(3)));
+        league.skip_nominator().unwrap();
+        assert_eq!(league.current_nominator(), Some(serenity::UserId(1)));
+        league.skip_nominator().unwrap();
+        assert_eq!(league.current_nominator(), Some(serenity::UserId(3)));
+        let mut fresh = new_league(1, DraftType::Auction, 1);
+        match fresh.set_nomination_order(NominationOrder::Custom(vec![1])) {
+            Err(LeagueError::InvalidSeatError) => {}
+            _ => panic!("there is no seat 1"),
+        }
+    }
+}
@@ -0,0 +1,275 @@
+use crate::{League, MessageKind, PickResult};
+use poise::serenity_prelude as serenity;
+use serenity::Mentionable;
+use std::future::Future;
+
+impl League {
+    /// Returns the channel the League posts to: its own output channel, or the [`DraftGuild`](crate::DraftGuild)'s
+    /// default_output if it has none.
+    pub fn output_channel(&self, default_output: serenity::ChannelId) -> serenity::ChannelId {
+        self.output.unwrap_or(default_output)
+    }
+    /// Moves the League's announcements to another channel, and returns the channel it had before, if any.
+    pub fn set_output(&mut self, channel: serenity::ChannelId) -> Option<serenity::ChannelId> {
+        self.thread = None;
+        self.output.replace(channel)
+    }
+    /// Sends the League's announcements back to the [`DraftGuild`](crate::DraftGuild)'s default_output, and returns
+    /// the channel it had before, if any.
+    pub fn clear_output(&mut self) -> Option<serenity::ChannelId> {
+        self.thread = None;
+        self.output.take()
+    }
+    /// Builds the announcement for picks returned by [`League::lock`] (or [`League::skip`], [`League::autopick`]...),
+    /// ending with who is on the clock next. Worded by the League's [`MessageKind::Pick`], [`MessageKind::OnTheClock`]
+    /// and [`MessageKind::Complete`] templates.
+    pub fn pick_announcement(&self, picks: &[PickResult]) -> String {
+        let league = ("league", self.name.clone());
+        let mut lines: Vec<String> = picks
+            .iter()
+            .map(|pick| {
+                let picker = self.player_label(pick.user);
+                self.template(MessageKind::Pick).render(&[
+                    ("player", picker),
+                    ("item", self.label_for_name(&pick.item)),
+                    ("source", self.text(pick.source.text_key(), &[])),
+                    ("round", pick.round.to_string()),
+                    ("pick", pick.pick.to_string()),
+                    league.clone(),
+                ])
+            })
+            .collect();
+        match self.current_player() {
+            Ok(player) => lines.push(self.template(MessageKind::OnTheClock).render(&[
+                ("next_player", player.display_name()),
+                ("pick", (self.total_picks + 1).to_string()),
+                league,
+            ])),
+            Err(_) => lines.push(self.template(MessageKind::Complete).render(&[league])),
+        }
+        lines.join("\n")
+    }
+    /// Builds the message telling the seat on the clock it is their turn, mentioning every owner of the seat and its proxy, if it has one.
+    /// Worded by the League's [`MessageKind::TurnPing`] template.
+    ///
+    /// Returns None if the draft is not underway.
+    pub fn turn_ping(&self) -> Option<String> {
+        let player = self.current_player().ok()?;
+        let mut mentions: Vec<String> =
+            player.owners().map(|id| id.mention().to_string()).collect();
+        if let Ok(Some(proxy)) = self.proxy(player.id) {
+            mentions.push(proxy.mention().to_string());
+        }
+        let players = self.players.len() as u32;
+        Some(self.template(MessageKind::TurnPing).render(&[
+            ("mentions", mentions.join(" ")),
+            ("next_player", player.display_name()),
+            ("league", self.name.clone()),
+            ("round", (self.total_picks / players + 1).to_string()),
+            ("pick", (self.total_picks + 1).to_string()),
+        ]))
+    }
+    /// Builds the announcement for a [`League::trade`] of item1 from user1 to user2 for item2, made after the trade went through.
+    pub fn trade_announcement(
+        &self,
+        user1: serenity::UserId,
+        item1: &str,
+        user2: serenity::UserId,
+        item2: &str,
+    ) -> String {
+        self.text(
+            "trade.announcement",
+            &[
+                ("user1", self.player_label(user1)),
+                ("item1", self.label_for_name(item1)),
+                ("user2", self.player_label(user2)),
+                ("item2", self.label_for_name(item2)),
+            ],
+        )
+    }
+    /// Builds the announcement for a [`League::waiver`] of dropped for claimed, made after the waiver went through.
+    pub fn waiver_announcement(
+        &self,
+        id: serenity::UserId,
+        dropped: &str,
+        claimed: &str,
+    ) -> String {
+        self.text(
+            "waiver.announcement",
+            &[
+                ("player", self.player_label(id)),
+                ("dropped", dropped.to_string()),
+                ("claimed", self.label_for_name(claimed)),
+            ],
+        )
+    }
+    /// Builds an embed listing every seat's picks, for posting once the draft is complete.
+    pub fn summary_embed(&self, embed: &mut serenity::CreateEmbed) {
+        embed.title(self.text("summary.title", &[("league", self.name.clone())]));
+        for player in &self.players {
+            let picks: Vec<String> = player
+                .picks
+                .iter()
+                .map(|item| self.item_label(item.as_ref()))
+                .collect();
+            let picks = match picks.is_empty() {
+                true => self.text("summary.no_picks", &[]),
+                false => picks.join("\n"),
+            };
+            embed.field(player.display_name(), picks, true);
+        }
+    }
+    /// Posts [`League::pick_announcement`] to the League's output channel.
+    pub fn announce_picks<'a>(
+        &self,
+        http: &'a serenity::Http,
+        default_output: serenity::ChannelId,
+        picks: &[PickResult],
+    ) -> impl Future<Output = serenity::Result<serenity::Message>> + Send + 'a {
+        let channel = self.output_channel(default_output);
+        let content = self.pick_announcement(picks);
+        async move { channel.say(http, content).await }
+    }
+    /// Posts [`League::turn_ping`] to the League's output channel.
+    ///
+    /// Resolves to Ok(None) without posting if the draft is not underway.
+    pub fn announce_turn<'a>(
+        &self,
+        http: &'a serenity::Http,
+        default_output: serenity::ChannelId,
+    ) -> impl Future<Output = serenity::Result<Option<serenity::Message>>> + Send + 'a {
+        let channel = self.output_channel(default_output);
+        let content = self.turn_ping();
+        async move {
+            match content {
+                Some(content) => channel.say(http, content).await.map(Some),
+                None => Ok(None),
+            }
+        }
+    }
+    /// Posts [`League::summary_embed`] to the League's output channel.
+    pub fn announce_summary<'a>(
+        &self,
+        http: &'a serenity::Http,
+        default_output: serenity::ChannelId,
+    ) -> impl Future<Output = serenity::Result<serenity::Message>> + Send + 'a {
+        let channel = self.output_channel(default_output);
+        let mut embed = serenity::CreateEmbed::default();
+        self.summary_embed(&mut embed);
+        async move { channel.send_message(http, |m| m.set_embed(embed)).await }
+    }
+    // a seat's display name, or the user's mention if they have no seat
+    fn player_label(&self, id: serenity::UserId) -> String {
+        match self.get_player(id) {
+            Some(player) => player.display_name(),
+            None => id.mention().to_string(),
+        }
+    }
+    // the emoji label of a picked item, by name
+    fn label_for_name(&self, name: &str) -> String {
+        self.players
+            .iter()
+            .flat_map(|p| p.picks.iter())
+            .find(|item| item.name() == name)
+            .map(|item| self.item_label(item.as_ref()))
+            .unwrap_or_else(|| name.to_string())
+    }
+}
+
+#[cfg(test)]
+mod notify_tests {
+    use super::*;
+    use crate::test_support::{new_league, Pokemon};
+    use crate::{DraftType, MessageTemplate, Team};
+
+    fn league() -> League {
+        let mut league = new_league(2, DraftType::Snake, 1);
+        league
+            .set_team(serenity::UserId(1), Team::new("Rockets".to_string()))
+            .unwrap();
+        league
+            .add_co_owner(serenity::UserId(2), serenity::UserId(3))
+            .unwrap();
+        league.activate().unwrap();
+        league
+    }
+
+    #[test]
+    fn announcement_names_next_seat() {
+        let mut league = league();
+        league.set_item_emoji("Charizard".to_string(), "🔥".to_string());
+        let picks = league
+            .lock(Box::new(Pokemon {
+                name: "Charizard".to_string(),
+            }))
+            .unwrap();
+        assert_eq!(
+            league.pick_announcement(&picks),
+            "Rockets picked **🔥 Charizard**\n<@2> is on the clock."
+        );
+        assert_eq!(
+            league.turn_ping().unwrap(),
+            "<@2> <@3> you're on the clock in **Creenis** (round 1, pick 2)."
+        );
+        assert_eq!(
+            league.output_channel(serenity::ChannelId(5)),
+            serenity::ChannelId(5)
+        );
+    }
+
+    #[test]
+    fn output_can_move_mid_season() {
+        let mut league = league();
+        assert_eq!(league.set_output(serenity::ChannelId(6)), None);
+        assert_eq!(
+            league.output_channel(serenity::ChannelId(5)),
+            serenity::ChannelId(6)
+        );
+        assert_eq!(league.clear_output(), Some(serenity::ChannelId(6)));
+        assert_eq!(
+            league.output_channel(serenity::ChannelId(5)),
+            serenity::ChannelId(5)
+        );
+    }
+
+    #[test]
+    fn announcements_use_league_templates() {
+        let mut league = league();
+        let template = MessageTemplate::new("{player} yoinked {item} (round {round})".to_string());
+        league.set_template(MessageKind::Pick, template.unwrap());
+        let picks = league
+            .lock(Box::new(Pokemon {
+                name: "Charizard".to_string(),
+            }))
+            .unwrap();
+        assert_eq!(
+            league.pick_announcement(&picks),
+            "Rockets yoinked Charizard (round 1)\n<@2> is on the clock."
+        );
+    }
+
+    #[test]
+    fn queued_picks_say_so() {
+        let mut league = league();
+        league
+            .add_to_player_queue(serenity::UserId(2), Box::new("Blastoise"))
+            .unwrap();
+        let picks = league.lock(Box::new("Charizard")).unwrap();
+        assert_eq!(
+            league.pick_announcement(&picks),
+            "Rockets picked **Charizard**\n<@2> picked **Blastoise** (auto-drafted from queue)\nThe **Creenis** draft is complete!"
+        );
+    }
+
+    #[test]
+    fn no_turn_ping_after_draft() {
+        let mut league = league();
+        league.skip().unwrap();
+        league.skip().unwrap();
+        assert!(league.turn_ping().is_none());
+        assert_eq!(
+            league.pick_announcement(&[]),
+            "The **Creenis** draft is complete!"
+        );
+    }
+}
@@ -0,0 +1,169 @@
+use crate::format::split_text;
+use crate::MESSAGE_LIMIT;
+use poise::serenity_prelude as serenity;
+
+const CUSTOM_ID_PREFIX: &str = "drftr:page";
+
+/// Splits long output (pool listings, [`League::events`](crate::League::events) logs, boards...) into numbered pages,
+/// with buttons to move between them.
+///
+/// Page buttons carry the page they lead to, so a bot does not need to keep Paginators around between presses:
+/// when one is pressed, [`Paginator::parse`] its custom_id, rebuild the output, and show the page it names.
+pub struct Paginator {
+    pages: Vec<String>,
+    // index into pages
+    current: usize,
+}
+
+impl Paginator {
+    /// Creates a Paginator over pages that have already been split, e.g. by [`League::board_messages`](crate::League::board_messages).
+    /// With no pages, there is a single empty one.
+    pub fn new(pages: Vec<String>) -> Paginator {
+        let pages = match pages.is_empty() {
+            true => vec![String::new()],
+            false => pages,
+        };
+        Paginator { pages, current: 0 }
+    }
+    /// Creates a Paginator over text, breaking it into pages that fit in a message the same way as [`split_message`](crate::split_message).
+    pub fn from_text(text: &str) -> Paginator {
+        Paginator::new(split_text(text, MESSAGE_LIMIT))
+    }
+    /// Creates a Paginator with up to per_page lines on each page, e.g. 20 items of a pool listing at a time.
+    /// Pages with too much text for a message are split further.
+    pub fn from_lines<I: IntoIterator<Item = String>>(lines: I, per_page: usize) -> Paginator {
+        let lines: Vec<String> = lines.into_iter().collect();
+        let pages = lines
+            .chunks(per_page.max(1))
+            .flat_map(|chunk| split_text(&chunk.join("\n"), MESSAGE_LIMIT))
+            .collect();
+        Paginator::new(pages)
+    }
+    /// Returns every page.
+    pub fn pages(&self) -> &[String] {
+        &self.pages
+    }
+    /// Returns the number of pages. Always at least 1.
+    pub fn page_count(&self) -> usize {
+        self.pages.len()
+    }
+    /// Returns the number of the page being shown, counting from 1.
+    pub fn page(&self) -> usize {
+        self.current + 1
+    }
+    /// Returns the text of the page being shown.
+    pub fn current(&self) -> &str {
+        &self.pages[self.current]
+    }
+    /// Moves to a page, counting from 1, and returns its text. Pages past either end show the first or last page.
+    pub fn set_page(&mut self, page: usize) -> &str {
+        self.current = page.clamp(1, self.pages.len()) - 1;
+        self.current()
+    }
+    /// Moves to the next page, if there is one, and returns the text of the page being shown.
+    pub fn next_page(&mut self) -> &str {
+        self.set_page(self.page() + 1)
+    }
+    /// Moves to the previous page, if there is one, and returns the text of the page being shown.
+    pub fn previous_page(&mut self) -> &str {
+        self.set_page(self.page() - 1)
+    }
+    /// Adds a row of buttons to a message for moving to the first, previous, next and last pages, with the page number between them.
+    /// Buttons that would go nowhere are disabled, and a single page gets no buttons at all.
+    ///
+    /// key tells the bot what is being paged through when a button is pressed, e.g. a League's ID; it comes back from [`Paginator::parse`].
+    pub fn components<'a>(
+        &self,
+        key: u64,
+        components: &'a mut serenity::CreateComponents,
+    ) -> &'a mut serenity::CreateComponents {
+        if self.pages.len() == 1 {
+            return components;
+        }
+        let page = self.page();
+        let last = self.pages.len();
+        let buttons = [
+            ("first", "⏮", 1, page == 1),
+            ("previous", "◀", page.saturating_sub(1).max(1), page == 1),
+            ("next", "▶", (page + 1).min(last), page == last),
+            ("last", "⏭", last, page == last),
+        ];
+        components.create_action_row(|row| {
+            for (action, label, target, disabled) in &buttons[..2] {
+                row.create_button(|b| {
+                    b.custom_id(format!("{CUSTOM_ID_PREFIX}:{key}:{action}:{target}"))
+                        .label(label)
+                        .style(serenity::ButtonStyle::Secondary)
+                        .disabled(*disabled)
+                });
+            }
+            // Discord wants a custom_id even on a button nobody can press
+            row.create_button(|b| {
+                b.custom_id(format!("{CUSTOM_ID_PREFIX}:{key}:current:{page}"))
+                    .label(format!("{page}/{last}"))
+                    .style(serenity::ButtonStyle::Secondary)
+                    .disabled(true)
+            });
+            for (action, label, target, disabled) in &buttons[2..] {
+                row.create_button(|b| {
+                    b.custom_id(format!("{CUSTOM_ID_PREFIX}:{key}:{action}:{target}"))
+                        .label(label)
+                        .style(serenity::ButtonStyle::Secondary)
+                        .disabled(*disabled)
+                });
+            }
+            row
+        })
+    }
+    /// Parses the custom_id of a button built by [`Paginator::components`] into the key it was built with and the page to show.
+    ///
+    /// Returns None if the button did not come from a Paginator, so your component handler can fall through to its own buttons.
+    pub fn parse(custom_id: &str) -> Option<(u64, usize)> {
+        let rest = custom_id
+            .strip_prefix(CUSTOM_ID_PREFIX)?
+            .strip_prefix(':')?;
+        let mut parts = rest.split(':');
+        let key = parts.next()?.parse().ok()?;
+        match parts.next()? {
+            "first" | "previous" | "current" | "next" | "last" => {}
+            _ => return None,
+        }
+        let page = parts.next()?.parse().ok()?;
+        match parts.next() {
+            None => Some((key, page)),
+            Some(_) => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod paginate_tests {
+    use super::*;
+
+    #[test]
+    fn pages_clamp_at_either_end() {
+        let mut pages = Paginator::from_lines((1..=45).map(|n| format!("Pokemon {n}")), 20);
+        assert_eq!(pages.page_count(), 3);
+        assert_eq!(pages.previous_page().lines().next(), Some("Pokemon 1"));
+        assert_eq!(pages.set_page(99).lines().next(), Some("Pokemon 41"));
+        assert_eq!(pages.page(), 3);
+        assert_eq!(pages.next_page().lines().count(), 5);
+        assert_eq!(Paginator::new(Vec::new()).page_count(), 1);
+    }
+
+    #[test]
+    fn buttons_name_their_page() {
+        let mut pages = Paginator::from_text(&"Pokemon\n".repeat(600));
+        assert!(pages.page_count() > 1);
+        pages.set_page(2);
+        let mut components = serenity::CreateComponents::default();
+        pages.components(69420, &mut components);
+        assert_eq!(components.0.len(), 1);
+        assert_eq!(
+            Paginator::parse("drftr:page:69420:next:3"),
+            Some((69420, 3))
+        );
+        assert_eq!(Paginator::parse("drftr:page:69420:sideways:3"), None);
+        assert_eq!(Paginator::parse("drftr:lobby:69420:join"), None);
+    }
+}
@@ -0,0 +1,317 @@
+use crate::{League, LeagueError};
+use poise::serenity_prelude as serenity;
+use std::collections::HashSet;
+
+/// Who is allowed to do what in a [`League`].
+#[derive(Clone)]
+pub(crate) struct Permissions {
+    pub(crate) commissioners: HashSet<serenity::UserId>,
+    pub(crate) observers: HashSet<serenity::UserId>,
+    pub(crate) observers_see_queues: bool,
+}
+
+impl Permissions {
+    pub(crate) fn new() -> Permissions {
+        Permissions {
+            commissioners: HashSet::new(),
+            observers: HashSet::new(),
+            observers_see_queues: false,
+        }
+    }
+}
+
+/// A user's standing in a [`League`], from most to least privileged.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Role {
+    /// Runs the League. Commissioners can do anything, and a League can have several.
+    Commissioner,
+    /// Can follow the whole League (scorekeepers, streamers) but cannot change anything, unless they also hold a seat,
+    /// which they can act for like a player.
+    Observer,
+    /// Drafts in the League, and can act for their own seat.
+    Player,
+    /// Has no part in the League.
+    Outsider,
+}
+
+/// Something a user wants to do in a [`League`], checked with [`League::authorize`].
+///
+/// Actions that target a player carry that player's ID, so the check can tell a player managing their own queue
+/// from one meddling with someone else's.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LeagueAction {
+    /// See the draft board, rosters and League settings.
+    ViewBoard,
+    /// See the history of picks, trades and waivers.
+    ViewHistory,
+    /// See a player's queue.
+    ViewQueue(serenity::UserId),
+    /// Add to, remove from or clear a player's queue.
+    EditQueue(serenity::UserId),
+    /// Lock in a pick for a player.
+    Pick(serenity::UserId),
+    /// Trade or waiver a player's picks.
+    Transact(serenity::UserId),
+    /// Anything else that changes the League: skipping, pausing, configuration, admin picks.
+    Manage,
+}
+
+impl League {
+    /// Makes a user a commissioner of the League. Returns false if they already were.
+    pub fn add_commissioner(&mut self, id: serenity::UserId) -> bool {
+        self.permissions.commissioners.insert(id)
+    }
+    /// Removes a user's commissioner rights. Returns false if they were not a commissioner.
+    pub fn remove_commissioner(&mut self, id: serenity::UserId) -> bool {
+        self.permissions.commissioners.remove(&id)
+    }
+    /// Lets a user observe the League read-only. Returns false if they already were an observer.
+    pub fn add_observer(&mut self, id: serenity::UserId) -> bool {
+        self.permissions.observers.insert(id)
+    }
+    /// Removes a user from the League's observers. Returns false if they were not an observer.
+    pub fn remove_observer(&mut self, id: serenity::UserId) -> bool {
+        self.permissions.observers.remove(&id)
+    }
+    /// Sets whether observers may see players' queues. Off by default, since queues give away a player's plans.
+    pub fn set_observers_see_queues(&mut self, allowed: bool) {
+        self.permissions.observers_see_queues = allowed;
+    }
+    /// Lets another user pick on a player's behalf while they are away (vacation mode), through overall pick number until_pick.
+    ///
+    /// Pick numbers count every pick in the draft, starting at 1. The proxy may only lock in picks for the seat -
+    /// [`League::authorize`] allows them [`LeagueAction::Pick`] for it, and nothing else. Setting a new proxy replaces the old one.
+    ///
+    /// # Errors
+    ///
+    /// If the player is not in the league, returns [`LeagueError::PlayerNotFoundError`].
+    ///
+    /// If until_pick has already been made, returns [`LeagueError::InvalidPickNumberError`].
+    pub fn set_proxy(
+        &mut self,
+        owner: serenity::UserId,
+        proxy: serenity::UserId,
+        until_pick: u32,
+    ) -> Result<(), LeagueError> {
+        if until_pick <= self.total_picks {
+            return Err(LeagueError::InvalidPickNumberError);
+        }
+        let Some(player) = self.get_player_mut(owner) else {
+            return Err(LeagueError::PlayerNotFoundError);
+        };
+        player.proxy = Some((proxy, until_pick));
+        Ok(())
+    }
+    /// Ends a player's proxy early, returning the proxy's ID if there was one.
+    ///
+    /// # Errors
+    ///
+    /// If the player is not in the league, returns [`LeagueError::PlayerNotFoundError`].
+    pub fn clear_proxy(
+        &mut self,
+        owner: serenity::UserId,
+    ) -> Result<Option<serenity::UserId>, LeagueError> {
+        let Some(player) = self.get_player_mut(owner) else {
+            return Err(LeagueError::PlayerNotFoundError);
+        };
+        Ok(player.proxy.take().map(|(proxy, _)| proxy))
+    }
+    /// Returns the user currently picking on a player's behalf, if their proxy has not run out.
+    ///
+    /// # Errors
+    ///
+    /// If the player is not in the league, returns [`LeagueError::PlayerNotFoundError`].
+    pub fn proxy(&self, owner: serenity::UserId) -> Result<Option<serenity::UserId>, LeagueError> {
+        let Some(player) = self.get_player(owner) else {
+            return Err(LeagueError::PlayerNotFoundError);
+        };
+        Ok(match player.proxy {
+            // total_picks is the zero-based index of the pick on the clock
+            Some((proxy, until_pick)) if self.total_picks < until_pick => Some(proxy),
+            _ => None,
+        })
+    }
+    /// Returns the most privileged [`Role`] a user holds in the League.
+    pub fn role(&self, id: serenity::UserId) -> Role {
+        if self.permissions.commissioners.contains(&id) {
+            Role::Commissioner
+        } else if self.permissions.observers.contains(&id) {
+            Role::Observer
+        } else if self.get_player(id).is_some() {
+            Role::Player
+        } else {
+            Role::Outsider
+        }
+    }
+    /// Checks whether a user may perform an action. Call this at the top of your command handlers, before calling the method that does the work.
+    ///
+    /// Commissioners may do anything. Players may view the League, see and edit their own queue, and pick and trade for themselves.
+    /// Observers may view the League, and see other players' queues only if [`League::set_observers_see_queues`] allows it; an
+    /// observer with a seat may also do what its players can. Outsiders may do nothing.
+    ///
+    /// A player's proxy (see [`League::set_proxy`]) may also pick for them, whatever their own role.
+    ///
+    /// Whether it is the player's turn to pick is not checked here; [`League::lock_as`] checks it.
+    ///
+    /// # Errors
+    ///
+    /// If the user is not allowed to perform the action, returns [`LeagueError::PermissionDeniedError`].
+    pub fn authorize(&self, id: serenity::UserId, action: LeagueAction) -> Result<(), LeagueError> {
+        if let LeagueAction::Pick(target) = action {
+            if matches!(self.proxy(target), Ok(Some(proxy)) if proxy == id) {
+                return Ok(());
+            }
+        }
+        let allowed = match self.role(id) {
+            Role::Commissioner => true,
+            Role::Player => match action {
+                LeagueAction::ViewBoard | LeagueAction::ViewHistory => true,
+                LeagueAction::ViewQueue(target)
+                | LeagueAction::EditQueue(target)
+                | LeagueAction::Pick(target)
+                | LeagueAction::Transact(target) => self.same_seat(id, target),
+                LeagueAction::Manage => false,
+            },
+            Role::Observer => match action {
+                LeagueAction::ViewBoard | LeagueAction::ViewHistory => true,
+                LeagueAction::ViewQueue(target) => {
+                    self.permissions.observers_see_queues || self.same_seat(id, target)
+                }
+                LeagueAction::EditQueue(target)
+                | LeagueAction::Pick(target)
+                | LeagueAction::Transact(target) => self.same_seat(id, target),
+                LeagueAction::Manage => false,
+            },
+            Role::Outsider => false,
+        };
+        if !allowed {
+            return Err(LeagueError::PermissionDeniedError);
+        }
+        Ok(())
+    }
+    // co-owners of a seat may act for each other
+    fn same_seat(&self, a: serenity::UserId, b: serenity::UserId) -> bool {
+        match (self.seat_of(a), self.seat_of(b)) {
+            (Ok(a), Ok(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod permissions_tests {
+    use super::*;
+    use crate::test_support::new_league;
+    use crate::DraftType;
+
+    fn league() -> League {
+        let mut league = new_league(2, DraftType::Snake, 2);
+        league.add_commissioner(serenity::UserId(10));
+        league.add_observer(serenity::UserId(20));
+        league
+    }
+
+    #[test]
+    fn observers_cannot_mutate() {
+        let league = league();
+        let observer = serenity::UserId(20);
+        assert_eq!(league.role(observer), Role::Observer);
+        assert!(league.authorize(observer, LeagueAction::ViewBoard).is_ok());
+        assert!(league
+            .authorize(observer, LeagueAction::ViewHistory)
+            .is_ok());
+        for action in [
+            LeagueAction::EditQueue(observer),
+            LeagueAction::Pick(observer),
+            LeagueAction::Transact(observer),
+            LeagueAction::Manage,
+        ] {
+            match league.authorize(observer, action) {
+                Err(LeagueError::PermissionDeniedError) => {}
+                _ => panic!("observers are read-only"),
+            }
+        }
+    }
+
+    #[test]
+    fn observer_queue_visibility_is_configurable() {
+        let mut league = league();
+        let peek = LeagueAction::ViewQueue(serenity::UserId(1));
+        assert!(league.authorize(serenity::UserId(20), peek).is_err());
+        league.set_observers_see_queues(true);
+        assert!(league.authorize(serenity::UserId(20), peek).is_ok());
+    }
+
+    #[test]
+    fn seated_observers_keep_their_seat() {
+        let mut league = league();
+        let player = serenity::UserId(1);
+        league.add_observer(player);
+        assert_eq!(league.role(player), Role::Observer);
+        assert!(league.authorize(player, LeagueAction::Pick(player)).is_ok());
+        assert!(league
+            .authorize(player, LeagueAction::ViewQueue(serenity::UserId(2)))
+            .is_err());
+        league.set_observers_see_queues(true);
+        assert!(league
+            .authorize(player, LeagueAction::ViewQueue(serenity::UserId(2)))
+            .is_ok());
+        assert!(league
+            .authorize(player, LeagueAction::EditQueue(serenity::UserId(2)))
+            .is_err());
+    }
+
+    #[test]
+    fn co_owners_act_for_their_seat() {
+        let mut league = league();
+        league
+            .add_co_owner(serenity::UserId(1), serenity::UserId(3))
+            .unwrap();
+        let co_owner = serenity::UserId(3);
+        assert_eq!(league.role(co_owner), Role::Player);
+        assert!(league
+            .authorize(co_owner, LeagueAction::Pick(serenity::UserId(1)))
+            .is_ok());
+        assert!(league
+            .authorize(co_owner, LeagueAction::Pick(serenity::UserId(2)))
+            .is_err());
+    }
+
+    #[test]
+    fn proxy_picks_until_their_last_pick() {
+        let mut league = league();
+        let proxy = serenity::UserId(20);
+        let pick_for_owner = LeagueAction::Pick(serenity::UserId(1));
+        league.set_proxy(serenity::UserId(1), proxy, 2).unwrap();
+        assert!(league.authorize(proxy, pick_for_owner).is_ok());
+        assert!(league
+            .authorize(proxy, LeagueAction::EditQueue(serenity::UserId(1)))
+            .is_err());
+        league.advance();
+        league.advance();
+        assert!(league.authorize(proxy, pick_for_owner).is_err());
+        match league.set_proxy(serenity::UserId(1), proxy, 1) {
+            Err(LeagueError::InvalidPickNumberError) => {}
+            _ => panic!("pick 1 is already over"),
+        }
+    }
+
+    #[test]
+    fn players_act_only_for_themselves() {
+        let league = league();
+        let player = serenity::UserId(1);
+        assert!(league
+            .authorize(player, LeagueAction::EditQueue(player))
+            .is_ok());
+        assert!(league
+            .authorize(player, LeagueAction::ViewQueue(serenity::UserId(2)))
+            .is_err());
+        assert!(league.authorize(player, LeagueAction::Manage).is_err());
+        assert!(league
+            .authorize(serenity::UserId(10), LeagueAction::Manage)
+            .is_ok());
+        assert!(league
+            .authorize(serenity::UserId(99), LeagueAction::ViewBoard)
+            .is_err());
+    }
+}
@@ -0,0 +1,183 @@
+use crate::{League, LeagueError};
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+
+/// The stage a [`League`] is at. Leagues start in Setup and move forward with [`League::set_phase`]; what can be done
+/// to a League (joining, picking, trading...) depends on its phase.
+///
+/// The phases between Setup and Drafting are optional - a League can go straight from Setup to Drafting,
+/// or through any of them in order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum LeaguePhase {
+    /// Players are joining and the League is being configured.
+    Setup,
+    /// The draft order is being decided.
+    OrderLottery,
+    /// Players are choosing which items to keep from a previous season.
+    Keepers,
+    /// Players are banning items from the pool.
+    Banning,
+    /// Players are picking.
+    Drafting,
+    /// The draft is frozen mid-way; no picks can be made and the [`PickClock`](crate::PickClock) is stopped.
+    Paused,
+    /// Every pick has been made, and the season is underway. Waivers and trades happen here.
+    PostDraft,
+    /// The League is finished and kept only for its history. Nothing about it can change.
+    Archived,
+}
+
+impl LeaguePhase {
+    /// Returns true for the phases before the draft starts, when players can still join and the pool can still change.
+    pub fn is_pre_draft(self) -> bool {
+        matches!(
+            self,
+            LeaguePhase::Setup
+                | LeaguePhase::OrderLottery
+                | LeaguePhase::Keepers
+                | LeaguePhase::Banning
+        )
+    }
+    /// Returns true while the draft is underway, paused or not.
+    pub fn is_drafting(self) -> bool {
+        matches!(self, LeaguePhase::Drafting | LeaguePhase::Paused)
+    }
+    /// Returns true if a League in this phase may move to the next one.
+    pub fn can_transition_to(self, next: LeaguePhase) -> bool {
+        use LeaguePhase::*;
+        matches!(
+            (self, next),
+            (Setup, OrderLottery | Keepers | Banning | Drafting)
+                | (OrderLottery, Keepers | Banning | Drafting)
+                | (Keepers, Banning | Drafting)
+                | (Banning, Drafting)
+                | (Drafting, Paused | PostDraft)
+                | (Paused, Drafting | PostDraft)
+                | (PostDraft, Archived)
+        )
+    }
+}
+
+impl League {
+    /// Returns the League's current [`LeaguePhase`].
+    pub fn phase(&self) -> LeaguePhase {
+        self.phase
+    }
+    /// Moves the League to another phase, starting, pausing, resuming or stopping the [`PickClock`](crate::PickClock) to match,
+    /// and returns the phase it left.
+    ///
+    /// # Errors
+    ///
+    /// If the League cannot go from its current phase to the next (see [`LeaguePhase::can_transition_to`]),
+    /// returns [`LeagueError::InvalidPhaseTransitionError`] with both phases.
+    ///
+    /// If the draft would start with every player gone from the lobby, returns [`LeagueError::NoPlayersError`].
+    pub fn set_phase(&mut self, next: LeaguePhase) -> Result<LeaguePhase, LeagueError> {
+        let previous = self.phase;
+        if !previous.can_transition_to(next) {
+            return Err(LeagueError::InvalidPhaseTransitionError(previous, next));
+        }
+        if previous.is_pre_draft() && next == LeaguePhase::Drafting && self.players.is_empty() {
+            return Err(LeagueError::NoPlayersError);
+        }
+        let now = Utc::now();
+        match (previous, next) {
+            (LeaguePhase::Paused, LeaguePhase::Drafting) => self.clock.resume(now),
+            (_, LeaguePhase::Drafting) => self.clock.start(now),
+            (_, LeaguePhase::Paused) => self.clock.pause(now),
+            _ => self.clock.stop(),
+        }
+        if previous.is_pre_draft() && next == LeaguePhase::Drafting {
+            // players are in their final seats now
+            self.order = self.uneven_order();
+            self.started_at = Some(now);
+        }
+        self.phase = next;
+        self.track_phase();
+        self.record(crate::DraftEvent::PhaseChanged(next));
+        if next == LeaguePhase::PostDraft {
+            self.discard_queues();
+            self.summarize();
+        }
+        Ok(previous)
+    }
+    // keeps the active leagues gauge in step with the phase; call after anything that moves the League in or out of the draft
+    pub(crate) fn track_phase(&mut self) {
+        self.active_gauge.update(self.phase.is_drafting());
+    }
+    // picks can only be made while drafting
+    pub(crate) fn require_drafting(&self) -> Result<(), LeagueError> {
+        match self.phase {
+            LeaguePhase::Drafting => Ok(()),
+            LeaguePhase::Paused => Err(LeagueError::LeaguePausedError),
+            _ => Err(LeagueError::LeagueInactiveError),
+        }
+    }
+    // players, pools and packs can only change before the draft
+    pub(crate) fn require_pre_draft(&self) -> Result<(), LeagueError> {
+        match self.phase {
+            phase if phase.is_pre_draft() => Ok(()),
+            phase if phase.is_drafting() => Err(LeagueError::LeagueActiveError),
+            _ => Err(LeagueError::WrongPhaseError),
+        }
+    }
+    // waivers and trades happen around the draft, never during it
+    pub(crate) fn require_transactions(&self) -> Result<(), LeagueError> {
+        match self.phase {
+            phase if phase.is_drafting() => Err(LeagueError::LeagueActiveError),
+            LeaguePhase::Archived => Err(LeagueError::WrongPhaseError),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod phase_tests {
+    use super::*;
+    use crate::test_support::new_league;
+    use crate::DraftType;
+    use poise::serenity_prelude as serenity;
+
+    fn league() -> League {
+        new_league(2, DraftType::Snake, 1)
+    }
+
+    #[test]
+    fn phases_only_move_forward() {
+        let mut league = league();
+        league.set_phase(LeaguePhase::Keepers).unwrap();
+        match league.set_phase(LeaguePhase::OrderLottery) {
+            Err(LeagueError::InvalidPhaseTransitionError(
+                LeaguePhase::Keepers,
+                LeaguePhase::OrderLottery,
+            )) => {}
+            _ => panic!("the lottery comes before keepers"),
+        }
+        league.set_phase(LeaguePhase::Drafting).unwrap();
+        league.set_phase(LeaguePhase::Paused).unwrap();
+        assert!(league.paused());
+        assert!(league.active());
+        league.set_phase(LeaguePhase::Drafting).unwrap();
+        assert!(league.clock().running());
+    }
+
+    #[test]
+    fn archived_leagues_are_frozen() {
+        let mut league = league();
+        league.activate().unwrap();
+        league.deactivate().unwrap();
+        match league.trade(serenity::UserId(1), "a", serenity::UserId(2), "b") {
+            Err(LeagueError::DraftableNotFoundError(_)) => {}
+            _ => panic!("trades are open after the draft"),
+        }
+        league.set_phase(LeaguePhase::Archived).unwrap();
+        match league.trade(serenity::UserId(1), "a", serenity::UserId(2), "b") {
+            Err(LeagueError::WrongPhaseError) => {}
+            _ => panic!("archived leagues cannot trade"),
+        }
+        match league.join_lobby(serenity::UserId(3)) {
+            Err(LeagueError::WrongPhaseError) => {}
+            _ => panic!("archived leagues cannot be joined"),
+        }
+    }
+}
@@ -0,0 +1,49 @@
+use crate::ItemName;
+use poise::serenity_prelude as serenity;
+use serde::{Deserialize, Serialize};
+
+/// One pick locked in by [`League::lock`](crate::League::lock), [`League::skip`](crate::League::skip),
+/// [`League::autopick`](crate::League::autopick) and the rest, in the order it was made.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PickResult {
+    /// The overall pick number, counting every pick in the draft from 1 (see [`League::pick_number`](crate::League::pick_number)).
+    pub pick: u32,
+    /// The round of the pick, from 1. Each round is one pick per seat.
+    pub round: u32,
+    /// The seat (starting at 0) that made the pick.
+    pub seat: u32,
+    /// The seat's original owner.
+    pub user: serenity::UserId,
+    /// The name of the item picked.
+    pub item: ItemName,
+    pub source: PickSource,
+}
+
+/// How a [`PickResult`] came to be locked in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum PickSource {
+    /// Picked by the seat on the clock.
+    Manual,
+    /// Taken from the front of the seat's queue when its turn came up.
+    Queue,
+    /// Chosen by the League's [`AutopickStrategy`](crate::AutopickStrategy), for a seat on autopick or by [`League::autopick`](crate::League::autopick).
+    Autopick,
+    /// Locked in by a commissioner on the seat's behalf with [`League::lock_as`](crate::League::lock_as).
+    Admin,
+}
+
+impl PickSource {
+    /// Returns true if nobody chose the pick when it was made.
+    pub fn is_automatic(self) -> bool {
+        matches!(self, PickSource::Queue | PickSource::Autopick)
+    }
+    // the i18n key of the note added to announcements of picks made this way
+    pub(crate) fn text_key(self) -> &'static str {
+        match self {
+            PickSource::Manual => "source.manual",
+            PickSource::Queue => "source.queue",
+            PickSource::Autopick => "source.autopick",
+            PickSource::Admin => "source.admin",
+        }
+    }
+}
@@ -0,0 +1,104 @@
+use crate::{Draftable, ItemName, League};
+use poise::serenity_prelude as serenity;
+use std::collections::HashMap;
+
+// k: an item's name, v: the seats it belongs to, by their original owner
+pub(crate) type NameIndex = HashMap<ItemName, Vec<serenity::UserId>>;
+
+// takes a seat off an item's entry, dropping the entry once no seat is left
+pub(crate) fn unindex(index: &mut NameIndex, name: &str, user: serenity::UserId) {
+    if let Some(users) = index.get_mut(name) {
+        if let Some(idx) = users.iter().position(|u| *u == user) {
+            users.swap_remove(idx);
+        }
+        if users.is_empty() {
+            index.remove(name);
+        }
+    }
+}
+
+impl League {
+    /// Returns true if any seat holds an item with the name. Picks are indexed by name, so this costs the same
+    /// however many picks have been made.
+    pub fn is_picked(&self, name: &str) -> bool {
+        self.picked.contains_key(name)
+    }
+    // the seats holding an item, by their original owner
+    pub(crate) fn holders(&self, name: &str) -> &[serenity::UserId] {
+        self.picked.get(name).map_or(&[], Vec::as_slice)
+    }
+    // true if the seat holds an item with the name
+    pub(crate) fn holds(&self, seat: u32, name: &str) -> bool {
+        self.holders(name).contains(&self.players[seat as usize].id)
+    }
+    // adds an item to a seat's picks, keeping the index in step
+    pub(crate) fn give_pick(&mut self, seat: u32, item: Draftable) {
+        let name = self.intern(item.name());
+        let player = &mut self.players[seat as usize];
+        self.picked.entry(name).or_default().push(player.id);
+        player.lock_in(item);
+    }
+    // takes an item out of a seat's picks, keeping the index in step
+    pub(crate) fn take_pick(&mut self, seat: u32, name: &str) -> Option<Draftable> {
+        let player = &mut self.players[seat as usize];
+        let item = player.delete_from_picks(name)?;
+        unindex(&mut self.picked, name, player.id);
+        Some(item)
+    }
+    // builds the pick and queue indexes again from every seat, after seats come and go
+    pub(crate) fn reindex(&mut self) {
+        self.picked.clear();
+        self.queued.clear();
+        let mut entries: Vec<(String, serenity::UserId, bool)> = Vec::new();
+        for player in &self.players {
+            let picks = player.picks.iter().map(|i| (i.name(), true));
+            let queue = player.queue.iter().map(|i| (i.name(), false));
+            for (name, picked) in picks.chain(queue) {
+                entries.push((name.to_string(), player.id, picked));
+            }
+        }
+        for (name, user, picked) in entries {
+            let name = self.intern(&name);
+            let index = match picked {
+                true => &mut self.picked,
+                false => &mut self.queued,
+            };
+            index.entry(name).or_default().push(user);
+        }
+    }
+}
+
+#[cfg(test)]
+mod picked_tests {
+    use super::*;
+    use crate::test_support::new_league;
+    use crate::{DraftType, LeagueError};
+
+    #[test]
+    fn the_index_follows_picks_around() {
+        let mut league = new_league(2, DraftType::Snake, 2);
+        league.activate().unwrap();
+        league.lock(Box::new("Pikachu")).unwrap();
+        league.lock(Box::new("Eevee")).unwrap();
+        assert!(league.is_picked("Pikachu"));
+        assert!(!league.is_picked("Mewtwo"));
+        match league.lock(Box::new("Pikachu")) {
+            Err(LeagueError::DraftableInUseError) => {}
+            _ => panic!("seat 0 holds Pikachu"),
+        }
+        league.rewind_to_pick(1).unwrap();
+        assert!(!league.is_picked("Eevee"), "the pick was reverted");
+        league.lock(Box::new("Eevee")).unwrap();
+        league.lock(Box::new("Mewtwo")).unwrap();
+        league.lock(Box::new("Ditto")).unwrap();
+        league
+            .trade(serenity::UserId(1), "Pikachu", serenity::UserId(2), "Eevee")
+            .unwrap();
+        assert_eq!(league.holders("Pikachu"), &[serenity::UserId(2)]);
+        assert!(league.holds(0, "Eevee"));
+
+        let restored =
+            League::restore(&league.snapshot(), |name| Some(Box::new(name.to_string()))).unwrap();
+        assert_eq!(restored.holders("Ditto"), &[serenity::UserId(1)]);
+    }
+}
@@ -0,0 +1,171 @@
+use crate::{DraftItem, Draftable, League, LeagueError};
+use std::sync::Arc;
+
+/// The DraftItems still available to be picked in a [`League`](crate::League), in ranking order.
+///
+/// Giving a League a pool is optional, but it lets DRFTR check that picks are actually available and pick on behalf of
+/// players who are not there to do it themselves (see [`League::set_autopick`](crate::League::set_autopick)).
+/// Picked items leave the pool, and items given up in waivers return to it.
+///
+/// Some drafts let the same item be picked by several teams: build the pool with [`DraftPool::with_copies`], and an item
+/// only leaves the pool once its last copy is picked. A team can still only hold one copy.
+pub struct DraftPool {
+    // each item with the number of copies left
+    items: Vec<(Arc<Draftable>, u32)>,
+}
+
+// a copy of an item handed out while the pool still holds others, or shared between seasons by League::rollover
+pub(crate) struct PoolCopy(pub(crate) Arc<Draftable>);
+
+impl DraftItem for PoolCopy {
+    fn name(&self) -> &str {
+        self.0.name()
+    }
+    fn category(&self) -> Option<&str> {
+        self.0.category()
+    }
+    fn cost(&self) -> Option<u32> {
+        self.0.cost()
+    }
+    fn tier(&self) -> Option<u32> {
+        self.0.tier()
+    }
+}
+
+impl DraftPool {
+    /// Creates a pool. Items should be ordered from best to worst, since "best available" means the first item left.
+    pub fn new(items: Vec<Draftable>) -> DraftPool {
+        DraftPool::with_copies(items.into_iter().map(|i| (i, 1)).collect())
+    }
+    /// Creates a pool where each item can be picked by up to the given number of teams. Items should be ordered from best to worst.
+    /// Items with no copies are left out.
+    pub fn with_copies(items: Vec<(Draftable, u32)>) -> DraftPool {
+        DraftPool {
+            items: items
+                .into_iter()
+                .filter(|(_, copies)| *copies > 0)
+                .map(|(item, copies)| (Arc::new(item), copies))
+                .collect(),
+        }
+    }
+    /// Returns the number of items left, not counting extra copies.
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+    /// Returns true if every item has been picked.
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+    /// Returns true if an item with the given name is still available.
+    pub fn contains(&self, name: &str) -> bool {
+        self.items.iter().any(|(i, _)| i.name() == name)
+    }
+    /// Returns the number of copies of an item left to pick, or 0 if it is not in the pool.
+    pub fn copies(&self, name: &str) -> u32 {
+        self.items
+            .iter()
+            .find(|(i, _)| i.name() == name)
+            .map_or(0, |(_, copies)| *copies)
+    }
+    /// Returns the available item with the given name, if there is one.
+    pub fn get(&self, name: &str) -> Option<&Draftable> {
+        self.iter().find(|i| i.name() == name)
+    }
+    /// Returns the highest ranked item left.
+    pub fn best_available(&self) -> Option<&Draftable> {
+        self.iter().next()
+    }
+    /// Iterates over the available items in ranking order.
+    pub fn iter(&self) -> impl Iterator<Item = &Draftable> {
+        self.items.iter().map(|(i, _)| i.as_ref())
+    }
+    /// Removes a copy of an item from the pool by name.
+    pub(crate) fn take(&mut self, name: &str) -> Option<Draftable> {
+        let idx = self.items.iter().position(|(i, _)| i.name() == name)?;
+        let (item, copies) = &mut self.items[idx];
+        if *copies > 1 {
+            *copies -= 1;
+            return Some(Box::new(PoolCopy(Arc::clone(item))));
+        }
+        let (item, _) = self.items.remove(idx);
+        Some(Arc::try_unwrap(item).unwrap_or_else(|shared| Box::new(PoolCopy(shared))))
+    }
+    /// Puts a copy of an item back in the pool. Items that had run out go to the bottom of the rankings.
+    pub(crate) fn restore(&mut self, item: Draftable) {
+        match self.items.iter_mut().find(|(i, _)| i.name() == item.name()) {
+            Some((_, copies)) => *copies += 1,
+            None => self.items.push((Arc::new(item), 1)),
+        }
+    }
+    // the pool's items with their copy counts, for events and snapshots
+    pub(crate) fn counts(&self) -> impl Iterator<Item = (&str, u32)> {
+        self.items.iter().map(|(i, copies)| (i.name(), *copies))
+    }
+}
+
+impl League {
+    // with a pool, an item can be picked while it has copies left that the seat does not already hold;
+    // without one, while nobody holds it
+    pub(crate) fn require_available(&self, seat: u32, name: &str) -> Result<(), LeagueError> {
+        if self.holds(seat, name) {
+            return Err(LeagueError::DraftableInUseError);
+        }
+        match &self.pool {
+            Some(pool) if pool.contains(name) => Ok(()),
+            _ if self.is_picked(name) => Err(LeagueError::DraftableInUseError),
+            Some(pool) => Err(crate::fuzzy::not_found(name, pool.iter())),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod pool_tests {
+    use super::*;
+    use crate::test_support::{new_league, pokemon};
+    use crate::DraftType;
+
+    fn league(players: u64, team_size: u32, pool: DraftPool) -> League {
+        let mut league = new_league(players, DraftType::Linear, team_size);
+        league.set_pool(pool).unwrap();
+        league.activate().unwrap();
+        league
+    }
+
+    #[test]
+    fn copies_run_out_one_team_at_a_time() {
+        let pool = DraftPool::with_copies(vec![(pokemon("Pikachu"), 2), (pokemon("Eevee"), 1)]);
+        let mut league = league(3, 1, pool);
+        league.lock(pokemon("Pikachu")).unwrap();
+        assert_eq!(league.pool().unwrap().copies("Pikachu"), 1);
+        league.lock(pokemon("Pikachu")).unwrap();
+        assert!(!league.pool().unwrap().contains("Pikachu"));
+        match league.lock(pokemon("Pikachu")) {
+            Err(LeagueError::DraftableInUseError) => {}
+            _ => panic!("both copies are gone"),
+        }
+        match league.lock(pokemon("Mew")) {
+            Err(LeagueError::DraftableNotFoundError(_)) => {}
+            _ => panic!("Mew was never in the pool"),
+        }
+    }
+
+    #[test]
+    fn teams_hold_one_copy() {
+        let pool = DraftPool::with_copies(vec![
+            (pokemon("Pikachu"), 2),
+            (pokemon("Eevee"), 1),
+            (pokemon("Snorlax"), 1),
+        ]);
+        let mut league = league(2, 2, pool);
+        league.lock(pokemon("Pikachu")).unwrap();
+        league.lock(pokemon("Eevee")).unwrap();
+        match league.lock(pokemon("Pikachu")) {
+            Err(LeagueError::DraftableInUseError) => {}
+            _ => panic!("seat 1 already has a Pikachu"),
+        }
+        league.lock(pokemon("Snorlax")).unwrap();
+        league.lock(pokemon("Pikachu")).unwrap();
+        assert!(league.pool().unwrap().is_empty());
+    }
+}
@@ -0,0 +1,181 @@
+use crate::{ArchivedLeague, DraftEvent, DraftGuild, League};
+use chrono::Duration;
+use poise::serenity_prelude as serenity;
+use std::collections::BTreeMap;
+
+/// A user's record across every League in a [`DraftGuild`], current and archived, as returned by [`DraftGuild::profile`]
+/// or [`SharedGuild::profile`](crate::SharedGuild::profile).
+///
+/// Profiles are read from the Leagues themselves each time, so they are always up to date with every pick and trade.
+/// Seats count towards their original owner, not their co-owners.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlayerProfile {
+    pub user: serenity::UserId,
+    /// How many Leagues the user has a seat in, archived ones included.
+    pub leagues: u32,
+    /// How many archived seasons the user finished top of the [`League::standings`] in. Seasons without results have no champion.
+    pub championships: u32,
+    pub picks: u32,
+    pub trades: u32,
+    /// How long the user's picks made on a running [`PickClock`](crate::PickClock) took on average, or None if none were timed.
+    pub average_pick_time: Option<Duration>,
+}
+
+impl PlayerProfile {
+    fn new(user: serenity::UserId) -> PlayerProfile {
+        PlayerProfile {
+            user,
+            leagues: 0,
+            championships: 0,
+            picks: 0,
+            trades: 0,
+            average_pick_time: None,
+        }
+    }
+}
+
+impl DraftGuild {
+    /// Returns a user's [`PlayerProfile`], e.g. for a /profile command. Users who never played have an empty one.
+    pub fn profile(&self, user: serenity::UserId) -> PlayerProfile {
+        self.tally(Some(user)).remove(0)
+    }
+    /// Returns the [`PlayerProfile`] of everyone who has had a seat in one of the guild's Leagues, ordered by user ID.
+    pub fn profiles(&self) -> Vec<PlayerProfile> {
+        self.tally(None)
+    }
+    // counts current leagues, then archived ones
+    fn tally(&self, only: Option<serenity::UserId>) -> Vec<PlayerProfile> {
+        let mut tally = ProfileTally::new(only);
+        for league in self
+            .leagues()
+            .chain(self.archives().iter().map(|a| a.league()))
+        {
+            tally.count(league);
+        }
+        tally.finish(self.archives())
+    }
+}
+
+// profiles being read from a guild's Leagues one at a time, each with the times of the user's timed picks
+pub(crate) struct ProfileTally {
+    only: Option<serenity::UserId>,
+    profiles: BTreeMap<serenity::UserId, (PlayerProfile, Vec<Duration>)>,
+}
+
+impl ProfileTally {
+    // a tally of everyone with a seat, or of only one user, who has a profile even if they never played
+    pub(crate) fn new(only: Option<serenity::UserId>) -> ProfileTally {
+        let mut profiles = BTreeMap::new();
+        if let Some(user) = only {
+            profiles.insert(user, (PlayerProfile::new(user), Vec::new()));
+        }
+        ProfileTally { only, profiles }
+    }
+    // counts a League, current or archived, towards its players' profiles
+    pub(crate) fn count(&mut self, league: &League) {
+        let (made, _) = league.pick_history();
+        for user in league.players.iter().map(|p| p.id) {
+            if self.only.is_some_and(|only| only != user) {
+                continue;
+            }
+            let (profile, timed) = self
+                .profiles
+                .entry(user)
+                .or_insert_with(|| (PlayerProfile::new(user), Vec::new()));
+            profile.leagues += 1;
+            profile.picks += made.iter().flatten().filter(|(u, _)| *u == user).count() as u32;
+            profile.trades += league
+                .events
+                .iter()
+                .filter(|e| matches!(e, DraftEvent::Traded { user1, user2, .. } if *user1 == user || *user2 == user))
+                .count() as u32;
+            timed.extend(
+                league
+                    .pick_times
+                    .iter()
+                    .filter(|(pick, _)| {
+                        made.get(*pick as usize - 1)
+                            .is_some_and(|m| m.is_some_and(|(u, _)| u == user))
+                    })
+                    .map(|(_, time)| *time),
+            );
+        }
+    }
+    // adds the championships won in the archives, once every League has been counted
+    pub(crate) fn finish(mut self, archives: &[ArchivedLeague]) -> Vec<PlayerProfile> {
+        for archived in archives {
+            let league = archived.league();
+            if league.results.is_empty() {
+                continue;
+            }
+            if let Some(champion) = league.standings().first() {
+                if let Some((profile, _)) = self.profiles.get_mut(&champion.user) {
+                    profile.championships += 1;
+                }
+            }
+        }
+        self.profiles
+            .into_values()
+            .map(|(mut profile, timed)| {
+                if !timed.is_empty() {
+                    let total: Duration = timed.iter().copied().sum();
+                    profile.average_pick_time = Some(total / timed.len() as i32);
+                }
+                profile
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod profile_tests {
+    use super::*;
+    use crate::test_support::new_league;
+    use crate::DraftType;
+    use chrono::Utc;
+
+    fn league(name: &str) -> League {
+        let mut league = new_league(2, DraftType::Snake, 1);
+        league.name = name.to_string();
+        league.activate().unwrap();
+        league.lock(Box::new(format!("{name} 1"))).unwrap();
+        league.lock(Box::new(format!("{name} 2"))).unwrap();
+        league
+    }
+
+    #[test]
+    fn profiles_span_seasons() {
+        let mut guild = DraftGuild::new(1, serenity::ChannelId(2));
+        let mut finished = league("Creenis");
+        finished
+            .trade(
+                serenity::UserId(1),
+                "Creenis 1",
+                serenity::UserId(2),
+                "Creenis 2",
+            )
+            .unwrap();
+        finished
+            .record_result(serenity::UserId(1), 10.0, serenity::UserId(2), 20.0)
+            .unwrap();
+        guild.add_league(finished).unwrap();
+        guild.archive_league("Creenis", Utc::now()).unwrap();
+        guild.add_league(league("Creenis II")).unwrap();
+
+        let profile = guild.profile(serenity::UserId(2));
+        assert_eq!(profile.leagues, 2);
+        assert_eq!(profile.championships, 1);
+        assert_eq!(profile.picks, 2);
+        assert_eq!(profile.trades, 1);
+        assert!(profile.average_pick_time.is_some());
+        assert_eq!(guild.profile(serenity::UserId(1)).championships, 0);
+        assert_eq!(guild.profile(serenity::UserId(3)).leagues, 0);
+        let profiles = guild.profiles();
+        assert_eq!(profiles.len(), 2);
+
+        let shared = crate::SharedGuild::from_guild(guild);
+        assert_eq!(shared.profiles(), profiles);
+        assert_eq!(shared.profile(serenity::UserId(2)), profiles[1]);
+        assert_eq!(shared.profile(serenity::UserId(3)).leagues, 0);
+    }
+}
@@ -0,0 +1,192 @@
+use crate::picked::unindex;
+use crate::{DraftEvent, Draftable, League, LeagueError, LeaguePhase, RateLimitedAction};
+use chrono::Utc;
+use poise::serenity_prelude as serenity;
+
+impl League {
+    /// Sets whether players can change their queue while another seat is on the clock. Allowed by default.
+    ///
+    /// Disallowing it means queues only change between drafts or on the player's own turn, so nobody can rearrange
+    /// their queue while the picks before theirs are cascading.
+    pub fn set_queue_edits_off_turn(&mut self, allowed: bool) {
+        self.queues_locked_off_turn = !allowed;
+    }
+    /// Returns whether players can change their queue while another seat is on the clock.
+    pub fn queue_edits_off_turn(&self) -> bool {
+        !self.queues_locked_off_turn
+    }
+    // queues only mean something until the draft is over, and may be frozen outside the seat's turn
+    pub(crate) fn require_queue_edit(&self, id: serenity::UserId) -> Result<(), LeagueError> {
+        let seat = self.seat_of(id)?;
+        self.require_rate(id, RateLimitedAction::QueueEdit, Utc::now())?;
+        match self.phase {
+            LeaguePhase::PostDraft | LeaguePhase::Archived => Err(LeagueError::WrongPhaseError),
+            phase
+                if phase.is_drafting()
+                    && self.queues_locked_off_turn
+                    && seat != self.current_seat =>
+            {
+                Err(LeagueError::NotYourTurnError)
+            }
+            _ => Ok(()),
+        }
+    }
+    // empties every queue once the draft is over, recording what each seat lost
+    pub(crate) fn discard_queues(&mut self) {
+        for seat in 0..self.players.len() {
+            let player = &mut self.players[seat];
+            if player.queue.is_empty() {
+                continue;
+            }
+            let user = player.id;
+            let items = player.queue.drain(..).map(|i| i.name().to_string());
+            let items = items.collect();
+            self.record(DraftEvent::QueueDiscarded { user, items });
+        }
+        self.queued.clear();
+    }
+    // adds an item to the end of a seat's queue, keeping the index in step
+    pub(crate) fn enqueue(&mut self, seat: u32, item: Draftable) {
+        let name = self.intern(item.name());
+        let player = &mut self.players[seat as usize];
+        self.queued.entry(name).or_default().push(player.id);
+        player.add_to_queue(item);
+    }
+    // takes an item out of a seat's queue by name, keeping the index in step
+    pub(crate) fn dequeue(&mut self, seat: u32, name: &str) -> Option<Draftable> {
+        let player = &mut self.players[seat as usize];
+        let item = player.delete_from_queue(name)?;
+        unindex(&mut self.queued, name, player.id);
+        Some(item)
+    }
+    // takes the item at a position in a seat's queue, keeping the index in step
+    pub(crate) fn dequeue_at(&mut self, seat: u32, idx: usize) -> Option<Draftable> {
+        let player = &mut self.players[seat as usize];
+        let item = player.queue.remove(idx)?;
+        unindex(&mut self.queued, item.name(), player.id);
+        Some(item)
+    }
+    // empties a seat's queue, keeping the index in step
+    pub(crate) fn drain_queue(&mut self, seat: u32) -> Vec<Draftable> {
+        let player = &mut self.players[seat as usize];
+        let drained: Vec<Draftable> = player.queue.drain(..).collect();
+        for item in &drained {
+            unindex(&mut self.queued, item.name(), player.id);
+        }
+        drained
+    }
+    // takes a picked item out of every queue it is in, visiting only the seats that queued it
+    pub(crate) fn unqueue_everywhere(&mut self, name: &str) {
+        let Some(users) = self.queued.remove(name) else {
+            return;
+        };
+        for user in users {
+            if let Some(player) = self.players.iter_mut().find(|p| p.id == user) {
+                player.delete_from_queue(name);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod queues_tests {
+    use super::*;
+    use crate::test_support::new_league;
+    use crate::DraftType;
+
+    fn league() -> League {
+        new_league(2, DraftType::Snake, 1)
+    }
+
+    #[test]
+    fn queues_can_freeze_off_turn() {
+        let mut league = league();
+        league.set_queue_edits_off_turn(false);
+        league
+            .add_to_player_queue(serenity::UserId(2), Box::new("Mew"))
+            .unwrap();
+        league.activate().unwrap();
+        match league.add_to_player_queue(serenity::UserId(2), Box::new("Eevee")) {
+            Err(LeagueError::NotYourTurnError) => {}
+            _ => panic!("seat 1 is on the clock"),
+        }
+        match league.clear_player_queue(serenity::UserId(2)) {
+            Err(LeagueError::NotYourTurnError) => {}
+            _ => panic!("seat 1 is on the clock"),
+        }
+        league
+            .add_to_player_queue(serenity::UserId(1), Box::new("Eevee"))
+            .unwrap();
+    }
+
+    #[test]
+    fn leftover_queues_are_discarded() {
+        let mut league = league();
+        league
+            .add_to_player_queue(serenity::UserId(1), Box::new("Mew"))
+            .unwrap();
+        league
+            .add_to_player_queue(serenity::UserId(2), Box::new("Eevee"))
+            .unwrap();
+        league
+            .add_to_player_queue(serenity::UserId(2), Box::new("Snorlax"))
+            .unwrap();
+        league.activate().unwrap();
+        // seat 2 picks Eevee from its queue, and the draft ends
+        league.lock(Box::new("Pikachu")).unwrap();
+        assert_eq!(league.phase(), LeaguePhase::PostDraft);
+        let events = league.events();
+        assert_eq!(
+            events[events.len() - 2..],
+            [
+                DraftEvent::QueueDiscarded {
+                    user: serenity::UserId(1),
+                    items: vec!["Mew".to_string()],
+                },
+                DraftEvent::QueueDiscarded {
+                    user: serenity::UserId(2),
+                    items: vec!["Snorlax".to_string()],
+                },
+            ]
+        );
+        assert!(league
+            .get_player(serenity::UserId(2))
+            .unwrap()
+            .queue
+            .is_empty());
+        match league.add_to_player_queue(serenity::UserId(1), Box::new("Mew")) {
+            Err(LeagueError::WrongPhaseError) => {}
+            _ => panic!("the draft is over"),
+        }
+
+        let mut replayed = self::league();
+        replayed
+            .replay(league.events(), |name| Some(Box::new(name.to_string())))
+            .unwrap();
+        assert_eq!(replayed.events(), league.events());
+    }
+
+    #[test]
+    fn picks_leave_only_the_queues_holding_them() {
+        let mut league = new_league(3, DraftType::Snake, 2);
+        for user in [2, 3] {
+            league
+                .add_to_player_queue(serenity::UserId(user), Box::new("Mew"))
+                .unwrap();
+        }
+        league
+            .add_to_player_queue(serenity::UserId(3), Box::new("Eevee"))
+            .unwrap();
+        assert_eq!(league.queued["Mew"].len(), 2);
+        league.activate().unwrap();
+        league.lock(Box::new("Mew")).unwrap();
+        assert!(!league.queued.contains_key("Mew"));
+        assert!(league
+            .get_player(serenity::UserId(2))
+            .unwrap()
+            .queue
+            .is_empty());
+        league.clear_player_queue(serenity::UserId(3)).unwrap();
+        assert!(league.queued.is_empty());
+    }
+}
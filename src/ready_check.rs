@@ -0,0 +1,216 @@
+use crate::{League, LeagueError};
+use chrono::{DateTime, Utc};
+use poise::serenity_prelude as serenity;
+use serde::{Deserialize, Serialize};
+
+/// What happens to players who have not readied up when the draft starts without them, from a quorum
+/// (see [`League::set_ready_quorum`]) or a timeout (see [`League::set_ready_timeout`]).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum NoShowPolicy {
+    /// They keep their seats and draft like everyone else.
+    #[default]
+    Keep,
+    /// They keep their seats, on autopick (see [`League::set_autopick`]) until they turn it off.
+    Autopick,
+    /// They are removed from the League, and the draft starts without them.
+    Remove,
+}
+
+/// Who has readied up in the lobby, as returned by [`League::ready_status`].
+pub struct ReadyStatus {
+    ready: Vec<serenity::UserId>,
+    waiting: Vec<serenity::UserId>,
+    needed: usize,
+    timeout: Option<DateTime<Utc>>,
+}
+
+impl ReadyStatus {
+    /// The players who have readied up, in seat order.
+    pub fn ready(&self) -> &[serenity::UserId] {
+        &self.ready
+    }
+    /// The players who have not readied up yet, in seat order.
+    pub fn waiting(&self) -> &[serenity::UserId] {
+        &self.waiting
+    }
+    /// How many players have to be ready for the draft to start.
+    pub fn needed(&self) -> usize {
+        self.needed
+    }
+    /// When the ready check times out, if it does.
+    pub fn timeout(&self) -> Option<DateTime<Utc>> {
+        self.timeout
+    }
+}
+
+// the lobby's rules for starting before everyone is ready
+#[derive(Clone, Default, Serialize, Deserialize)]
+pub(crate) struct ReadyCheck {
+    pub(crate) quorum: Option<u32>,
+    pub(crate) timeout: Option<DateTime<Utc>>,
+    pub(crate) no_shows: NoShowPolicy,
+}
+
+impl League {
+    /// Starts the draft once the given number of players have readied up, instead of waiting for everyone.
+    /// None (the default) waits for every player. Players who are not ready by then are handled by the
+    /// League's [`NoShowPolicy`] (see [`League::set_ready_timeout`]).
+    ///
+    /// # Errors
+    ///
+    /// If the draft has already started, returns [`LeagueError::LeagueActiveError`], or [`LeagueError::WrongPhaseError`] if it is over.
+    pub fn set_ready_quorum(&mut self, quorum: Option<u32>) -> Result<(), LeagueError> {
+        self.require_pre_draft()?;
+        self.lobby.check.quorum = quorum;
+        Ok(())
+    }
+    /// Ends the ready check at the given time, or never with None, and sets what happens to players who are not ready
+    /// when the draft starts without them. See [`League::start_if_timed_out`].
+    ///
+    /// # Errors
+    ///
+    /// If the draft has already started, returns [`LeagueError::LeagueActiveError`], or [`LeagueError::WrongPhaseError`] if it is over.
+    pub fn set_ready_timeout(
+        &mut self,
+        timeout: Option<DateTime<Utc>>,
+        no_shows: NoShowPolicy,
+    ) -> Result<(), LeagueError> {
+        self.require_pre_draft()?;
+        self.lobby.check.timeout = timeout;
+        self.lobby.check.no_shows = no_shows;
+        Ok(())
+    }
+    /// Returns who has readied up and who the ready check is still waiting for, e.g. to ping the stragglers.
+    pub fn ready_status(&self) -> ReadyStatus {
+        let (ready, waiting) = self
+            .lobby()
+            .into_iter()
+            .map(|entry| (entry.id(), entry.ready()))
+            .partition::<Vec<_>, _>(|(_, ready)| *ready);
+        ReadyStatus {
+            ready: ready.into_iter().map(|(id, _)| id).collect(),
+            waiting: waiting.into_iter().map(|(id, _)| id).collect(),
+            needed: self.ready_needed(),
+            timeout: self.lobby.check.timeout,
+        }
+    }
+    /// Starts the draft if the ready check has timed out and at least one player is ready, handling everyone else by the
+    /// League's [`NoShowPolicy`]. Call this from a background task, alongside [`League::start_if_due`].
+    ///
+    /// # Returns
+    ///
+    /// Returns true if this call started the draft, so the bot knows to announce the first pick.
+    pub fn start_if_timed_out(&mut self, now: DateTime<Utc>) -> bool {
+        match self.lobby.check.timeout {
+            Some(timeout) if timeout <= now && self.phase.is_pre_draft() => {
+                if self.lobby.ready.is_empty() || self.start_ready_check().is_err() {
+                    // the timeout stays, so the next call tries again
+                    return false;
+                }
+                self.lobby.check.timeout = None;
+                true
+            }
+            _ => false,
+        }
+    }
+    // the number of ready players that starts the draft
+    pub(crate) fn ready_needed(&self) -> usize {
+        let players = self.players.len();
+        match self.lobby.check.quorum {
+            Some(quorum) => (quorum as usize).min(players),
+            None => players,
+        }
+    }
+    // seats everyone, deals with the players who are not ready, and starts the draft
+    pub(crate) fn start_ready_check(&mut self) -> Result<(), LeagueError> {
+        let waiting = self.ready_status().waiting;
+        match self.lobby.check.no_shows {
+            NoShowPolicy::Keep => {}
+            NoShowPolicy::Autopick => {
+                for id in &waiting {
+                    self.set_autopick(*id, true)?;
+                }
+            }
+            NoShowPolicy::Remove => {
+                self.players.retain(|p| !waiting.contains(&p.id));
+                for id in &waiting {
+                    self.lobby.remove(*id);
+                }
+                self.recalculate_final_pick();
+                self.reindex();
+            }
+        }
+        self.seat_players();
+        self.start().map(|_| ())
+    }
+}
+
+#[cfg(test)]
+mod ready_check_tests {
+    use super::*;
+    use crate::test_support::new_league;
+    use crate::DraftType;
+    use chrono::TimeZone;
+
+    fn at(secs: i64) -> DateTime<Utc> {
+        Utc.timestamp_opt(1_700_000_000 + secs, 0).unwrap()
+    }
+
+    fn league() -> League {
+        new_league(3, DraftType::Snake, 2)
+    }
+
+    #[test]
+    fn a_quorum_starts_the_draft() {
+        let mut league = league();
+        league.set_ready_quorum(Some(2)).unwrap();
+        league
+            .set_ready_timeout(None, NoShowPolicy::Autopick)
+            .unwrap();
+        assert!(!league.set_ready(serenity::UserId(3), true).unwrap());
+        let status = league.ready_status();
+        assert_eq!(status.ready(), &[serenity::UserId(3)]);
+        assert_eq!(status.waiting().len(), 2);
+        assert_eq!(status.needed(), 2);
+        assert!(league.set_ready(serenity::UserId(1), true).unwrap());
+        assert!(league.active());
+        assert!(league.get_player(serenity::UserId(2)).unwrap().autopick);
+        assert!(!league.get_player(serenity::UserId(1)).unwrap().autopick);
+    }
+
+    #[test]
+    fn no_shows_are_removed_at_the_timeout() {
+        let mut league = league();
+        league
+            .set_ready_timeout(Some(at(60)), NoShowPolicy::Remove)
+            .unwrap();
+        assert_eq!(league.next_deadline(at(0)), Some(at(60)));
+        assert!(!league.start_if_timed_out(at(60)), "nobody is ready");
+        league.set_ready(serenity::UserId(2), true).unwrap();
+        league.set_ready(serenity::UserId(3), true).unwrap();
+        assert!(!league.start_if_timed_out(at(59)));
+        assert!(league.start_if_timed_out(at(60)));
+        assert!(league.active());
+        assert!(league.get_player(serenity::UserId(1)).is_none());
+        assert_eq!(league.final_pick, 3);
+        match league.set_ready_quorum(Some(1)) {
+            Err(LeagueError::LeagueActiveError) => {}
+            _ => panic!("the draft has started"),
+        }
+    }
+
+    #[test]
+    fn failed_starts_are_tried_again() {
+        let mut league = league();
+        league
+            .set_ready_timeout(Some(at(60)), NoShowPolicy::Remove)
+            .unwrap();
+        // a ready user with no seat: removing the no-shows leaves nobody to draft
+        league.lobby.ready.insert(serenity::UserId(9));
+        assert!(!league.start_if_timed_out(at(60)));
+        assert_eq!(league.ready_status().timeout(), Some(at(60)));
+        league.join_lobby(serenity::UserId(9)).unwrap();
+        assert!(league.start_if_timed_out(at(61)));
+        assert_eq!(league.ready_status().timeout(), None);
+    }
+}
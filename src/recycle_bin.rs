@@ -0,0 +1,127 @@
+use crate::{DraftGuild, DraftGuildError, League};
+use chrono::{DateTime, Utc};
+
+/// A [`League`] deleted with [`DraftGuild::delete_league`], kept in the guild's recycle bin with the date it was deleted
+/// until [`DraftGuild::restore_league`] brings it back or [`DraftGuild::purge_deleted`] drops it for good.
+pub struct DeletedLeague {
+    league: League,
+    deleted: DateTime<Utc>,
+}
+
+impl DeletedLeague {
+    pub(crate) fn new(league: League, deleted: DateTime<Utc>) -> DeletedLeague {
+        DeletedLeague { league, deleted }
+    }
+    /// Returns the League as it was when it was deleted.
+    pub fn league(&self) -> &League {
+        &self.league
+    }
+    /// Returns the League's name.
+    pub fn name(&self) -> &str {
+        &self.league.name
+    }
+    /// Returns when the League was deleted.
+    pub fn deleted(&self) -> DateTime<Utc> {
+        self.deleted
+    }
+    /// Takes the League out of the recycle bin.
+    pub fn into_league(self) -> League {
+        self.league
+    }
+}
+
+impl DraftGuild {
+    /// Returns every League in the recycle bin, oldest deletion first. Names repeat when a League was deleted, replaced and deleted again.
+    pub fn deleted_leagues(&self) -> &[DeletedLeague] {
+        &self.recycle_bin
+    }
+    /// Takes the most recently deleted League with the name out of the recycle bin and puts it back in the guild,
+    /// e.g. for a /restore-draft command after a commissioner deletes the wrong draft, and returns it.
+    ///
+    /// # Errors
+    ///
+    /// If there is no League with the name in the recycle bin, returns [`DraftGuildError::LeagueNotFoundError`].
+    ///
+    /// If another League has taken the name since, returns [`DraftGuildError::LeagueNameAlreadyInUseError`]. Rename it to restore this one.
+    pub fn restore_league(&mut self, name: &str) -> Result<&mut League, DraftGuildError> {
+        let idx = self.recycle_bin.iter().rposition(|d| d.name() == name);
+        self.restore_at(idx)
+    }
+    /// Takes the League with the ID out of the recycle bin and puts it back in the guild, and returns it.
+    ///
+    /// # Errors
+    ///
+    /// If there is no League with the ID in the recycle bin, returns [`DraftGuildError::LeagueNotFoundError`].
+    ///
+    /// If another League has taken its name since, returns [`DraftGuildError::LeagueNameAlreadyInUseError`].
+    pub fn restore_league_by_id(&mut self, id: u64) -> Result<&mut League, DraftGuildError> {
+        let idx = self.recycle_bin.iter().rposition(|d| d.league.id == id);
+        self.restore_at(idx)
+    }
+    /// Drops every League deleted at least the server's [`GuildSettings::deleted_ttl`](crate::GuildSettings::deleted_ttl)
+    /// before now from the recycle bin for good, and returns them, oldest deletion first. Call this from a background task,
+    /// e.g. alongside [`DraftGuild::purge_stale`].
+    pub fn purge_deleted(&mut self, now: DateTime<Utc>) -> Vec<League> {
+        let ttl = self.settings.deleted_ttl();
+        let (expired, kept) = std::mem::take(&mut self.recycle_bin)
+            .into_iter()
+            .partition(|d| now - d.deleted >= ttl);
+        self.recycle_bin = kept;
+        expired
+            .into_iter()
+            .map(DeletedLeague::into_league)
+            .collect()
+    }
+    fn restore_at(&mut self, idx: Option<usize>) -> Result<&mut League, DraftGuildError> {
+        let idx = idx.ok_or(DraftGuildError::LeagueNotFoundError)?;
+        let name = self.recycle_bin[idx].name().to_string();
+        if self.leagues.contains_key(&name) {
+            return Err(DraftGuildError::LeagueNameAlreadyInUseError);
+        }
+        let league = self.recycle_bin.remove(idx).into_league();
+        Ok(self.leagues.entry(name).or_insert(league))
+    }
+}
+
+#[cfg(test)]
+mod recycle_bin_tests {
+    use super::*;
+    use crate::test_support::new_league;
+    use crate::DraftType;
+    use chrono::Duration;
+    use poise::serenity_prelude as serenity;
+
+    fn league(id: u64) -> League {
+        let mut league = new_league(2, DraftType::Snake, 2);
+        league.id = id;
+        league
+    }
+
+    #[test]
+    fn deleted_leagues_can_be_restored() {
+        let mut guild = DraftGuild::new(1, serenity::ChannelId(2));
+        let now = Utc::now();
+        guild.add_league(league(1)).unwrap();
+        guild.delete_league("Creenis".to_string(), now).unwrap();
+        assert!(!guild.contains("Creenis"));
+        guild.add_league(league(2)).unwrap();
+        guild.delete_league_by_id(2, now).unwrap();
+        assert_eq!(guild.deleted_leagues().len(), 2);
+
+        assert_eq!(guild.restore_league("Creenis").unwrap().id, 2);
+        match guild.restore_league_by_id(1) {
+            Err(DraftGuildError::LeagueNameAlreadyInUseError) => {}
+            _ => panic!("the other Creenis is back"),
+        }
+        match guild.restore_league("Ash") {
+            Err(DraftGuildError::LeagueNotFoundError) => {}
+            _ => panic!("Ash was never deleted"),
+        }
+
+        guild.settings_mut().set_deleted_ttl(Duration::days(7));
+        assert!(guild.purge_deleted(now + Duration::days(6)).is_empty());
+        let purged = guild.purge_deleted(now + Duration::days(7));
+        assert_eq!(purged[0].id, 1);
+        assert!(guild.deleted_leagues().is_empty());
+    }
+}
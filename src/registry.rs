@@ -0,0 +1,228 @@
+use crate::{DraftGuildError, League, SharedGuild};
+use chrono::{DateTime, Utc};
+use poise::serenity_prelude as serenity;
+use std::collections::HashMap;
+use std::sync::{Arc, PoisonError, RwLock};
+
+/// A thread-safe map of every guild your bot is running, keyed by guild id, ready to put in your poise `Data`.
+///
+/// Each guild is a [`SharedGuild`], and each of its Leagues has its own lock, so commands in different Leagues never wait on each other.
+/// Locks are std mutexes: do not hold a League across an `.await` - take what you need, drop the guard, then await.
+/// If a command panics while holding a League, the League stays usable as the panic left it.
+pub struct DraftRegistry {
+    // k: guild id
+    guilds: RwLock<HashMap<u64, Arc<SharedGuild>>>,
+    // k: Discord guild, v: guild id; only written while holding the guilds lock
+    discord: RwLock<HashMap<serenity::GuildId, u64>>,
+}
+
+impl DraftRegistry {
+    /// Creates an empty DraftRegistry.
+    pub fn new() -> DraftRegistry {
+        DraftRegistry {
+            guilds: RwLock::new(HashMap::new()),
+            discord: RwLock::new(HashMap::new()),
+        }
+    }
+    /// Returns the guild with the given id, creating it with default_output if this is its first use.
+    pub fn get_or_create_guild(
+        &self,
+        id: u64,
+        default_output: serenity::ChannelId,
+    ) -> Arc<SharedGuild> {
+        if let Some(guild) = self.guild(id) {
+            return guild;
+        }
+        let mut guilds = self.guilds.write().unwrap_or_else(PoisonError::into_inner);
+        let guild = guilds
+            .entry(id)
+            .or_insert_with(|| Arc::new(SharedGuild::new(id, default_output)));
+        self.index(guild);
+        guild.clone()
+    }
+    /// Adds a guild built elsewhere, e.g. a [`DraftGuild`](crate::DraftGuild) with a custom id restored at startup,
+    /// and returns it. Replaces any guild with the same id.
+    pub fn insert_guild(&self, guild: SharedGuild) -> Arc<SharedGuild> {
+        let mut guilds = self.guilds.write().unwrap_or_else(PoisonError::into_inner);
+        let guild = Arc::new(guild);
+        if let Some(old) = guilds.insert(guild.id(), Arc::clone(&guild)) {
+            self.unindex(&old);
+        }
+        self.index(&guild);
+        guild
+    }
+    /// Returns the guild belonging to a Discord server, whether its id is the server's or a custom one
+    /// (see [`DraftGuild::set_discord_guild`](crate::DraftGuild::set_discord_guild)).
+    pub fn guild_for(&self, discord_guild: serenity::GuildId) -> Option<Arc<SharedGuild>> {
+        let id = *self
+            .discord
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(&discord_guild)?;
+        self.guild(id)
+    }
+    /// Returns the id of every guild in the registry with the Discord server it belongs to, ordered by id,
+    /// e.g. to save the mapping of custom ids alongside your guilds.
+    pub fn guild_ids(&self) -> Vec<(u64, serenity::GuildId)> {
+        let guilds = self.guilds.read().unwrap_or_else(PoisonError::into_inner);
+        let mut ids: Vec<(u64, serenity::GuildId)> = guilds
+            .values()
+            .map(|guild| (guild.id(), guild.discord_guild()))
+            .collect();
+        ids.sort();
+        ids
+    }
+    fn index(&self, guild: &SharedGuild) {
+        self.discord
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(guild.discord_guild(), guild.id());
+    }
+    fn unindex(&self, guild: &SharedGuild) {
+        let mut discord = self.discord.write().unwrap_or_else(PoisonError::into_inner);
+        if discord.get(&guild.discord_guild()) == Some(&guild.id()) {
+            discord.remove(&guild.discord_guild());
+        }
+    }
+    /// Returns the guild with the given id, if it exists.
+    pub fn guild(&self, id: u64) -> Option<Arc<SharedGuild>> {
+        let guilds = self.guilds.read().unwrap_or_else(PoisonError::into_inner);
+        guilds.get(&id).cloned()
+    }
+    /// Removes a guild, e.g. when the bot is removed from a server, and returns it.
+    pub fn remove_guild(&self, id: u64) -> Option<Arc<SharedGuild>> {
+        let mut guilds = self.guilds.write().unwrap_or_else(PoisonError::into_inner);
+        let guild = guilds.remove(&id)?;
+        self.unindex(&guild);
+        Some(guild)
+    }
+    /// Returns the number of guilds in the registry.
+    pub fn len(&self) -> usize {
+        self.guilds
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .len()
+    }
+    /// Returns true if the registry has no guilds.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    /// Freezes the clock of every active, unpaused [`League`] in every guild, e.g. during a Discord outage, and returns how
+    /// many were paused. See [`SharedGuild::pause_all`], and [`GuildManager`](crate::GuildManager) to pause on a missed heartbeat.
+    pub fn pause_all(&self, now: DateTime<Utc>) -> usize {
+        self.guilds().iter().map(|guild| guild.pause_all(now)).sum()
+    }
+    /// Resumes every [`League`] paused by [`DraftRegistry::pause_all`], and returns how many were resumed.
+    /// Leagues that were paused on their own before the outage stay paused.
+    pub fn resume_all(&self, now: DateTime<Utc>) -> usize {
+        self.guilds()
+            .iter()
+            .map(|guild| guild.resume_all(now))
+            .sum()
+    }
+    /// Returns true if Leagues are frozen by [`DraftRegistry::pause_all`].
+    pub fn is_outage_paused(&self) -> bool {
+        self.guilds().iter().any(|guild| guild.is_outage_paused())
+    }
+    // every guild, copied out so no League is locked while the map is
+    fn guilds(&self) -> Vec<Arc<SharedGuild>> {
+        self.guilds
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .values()
+            .cloned()
+            .collect()
+    }
+    /// Runs f on a guild's [`League`] by name, holding only that League's lock for the call, and returns its result.
+    ///
+    /// # Errors
+    ///
+    /// If the guild or the league does not exist, returns [`DraftGuildError::LeagueNotFoundError`].
+    pub fn league<R>(
+        &self,
+        guild: u64,
+        name: &str,
+        f: impl FnOnce(&mut League) -> R,
+    ) -> Result<R, DraftGuildError> {
+        let Some(guild) = self.guild(guild) else {
+            return Err(DraftGuildError::LeagueNotFoundError);
+        };
+        guild.with_league(name, f)
+    }
+}
+
+impl Default for DraftRegistry {
+    fn default() -> Self {
+        DraftRegistry::new()
+    }
+}
+
+#[cfg(test)]
+mod registry_tests {
+    use super::*;
+    use crate::test_support::new_league;
+    use crate::{DraftGuild, DraftType};
+
+    fn assert_send_sync<T: Send + Sync>() {}
+
+    #[test]
+    fn registry_is_shareable() {
+        assert_send_sync::<DraftRegistry>();
+    }
+
+    #[test]
+    fn guilds_are_created_once() {
+        let registry = DraftRegistry::new();
+        let guild = registry.get_or_create_guild(1, serenity::ChannelId(2));
+        guild
+            .add_league(new_league(1, DraftType::Snake, 1))
+            .unwrap();
+        let again = registry.get_or_create_guild(1, serenity::ChannelId(3));
+        assert_eq!(again.default_output(), serenity::ChannelId(2));
+        let id = registry.league(1, "Creenis", |league| league.id).unwrap();
+        assert_eq!(id, 69420);
+        match registry.league(2, "Creenis", |_| ()) {
+            Err(DraftGuildError::LeagueNotFoundError) => {}
+            _ => panic!("guild 2 does not exist"),
+        }
+    }
+
+    #[test]
+    fn guilds_are_found_by_discord_server() {
+        let registry = DraftRegistry::new();
+        registry.get_or_create_guild(1, serenity::ChannelId(2));
+        let mut custom = DraftGuild::new(69420, serenity::ChannelId(3));
+        custom.set_discord_guild(serenity::GuildId(7));
+        assert_eq!(custom.id(), 69420);
+        registry.insert_guild(SharedGuild::from_guild(custom));
+        assert_eq!(
+            registry.guild_for(serenity::GuildId(7)).unwrap().id(),
+            69420
+        );
+        assert_eq!(registry.guild_for(serenity::GuildId(1)).unwrap().id(), 1);
+        assert!(registry.guild_for(serenity::GuildId(69420)).is_none());
+        assert_eq!(
+            registry.guild_ids(),
+            vec![(1, serenity::GuildId(1)), (69420, serenity::GuildId(7))]
+        );
+        registry.remove_guild(69420);
+        assert!(registry.guild_for(serenity::GuildId(7)).is_none());
+    }
+
+    #[test]
+    fn threads_share_guilds() {
+        let registry = Arc::new(DraftRegistry::new());
+        let handles: Vec<_> = (0..8)
+            .map(|i| {
+                let registry = Arc::clone(&registry);
+                std::thread::spawn(move || {
+                    registry.get_or_create_guild(i % 2, serenity::ChannelId(i));
+                })
+            })
+            .collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+        assert_eq!(registry.len(), 2);
+    }
+}
@@ -0,0 +1,26 @@
+use crate::{Draftable, LeagueError};
+use std::fmt;
+
+/// The error from a [`League`](crate::League) method that takes ownership of a [`Draftable`](crate::DraftItem), with the item handed back
+/// untouched so it can be retried or used elsewhere without rebuilding it.
+///
+/// Converts into its [`LeagueError`] with `?` or [`From`] when the item is not needed.
+pub struct Rejected {
+    pub error: LeagueError,
+    pub item: Draftable,
+}
+
+impl fmt::Debug for Rejected {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Rejected")
+            .field("error", &self.error)
+            .field("item", &self.item.name())
+            .finish()
+    }
+}
+
+impl From<Rejected> for LeagueError {
+    fn from(rejected: Rejected) -> LeagueError {
+        rejected.error
+    }
+}
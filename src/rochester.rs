@@ -0,0 +1,63 @@
+use crate::Draftable;
+use std::collections::VecDeque;
+
+/// The pool of a Rochester draft, partitioned into packs that are revealed one at a time.
+///
+/// In a Rochester draft, each pack is laid out face up for the whole table to see, and players snake through
+/// picking from it until it is empty. Only then is the next pack revealed.
+pub struct PackSet {
+    pub(crate) sealed: VecDeque<Vec<Draftable>>,
+    pub(crate) revealed: Vec<Draftable>,
+    pub(crate) pack_round: u32,
+}
+
+impl PackSet {
+    /// Splits the pool into packs of pack_size items, in the order given. The last pack may be smaller.
+    pub(crate) fn partition(pool: Vec<Draftable>, pack_size: usize) -> PackSet {
+        let mut sealed = VecDeque::new();
+        let mut pack = Vec::new();
+        for item in pool {
+            pack.push(item);
+            if pack.len() == pack_size {
+                sealed.push_back(pack);
+                pack = Vec::new();
+            }
+        }
+        if !pack.is_empty() {
+            sealed.push_back(pack);
+        }
+        PackSet {
+            sealed,
+            revealed: Vec::new(),
+            pack_round: 0,
+        }
+    }
+    /// Turns the next sealed pack face up. Returns None if there are no sealed packs left.
+    pub(crate) fn reveal(&mut self) -> Option<&Vec<Draftable>> {
+        let pack = self.sealed.pop_front()?;
+        self.revealed = pack;
+        self.pack_round += 1;
+        Some(&self.revealed)
+    }
+    /// Removes an item from the face-up pack by name.
+    pub(crate) fn take(&mut self, name: &str) -> Option<Draftable> {
+        let idx = self.revealed.iter().position(|i| i.name() == name)?;
+        Some(self.revealed.remove(idx))
+    }
+    /// Returns true if the face-up pack contains an item with the given name.
+    pub fn contains(&self, name: &str) -> bool {
+        self.revealed.iter().any(|i| i.name() == name)
+    }
+    /// Returns the items left in the face-up pack.
+    pub fn revealed(&self) -> &Vec<Draftable> {
+        &self.revealed
+    }
+    /// Returns the number of packs that have not been revealed yet.
+    pub fn packs_remaining(&self) -> usize {
+        self.sealed.len()
+    }
+    /// Returns the number of packs revealed so far, i.e. the current pack round (starting at 1), or 0 before the first reveal.
+    pub fn pack_round(&self) -> u32 {
+        self.pack_round
+    }
+}
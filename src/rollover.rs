@@ -0,0 +1,274 @@
+use crate::fuzzy;
+use crate::pool::PoolCopy;
+use crate::{Draftable, League, LeagueError, LeaguePhase};
+use poise::serenity_prelude as serenity;
+use std::sync::Arc;
+
+impl League {
+    /// Starts next season of a dynasty league: returns a new League in [`LeaguePhase::Setup`] with the same players, teams and
+    /// configuration, each player's keepers already on their roster, and this League moved to [`LeaguePhase::Archived`].
+    ///
+    /// Seats are in reverse order of this season's [`League::standings`], so the last placed team picks first. If no results were
+    /// recorded, seats stay as they are.
+    ///
+    /// Keepers are given as (player, item) pairs and are added on top of each team's size (see [`League::set_player_team_size`]
+    /// to count them against it). They stay on this season's rosters as well, so its history is untouched. The new League has no
+    /// [`DraftPool`](crate::DraftPool): give it next season's, leaving the keepers out.
+    ///
+    /// To keep this season's history, pass it to [`DraftGuild::archive_league`](crate::DraftGuild::archive_league) before adding the new League under the same name.
+    ///
+    /// # Errors
+    ///
+    /// If the draft is not finished, returns [`LeagueError::WrongPhaseError`].
+    ///
+    /// If a keeper's player is not in the league, returns [`LeagueError::PlayerNotFoundError`].
+    ///
+    /// If a player does not have one of their keepers, returns [`LeagueError::DraftableNotFoundError`] with the closest names they do have.
+    ///
+    /// If a keeper is locked (see [`League::lock_item`]), returns [`LeagueError::DraftableLockedError`].
+    ///
+    /// If two players keep copies of the same item, returns [`LeagueError::DraftableInUseError`], since next season has no pool to hold copies.
+    ///
+    /// Every keeper is checked before anything changes, so on an error both this League and its rosters are left as they were.
+    pub fn rollover(
+        &mut self,
+        id: u64,
+        keepers: &[(serenity::UserId, &str)],
+    ) -> Result<League, LeagueError> {
+        if self.phase != LeaguePhase::PostDraft {
+            return Err(LeagueError::WrongPhaseError);
+        }
+        let mut kept: Vec<(u32, String)> = Vec::new();
+        for (user, name) in keepers {
+            let seat = self.seat_of(*user)?;
+            let name = self.resolve_name(name).to_string();
+            if !self.holds(seat, &name) {
+                return Err(fuzzy::not_found(&name, &self.players[seat as usize].picks));
+            }
+            self.require_unlocked(&[&name])?;
+            if kept.contains(&(seat, name.clone())) {
+                continue;
+            }
+            if kept.iter().any(|(_, kept)| *kept == name) {
+                return Err(LeagueError::DraftableInUseError);
+            }
+            kept.push((seat, name));
+        }
+
+        let mut seats: Vec<usize> = (0..self.players.len()).collect();
+        if !self.results.is_empty() {
+            seats = self
+                .standings()
+                .iter()
+                .rev()
+                .filter_map(|s| self.players.iter().position(|p| p.id == s.user))
+                .collect();
+        }
+        let users: Vec<serenity::UserId> = seats.iter().map(|s| self.players[*s].id).collect();
+        let mut next = League::new(
+            &users,
+            id,
+            self.name.clone(),
+            self.channel_outside_thread(),
+            self.draft_type,
+            self.team_size,
+        );
+        self.copy_seats(&mut next, &seats);
+        self.copy_configuration(&mut next);
+
+        for (seat, name) in kept {
+            let player = &mut self.players[seat as usize];
+            let idx = player.picks.iter().position(|p| p.name() == name).unwrap();
+            // both seasons hold the same item
+            let item = Arc::new(player.picks.remove(idx));
+            player
+                .picks
+                .insert(idx, Box::new(PoolCopy(Arc::clone(&item))));
+            let keeper: Draftable = Box::new(PoolCopy(item));
+            let new_seat = seats.iter().position(|s| *s == seat as usize).unwrap();
+            next.add_checked(new_seat as u32, keeper);
+        }
+        self.set_phase(LeaguePhase::Archived)?;
+        Ok(next)
+    }
+    /// Returns a copy of the League in [`LeaguePhase::Setup`] under a new name and ID, with the same draft type, team size and
+    /// configuration but no picks, queues or results, e.g. for a server running the same draft every week.
+    ///
+    /// With players, the copy has the same players in the same seats, with their co-owners, teams, divisions and team sizes,
+    /// and the same team roles. Without, it starts empty for players to [`League::join_lobby`]. Either way it has no
+    /// [`DraftPool`](crate::DraftPool): give it a fresh one.
+    pub fn duplicate(&self, new_name: String, new_id: u64, players: bool) -> League {
+        let mut users: Vec<serenity::UserId> = match players {
+            true => self.players.iter().map(|p| p.id).collect(),
+            false => Vec::new(),
+        };
+        // League::new needs a player, so seat a stand-in and take it back out
+        let stand_in = users.is_empty();
+        if stand_in {
+            users.push(serenity::UserId(0));
+        }
+        let mut copy = League::new(
+            &users,
+            new_id,
+            new_name,
+            self.channel_outside_thread(),
+            self.draft_type,
+            self.team_size,
+        );
+        if stand_in {
+            copy.players.clear();
+            copy.recalculate_final_pick();
+        }
+        if players {
+            self.copy_seats(&mut copy, &(0..self.players.len()).collect::<Vec<_>>());
+        }
+        self.copy_configuration(&mut copy);
+        copy
+    }
+    // gives next's seats the co-owners, teams, divisions, team sizes and team roles of the seats they came from
+    fn copy_seats(&self, next: &mut League, seats: &[usize]) {
+        for (new_seat, old_seat) in seats.iter().enumerate() {
+            let (old, new) = (&self.players[*old_seat], &mut next.players[new_seat]);
+            new.co_owners = old.co_owners.clone();
+            new.team = old.team.clone();
+            new.division = old.division.clone();
+            new.team_size = old.team_size;
+        }
+        next.recalculate_final_pick();
+        next.team_roles = self.team_roles.clone();
+    }
+    // copies every setting that is not about the players
+    fn copy_configuration(&self, next: &mut League) {
+        next.set_pick_time_limit(self.clock.limit());
+        next.permissions = self.permissions.clone();
+        next.emoji = self.emoji.clone();
+        next.templates = self.templates.clone();
+        next.aliases = self.aliases.clone();
+        next.locale = self.locale.clone();
+        next.queue_limit = self.queue_limit;
+        next.queues_locked_off_turn = self.queues_locked_off_turn;
+        next.round_categories = self.round_categories.clone();
+        next.roster_slots = self.roster_slots.clone();
+        next.salary_cap = self.salary_cap;
+        next.skip_policy = self.skip_policy;
+        next.auction.pricing = self.auction.pricing;
+        next.auction.budget = self.auction.budget;
+        next.auction.order = self.auction.order.clone();
+        next.timezone = self.timezone;
+        next.reminders.every = self.reminders.every;
+        next.deadlines.max_duration = self.deadlines.max_duration;
+        next.idempotency.window = self.idempotency.window;
+        next.rate_limits.limits = self.rate_limits.limits.clone();
+        next.lobby.check.quorum = self.lobby.check.quorum;
+        next.lobby.check.no_shows = self.lobby.check.no_shows;
+    }
+}
+
+#[cfg(test)]
+mod rollover_tests {
+    use super::*;
+    use crate::test_support::new_league;
+    use crate::{DraftGuild, DraftType, Team};
+    use chrono::Utc;
+
+    fn league() -> League {
+        let mut league = new_league(2, DraftType::Snake, 2);
+        league
+            .set_team(serenity::UserId(1), Team::new("Rockets".to_string()))
+            .unwrap();
+        league.activate().unwrap();
+        for name in ["Mewtwo", "Pikachu", "Eevee", "Ditto"] {
+            league.lock(Box::new(name)).unwrap();
+        }
+        league
+    }
+
+    #[test]
+    fn next_season_keeps_keepers_and_flips_the_order() {
+        let mut league = league();
+        league
+            .record_result(serenity::UserId(1), 100.0, serenity::UserId(2), 50.0)
+            .unwrap();
+        match league.rollover(1, &[(serenity::UserId(1), "Mewtoo")]) {
+            Err(LeagueError::DraftableNotFoundError(names)) => assert_eq!(names, ["Mewtwo"]),
+            _ => panic!("Mewtoo is a typo"),
+        }
+        league.lock_item("Pikachu");
+        match league.rollover(
+            1,
+            &[
+                (serenity::UserId(1), "Mewtwo"),
+                (serenity::UserId(2), "Pikachu"),
+            ],
+        ) {
+            Err(LeagueError::DraftableLockedError) => {}
+            _ => panic!("Pikachu is locked"),
+        }
+        assert_eq!(league.phase(), LeaguePhase::PostDraft);
+        league.unlock_item("Pikachu");
+        let mut next = league
+            .rollover(1, &[(serenity::UserId(1), "Mewtwo")])
+            .unwrap();
+        assert_eq!(league.phase(), LeaguePhase::Archived);
+        assert_eq!(
+            league.get_player(serenity::UserId(1)).unwrap().picks.len(),
+            2,
+            "last season's roster is untouched"
+        );
+        assert_eq!(next.phase(), LeaguePhase::Setup);
+        assert_eq!(
+            next.players[0].id,
+            serenity::UserId(2),
+            "the loser picks first"
+        );
+        let rockets = next.get_player(serenity::UserId(1)).unwrap();
+        assert_eq!(rockets.team().unwrap().name(), "Rockets");
+        assert_eq!(rockets.picks[0].name(), "Mewtwo");
+        next.activate().unwrap();
+        assert_eq!(next.current_player().unwrap().id, serenity::UserId(2));
+
+        let mut guild = DraftGuild::new(1, serenity::ChannelId(2));
+        guild.add_league(league).unwrap();
+        guild.archive_league("Creenis", Utc::now()).unwrap();
+        guild.add_league(next).unwrap();
+        assert_eq!(guild.archives().len(), 1);
+    }
+
+    #[test]
+    fn only_finished_seasons_roll_over() {
+        let mut league = new_league(1, DraftType::Snake, 1);
+        match league.rollover(1, &[]) {
+            Err(LeagueError::WrongPhaseError) => {}
+            _ => panic!("the draft has not happened"),
+        }
+    }
+
+    #[test]
+    fn duplicates_copy_settings_but_not_picks() {
+        let mut league = league();
+        league.set_skip_policy(crate::SkipPolicy::PauseDraft);
+        let copy = league.duplicate("Bofa".to_string(), 2, true);
+        assert_eq!(copy.name, "Bofa");
+        assert_eq!(copy.id, 2);
+        assert_eq!(copy.phase(), LeaguePhase::Setup);
+        assert_eq!(copy.skip_policy(), crate::SkipPolicy::PauseDraft);
+        let rockets = copy.get_player(serenity::UserId(1)).unwrap();
+        assert_eq!(rockets.team().unwrap().name(), "Rockets");
+        assert!(rockets.picks.is_empty());
+        assert_eq!(copy.final_pick, 3);
+
+        let mut empty = league.duplicate("Bofa".to_string(), 3, false);
+        assert!(empty.players.is_empty());
+        assert!(empty
+            .duplicate("Bofa".to_string(), 4, true)
+            .players
+            .is_empty());
+        empty.join_lobby(serenity::UserId(3)).unwrap();
+        assert_eq!(empty.final_pick, 1);
+        assert_eq!(
+            league.phase(),
+            LeaguePhase::PostDraft,
+            "the original is untouched"
+        );
+    }
+}
@@ -0,0 +1,186 @@
+use crate::{Draftable, League, LeagueError};
+
+impl League {
+    /// Limits how many items of one [`DraftItem::category`](crate::DraftItem::category) each team may hold, e.g. "2 goalkeepers",
+    /// or lifts the limit with None. Categories without a limit can fill the whole team.
+    ///
+    /// [`League::lock`] rejects picks for a team whose slots for the category are full. Queued picks that no longer fit are
+    /// passed over, and autopicks only choose items that fit. Trades, waivers and items added with [`League::add_to_player_picks`]
+    /// are not checked.
+    ///
+    /// # Errors
+    ///
+    /// If the draft has started, returns [`LeagueError::LeagueActiveError`], or [`LeagueError::WrongPhaseError`] once it is over.
+    pub fn set_roster_slot(
+        &mut self,
+        category: String,
+        count: Option<u32>,
+    ) -> Result<(), LeagueError> {
+        self.require_pre_draft()?;
+        match count {
+            Some(count) => self.roster_slots.insert(category, count),
+            None => self.roster_slots.remove(&category),
+        };
+        Ok(())
+    }
+    /// Returns the most items of a category each team may hold, if there is a limit.
+    pub fn roster_slot(&self, category: &str) -> Option<u32> {
+        self.roster_slots.get(category).copied()
+    }
+    /// Caps the total [`DraftItem::cost`](crate::DraftItem::cost) of each team's picks, or lifts the cap with None.
+    /// Items without a cost count as 0.
+    ///
+    /// Picks that would take a team over the cap are handled like picks with no roster slot left (see [`League::set_roster_slot`]).
+    ///
+    /// # Errors
+    ///
+    /// If the draft has started, returns [`LeagueError::LeagueActiveError`], or [`LeagueError::WrongPhaseError`] once it is over.
+    pub fn set_salary_cap(&mut self, cap: Option<u32>) -> Result<(), LeagueError> {
+        self.require_pre_draft()?;
+        self.salary_cap = cap;
+        Ok(())
+    }
+    /// Returns the most each team's picks may cost in total, if there is a cap.
+    pub fn salary_cap(&self) -> Option<u32> {
+        self.salary_cap
+    }
+    // true if the seat has room for the item under the roster slots and salary cap
+    pub(crate) fn fits_roster(&self, seat: u32, item: &Draftable) -> bool {
+        self.require_roster_room(seat, item, &[]).is_ok()
+    }
+    // checks the seat has room for the item, counting the earlier items given as already on its team;
+    // the pool's copy of an item knows its category and cost even if the caller's does not
+    pub(crate) fn require_roster_room(
+        &self,
+        seat: u32,
+        item: &Draftable,
+        earlier: &[&Draftable],
+    ) -> Result<(), LeagueError> {
+        if self.roster_slots.is_empty() && self.salary_cap.is_none() {
+            return Ok(());
+        }
+        let known = |item: &'_ Draftable| -> (Option<String>, u32) {
+            let item = match &self.pool {
+                Some(pool) => pool.get(item.name()).unwrap_or(item),
+                None => item,
+            };
+            (item.category().map(String::from), item.cost().unwrap_or(0))
+        };
+        let team: Vec<(Option<String>, u32)> = self.players[seat as usize]
+            .picks
+            .iter()
+            .chain(earlier.iter().copied())
+            .map(known)
+            .collect();
+        let (category, cost) = known(item);
+        if let Some(category) = category {
+            if let Some(slots) = self.roster_slot(&category) {
+                let held = team
+                    .iter()
+                    .filter(|(c, _)| c.as_deref() == Some(category.as_str()))
+                    .count() as u32;
+                if held >= slots {
+                    return Err(LeagueError::RosterSlotsFullError(category));
+                }
+            }
+        }
+        if let Some(cap) = self.salary_cap {
+            let spent: u32 = team.iter().map(|(_, cost)| *cost).sum();
+            if spent.saturating_add(cost) > cap {
+                return Err(LeagueError::SalaryCapError(cap));
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod roster_tests {
+    use super::*;
+    use crate::test_support::new_league;
+    use crate::{DraftItem, DraftPool, DraftType, PickSource};
+    use poise::serenity_prelude as serenity;
+
+    struct Player {
+        name: String,
+        position: String,
+        salary: u32,
+    }
+    impl DraftItem for Player {
+        fn name(&self) -> &str {
+            &self.name
+        }
+        fn category(&self) -> Option<&str> {
+            Some(&self.position)
+        }
+        fn cost(&self) -> Option<u32> {
+            Some(self.salary)
+        }
+    }
+
+    fn player(name: &str, position: &str, salary: u32) -> Draftable {
+        Box::new(Player {
+            name: name.to_string(),
+            position: position.to_string(),
+            salary,
+        })
+    }
+
+    fn league() -> League {
+        let mut league = new_league(2, DraftType::Linear, 3);
+        league
+            .set_pool(DraftPool::new(vec![
+                player("Messi", "FW", 50),
+                player("Ronaldo", "FW", 40),
+                player("Alisson", "GK", 10),
+                player("Ederson", "GK", 10),
+                player("Neuer", "GK", 5),
+                player("Haaland", "FW", 30),
+            ]))
+            .unwrap();
+        league.set_roster_slot("GK".to_string(), Some(1)).unwrap();
+        league.set_salary_cap(Some(60)).unwrap();
+        league
+    }
+
+    #[test]
+    fn picks_must_fit_the_roster() {
+        let mut league = league();
+        league.activate().unwrap();
+        match league.set_salary_cap(None) {
+            Err(LeagueError::LeagueActiveError) => {}
+            _ => panic!("the draft has started"),
+        }
+        league.lock(Box::new("Alisson")).unwrap();
+        league.lock(Box::new("Ederson")).unwrap();
+        match league.lock(Box::new("Neuer")) {
+            Err(LeagueError::RosterSlotsFullError(category)) => assert_eq!(category, "GK"),
+            _ => panic!("seat 1 already has a goalkeeper"),
+        }
+        league.lock(Box::new("Messi")).unwrap();
+        league.lock(Box::new("Ronaldo")).unwrap();
+        match league.lock(Box::new("Haaland")) {
+            Err(LeagueError::SalaryCapError(cap)) => assert_eq!(cap, 60),
+            _ => panic!("seat 1 has spent 60 already"),
+        }
+    }
+
+    #[test]
+    fn queues_and_autopicks_pass_over_what_does_not_fit() {
+        let mut league = league();
+        for name in ["Alisson", "Neuer"] {
+            league
+                .add_to_player_queue(serenity::UserId(2), Box::new(name))
+                .unwrap();
+        }
+        league.set_autopick(serenity::UserId(2), true).unwrap();
+        league.activate().unwrap();
+        let picks = league.lock(Box::new("Ederson")).unwrap();
+        assert_eq!(picks[1].item, "Alisson");
+        assert_eq!(picks[1].source, PickSource::Queue);
+        // Neuer waits in the queue with no goalkeeper slot left
+        let picks = league.lock(Box::new("Ronaldo")).unwrap();
+        assert_eq!(picks[1].item, "Messi");
+        assert_eq!(picks[1].source, PickSource::Autopick);
+    }
+}
@@ -0,0 +1,252 @@
+use crate::{Draftable, League, LeagueError};
+use poise::serenity_prelude as serenity;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// A pick still to come in the draft, from [`League::upcoming_picks`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UpcomingPick {
+    /// The overall pick number, counting every pick in the draft from 1.
+    pub pick: u32,
+    /// The round the pick is in, starting at 1.
+    pub round: u32,
+    /// The seat that will make the pick.
+    pub seat: u32,
+    /// The seat's original owner.
+    pub user: serenity::UserId,
+    /// The [`DraftItem::category`](crate::DraftItem::category) the pick must be, if its round has one
+    /// (see [`League::set_round_category`]).
+    pub category: Option<String>,
+}
+
+impl League {
+    /// Restricts every pick in a round to one [`DraftItem::category`](crate::DraftItem::category), e.g. "round 1 must be a goalkeeper",
+    /// or lifts the restriction with None. Rounds start at 1, and each is one pick per seat.
+    ///
+    /// [`League::lock`] rejects picks from any other category during the round. Queued picks from other categories wait in
+    /// the queue for a round they fit, and autopicks only choose from the round's category. Items added with
+    /// [`League::add_to_player_picks`] are not checked.
+    ///
+    /// # Errors
+    ///
+    /// If round is 0, returns [`LeagueError::InvalidRoundError`].
+    ///
+    /// If the draft has started, returns [`LeagueError::LeagueActiveError`], or [`LeagueError::WrongPhaseError`] once it is over.
+    pub fn set_round_category(
+        &mut self,
+        round: u32,
+        category: Option<String>,
+    ) -> Result<(), LeagueError> {
+        if round == 0 {
+            return Err(LeagueError::InvalidRoundError);
+        }
+        self.require_pre_draft()?;
+        match category {
+            Some(category) => self.round_categories.insert(round, category),
+            None => self.round_categories.remove(&round),
+        };
+        Ok(())
+    }
+    /// Returns the category every pick in a round must be, if it has one.
+    pub fn round_category(&self, round: u32) -> Option<&str> {
+        self.round_categories.get(&round).map(String::as_str)
+    }
+    /// Returns the next count picks still to be made in the draft, starting with the one on the clock, with the category
+    /// each must be - e.g. for an "on deck" list. Returns fewer near the end of the draft, and none once it is over.
+    pub fn upcoming_picks(&self, count: usize) -> Vec<UpcomingPick> {
+        if !self.phase.is_pre_draft() && !self.phase.is_drafting() {
+            return Vec::new();
+        }
+        (self.total_picks..=self.final_pick)
+            .take(count)
+            .map(|pick| {
+                let seat = self.seat_at(pick);
+                let round = self.round_of(pick);
+                UpcomingPick {
+                    pick: pick + 1,
+                    round,
+                    seat,
+                    user: self.players[seat as usize].id,
+                    category: self.round_category(round).map(String::from),
+                }
+            })
+            .collect()
+    }
+    // the round of the pick with the given zero-based index: a seat's first pick is in round 1, its second in round 2...
+    // which is not every seat's nth pick once some have smaller teams or a seat was removed
+    pub(crate) fn round_of(&self, pick: u32) -> u32 {
+        match &self.order {
+            Some(order) if (pick as usize) < order.len() => {
+                let seat = order[pick as usize];
+                order[..pick as usize]
+                    .iter()
+                    .filter(|s| **s == seat)
+                    .count() as u32
+                    + 1
+            }
+            _ => pick / (self.players.len() as u32).max(1) + 1,
+        }
+    }
+    // the number of rounds in the whole draft, the most picks any seat makes
+    pub(crate) fn rounds(&self) -> u32 {
+        match &self.order {
+            Some(order) => {
+                let mut made: HashMap<u32, u32> = HashMap::new();
+                for seat in order.iter().filter(|s| **s != crate::forfeit::REMOVED_SEAT) {
+                    *made.entry(*seat).or_default() += 1;
+                }
+                made.into_values().max().unwrap_or(0)
+            }
+            None if self.players.is_empty() => 0,
+            None => (self.final_pick + 1).div_ceil(self.players.len() as u32),
+        }
+    }
+    // true if the item may be made the pick with the given zero-based index;
+    // the pool's copy of an item knows its category even if the caller's does not
+    pub(crate) fn fits_round(&self, item: &Draftable, pick: u32) -> bool {
+        if self.round_categories.is_empty() {
+            return true;
+        }
+        let Some(category) = self.round_category(self.round_of(pick)) else {
+            return true;
+        };
+        let item = match &self.pool {
+            Some(pool) => pool.get(item.name()).unwrap_or(item),
+            None => item,
+        };
+        item.category() == Some(category)
+    }
+    pub(crate) fn require_round_category(
+        &self,
+        item: &Draftable,
+        pick: u32,
+    ) -> Result<(), LeagueError> {
+        if self.round_categories.is_empty() {
+            return Ok(());
+        }
+        match self.round_category(self.round_of(pick)) {
+            Some(category) if !self.fits_round(item, pick) => {
+                Err(LeagueError::WrongCategoryError(category.to_string()))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod round_categories_tests {
+    use super::*;
+    use crate::test_support::new_league;
+    use crate::{DraftItem, DraftPool, DraftType, ForfeitPolicy, PickSource};
+
+    struct Player {
+        name: String,
+        position: String,
+    }
+    impl DraftItem for Player {
+        fn name(&self) -> &str {
+            &self.name
+        }
+        fn category(&self) -> Option<&str> {
+            Some(&self.position)
+        }
+    }
+
+    fn player(name: &str, position: &str) -> Draftable {
+        Box::new(Player {
+            name: name.to_string(),
+            position: position.to_string(),
+        })
+    }
+
+    fn league() -> League {
+        let mut league = new_league(2, DraftType::Snake, 2);
+        league
+            .set_pool(DraftPool::new(vec![
+                player("Messi", "FW"),
+                player("Ronaldo", "FW"),
+                player("Alisson", "GK"),
+                player("Ederson", "GK"),
+            ]))
+            .unwrap();
+        league
+            .set_round_category(1, Some("GK".to_string()))
+            .unwrap();
+        league
+    }
+
+    #[test]
+    fn rounds_only_take_their_category() {
+        let mut league = league();
+        let upcoming = league.upcoming_picks(3);
+        assert_eq!(upcoming.len(), 3);
+        assert_eq!(upcoming[1].user, serenity::UserId(2));
+        assert_eq!(upcoming[1].category.as_deref(), Some("GK"));
+        assert_eq!(upcoming[2].category, None);
+        league.activate().unwrap();
+        match league.lock(Box::new("Messi")) {
+            Err(LeagueError::WrongCategoryError(category)) => assert_eq!(category, "GK"),
+            _ => panic!("round 1 is for goalkeepers"),
+        }
+        league.lock(Box::new("Alisson")).unwrap();
+        assert_eq!(league.upcoming_picks(10).len(), 3);
+    }
+
+    #[test]
+    fn queues_and_autopicks_wait_for_a_fitting_round() {
+        let mut league = league();
+        league
+            .add_to_player_queue(serenity::UserId(2), player("Messi", "FW"))
+            .unwrap();
+        league.set_autopick(serenity::UserId(2), true).unwrap();
+        league.activate().unwrap();
+        let picks = league.lock(Box::new("Ederson")).unwrap();
+        assert_eq!(picks[1].item, "Alisson", "Messi waits for round 2");
+        assert_eq!(picks[1].source, PickSource::Autopick);
+        assert_eq!(picks[2].item, "Messi");
+        assert_eq!(picks[2].source, PickSource::Queue);
+    }
+
+    #[test]
+    fn rounds_follow_each_seats_own_picks() {
+        let mut league = new_league(3, DraftType::Snake, 2);
+        league
+            .set_pool(DraftPool::new(vec![
+                player("Alisson", "GK"),
+                player("Ederson", "GK"),
+                player("Neuer", "GK"),
+                player("Messi", "FW"),
+                player("Ronaldo", "FW"),
+            ]))
+            .unwrap();
+        league
+            .set_round_category(1, Some("GK".to_string()))
+            .unwrap();
+        league.activate().unwrap();
+        league.lock(Box::new("Alisson")).unwrap();
+        league.lock(Box::new("Ederson")).unwrap();
+        league
+            .forfeit_player(serenity::UserId(2), ForfeitPolicy::RemoveSeat)
+            .unwrap();
+        let upcoming = league.upcoming_picks(3);
+        assert_eq!(
+            upcoming.iter().map(|p| p.round).collect::<Vec<u32>>(),
+            [1, 2, 2]
+        );
+        match league.lock(Box::new("Messi")) {
+            Err(LeagueError::WrongCategoryError(category)) => assert_eq!(category, "GK"),
+            _ => panic!("this is seat 3's first pick"),
+        }
+        league.lock(Box::new("Neuer")).unwrap();
+        league.lock(Box::new("Messi")).unwrap();
+        league.lock(Box::new("Ronaldo")).unwrap();
+        assert_eq!(
+            league.board_grid(),
+            [
+                [Some("Alisson"), Some("Neuer")],
+                [Some("Ronaldo"), Some("Messi")]
+            ]
+        );
+        assert_eq!(league.rounds(), 2);
+    }
+}
@@ -0,0 +1,73 @@
+use crate::{League, LeagueError};
+use poise::serenity_prelude as serenity;
+
+impl League {
+    /// Returns the picks made in a round, in the order they were made and in the same form as [`League::lock`],
+    /// e.g. for a "Round 3 recap" embed. Rounds start at 1, and each is one pick per seat. Skipped picks are left out,
+    /// and a round still underway returns the picks made so far.
+    ///
+    /// Picks are read from [`League::events`], so a League restored from a snapshot only knows about picks made since.
+    ///
+    /// # Errors
+    ///
+    /// If round is 0, returns [`LeagueError::InvalidRoundError`].
+    pub fn round_picks(&self, round: u32) -> Result<Vec<(serenity::UserId, String)>, LeagueError> {
+        if round == 0 {
+            return Err(LeagueError::InvalidRoundError);
+        }
+        let (made, _) = self.pick_history();
+        let seats = self.players.len();
+        Ok(made
+            .into_iter()
+            .skip((round as usize - 1) * seats)
+            .take(seats)
+            .flatten()
+            .map(|(user, item)| (user, item.to_string()))
+            .collect())
+    }
+    /// Returns the last round every seat has picked (or been skipped) in, or None before the first round is over.
+    /// Check it after each pick to know when to post a round recap (see [`League::round_picks`]).
+    pub fn latest_round_complete(&self) -> Option<u32> {
+        let (made, _) = self.pick_history();
+        match made.len().checked_div(self.players.len()) {
+            None | Some(0) => None,
+            Some(round) => Some(round as u32),
+        }
+    }
+}
+
+#[cfg(test)]
+mod rounds_tests {
+    use super::*;
+    use crate::test_support::{new_league, pokemon};
+    use crate::DraftType;
+
+    #[test]
+    fn rounds_recap_in_pick_order() {
+        let mut league = new_league(2, DraftType::Snake, 2);
+        league.activate().unwrap();
+        league.lock(pokemon("Charizard")).unwrap();
+        assert_eq!(league.latest_round_complete(), None);
+        league.lock(pokemon("Blastoise")).unwrap();
+        league.skip().unwrap();
+        assert_eq!(league.latest_round_complete(), Some(1));
+        assert_eq!(
+            league.round_picks(1).unwrap(),
+            vec![
+                (serenity::UserId(1), "Charizard".to_string()),
+                (serenity::UserId(2), "Blastoise".to_string()),
+            ]
+        );
+        assert!(league.round_picks(2).unwrap().is_empty());
+        league.lock(pokemon("Venusaur")).unwrap();
+        assert_eq!(league.latest_round_complete(), Some(2));
+        assert_eq!(
+            league.round_picks(2).unwrap(),
+            vec![(serenity::UserId(1), "Venusaur".to_string())]
+        );
+        match league.round_picks(0) {
+            Err(LeagueError::InvalidRoundError) => {}
+            _ => panic!("rounds start at 1"),
+        }
+    }
+}
@@ -0,0 +1,145 @@
+use crate::{League, LeagueError};
+use poise::serenity_prelude as serenity;
+use serde::{Deserialize, Serialize};
+
+/// One game in a League's season schedule (see [`League::generate_schedule`]).
+///
+/// Players are recorded by their seat's original owner. Weeks start at 1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Matchup {
+    pub week: u32,
+    pub home: serenity::UserId,
+    pub away: serenity::UserId,
+}
+
+// the games in one round of a round robin, starting from round 0. Rounds past the last go around again with home and away swapped
+pub(crate) fn round_robin(
+    users: &[serenity::UserId],
+    round: usize,
+) -> Vec<(serenity::UserId, serenity::UserId)> {
+    if users.len() < 2 {
+        return Vec::new();
+    }
+    // the circle method: seat 0 stays put while the rest rotate around it, with None standing in for a bye
+    let mut seats: Vec<Option<serenity::UserId>> = users.iter().copied().map(Some).collect();
+    if seats.len() % 2 == 1 {
+        seats.push(None);
+    }
+    let n = seats.len();
+    let second_time_around = round / (n - 1) % 2 == 1;
+    let round = round % (n - 1);
+    let rotated: Vec<Option<serenity::UserId>> = std::iter::once(seats[0])
+        .chain((0..n - 1).map(|i| seats[1 + (i + round) % (n - 1)]))
+        .collect();
+    let mut games = Vec::new();
+    for i in 0..n / 2 {
+        let (mut home, mut away) = (rotated[i], rotated[n - 1 - i]);
+        // alternate home games so nobody hosts every week
+        if ((round + i) % 2 == 1) != second_time_around {
+            std::mem::swap(&mut home, &mut away);
+        }
+        if let (Some(home), Some(away)) = (home, away) {
+            games.push((home, away));
+        }
+    }
+    games
+}
+
+impl League {
+    /// Generates a round-robin season schedule over the given number of weeks, replacing any schedule the League had, and returns it.
+    ///
+    /// Every player meets every other player once before anyone meets someone twice; longer seasons go around again with home
+    /// and away swapped. With an odd number of players, one player has a bye each week. For a schedule weighted towards division rivals,
+    /// see [`League::generate_divisional_schedule`].
+    ///
+    /// # Errors
+    ///
+    /// If the League has fewer than two players, returns [`LeagueError::NotEnoughPlayersError`].
+    pub fn generate_schedule(&mut self, weeks: u32) -> Result<&[Matchup], LeagueError> {
+        if self.players.len() < 2 {
+            return Err(LeagueError::NotEnoughPlayersError);
+        }
+        let users: Vec<serenity::UserId> = self.players.iter().map(|p| p.id).collect();
+        let mut schedule = Vec::new();
+        for week in 1..=weeks {
+            for (home, away) in round_robin(&users, week as usize - 1) {
+                schedule.push(Matchup { week, home, away });
+            }
+        }
+        self.schedule = schedule;
+        Ok(&self.schedule)
+    }
+    /// Returns every [`Matchup`] in the League's schedule, week by week.
+    pub fn schedule(&self) -> &[Matchup] {
+        &self.schedule
+    }
+    /// Returns the games played in a week.
+    pub fn matchups_for_week(&self, week: u32) -> Vec<&Matchup> {
+        self.schedule.iter().filter(|m| m.week == week).collect()
+    }
+    /// Removes the League's schedule, and returns it.
+    pub fn clear_schedule(&mut self) -> Vec<Matchup> {
+        std::mem::take(&mut self.schedule)
+    }
+}
+
+#[cfg(test)]
+mod schedule_tests {
+    use super::*;
+    use crate::test_support::new_league;
+    use crate::DraftType;
+    use std::collections::HashSet;
+
+    fn league(players: u64) -> League {
+        new_league(players, DraftType::Snake, 1)
+    }
+
+    #[test]
+    fn everyone_meets_everyone_once() {
+        for players in 2..=9 {
+            let mut league = league(players);
+            let weeks = if players % 2 == 0 {
+                players - 1
+            } else {
+                players
+            } as u32;
+            let schedule = league.generate_schedule(weeks).unwrap().to_vec();
+            let mut pairs = HashSet::new();
+            for m in &schedule {
+                let pair = (m.home.0.min(m.away.0), m.home.0.max(m.away.0));
+                assert!(pairs.insert(pair), "{players} players: {pair:?} meet twice");
+            }
+            assert_eq!(pairs.len() as u64, players * (players - 1) / 2);
+            for week in 1..=weeks {
+                let mut playing = HashSet::new();
+                for m in league.matchups_for_week(week) {
+                    assert!(playing.insert(m.home) && playing.insert(m.away));
+                }
+                assert_eq!(playing.len() as u64, players - players % 2);
+            }
+        }
+    }
+
+    #[test]
+    fn second_time_around_swaps_home_and_away() {
+        let mut league = league(4);
+        league.generate_schedule(6).unwrap();
+        for week in 1..=3 {
+            let first = league.matchups_for_week(week);
+            let second = league.matchups_for_week(week + 3);
+            for (a, b) in first.iter().zip(second) {
+                assert_eq!((a.home, a.away), (b.away, b.home));
+            }
+        }
+        assert_eq!(league.clear_schedule().len(), 12);
+        assert!(league.schedule().is_empty());
+    }
+
+    #[test]
+    fn one_player_cannot_be_scheduled() {
+        match league(1).generate_schedule(3) {
+            Err(LeagueError::NotEnoughPlayersError) => {}
+            _ => panic!("nobody to play"),
+        }
+    }
+}
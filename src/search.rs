@@ -0,0 +1,239 @@
+use crate::{DraftPool, Draftable};
+
+/// Filters for [`DraftPool::search`], built up one at a time from [`PoolQuery::new`].
+///
+/// Filters left unset match every item. Results come back a page at a time, 25 items to a page unless
+/// [`PoolQuery::per_page`] says otherwise, so they fit in a Discord message or select menu.
+#[derive(Debug, Clone)]
+pub struct PoolQuery {
+    name: Option<String>,
+    category: Option<String>,
+    tier: Option<u32>,
+    min_cost: Option<u32>,
+    max_cost: Option<u32>,
+    page: usize,
+    per_page: usize,
+}
+
+impl Default for PoolQuery {
+    fn default() -> PoolQuery {
+        PoolQuery::new()
+    }
+}
+
+impl PoolQuery {
+    /// Creates a query that matches every item, starting at the first page.
+    pub fn new() -> PoolQuery {
+        PoolQuery {
+            name: None,
+            category: None,
+            tier: None,
+            min_cost: None,
+            max_cost: None,
+            page: 1,
+            per_page: 25,
+        }
+    }
+    /// Matches items whose name contains the text, ignoring case. Names that only contain its letters in order
+    /// (so "pkchu" finds "Pikachu") match too, after the closer matches.
+    pub fn name(mut self, name: &str) -> PoolQuery {
+        self.name = Some(name.to_lowercase());
+        self
+    }
+    /// Matches items in the category, ignoring case.
+    pub fn category(mut self, category: &str) -> PoolQuery {
+        self.category = Some(category.to_lowercase());
+        self
+    }
+    /// Matches items in the tier.
+    pub fn tier(mut self, tier: u32) -> PoolQuery {
+        self.tier = Some(tier);
+        self
+    }
+    /// Matches items costing at least this much. Items with no cost never match a cost filter.
+    pub fn min_cost(mut self, cost: u32) -> PoolQuery {
+        self.min_cost = Some(cost);
+        self
+    }
+    /// Matches items costing at most this much. Items with no cost never match a cost filter.
+    pub fn max_cost(mut self, cost: u32) -> PoolQuery {
+        self.max_cost = Some(cost);
+        self
+    }
+    /// Sets the page to return, counting from 1. Pages past the last come back empty.
+    pub fn page(mut self, page: usize) -> PoolQuery {
+        self.page = page.max(1);
+        self
+    }
+    /// Sets the number of items on each page. Defaults to 25.
+    pub fn per_page(mut self, per_page: usize) -> PoolQuery {
+        self.per_page = per_page.max(1);
+        self
+    }
+    // None if the item is filtered out, otherwise how closely its name matched (lower is closer)
+    fn rank(&self, item: &Draftable) -> Option<u8> {
+        if self.category.is_some() && item.category().map(str::to_lowercase) != self.category {
+            return None;
+        }
+        if self.tier.is_some() && item.tier() != self.tier {
+            return None;
+        }
+        if self.min_cost.is_some() || self.max_cost.is_some() {
+            let cost = item.cost()?;
+            if self.min_cost.is_some_and(|min| cost < min)
+                || self.max_cost.is_some_and(|max| cost > max)
+            {
+                return None;
+            }
+        }
+        let Some(query) = &self.name else {
+            return Some(0);
+        };
+        let name = item.name().to_lowercase();
+        if name.contains(query.as_str()) {
+            return Some(0);
+        }
+        let mut letters = name.chars();
+        query.chars().all(|q| letters.any(|c| c == q)).then_some(1)
+    }
+}
+
+/// A page of items from [`DraftPool::search`], in ranking order with the closest name matches first.
+pub struct PoolPage<'a> {
+    items: Vec<&'a Draftable>,
+    page: usize,
+    pages: usize,
+    total: usize,
+}
+
+impl<'a> PoolPage<'a> {
+    /// Returns the items on this page.
+    pub fn items(&self) -> &[&'a Draftable] {
+        &self.items
+    }
+    /// Returns the number of this page, counting from 1.
+    pub fn page(&self) -> usize {
+        self.page
+    }
+    /// Returns the number of pages the matches fill. Always at least 1, even with nothing matching.
+    pub fn pages(&self) -> usize {
+        self.pages
+    }
+    /// Returns the number of items matching the query across every page.
+    pub fn total(&self) -> usize {
+        self.total
+    }
+    /// Returns true if there are more pages after this one.
+    pub fn has_next(&self) -> bool {
+        self.page < self.pages
+    }
+}
+
+impl DraftPool {
+    /// Finds the available items matching a [`PoolQuery`], one page at a time, e.g. for an `/available` command that
+    /// should not dump the whole pool at once.
+    pub fn search(&self, query: &PoolQuery) -> PoolPage<'_> {
+        let mut matches: Vec<(u8, &Draftable)> = self
+            .iter()
+            .filter_map(|item| query.rank(item).map(|rank| (rank, item)))
+            .collect();
+        // stable, so ranking order holds within each kind of match
+        matches.sort_by_key(|(rank, _)| *rank);
+        let total = matches.len();
+        let items = matches
+            .into_iter()
+            .skip((query.page - 1).saturating_mul(query.per_page))
+            .take(query.per_page)
+            .map(|(_, item)| item)
+            .collect();
+        PoolPage {
+            items,
+            page: query.page,
+            pages: total.div_ceil(query.per_page).max(1),
+            total,
+        }
+    }
+}
+
+#[cfg(test)]
+mod search_tests {
+    use super::*;
+    use crate::DraftItem;
+
+    struct Pokemon {
+        name: String,
+        category: String,
+        cost: u32,
+        tier: u32,
+    }
+    impl DraftItem for Pokemon {
+        fn name(&self) -> &str {
+            &self.name
+        }
+        fn category(&self) -> Option<&str> {
+            Some(&self.category)
+        }
+        fn cost(&self) -> Option<u32> {
+            Some(self.cost)
+        }
+        fn tier(&self) -> Option<u32> {
+            Some(self.tier)
+        }
+    }
+
+    fn pool() -> DraftPool {
+        let pokemon = |name: &str, category: &str, cost: u32, tier: u32| -> Draftable {
+            Box::new(Pokemon {
+                name: name.to_string(),
+                category: category.to_string(),
+                cost,
+                tier,
+            })
+        };
+        DraftPool::new(vec![
+            pokemon("Pikachu", "Electric", 10, 1),
+            pokemon("Pichu", "Electric", 4, 3),
+            pokemon("Raichu", "Electric", 12, 1),
+            pokemon("Charizard", "Fire", 15, 1),
+            pokemon("Charmander", "Fire", 5, 3),
+            pokemon("Squirtle", "Water", 5, 3),
+        ])
+    }
+
+    fn names(page: &PoolPage) -> Vec<String> {
+        page.items().iter().map(|i| i.name().to_string()).collect()
+    }
+
+    #[test]
+    fn filters_combine() {
+        let pool = pool();
+        let page = pool.search(&PoolQuery::new().category("electric").tier(1));
+        assert_eq!(names(&page), vec!["Pikachu", "Raichu"]);
+        let page = pool.search(&PoolQuery::new().min_cost(5).max_cost(10));
+        assert_eq!(names(&page), vec!["Pikachu", "Charmander", "Squirtle"]);
+    }
+
+    #[test]
+    fn close_names_come_first() {
+        let pool = pool();
+        let page = pool.search(&PoolQuery::new().name("chu"));
+        assert_eq!(names(&page), vec!["Pikachu", "Pichu", "Raichu"]);
+        let page = pool.search(&PoolQuery::new().name("pchu"));
+        assert_eq!(names(&page), vec!["Pikachu", "Pichu"]);
+        let page = pool.search(&PoolQuery::new().name("ichu"));
+        assert_eq!(names(&page), vec!["Pichu", "Raichu", "Pikachu"]);
+    }
+
+    #[test]
+    fn results_are_paginated() {
+        let pool = pool();
+        let page = pool.search(&PoolQuery::new().per_page(4).page(2));
+        assert_eq!(names(&page), vec!["Charmander", "Squirtle"]);
+        assert_eq!(page.pages(), 2);
+        assert_eq!(page.total(), 6);
+        assert!(!page.has_next());
+        let page = pool.search(&PoolQuery::new().name("Mew"));
+        assert!(page.items().is_empty());
+        assert_eq!(page.pages(), 1);
+    }
+}
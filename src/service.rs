@@ -0,0 +1,820 @@
+use crate::snapshot::Checkpoint;
+use crate::{DraftEvent, DraftItem, League, LeagueError, PickResult, SharedGuild, SnapshotError};
+use chrono::{DateTime, Utc};
+use poise::serenity_prelude as serenity;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, PoisonError};
+
+/// The boxed futures returned by [`DraftStore`] and [`Announcer`], which are Send so they can be awaited in poise commands.
+pub type ServiceFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
+
+/// Whatever went wrong in a [`DraftStore`], e.g. a database driver's error.
+pub type StoreError = Box<dyn std::error::Error + Send + Sync>;
+
+// turns a saved item name back into an item, for rolling back
+type Resolver = Box<dyn Fn(&str) -> Option<Box<dyn DraftItem>> + Send + Sync>;
+
+/// An announcement of a change made through a [`LeagueService`], saved with the change and kept in the service's outbox
+/// until it has been posted.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OutboxEntry {
+    /// Identifies the entry within its guild. Later entries have larger ids.
+    pub id: u64,
+    /// The name of the League the change was made to.
+    pub league: String,
+    /// The channel to post to.
+    pub channel: serenity::ChannelId,
+    /// The announcement.
+    pub content: String,
+}
+
+/// Where a [`LeagueService`] saves Leagues after every change it makes, e.g. a database table keyed by guild and League name,
+/// with a second table for the announcements still to be posted.
+pub trait DraftStore: Send + Sync {
+    /// Saves a League's [`League::snapshot`], replacing the one saved before for the same guild and League name, along with the
+    /// announcement of the change, in one transaction: both are saved, or neither is.
+    fn save<'a>(
+        &'a self,
+        guild: u64,
+        league: &'a str,
+        snapshot: String,
+        announcement: &'a OutboxEntry,
+    ) -> ServiceFuture<'a, Result<(), StoreError>>;
+    /// Marks an announcement saved with [`DraftStore::save`] as posted, so it is not posted again.
+    fn delivered<'a>(
+        &'a self,
+        guild: u64,
+        announcement: &'a OutboxEntry,
+    ) -> ServiceFuture<'a, Result<(), StoreError>>;
+    /// Returns the guild's announcements that were saved but not marked as posted, oldest first.
+    fn pending(&self, guild: u64) -> ServiceFuture<'_, Result<Vec<OutboxEntry>, StoreError>>;
+}
+
+/// Where a [`LeagueService`] posts the announcement for every change it makes. Implemented for [`serenity::Http`],
+/// which posts to the channel.
+pub trait Announcer: Send + Sync {
+    /// Posts content to channel.
+    fn announce<'a>(
+        &'a self,
+        channel: serenity::ChannelId,
+        content: String,
+    ) -> ServiceFuture<'a, serenity::Result<()>>;
+}
+
+impl Announcer for serenity::Http {
+    fn announce<'a>(
+        &'a self,
+        channel: serenity::ChannelId,
+        content: String,
+    ) -> ServiceFuture<'a, serenity::Result<()>> {
+        Box::pin(async move { channel.say(self, content).await.map(|_| ()) })
+    }
+}
+
+impl<T: Announcer + ?Sized> Announcer for Arc<T> {
+    fn announce<'a>(
+        &'a self,
+        channel: serenity::ChannelId,
+        content: String,
+    ) -> ServiceFuture<'a, serenity::Result<()>> {
+        (**self).announce(channel, content)
+    }
+}
+
+impl<T: DraftStore + ?Sized> DraftStore for Arc<T> {
+    fn save<'a>(
+        &'a self,
+        guild: u64,
+        league: &'a str,
+        snapshot: String,
+        announcement: &'a OutboxEntry,
+    ) -> ServiceFuture<'a, Result<(), StoreError>> {
+        (**self).save(guild, league, snapshot, announcement)
+    }
+    fn delivered<'a>(
+        &'a self,
+        guild: u64,
+        announcement: &'a OutboxEntry,
+    ) -> ServiceFuture<'a, Result<(), StoreError>> {
+        (**self).delivered(guild, announcement)
+    }
+    fn pending(&self, guild: u64) -> ServiceFuture<'_, Result<Vec<OutboxEntry>, StoreError>> {
+        (**self).pending(guild)
+    }
+}
+
+/// Errors returned by [`LeagueService`] methods.
+#[derive(Debug)]
+pub enum ServiceError {
+    /// There is no League with the name in the service's guild.
+    LeagueNotFoundError,
+    /// The League refused the change, and is unchanged.
+    LeagueError(LeagueError),
+    /// The [`DraftStore`] could not save the change, so it was rolled back and not announced. Holds the store's error.
+    StoreError(StoreError),
+    /// The [`DraftStore`] could not save the change, and it could not be rolled back either because the service's resolver did
+    /// not recognize an item, so the League keeps a change that was never saved. Holds the store's error and the restore's.
+    RollbackError(StoreError, SnapshotError),
+    /// The [`DraftStore`] could not save the change, and it was not rolled back because the League changed again while it was
+    /// being saved, e.g. a timer skipped a pick, and rolling back would have undone that too. The League keeps the change, which
+    /// is saved with the next change the service makes to it. Holds the store's error.
+    ConflictError(StoreError),
+    /// The [`Announcer`] could not post an announcement, which stays in the outbox. Holds Discord's error.
+    AnnounceError(Box<serenity::Error>),
+}
+
+// a change made to a League, waiting to be saved
+struct Change {
+    // the League as it stood before the change, and what its snapshot leaves out
+    before: String,
+    checkpoint: Checkpoint,
+    // the League's revision once the change was made, to tell whether anything else has changed it since
+    revision: (usize, DateTime<Utc>),
+    // the events the change recorded, held back from subscribers until the change is saved
+    events: Vec<DraftEvent>,
+    after: String,
+    announcement: OutboxEntry,
+}
+
+/// The async face of a [`SharedGuild`] for poise command handlers, which `.await` every change without ever blocking on Discord
+/// or the store while holding a League.
+///
+/// Every change is one transaction: it is saved to a [`DraftStore`] along with its announcement before anything is posted,
+/// and rolled back if it cannot be saved, so Discord never hears about a change the store does not have. Its
+/// [`DraftEvent`]s only reach [`League::subscribe`] receivers once it is saved. Only changes made through the service wait
+/// for each other, so a League that something else changed while a change was being saved, e.g. a timer or the web API,
+/// keeps the unsaved change rather than lose both (see [`ServiceError::ConflictError`]). Announcements wait in
+/// an outbox until they are posted, in order, and are only taken out of the store once Discord has them; call
+/// [`LeagueService::recover`] at startup to post any a crash left behind, and [`LeagueService::flush_outbox`] from a background
+/// task to retry any Discord refused. An announcement whose delivery could not be marked may be posted twice, but never lost.
+///
+/// Changes to the same League go through one at a time, so saves land in the order the changes were made. Changes to different
+/// Leagues never wait on each other.
+///
+/// Every change takes an idempotency key, e.g. the id of the interaction that asked for it, and is made once per key with
+/// [`League::once`]: a retry Discord sends with the same key resolves to the first call's result, and is neither saved nor
+/// announced again. A change that could not be saved forgets its key, so a retry makes it.
+///
+/// ```ignore
+/// let service = LeagueService::new(guild, store, ctx.serenity_context().http.clone(), |name| Some(Box::new(name.to_string())));
+/// service.recover().await?;
+/// let picks = service.lock("Creenis", ctx.id(), ctx.author().id, Box::new(item)).await?;
+/// ```
+pub struct LeagueService<S, A> {
+    guild: Arc<SharedGuild>,
+    store: S,
+    announcer: A,
+    resolver: Resolver,
+    // one gate per League, held from a change until it is saved
+    gates: Mutex<HashMap<String, Arc<tokio::sync::Mutex<()>>>>,
+    outbox: Mutex<VecDeque<OutboxEntry>>,
+    // held while posting the outbox, so nothing is posted twice or out of order
+    flushing: tokio::sync::Mutex<()>,
+    next_id: AtomicU64,
+}
+
+impl<S: DraftStore, A: Announcer> LeagueService<S, A> {
+    /// Creates a service over a guild's Leagues, saving to store and announcing with announcer. resolver turns item names back
+    /// into items, like the resolver given to [`League::restore`], to roll back changes that could not be saved.
+    pub fn new(
+        guild: Arc<SharedGuild>,
+        store: S,
+        announcer: A,
+        resolver: impl Fn(&str) -> Option<Box<dyn DraftItem>> + Send + Sync + 'static,
+    ) -> LeagueService<S, A> {
+        LeagueService {
+            guild,
+            store,
+            announcer,
+            resolver: Box::new(resolver),
+            gates: Mutex::new(HashMap::new()),
+            outbox: Mutex::new(VecDeque::new()),
+            flushing: tokio::sync::Mutex::new(()),
+            // ids carry on from the last run's, which started earlier
+            next_id: AtomicU64::new(Utc::now().timestamp_micros() as u64),
+        }
+    }
+    /// Returns the guild the service changes.
+    pub fn guild(&self) -> &Arc<SharedGuild> {
+        &self.guild
+    }
+    /// Returns the announcements waiting to be posted, oldest first.
+    pub fn outbox(&self) -> Vec<OutboxEntry> {
+        self.outbox
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .iter()
+            .cloned()
+            .collect()
+    }
+    /// Loads the announcements the [`DraftStore`] still has waiting, e.g. from before a crash, into the outbox and posts them.
+    /// Resolves to the number posted.
+    ///
+    /// # Errors
+    ///
+    /// If the store cannot list them, or an announcement cannot be marked as posted, returns [`ServiceError::StoreError`].
+    ///
+    /// If an announcement cannot be posted, returns [`ServiceError::AnnounceError`]. It and everything after it stay in the outbox.
+    pub async fn recover(&self) -> Result<usize, ServiceError> {
+        let pending = self
+            .store
+            .pending(self.guild.id())
+            .await
+            .map_err(ServiceError::StoreError)?;
+        {
+            let mut outbox = self.outbox.lock().unwrap_or_else(PoisonError::into_inner);
+            for entry in pending {
+                if !outbox.iter().any(|e| e.id == entry.id) {
+                    outbox.push_back(entry);
+                }
+            }
+            outbox.make_contiguous().sort_by_key(|e| e.id);
+        }
+        self.flush_outbox().await
+    }
+    /// Posts the announcements in the outbox, oldest first, marking each as posted in the [`DraftStore`]. Resolves to the number
+    /// posted. Changes post their own announcement, so call this to retry the ones Discord refused, e.g. from a background task.
+    ///
+    /// # Errors
+    ///
+    /// If an announcement cannot be posted, returns [`ServiceError::AnnounceError`]. It and everything after it stay in the outbox.
+    ///
+    /// If an announcement was posted but cannot be marked as posted, returns [`ServiceError::StoreError`]; the store will have it
+    /// posted again by [`LeagueService::recover`].
+    pub async fn flush_outbox(&self) -> Result<usize, ServiceError> {
+        let _flushing = self.flushing.lock().await;
+        let mut posted = 0;
+        loop {
+            let next = self
+                .outbox
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .front()
+                .cloned();
+            let Some(entry) = next else {
+                return Ok(posted);
+            };
+            self.announcer
+                .announce(entry.channel, entry.content.clone())
+                .await
+                .map_err(|e| ServiceError::AnnounceError(Box::new(e)))?;
+            self.outbox
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .pop_front();
+            posted += 1;
+            self.store
+                .delivered(self.guild.id(), &entry)
+                .await
+                .map_err(ServiceError::StoreError)?;
+        }
+    }
+    /// Locks in a pick on behalf of the user who asked for it, like [`League::lock_as`], then saves the League and announces
+    /// the picks with [`League::pick_announcement`]. Resolves to the picks made, or to the first call's result for a key used before.
+    ///
+    /// # Errors
+    ///
+    /// If there is no League with the name, returns [`ServiceError::LeagueNotFoundError`].
+    ///
+    /// If the League refuses the pick, returns [`ServiceError::LeagueError`] with the same errors as [`League::lock_as`].
+    ///
+    /// If the pick cannot be saved, returns [`ServiceError::StoreError`], or [`ServiceError::RollbackError`] if it cannot be rolled back.
+    pub async fn lock(
+        &self,
+        league: &str,
+        key: u64,
+        user: serenity::UserId,
+        pick: Box<dyn DraftItem>,
+    ) -> Result<Vec<PickResult>, ServiceError> {
+        self.change(league, key, |league| {
+            let picks = league.lock_as(user, pick)?;
+            let announcement = league.pick_announcement(&picks);
+            Ok((picks, announcement))
+        })
+        .await
+    }
+    /// Trades item1 from user1 to user2 for item2 like [`League::trade`], then saves the League and announces the trade with
+    /// [`League::trade_announcement`].
+    ///
+    /// # Errors
+    ///
+    /// If there is no League with the name, returns [`ServiceError::LeagueNotFoundError`].
+    ///
+    /// If the League refuses the trade, returns [`ServiceError::LeagueError`] with the same errors as [`League::trade`].
+    ///
+    /// If the trade cannot be saved, returns [`ServiceError::StoreError`], or [`ServiceError::RollbackError`] if it cannot be rolled back.
+    pub async fn trade(
+        &self,
+        league: &str,
+        key: u64,
+        user1: serenity::UserId,
+        item1: &str,
+        user2: serenity::UserId,
+        item2: &str,
+    ) -> Result<(), ServiceError> {
+        self.change(league, key, |league| {
+            league.trade(user1, item1, user2, item2)?;
+            Ok(((), league.trade_announcement(user1, item1, user2, item2)))
+        })
+        .await
+    }
+    /// Drops a player's pick for another item like [`League::waiver`], then saves the League and announces the waiver with
+    /// [`League::waiver_announcement`].
+    ///
+    /// # Errors
+    ///
+    /// If there is no League with the name, returns [`ServiceError::LeagueNotFoundError`].
+    ///
+    /// If the League refuses the waiver, returns [`ServiceError::LeagueError`] with the same errors as [`League::waiver`].
+    ///
+    /// If the waiver cannot be saved, returns [`ServiceError::StoreError`], or [`ServiceError::RollbackError`] if it cannot be rolled back.
+    pub async fn waiver(
+        &self,
+        league: &str,
+        key: u64,
+        id: serenity::UserId,
+        waivered_from: &str,
+        waivered_for: Box<dyn DraftItem>,
+    ) -> Result<(), ServiceError> {
+        let claimed = waivered_for.name().to_string();
+        self.change(league, key, |league| {
+            league
+                .waiver(id, waivered_from, waivered_for)
+                .map_err(|rejected| rejected.error)?;
+            Ok(((), league.waiver_announcement(id, waivered_from, &claimed)))
+        })
+        .await
+    }
+    // makes a change with f, which returns its result and announcement, saves it or rolls it back, then posts the outbox
+    async fn change<R: Clone + Send + Sync + 'static>(
+        &self,
+        name: &str,
+        key: u64,
+        f: impl FnOnce(&mut League) -> Result<(R, String), LeagueError>,
+    ) -> Result<R, ServiceError> {
+        let gate = self.gate(name);
+        let held = gate.lock().await;
+        let (result, change) = self.apply(name, key, f)?;
+        // a retry was answered from the first call, which saved and announced the change
+        let Some(mut change) = change else {
+            return Ok(result);
+        };
+        let saved = self
+            .store
+            .save(
+                self.guild.id(),
+                name,
+                std::mem::take(&mut change.after),
+                &change.announcement,
+            )
+            .await;
+        if let Err(error) = saved {
+            return Err(self.roll_back(name, change, error));
+        }
+        let _ = self
+            .guild
+            .with_league(name, |league| league.broadcast(&change.events));
+        self.outbox
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push_back(change.announcement);
+        drop(held);
+        // the change is saved, so an announcement Discord refuses waits in the outbox for the next flush
+        let _ = self.flush_outbox().await;
+        Ok(result)
+    }
+    // undoes a change that could not be saved, unless the League has changed again since, and returns the error to report
+    fn roll_back(&self, name: &str, change: Change, error: StoreError) -> ServiceError {
+        let restored = League::restore(&change.before, &self.resolver);
+        let rolled_back = self.guild.with_league(name, |league| match restored {
+            Ok(before) if league.revision() == change.revision => {
+                league.roll_back(before, change.checkpoint);
+                Ok(())
+            }
+            // rolling back would undo whatever changed the League since, so it keeps the change, and its subscribers hear about it
+            Ok(_) => {
+                league.broadcast(&change.events);
+                Err(None)
+            }
+            Err(restore) => {
+                league.broadcast(&change.events);
+                Err(Some(restore))
+            }
+        });
+        match rolled_back {
+            // a League deleted while the change was being saved has nothing to roll back
+            Ok(Ok(())) | Err(_) => ServiceError::StoreError(error),
+            Ok(Err(None)) => ServiceError::ConflictError(error),
+            Ok(Err(Some(restore))) => ServiceError::RollbackError(error, restore),
+        }
+    }
+    // makes the change under the League's lock, which is let go before anything is awaited. There is no Change to save
+    // if the key has been used before
+    fn apply<R: Clone + Send + Sync + 'static>(
+        &self,
+        name: &str,
+        key: u64,
+        f: impl FnOnce(&mut League) -> Result<(R, String), LeagueError>,
+    ) -> Result<(R, Option<Change>), ServiceError> {
+        let default_output = self.guild.default_output();
+        self.guild
+            .with_league(name, |league| {
+                let before = league.snapshot();
+                let checkpoint = league.checkpoint();
+                let recorded = league.events().len();
+                // subscribers hear about the change once it is saved
+                let stream = league.stream.take();
+                let mut ran = false;
+                let made = league.once(key, Utc::now(), |league| {
+                    ran = true;
+                    f(league)
+                });
+                league.stream = stream;
+                let (result, content) = made?;
+                if !ran {
+                    return Ok((result, None));
+                }
+                let announcement = OutboxEntry {
+                    id: self.next_id.fetch_add(1, Ordering::Relaxed),
+                    league: name.to_string(),
+                    channel: league.output_channel(default_output),
+                    content,
+                };
+                Ok((
+                    result,
+                    Some(Change {
+                        before,
+                        checkpoint,
+                        revision: league.revision(),
+                        events: league.events()[recorded..].to_vec(),
+                        after: league.snapshot(),
+                        announcement,
+                    }),
+                ))
+            })
+            .map_err(|_| ServiceError::LeagueNotFoundError)?
+            .map_err(ServiceError::LeagueError)
+    }
+    // the gate changes to a League go through, made the first time it is changed
+    fn gate(&self, name: &str) -> Arc<tokio::sync::Mutex<()>> {
+        let mut gates = self.gates.lock().unwrap_or_else(PoisonError::into_inner);
+        Arc::clone(gates.entry(name.to_string()).or_default())
+    }
+}
+
+#[cfg(test)]
+mod service_tests {
+    use super::*;
+    use crate::test_support::new_league;
+    use crate::{DraftPool, DraftType, Draftable, RateLimit, RateLimitedAction};
+    use std::sync::atomic::AtomicBool;
+
+    #[derive(Default)]
+    struct Recorder {
+        saved: Mutex<Vec<(String, String)>>,
+        pending: Mutex<Vec<OutboxEntry>>,
+        posted: Mutex<Vec<(serenity::ChannelId, String)>>,
+        fail_saves: AtomicBool,
+        fail_posts: AtomicBool,
+        // a guild whose League is changed while the next save is under way
+        meddle: Mutex<Option<Arc<SharedGuild>>>,
+    }
+
+    impl DraftStore for Recorder {
+        fn save<'a>(
+            &'a self,
+            _guild: u64,
+            league: &'a str,
+            snapshot: String,
+            announcement: &'a OutboxEntry,
+        ) -> ServiceFuture<'a, Result<(), StoreError>> {
+            Box::pin(async move {
+                if let Some(guild) = self.meddle.lock().unwrap().take() {
+                    guild
+                        .with_league(league, |league| {
+                            league
+                                .add_to_player_queue(serenity::UserId(2), Box::new("Charmander"))
+                                .map(|_| ())
+                        })
+                        .unwrap()
+                        .unwrap();
+                }
+                if self.fail_saves.load(Ordering::Relaxed) {
+                    return Err("the database is down".into());
+                }
+                self.saved
+                    .lock()
+                    .unwrap()
+                    .push((league.to_string(), snapshot));
+                self.pending.lock().unwrap().push(announcement.clone());
+                Ok(())
+            })
+        }
+        fn delivered<'a>(
+            &'a self,
+            _guild: u64,
+            announcement: &'a OutboxEntry,
+        ) -> ServiceFuture<'a, Result<(), StoreError>> {
+            Box::pin(async move {
+                self.pending
+                    .lock()
+                    .unwrap()
+                    .retain(|e| e.id != announcement.id);
+                Ok(())
+            })
+        }
+        fn pending(&self, _guild: u64) -> ServiceFuture<'_, Result<Vec<OutboxEntry>, StoreError>> {
+            Box::pin(async move { Ok(self.pending.lock().unwrap().clone()) })
+        }
+    }
+
+    impl Announcer for Recorder {
+        fn announce<'a>(
+            &'a self,
+            channel: serenity::ChannelId,
+            content: String,
+        ) -> ServiceFuture<'a, serenity::Result<()>> {
+            Box::pin(async move {
+                if self.fail_posts.load(Ordering::Relaxed) {
+                    return Err(serenity::Error::Other("Discord is down"));
+                }
+                self.posted.lock().unwrap().push((channel, content));
+                Ok(())
+            })
+        }
+    }
+
+    fn service(recorder: &Arc<Recorder>) -> LeagueService<Arc<Recorder>, Arc<Recorder>> {
+        let guild = SharedGuild::new(1, serenity::ChannelId(2));
+        let mut league = new_league(2, DraftType::Snake, 1);
+        let pool = ["Pikachu", "Bulbasaur", "Charmander", "Squirtle"]
+            .iter()
+            .map(|name| Box::new(*name) as Draftable)
+            .collect();
+        league.set_pool(DraftPool::new(pool)).unwrap();
+        league.activate().unwrap();
+        guild.add_league(league).unwrap();
+        LeagueService::new(
+            Arc::new(guild),
+            Arc::clone(recorder),
+            Arc::clone(recorder),
+            |name| Some(Box::new(name.to_string())),
+        )
+    }
+
+    #[tokio::test]
+    async fn changes_are_saved_then_announced() {
+        let recorder = Arc::new(Recorder::default());
+        let service = service(&recorder);
+        let picks = service
+            .lock("Creenis", 1, serenity::UserId(1), Box::new("Pikachu"))
+            .await
+            .unwrap();
+        assert_eq!(picks[0].item, "Pikachu");
+        let retried = service
+            .lock("Creenis", 1, serenity::UserId(1), Box::new("Pikachu"))
+            .await
+            .unwrap();
+        assert_eq!(retried, picks, "the retry gets the first call's picks");
+        assert_eq!(recorder.saved.lock().unwrap().len(), 1);
+        assert_eq!(recorder.posted.lock().unwrap().len(), 1);
+        service
+            .lock("Creenis", 2, serenity::UserId(2), Box::new("Squirtle"))
+            .await
+            .unwrap();
+        match service
+            .lock("Creenis", 3, serenity::UserId(1), Box::new("Bulbasaur"))
+            .await
+        {
+            Err(ServiceError::LeagueError(LeagueError::LeagueInactiveError)) => {}
+            _ => panic!("the draft is over"),
+        }
+        service
+            .trade(
+                "Creenis",
+                4,
+                serenity::UserId(1),
+                "Pikachu",
+                serenity::UserId(2),
+                "Squirtle",
+            )
+            .await
+            .unwrap();
+        service
+            .waiver(
+                "Creenis",
+                5,
+                serenity::UserId(1),
+                "Squirtle",
+                Box::new("Bulbasaur"),
+            )
+            .await
+            .unwrap();
+        match service
+            .trade(
+                "Bofa",
+                6,
+                serenity::UserId(1),
+                "Bulbasaur",
+                serenity::UserId(2),
+                "Pikachu",
+            )
+            .await
+        {
+            Err(ServiceError::LeagueNotFoundError) => {}
+            _ => panic!("there is no Bofa"),
+        }
+
+        let saved = recorder.saved.lock().unwrap();
+        assert_eq!(saved.len(), 4, "refused changes are not saved");
+        let mut restored =
+            League::restore(&saved[3].1, |name| Some(Box::new(name.to_string()))).unwrap();
+        assert_eq!(
+            restored.player_picks(serenity::UserId(1)).unwrap()[0].name(),
+            "Bulbasaur"
+        );
+        let posted = recorder.posted.lock().unwrap();
+        assert_eq!(posted.len(), 4);
+        assert!(posted
+            .iter()
+            .all(|(channel, _)| *channel == serenity::ChannelId(2)));
+        assert!(posted[2].1.contains("**Pikachu**"));
+        assert!(posted[3]
+            .1
+            .contains("dropped **Squirtle** for **Bulbasaur**"));
+        assert!(recorder.pending.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn unsaved_changes_are_rolled_back() {
+        let recorder = Arc::new(Recorder::default());
+        let service = service(&recorder);
+        recorder.fail_saves.store(true, Ordering::Relaxed);
+        match service
+            .lock("Creenis", 7, serenity::UserId(1), Box::new("Pikachu"))
+            .await
+        {
+            Err(ServiceError::StoreError(_)) => {}
+            _ => panic!("the store is down"),
+        }
+        assert!(recorder.posted.lock().unwrap().is_empty());
+        let events = service
+            .guild()
+            .with_league("Creenis", |league| {
+                assert_eq!(league.pick_number(), 1);
+                assert!(!league.is_picked("Pikachu"));
+                assert!(league.pool().unwrap().get("Pikachu").is_some());
+                league.events().len()
+            })
+            .unwrap();
+
+        recorder.fail_saves.store(false, Ordering::Relaxed);
+        service
+            .lock("Creenis", 7, serenity::UserId(1), Box::new("Pikachu"))
+            .await
+            .unwrap();
+        assert_eq!(recorder.posted.lock().unwrap().len(), 1);
+        service
+            .guild()
+            .with_league("Creenis", |league| {
+                assert_eq!(league.events().len(), events + 1, "one pick was recorded");
+            })
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn subscribers_only_hear_saved_changes() {
+        let recorder = Arc::new(Recorder::default());
+        let service = service(&recorder);
+        let mut events = service
+            .guild()
+            .with_league("Creenis", |league| league.subscribe())
+            .unwrap();
+        recorder.fail_saves.store(true, Ordering::Relaxed);
+        match service
+            .lock("Creenis", 1, serenity::UserId(1), Box::new("Pikachu"))
+            .await
+        {
+            Err(ServiceError::StoreError(_)) => {}
+            _ => panic!("the store is down"),
+        }
+        assert!(events.try_recv().is_err(), "the pick was rolled back");
+
+        // the League changes again while the pick is being saved, so rolling back would undo that too
+        *recorder.meddle.lock().unwrap() = Some(Arc::clone(service.guild()));
+        match service
+            .lock("Creenis", 2, serenity::UserId(1), Box::new("Pikachu"))
+            .await
+        {
+            Err(ServiceError::ConflictError(_)) => {}
+            _ => panic!("the League changed during the save"),
+        }
+        service
+            .guild()
+            .with_league("Creenis", |league| {
+                assert!(league.is_picked("Pikachu"));
+                assert_eq!(league.player_queue(serenity::UserId(2)).unwrap().len(), 1);
+            })
+            .unwrap();
+        assert!(matches!(
+            events.try_recv().unwrap(),
+            DraftEvent::Queued { .. }
+        ));
+        assert!(matches!(
+            events.try_recv().unwrap(),
+            DraftEvent::Picked { .. }
+        ));
+
+        recorder.fail_saves.store(false, Ordering::Relaxed);
+        service
+            .lock("Creenis", 3, serenity::UserId(2), Box::new("Squirtle"))
+            .await
+            .unwrap();
+        let saved = recorder.saved.lock().unwrap();
+        let restored =
+            League::restore(&saved[0].1, |name| Some(Box::new(name.to_string()))).unwrap();
+        assert!(
+            restored.is_picked("Pikachu"),
+            "the kept pick is saved with the next change"
+        );
+        assert!(matches!(
+            events.try_recv().unwrap(),
+            DraftEvent::Picked { .. }
+        ));
+    }
+
+    #[tokio::test]
+    async fn rolled_back_changes_do_not_count_towards_rate_limits() {
+        let recorder = Arc::new(Recorder::default());
+        let service = service(&recorder);
+        service
+            .lock("Creenis", 9, serenity::UserId(1), Box::new("Pikachu"))
+            .await
+            .unwrap();
+        service
+            .lock("Creenis", 10, serenity::UserId(2), Box::new("Squirtle"))
+            .await
+            .unwrap();
+        service
+            .guild()
+            .with_league("Creenis", |league| {
+                league.set_rate_limit(
+                    RateLimitedAction::Trade,
+                    Some(RateLimit::new(1, chrono::Duration::minutes(1))),
+                )
+            })
+            .unwrap();
+
+        recorder.fail_saves.store(true, Ordering::Relaxed);
+        let trade = || {
+            service.trade(
+                "Creenis",
+                11,
+                serenity::UserId(1),
+                "Pikachu",
+                serenity::UserId(2),
+                "Squirtle",
+            )
+        };
+        match trade().await {
+            Err(ServiceError::StoreError(_)) => {}
+            _ => panic!("the store is down"),
+        }
+        recorder.fail_saves.store(false, Ordering::Relaxed);
+        trade().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn refused_announcements_wait_in_the_outbox() {
+        let recorder = Arc::new(Recorder::default());
+        let crashed = service(&recorder);
+        recorder.fail_posts.store(true, Ordering::Relaxed);
+        crashed
+            .lock("Creenis", 12, serenity::UserId(1), Box::new("Pikachu"))
+            .await
+            .unwrap();
+        crashed
+            .lock("Creenis", 13, serenity::UserId(2), Box::new("Squirtle"))
+            .await
+            .unwrap();
+        assert_eq!(crashed.outbox().len(), 2);
+        assert_eq!(recorder.pending.lock().unwrap().len(), 2);
+        match crashed.flush_outbox().await {
+            Err(ServiceError::AnnounceError(_)) => {}
+            _ => panic!("Discord is down"),
+        }
+
+        // a restart picks up where the last run left off
+        recorder.fail_posts.store(false, Ordering::Relaxed);
+        let restarted = service(&recorder);
+        assert_eq!(restarted.recover().await.unwrap(), 2);
+        let posted = recorder.posted.lock().unwrap();
+        assert!(posted[0].1.contains("**Pikachu**"));
+        assert!(posted[1].1.contains("**Squirtle**"));
+        assert!(recorder.pending.lock().unwrap().is_empty());
+    }
+}
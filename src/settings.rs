@@ -0,0 +1,158 @@
+use crate::{DraftGuild, DraftType, League, LeagueBuilder};
+use chrono::Duration;
+use poise::serenity_prelude as serenity;
+
+// how long deleted Leagues stay in the recycle bin unless the server says otherwise
+const DEFAULT_DELETED_TTL_DAYS: i64 = 30;
+
+/// Server-wide defaults for a [`DraftGuild`], for `/config`-style commands to store. Leagues started with
+/// [`DraftGuild::league_builder`] inherit them.
+#[derive(Debug, Clone)]
+pub struct GuildSettings {
+    draft_type: DraftType,
+    team_size: Option<u32>,
+    pick_time_limit: Option<Duration>,
+    admin_role: Option<serenity::RoleId>,
+    locale: Option<String>,
+    deleted_ttl: Duration,
+}
+
+impl GuildSettings {
+    /// Creates settings with no defaults beyond [`DraftType::Snake`].
+    pub fn new() -> GuildSettings {
+        GuildSettings {
+            draft_type: DraftType::Snake,
+            team_size: None,
+            pick_time_limit: None,
+            admin_role: None,
+            locale: None,
+            deleted_ttl: Duration::days(DEFAULT_DELETED_TTL_DAYS),
+        }
+    }
+    /// The [`DraftType`] new Leagues use.
+    pub fn draft_type(&self) -> DraftType {
+        self.draft_type
+    }
+    /// Sets the [`DraftType`] new Leagues use.
+    pub fn set_draft_type(&mut self, draft_type: DraftType) {
+        self.draft_type = draft_type;
+    }
+    /// The number of picks each player makes in new Leagues, if the server has a default.
+    pub fn team_size(&self) -> Option<u32> {
+        self.team_size
+    }
+    /// Sets the number of picks each player makes in new Leagues.
+    pub fn set_team_size(&mut self, team_size: Option<u32>) {
+        self.team_size = team_size;
+    }
+    /// The time each player has to pick in new Leagues, if the server has a default.
+    pub fn pick_time_limit(&self) -> Option<Duration> {
+        self.pick_time_limit
+    }
+    /// Sets the time each player has to pick in new Leagues.
+    pub fn set_pick_time_limit(&mut self, limit: Option<Duration>) {
+        self.pick_time_limit = limit;
+    }
+    /// The Discord role allowed to run admin commands in this server, if one has been set.
+    pub fn admin_role(&self) -> Option<serenity::RoleId> {
+        self.admin_role
+    }
+    /// Sets the Discord role allowed to run admin commands in this server.
+    pub fn set_admin_role(&mut self, role: Option<serenity::RoleId>) {
+        self.admin_role = role;
+    }
+    /// The server's locale (e.g. `"en-US"`), if one has been set. See [`DraftGuild::set_locale`].
+    pub fn locale(&self) -> Option<&str> {
+        self.locale.as_deref()
+    }
+    // set through DraftGuild::set_locale, which passes it on to the server's Leagues
+    pub(crate) fn set_locale(&mut self, locale: Option<String>) {
+        self.locale = locale;
+    }
+    /// How long deleted Leagues stay in the recycle bin before [`DraftGuild::purge_deleted`] drops them. Defaults to 30 days.
+    pub fn deleted_ttl(&self) -> Duration {
+        self.deleted_ttl
+    }
+    /// Sets how long deleted Leagues stay in the recycle bin.
+    pub fn set_deleted_ttl(&mut self, ttl: Duration) {
+        self.deleted_ttl = ttl;
+    }
+}
+
+impl Default for GuildSettings {
+    fn default() -> Self {
+        GuildSettings::new()
+    }
+}
+
+impl DraftGuild {
+    /// Returns the server's [`GuildSettings`].
+    pub fn settings(&self) -> &GuildSettings {
+        &self.settings
+    }
+    /// Returns the server's [`GuildSettings`] for changing. Changes apply to Leagues created afterwards.
+    pub fn settings_mut(&mut self) -> &mut GuildSettings {
+        &mut self.settings
+    }
+    /// Starts a [`LeagueBuilder`] with the server's default draft type, team size and pick time limit already set.
+    /// Any of them can still be overridden before building.
+    pub fn league_builder(&self, id: u64, name: String) -> LeagueBuilder {
+        let mut builder = League::builder(id, name).draft_type(self.settings.draft_type);
+        if let Some(team_size) = self.settings.team_size {
+            builder = builder.team_size(team_size);
+        }
+        if let Some(limit) = self.settings.pick_time_limit {
+            builder = builder.pick_time_limit(limit);
+        }
+        if let Some(locale) = &self.settings.locale {
+            builder = builder.locale(locale.clone());
+        }
+        builder
+    }
+    /// Sets the locale every League in the server writes its messages in, using the [`Translations`](crate::Translations)
+    /// registered for it, or goes back to English with None. Leagues added afterwards take it too.
+    pub fn set_locale(&mut self, locale: Option<String>) {
+        for league in self.leagues.values_mut() {
+            league.set_locale(locale.clone());
+        }
+        self.settings.set_locale(locale);
+    }
+    /// Returns true if a member with the given roles may run admin commands: they hold the server's admin role.
+    /// Without an admin role set, nobody is an admin by role, and bots should fall back to Discord permissions.
+    pub fn is_admin(&self, member_roles: &[serenity::RoleId]) -> bool {
+        match self.settings.admin_role {
+            Some(role) => member_roles.contains(&role),
+            None => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod settings_tests {
+    use super::*;
+
+    #[test]
+    fn leagues_inherit_guild_defaults() {
+        let mut guild = DraftGuild::new(1, serenity::ChannelId(2));
+        guild.settings_mut().set_draft_type(DraftType::Linear);
+        guild.settings_mut().set_team_size(Some(3));
+        let league = guild
+            .league_builder(69420, "Creenis".to_string())
+            .player(serenity::UserId(1))
+            .player(serenity::UserId(2))
+            .build()
+            .unwrap();
+        assert_eq!(league.final_pick, 5);
+        assert_eq!(league.draft_type, DraftType::Linear);
+    }
+
+    #[test]
+    fn admin_role_grants_admin() {
+        let mut guild = DraftGuild::new(1, serenity::ChannelId(2));
+        assert!(!guild.is_admin(&[serenity::RoleId(7)]));
+        guild
+            .settings_mut()
+            .set_admin_role(Some(serenity::RoleId(7)));
+        assert!(guild.is_admin(&[serenity::RoleId(3), serenity::RoleId(7)]));
+    }
+}
@@ -0,0 +1,584 @@
+use crate::profile::ProfileTally;
+use crate::{
+    ArchivedLeague, DeletedLeague, DraftGuild, DraftGuildError, GuildSettings, League, LeaguePhase,
+    PlayerProfile,
+};
+use chrono::{DateTime, Duration, Utc};
+use poise::serenity_prelude as serenity;
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError, RwLock, TryLockError};
+
+/// A thread-safe [`DraftGuild`] for busy servers running several drafts at once: every [`League`] has its own lock,
+/// so a pick in one League never waits on another.
+///
+/// SharedGuilds are what a [`DraftRegistry`](crate::DraftRegistry) holds. The guild-wide parts - its settings and the list of
+/// Leagues - sit behind read-write locks that are only held long enough to read or swap them.
+pub struct SharedGuild {
+    id: u64,
+    discord_guild: serenity::GuildId,
+    default_output: RwLock<serenity::ChannelId>,
+    settings: RwLock<GuildSettings>,
+    // k: name provided on League initialization
+    leagues: RwLock<HashMap<String, Arc<Mutex<League>>>>,
+    archives: Mutex<Vec<ArchivedLeague>>,
+    recycle_bin: Mutex<Vec<DeletedLeague>>,
+    // names of the Leagues frozen by pause_all, so resume_all leaves Leagues paused by their own commissioners alone
+    outage_paused: Mutex<HashSet<String>>,
+}
+
+impl SharedGuild {
+    /// Creates a new SharedGuild with no Leagues. See [`DraftGuild::new`].
+    pub fn new(id: u64, default_output: serenity::ChannelId) -> SharedGuild {
+        SharedGuild::from_guild(DraftGuild::new(id, default_output))
+    }
+    /// Wraps an existing [`DraftGuild`], giving each of its Leagues its own lock.
+    pub fn from_guild(guild: DraftGuild) -> SharedGuild {
+        SharedGuild {
+            id: guild.id,
+            discord_guild: guild.discord_guild(),
+            default_output: RwLock::new(guild.default_output),
+            settings: RwLock::new(guild.settings),
+            leagues: RwLock::new(
+                guild
+                    .leagues
+                    .into_iter()
+                    .map(|(name, league)| (name, Arc::new(Mutex::new(league))))
+                    .collect(),
+            ),
+            archives: Mutex::new(guild.archives),
+            recycle_bin: Mutex::new(guild.recycle_bin),
+            outage_paused: Mutex::new(HashSet::new()),
+        }
+    }
+    /// Returns the SharedGuild's id.
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+    /// Returns the Discord server the guild belongs to. See [`DraftGuild::discord_guild`].
+    pub fn discord_guild(&self) -> serenity::GuildId {
+        self.discord_guild
+    }
+    /// Returns the channel Leagues without an output channel of their own post to (see [`League::output_channel`]).
+    pub fn default_output(&self) -> serenity::ChannelId {
+        *self
+            .default_output
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+    /// Changes the channel Leagues without an output channel of their own post to, and returns the old one.
+    /// See [`DraftGuild::set_default_output`].
+    pub fn set_default_output(&self, channel: serenity::ChannelId) -> serenity::ChannelId {
+        std::mem::replace(
+            &mut self
+                .default_output
+                .write()
+                .unwrap_or_else(PoisonError::into_inner),
+            channel,
+        )
+    }
+    /// Returns a copy of the server's [`GuildSettings`].
+    pub fn settings(&self) -> GuildSettings {
+        self.settings
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+    /// Changes the server's [`GuildSettings`] with f. Changes apply to Leagues created afterwards.
+    pub fn update_settings<R>(&self, f: impl FnOnce(&mut GuildSettings) -> R) -> R {
+        f(&mut self
+            .settings
+            .write()
+            .unwrap_or_else(PoisonError::into_inner))
+    }
+    /// Sets the locale every League in the guild writes its messages in. See [`DraftGuild::set_locale`].
+    pub fn set_locale(&self, locale: Option<String>) {
+        let leagues: Vec<Arc<Mutex<League>>> = {
+            // Leagues are added under the write lock, so one added meanwhile is either listed here or sees the new locale
+            let leagues = self.leagues.read().unwrap_or_else(PoisonError::into_inner);
+            self.update_settings(|settings| settings.set_locale(locale));
+            leagues.values().cloned().collect()
+        };
+        for league in leagues {
+            let mut league = SharedGuild::lock(&league);
+            // the server's locale as it is now, in case it changed again while this League was held
+            league.set_locale(self.settings().locale().map(String::from));
+        }
+    }
+    /// Adds a [`League`] to the guild, and returns its lock. If the guild has a locale, the League writes its messages in it.
+    ///
+    /// # Errors
+    ///
+    /// If the guild already has a League with the same name, returns [`DraftGuildError::LeagueNameAlreadyInUseError`].
+    pub fn add_league(&self, mut league: League) -> Result<Arc<Mutex<League>>, DraftGuildError> {
+        let mut leagues = self.leagues.write().unwrap_or_else(PoisonError::into_inner);
+        if leagues.contains_key(&league.name) {
+            return Err(DraftGuildError::LeagueNameAlreadyInUseError);
+        }
+        if let Some(locale) = self.settings().locale() {
+            league.set_locale(Some(locale.to_string()));
+        }
+        let name = league.name.clone();
+        let league = Arc::new(Mutex::new(league));
+        leagues.insert(name, Arc::clone(&league));
+        Ok(league)
+    }
+    /// Returns a [`League`]'s lock by name, if it exists. Lock it with [`SharedGuild::lock`].
+    pub fn league(&self, name: &str) -> Option<Arc<Mutex<League>>> {
+        self.leagues
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(name)
+            .cloned()
+    }
+    /// Runs f on a [`League`] by name, holding only that League's lock for the call, and returns its result.
+    ///
+    /// # Errors
+    ///
+    /// If the League does not exist, returns [`DraftGuildError::LeagueNotFoundError`].
+    pub fn with_league<R>(
+        &self,
+        name: &str,
+        f: impl FnOnce(&mut League) -> R,
+    ) -> Result<R, DraftGuildError> {
+        let Some(league) = self.league(name) else {
+            return Err(DraftGuildError::LeagueNotFoundError);
+        };
+        let mut league = SharedGuild::lock(&league);
+        Ok(f(&mut league))
+    }
+    /// Moves a [`League`] to the guild's recycle bin by name. See [`DraftGuild::delete_league`].
+    ///
+    /// # Errors
+    ///
+    /// If the League does not exist, returns [`DraftGuildError::LeagueNotFoundError`].
+    ///
+    /// If a command is still holding the League's lock, returns [`DraftGuildError::LeagueInUseError`]; try again once it is done.
+    pub fn delete_league(&self, name: &str, now: DateTime<Utc>) -> Result<(), DraftGuildError> {
+        let league = SharedGuild::take_league(
+            &mut self.leagues.write().unwrap_or_else(PoisonError::into_inner),
+            name,
+        )?;
+        self.outage_paused().remove(name);
+        self.recycle_bin
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push(DeletedLeague::new(league, now));
+        Ok(())
+    }
+    /// Puts the most recently deleted League with the name back in the guild, and returns its lock. See [`DraftGuild::restore_league`].
+    ///
+    /// # Errors
+    ///
+    /// If there is no League with the name in the recycle bin, returns [`DraftGuildError::LeagueNotFoundError`].
+    ///
+    /// If another League has taken the name since, returns [`DraftGuildError::LeagueNameAlreadyInUseError`].
+    pub fn restore_league(&self, name: &str) -> Result<Arc<Mutex<League>>, DraftGuildError> {
+        let mut leagues = self.leagues.write().unwrap_or_else(PoisonError::into_inner);
+        let mut recycle_bin = self
+            .recycle_bin
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        let idx = recycle_bin
+            .iter()
+            .rposition(|d| d.name() == name)
+            .ok_or(DraftGuildError::LeagueNotFoundError)?;
+        if leagues.contains_key(name) {
+            return Err(DraftGuildError::LeagueNameAlreadyInUseError);
+        }
+        let league = Arc::new(Mutex::new(recycle_bin.remove(idx).into_league()));
+        leagues.insert(name.to_string(), league.clone());
+        Ok(league)
+    }
+    /// Drops Leagues deleted longer ago than the server's deleted-League TTL for good, and returns them.
+    /// See [`DraftGuild::purge_deleted`].
+    pub fn purge_deleted(&self, now: DateTime<Utc>) -> Vec<League> {
+        let ttl = self.settings().deleted_ttl();
+        let mut recycle_bin = self
+            .recycle_bin
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        let (expired, kept) = std::mem::take(&mut *recycle_bin)
+            .into_iter()
+            .partition(|d| now - d.deleted() >= ttl);
+        *recycle_bin = kept;
+        expired
+            .into_iter()
+            .map(DeletedLeague::into_league)
+            .collect()
+    }
+    /// Returns the lock of every [`League`] with no activity for at least threshold before now, least recently active first.
+    /// See [`DraftGuild::stale_leagues`].
+    ///
+    /// A League a command is holding is in use, so it is left out rather than waited for.
+    pub fn stale_leagues(
+        &self,
+        threshold: Duration,
+        now: DateTime<Utc>,
+    ) -> Vec<Arc<Mutex<League>>> {
+        self.stale(threshold, now)
+            .into_iter()
+            .map(|(_, league)| league)
+            .collect()
+    }
+    /// Deletes every League [`SharedGuild::stale_leagues`] returns and returns them, least recently active first.
+    /// See [`DraftGuild::purge_stale`].
+    ///
+    /// A League a command is holding is in use, so it is kept.
+    pub fn purge_stale(&self, threshold: Duration, now: DateTime<Utc>) -> Vec<League> {
+        let stale = self.stale(threshold, now);
+        let mut leagues = self.leagues.write().unwrap_or_else(PoisonError::into_inner);
+        let mut purged = Vec::new();
+        for (name, league) in stale {
+            // the name may have gone to another League since it was checked
+            if !leagues.get(&name).is_some_and(|l| Arc::ptr_eq(l, &league)) {
+                continue;
+            }
+            drop(league);
+            let Ok(league) = SharedGuild::take_league(&mut leagues, &name) else {
+                continue;
+            };
+            // a command may have used it in the meantime
+            if now - league.last_activity() < threshold {
+                leagues.insert(name, Arc::new(Mutex::new(league)));
+                continue;
+            }
+            self.outage_paused().remove(&name);
+            purged.push(league);
+        }
+        purged
+    }
+    // the names and locks of every idle League no command is holding, least recently active first
+    fn stale(&self, threshold: Duration, now: DateTime<Utc>) -> Vec<(String, Arc<Mutex<League>>)> {
+        let mut stale: Vec<(DateTime<Utc>, String, Arc<Mutex<League>>)> = self
+            .league_locks()
+            .into_iter()
+            .filter_map(|(name, league)| {
+                let last_activity = SharedGuild::try_lock(&league)?.last_activity();
+                (now - last_activity >= threshold).then_some((last_activity, name, league))
+            })
+            .collect();
+        stale.sort_by_key(|(last_activity, _, _)| *last_activity);
+        stale
+            .into_iter()
+            .map(|(_, name, league)| (name, league))
+            .collect()
+    }
+    /// Returns a user's [`PlayerProfile`]. Each League is locked in turn to read it. See [`DraftGuild::profile`].
+    pub fn profile(&self, user: serenity::UserId) -> PlayerProfile {
+        self.tally(Some(user)).remove(0)
+    }
+    /// Returns the [`PlayerProfile`] of everyone who has had a seat in one of the guild's Leagues, ordered by user ID.
+    /// See [`DraftGuild::profiles`].
+    pub fn profiles(&self) -> Vec<PlayerProfile> {
+        self.tally(None)
+    }
+    // counts current leagues one lock at a time, then archived ones
+    fn tally(&self, only: Option<serenity::UserId>) -> Vec<PlayerProfile> {
+        let mut tally = ProfileTally::new(only);
+        let leagues: Vec<Arc<Mutex<League>>> = self
+            .leagues
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .values()
+            .cloned()
+            .collect();
+        for league in leagues {
+            tally.count(&SharedGuild::lock(&league));
+        }
+        self.with_archives(|archives| {
+            for archived in archives {
+                tally.count(archived.league());
+            }
+            tally.finish(archives)
+        })
+    }
+    /// Freezes the clock of every active, unpaused [`League`] in the guild, e.g. during a Discord outage, and returns how many
+    /// were paused. Each League is locked in turn.
+    pub fn pause_all(&self, now: DateTime<Utc>) -> usize {
+        let mut paused = 0;
+        for (name, league) in self.league_locks() {
+            let mut league = SharedGuild::lock(&league);
+            if league.active() && !league.paused() {
+                league.pause(now);
+                self.outage_paused().insert(name);
+                paused += 1;
+            }
+        }
+        paused
+    }
+    /// Resumes every [`League`] paused by [`SharedGuild::pause_all`], and returns how many were resumed.
+    ///
+    /// Leagues that were paused on their own before the outage stay paused.
+    pub fn resume_all(&self, now: DateTime<Utc>) -> usize {
+        let names = std::mem::take(&mut *self.outage_paused());
+        let mut resumed = 0;
+        for name in names {
+            let Some(league) = self.league(&name) else {
+                continue;
+            };
+            let mut league = SharedGuild::lock(&league);
+            if league.paused() {
+                league.resume(now);
+                resumed += 1;
+            }
+        }
+        resumed
+    }
+    /// Returns true if Leagues in the guild are frozen by [`SharedGuild::pause_all`].
+    pub fn is_outage_paused(&self) -> bool {
+        !self.outage_paused().is_empty()
+    }
+    fn outage_paused(&self) -> MutexGuard<'_, HashSet<String>> {
+        self.outage_paused
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+    /// Runs f on the guild's recycle bin, oldest deletion first, and returns its result.
+    pub fn with_deleted<R>(&self, f: impl FnOnce(&[DeletedLeague]) -> R) -> R {
+        f(&self
+            .recycle_bin
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner))
+    }
+    /// Renames a [`League`], keeping its key in the guild and its own name in step. Waits for any command holding the League
+    /// to finish with it. See [`DraftGuild::rename_league`].
+    ///
+    /// # Errors
+    ///
+    /// If there is no League named old, returns [`DraftGuildError::LeagueNotFoundError`].
+    ///
+    /// If another League is already named new, returns [`DraftGuildError::LeagueNameAlreadyInUseError`].
+    pub fn rename_league(&self, old: &str, new: String) -> Result<(), DraftGuildError> {
+        let league = self
+            .league(old)
+            .ok_or(DraftGuildError::LeagueNotFoundError)?;
+        // the League is locked before the list, so a command holding it only holds up this rename
+        let mut held = SharedGuild::lock(&league);
+        let mut leagues = self.leagues.write().unwrap_or_else(PoisonError::into_inner);
+        if !leagues.get(old).is_some_and(|l| Arc::ptr_eq(l, &league)) {
+            return Err(DraftGuildError::LeagueNotFoundError);
+        }
+        if old != new && leagues.contains_key(&new) {
+            return Err(DraftGuildError::LeagueNameAlreadyInUseError);
+        }
+        held.name = new.clone();
+        let league = leagues.remove(old).unwrap();
+        if self.outage_paused().remove(old) {
+            self.outage_paused().insert(new.clone());
+        }
+        leagues.insert(new, league);
+        Ok(())
+    }
+    /// Returns the names of every League in the guild, in no particular order.
+    pub fn league_names(&self) -> Vec<String> {
+        self.leagues
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .keys()
+            .cloned()
+            .collect()
+    }
+    /// Moves a finished League into the guild's archives. See [`DraftGuild::archive_league`].
+    ///
+    /// # Errors
+    ///
+    /// If there is no League with the name, returns [`DraftGuildError::LeagueNotFoundError`].
+    ///
+    /// If the League's draft has not finished, returns [`DraftGuildError::LeagueNotFinishedError`].
+    ///
+    /// If a command is still holding the League's lock (see [`SharedGuild::league`]), returns [`DraftGuildError::LeagueInUseError`];
+    /// try again once it is done.
+    pub fn archive_league(&self, name: &str, now: DateTime<Utc>) -> Result<(), DraftGuildError> {
+        let finished = |league: &League| {
+            matches!(
+                league.phase(),
+                LeaguePhase::PostDraft | LeaguePhase::Archived
+            )
+        };
+        let league = self
+            .league(name)
+            .ok_or(DraftGuildError::LeagueNotFoundError)?;
+        if !finished(&SharedGuild::lock(&league)) {
+            return Err(DraftGuildError::LeagueNotFinishedError);
+        }
+        let mut leagues = self.leagues.write().unwrap_or_else(PoisonError::into_inner);
+        // the name may have gone to another League since it was checked
+        if !leagues.get(name).is_some_and(|l| Arc::ptr_eq(l, &league)) {
+            return Err(DraftGuildError::LeagueNotFoundError);
+        }
+        drop(league);
+        let league = SharedGuild::take_league(&mut leagues, name)?;
+        // a command may have rewound it in the meantime
+        if !finished(&league) {
+            leagues.insert(name.to_string(), Arc::new(Mutex::new(league)));
+            return Err(DraftGuildError::LeagueNotFinishedError);
+        }
+        drop(leagues);
+        self.archives
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push(ArchivedLeague::new(league, now));
+        Ok(())
+    }
+    /// Runs f on the guild's archived Leagues, oldest first, and returns its result.
+    pub fn with_archives<R>(&self, f: impl FnOnce(&[ArchivedLeague]) -> R) -> R {
+        f(&self.archives.lock().unwrap_or_else(PoisonError::into_inner))
+    }
+    // removes a League from the guild, as long as no command is holding it
+    fn take_league(
+        leagues: &mut HashMap<String, Arc<Mutex<League>>>,
+        name: &str,
+    ) -> Result<League, DraftGuildError> {
+        let league = leagues
+            .remove(name)
+            .ok_or(DraftGuildError::LeagueNotFoundError)?;
+        // commands still holding the League keep a copy of the Arc; take the League from behind it
+        match Arc::try_unwrap(league) {
+            Ok(league) => Ok(league.into_inner().unwrap_or_else(PoisonError::into_inner)),
+            Err(shared) => {
+                leagues.insert(name.to_string(), shared);
+                Err(DraftGuildError::LeagueInUseError)
+            }
+        }
+    }
+    // every League's name and lock, copied out so no League is locked while the list is
+    fn league_locks(&self) -> Vec<(String, Arc<Mutex<League>>)> {
+        self.leagues
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .iter()
+            .map(|(name, league)| (name.clone(), Arc::clone(league)))
+            .collect()
+    }
+    // locks a League unless a command is holding it
+    fn try_lock(league: &Mutex<League>) -> Option<MutexGuard<'_, League>> {
+        match league.try_lock() {
+            Ok(league) => Some(league),
+            Err(TryLockError::Poisoned(poisoned)) => Some(poisoned.into_inner()),
+            Err(TryLockError::WouldBlock) => None,
+        }
+    }
+    /// Locks a League taken from the guild, recovering it if an earlier holder panicked.
+    pub fn lock(league: &Mutex<League>) -> MutexGuard<'_, League> {
+        league.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+#[cfg(test)]
+mod shared_guild_tests {
+    use super::*;
+    use crate::test_support::new_league;
+    use crate::DraftType;
+
+    fn league(name: &str) -> League {
+        let mut league = new_league(2, DraftType::Snake, 2);
+        league.name = name.to_string();
+        league
+    }
+
+    #[test]
+    fn leagues_lock_independently() {
+        let guild = SharedGuild::new(1, serenity::ChannelId(2));
+        guild.add_league(league("Creenis")).unwrap();
+        guild.add_league(league("Bofa")).unwrap();
+        match guild.add_league(league("Creenis")) {
+            Err(DraftGuildError::LeagueNameAlreadyInUseError) => {}
+            _ => panic!("Creenis already exists"),
+        }
+        let creenis = guild.league("Creenis").unwrap();
+        let _held = SharedGuild::lock(&creenis);
+        // another League is free while Creenis is held
+        let activated = guild
+            .with_league("Bofa", |league| league.activate().is_ok())
+            .unwrap();
+        assert!(activated);
+        assert!(guild.league("Bofa").unwrap().try_lock().is_ok());
+        assert!(creenis.try_lock().is_err());
+    }
+
+    #[test]
+    fn wraps_an_existing_guild() {
+        let mut guild = DraftGuild::new(1, serenity::ChannelId(2));
+        guild.settings_mut().set_team_size(Some(3));
+        guild.add_league(league("Creenis")).unwrap();
+        let shared = SharedGuild::from_guild(guild);
+        assert_eq!(shared.settings().team_size(), Some(3));
+        shared.update_settings(|s| s.set_team_size(None));
+        assert_eq!(shared.settings().team_size(), None);
+        assert_eq!(
+            shared.set_default_output(serenity::ChannelId(3)),
+            serenity::ChannelId(2)
+        );
+        assert_eq!(shared.default_output(), serenity::ChannelId(3));
+        assert_eq!(shared.league_names(), vec!["Creenis".to_string()]);
+        let held = shared.league("Creenis").unwrap();
+        match shared.delete_league("Creenis", Utc::now()) {
+            Err(DraftGuildError::LeagueInUseError) => {}
+            _ => panic!("Creenis is still held"),
+        }
+        drop(held);
+        shared.delete_league("Creenis", Utc::now()).unwrap();
+        match shared.with_league("Creenis", |_| ()) {
+            Err(DraftGuildError::LeagueNotFoundError) => {}
+            _ => panic!("Creenis was deleted"),
+        }
+        assert_eq!(shared.with_deleted(|deleted| deleted.len()), 1);
+        shared.restore_league("Creenis").unwrap();
+        assert_eq!(shared.league_names(), vec!["Creenis".to_string()]);
+    }
+
+    #[test]
+    fn waiting_on_a_league_leaves_the_rest_free() {
+        let guild = SharedGuild::new(1, serenity::ChannelId(2));
+        guild.add_league(league("Creenis")).unwrap();
+        guild.add_league(league("Bofa")).unwrap();
+        let creenis = guild.league("Creenis").unwrap();
+        let held = SharedGuild::lock(&creenis);
+        std::thread::scope(|scope| {
+            let renamed = scope.spawn(|| guild.rename_league("Creenis", "Ash".to_string()));
+            let relocalized = scope.spawn(|| guild.set_locale(Some("fr".to_string())));
+            // both are waiting on Creenis, which must not hold up Bofa or the list of Leagues
+            std::thread::sleep(std::time::Duration::from_millis(50));
+            assert!(guild
+                .with_league("Bofa", |league| league.activate())
+                .is_ok());
+            guild.add_league(league("Misty")).unwrap();
+            drop(held);
+            renamed.join().unwrap().unwrap();
+            relocalized.join().unwrap();
+        });
+        assert_eq!(SharedGuild::lock(&creenis).name, "Ash");
+        assert_eq!(SharedGuild::lock(&creenis).locale(), Some("fr"));
+        let misty = guild.league("Misty").unwrap();
+        assert_eq!(SharedGuild::lock(&misty).locale(), Some("fr"));
+    }
+
+    #[test]
+    fn idle_leagues_are_purged() {
+        let mut guild = DraftGuild::new(1, serenity::ChannelId(2));
+        for name in ["Creenis", "Bofa", "Ash"] {
+            let mut league = league(name);
+            league.last_activity -= Duration::days(31);
+            guild.add_league(league).unwrap();
+        }
+        let guild = SharedGuild::from_guild(guild);
+        guild
+            .with_league("Ash", |league| league.activate())
+            .unwrap()
+            .unwrap();
+
+        let now = Utc::now();
+        assert_eq!(guild.stale_leagues(Duration::days(30), now).len(), 2);
+        let bofa = guild.league("Bofa").unwrap();
+        let held = SharedGuild::lock(&bofa);
+        // a command is holding Bofa, which is skipped instead of waited for
+        assert_eq!(guild.stale_leagues(Duration::days(30), now).len(), 1);
+        let purged = guild.purge_stale(Duration::days(30), now);
+        assert_eq!(purged.len(), 1);
+        assert_eq!(purged[0].name, "Creenis");
+        drop(held);
+        drop(bofa);
+        assert_eq!(guild.purge_stale(Duration::days(30), now).len(), 1);
+        assert_eq!(guild.league_names(), vec!["Ash".to_string()]);
+    }
+}
@@ -0,0 +1,288 @@
+use crate::{DraftPool, DraftType, League, LeagueError};
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Mutex, PoisonError};
+
+/// Links the pools of several [`League`]s drafting from the same items at once, e.g. East and West divisions drafting
+/// simultaneously, so an item picked in one League is gone for all of them.
+///
+/// Each League keeps its own [`DraftPool`] of the same items, and the SharedPool records which League took each item.
+/// Clones share the same record, so give every League a clone with [`League::share_pool`].
+/// A pick is only locked in once its League has claimed the item, and claims are atomic: if two Leagues try to lock the
+/// same item at the same time, exactly one of them gets it.
+///
+/// Items with copies (see [`DraftPool::with_copies`]) are not shared out: the first League to pick one has every copy.
+///
+/// Claims are recorded by League id, so every League sharing a pool needs its own id.
+#[derive(Clone, Default)]
+pub struct SharedPool {
+    // k: item name, v: id of the League that took it
+    claims: Arc<Mutex<HashMap<String, u64>>>,
+    // ids of the Leagues sharing the pool
+    leagues: Arc<Mutex<HashSet<u64>>>,
+}
+
+impl SharedPool {
+    /// Creates a SharedPool with nothing taken.
+    pub fn new() -> SharedPool {
+        SharedPool::default()
+    }
+    /// Returns the id of the League that took an item, if any League has.
+    pub fn taken_by(&self, name: &str) -> Option<u64> {
+        self.claims
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(name)
+            .copied()
+    }
+    /// Returns the number of items taken across every League.
+    pub fn len(&self) -> usize {
+        self.claims
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .len()
+    }
+    /// Returns true if no League has taken anything yet.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    // returns false if another League sharing the pool has the id
+    fn join(&self, league: u64) -> bool {
+        self.leagues
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(league)
+    }
+    // true if both are clones of the same SharedPool
+    fn same_pool(&self, other: &SharedPool) -> bool {
+        Arc::ptr_eq(&self.claims, &other.claims)
+    }
+    // returns false if another League has already taken the item
+    fn claim(&self, name: &str, league: u64) -> bool {
+        let mut claims = self.claims.lock().unwrap_or_else(PoisonError::into_inner);
+        *claims.entry(name.to_string()).or_insert(league) == league
+    }
+    // lets go of an item the League claimed, e.g. for a pick that was rolled back
+    pub(crate) fn release(&self, name: &str, league: u64) {
+        let mut claims = self.claims.lock().unwrap_or_else(PoisonError::into_inner);
+        if claims.get(name) == Some(&league) {
+            claims.remove(name);
+        }
+    }
+    // claims every item or none of them, returning the positions of the items another League has taken
+    pub(crate) fn claim_all(&self, names: &[&str], league: u64) -> Result<(), Vec<usize>> {
+        let mut claims = self.claims.lock().unwrap_or_else(PoisonError::into_inner);
+        let taken: Vec<usize> = names
+            .iter()
+            .enumerate()
+            .filter(|(_, name)| matches!(claims.get(**name), Some(other) if *other != league))
+            .map(|(idx, _)| idx)
+            .collect();
+        if !taken.is_empty() {
+            return Err(taken);
+        }
+        for name in names {
+            claims.entry(name.to_string()).or_insert(league);
+        }
+        Ok(())
+    }
+}
+
+impl League {
+    /// Links the League's [`DraftPool`] to other Leagues drafting from the same items through a [`SharedPool`].
+    ///
+    /// # Errors
+    ///
+    /// If the league is active, returns [`LeagueError::LeagueActiveError`].
+    ///
+    /// If the draft is already over, returns [`LeagueError::WrongPhaseError`].
+    ///
+    /// If the League has no pool, returns [`LeagueError::NoPoolError`].
+    ///
+    /// Rochester, Winston, Grid and Solomon drafts pick from their own packs or decks, so they cannot share a pool - returns
+    /// [`LeagueError::WrongDraftTypeError`].
+    ///
+    /// If another League sharing the pool has the same id, returns [`LeagueError::LeagueIdInUseError`], since the pool could
+    /// not tell their picks apart.
+    pub fn share_pool(&mut self, shared: SharedPool) -> Result<(), LeagueError> {
+        self.require_pre_draft()?;
+        if matches!(
+            self.draft_type,
+            DraftType::Rochester | DraftType::Winston | DraftType::Grid | DraftType::Solomon
+        ) {
+            return Err(LeagueError::WrongDraftTypeError);
+        }
+        if self.pool.is_none() {
+            return Err(LeagueError::NoPoolError);
+        }
+        let rejoining = matches!(&self.shared_pool, Some(current) if current.same_pool(&shared));
+        if !rejoining && !shared.join(self.id) {
+            return Err(LeagueError::LeagueIdInUseError);
+        }
+        self.shared_pool = Some(shared);
+        Ok(())
+    }
+    /// Returns the [`SharedPool`] the League's pool is linked to, if any.
+    pub fn shared_pool(&self) -> Option<&SharedPool> {
+        self.shared_pool.as_ref()
+    }
+    /// Removes items other Leagues have taken from the League's [`DraftPool`], e.g. before showing it, and returns the pool.
+    /// Picks and autopicks do this for the items they need, so the pool can list a few taken items in between.
+    ///
+    /// # Errors
+    ///
+    /// If the League has no pool, returns [`LeagueError::NoPoolError`].
+    pub fn sync_pool(&mut self) -> Result<&DraftPool, LeagueError> {
+        let Some(pool) = &mut self.pool else {
+            return Err(LeagueError::NoPoolError);
+        };
+        if let Some(shared) = &self.shared_pool {
+            let taken: Vec<String> = pool
+                .iter()
+                .map(|i| i.name())
+                .filter(|name| matches!(shared.taken_by(name), Some(league) if league != self.id))
+                .map(String::from)
+                .collect();
+            for name in taken {
+                pool.take(&name);
+            }
+        }
+        Ok(pool)
+    }
+    // lets other Leagues pick an item that went back into the pool again, once no seat in this League holds it
+    pub(crate) fn release_shared(&self, name: &str) {
+        if let Some(shared) = &self.shared_pool {
+            if !self.is_picked(name) {
+                shared.release(name, self.id);
+            }
+        }
+    }
+    // claims an item for this League before it is picked, dropping it from the pool if another League beat us to it
+    pub(crate) fn claim_shared(&mut self, name: &str) -> Result<(), LeagueError> {
+        let Some(shared) = &self.shared_pool else {
+            return Ok(());
+        };
+        if shared.claim(name, self.id) {
+            return Ok(());
+        }
+        if let Some(pool) = &mut self.pool {
+            pool.take(name);
+        }
+        Err(LeagueError::PickConflictError)
+    }
+}
+
+#[cfg(test)]
+mod shared_pool_tests {
+    use super::*;
+    use crate::test_support::{new_league, pokemon};
+    use crate::{ForfeitPolicy, LeaguePhase};
+    use poise::serenity_prelude as serenity;
+    use std::sync::Mutex;
+
+    fn league(id: u64, shared: &SharedPool) -> League {
+        let mut league = League::new(
+            &[serenity::UserId(id), serenity::UserId(id + 1)],
+            id,
+            "Creenis".to_string(),
+            None,
+            DraftType::Snake,
+            2,
+        );
+        let pool = ["Pikachu", "Eevee", "Snorlax", "Mew"]
+            .iter()
+            .map(|n| pokemon(n))
+            .collect();
+        league.set_pool(DraftPool::new(pool)).unwrap();
+        league.share_pool(shared.clone()).unwrap();
+        league.activate().unwrap();
+        league
+    }
+
+    #[test]
+    fn picks_in_one_league_take_items_from_both() {
+        let shared = SharedPool::new();
+        let mut east = league(1, &shared);
+        let mut west = league(10, &shared);
+        east.lock(pokemon("Pikachu")).unwrap();
+        assert_eq!(shared.taken_by("Pikachu"), Some(1));
+        match west.lock(pokemon("Pikachu")) {
+            Err(LeagueError::PickConflictError) => {}
+            _ => panic!("East took Pikachu"),
+        }
+        assert!(!west.pool().unwrap().contains("Pikachu"));
+        east.lock(pokemon("Eevee")).unwrap();
+        assert_eq!(west.sync_pool().unwrap().len(), 2);
+        west.set_autopick(serenity::UserId(10), true).unwrap();
+        let picks = west.skip().unwrap();
+        assert!(picks.is_empty());
+        west.lock(pokemon("Snorlax")).unwrap();
+        assert!(west.autopick().is_ok());
+        assert_eq!(shared.taken_by("Mew"), Some(10));
+    }
+
+    #[test]
+    fn leagues_sharing_a_pool_need_their_own_ids() {
+        let shared = SharedPool::new();
+        league(1, &shared);
+        let mut west = new_league(2, DraftType::Snake, 2);
+        west.id = 1;
+        west.set_pool(DraftPool::new(vec![pokemon("Pikachu")]))
+            .unwrap();
+        match west.share_pool(shared.clone()) {
+            Err(LeagueError::LeagueIdInUseError) => {}
+            _ => panic!("East already has id 1"),
+        }
+        assert!(west.shared_pool().is_none());
+        west.id = 10;
+        west.share_pool(shared.clone()).unwrap();
+        // sharing the same pool again is not a second League
+        west.share_pool(shared).unwrap();
+    }
+
+    #[test]
+    fn concurrent_locks_conflict() {
+        let shared = SharedPool::new();
+        let leagues = [
+            Arc::new(Mutex::new(league(1, &shared))),
+            Arc::new(Mutex::new(league(10, &shared))),
+        ];
+        let handles: Vec<_> = leagues
+            .iter()
+            .map(|league| {
+                let league = Arc::clone(league);
+                std::thread::spawn(move || league.lock().unwrap().lock(pokemon("Mew")).is_ok())
+            })
+            .collect();
+        let won = handles
+            .into_iter()
+            .map(|h| h.join().unwrap())
+            .filter(|won| *won)
+            .count();
+        assert_eq!(won, 1);
+    }
+
+    #[test]
+    fn items_back_in_the_pool_are_released() {
+        let shared = SharedPool::new();
+        let mut east = league(1, &shared);
+        east.lock(pokemon("Pikachu")).unwrap();
+        east.rewind_to_pick(0).unwrap();
+        assert_eq!(shared.taken_by("Pikachu"), None, "the pick was rewound");
+
+        east.lock(pokemon("Pikachu")).unwrap();
+        east.lock(pokemon("Eevee")).unwrap();
+        east.forfeit_player(serenity::UserId(1), ForfeitPolicy::RemoveSeat)
+            .unwrap();
+        assert_eq!(shared.taken_by("Pikachu"), None, "its seat was removed");
+        assert_eq!(shared.taken_by("Eevee"), Some(1));
+
+        east.lock(pokemon("Snorlax")).unwrap();
+        assert_eq!(east.phase(), LeaguePhase::PostDraft);
+        east.waiver(serenity::UserId(2), "Eevee", pokemon("Mew"))
+            .unwrap();
+        assert_eq!(shared.taken_by("Eevee"), None, "it was waivered");
+        assert_eq!(shared.taken_by("Mew"), Some(1));
+        let mut west = league(10, &shared);
+        west.lock(pokemon("Eevee")).unwrap();
+    }
+}
@@ -0,0 +1,134 @@
+use crate::League;
+use serde::{Deserialize, Serialize};
+
+/// The body of a Google Sheets
+/// [`spreadsheets.values.batchUpdate`](https://developers.google.com/sheets/api/reference/rest/v4/spreadsheets.values/batchUpdate)
+/// request that mirrors a League's draft board and rosters into a spreadsheet, from [`League::sheets_update`].
+///
+/// DRFTR does not talk to Google itself: serialize this with serde_json and POST it with your bot's own credentials
+/// to `https://sheets.googleapis.com/v4/spreadsheets/{spreadsheetId}/values:batchUpdate`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SheetsUpdate {
+    /// Always `"RAW"`, so item names are never read as formulas.
+    pub value_input_option: String,
+    /// The ranges to write.
+    pub data: Vec<SheetRange>,
+}
+
+/// One block of cells in a [`SheetsUpdate`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SheetRange {
+    /// Where the block starts, in A1 notation, e.g. `'Board'!A1`.
+    pub range: String,
+    /// Always `"ROWS"`: values holds a list of rows.
+    pub major_dimension: String,
+    /// The cells, row by row.
+    pub values: Vec<Vec<String>>,
+}
+
+impl League {
+    /// Builds a [`SheetsUpdate`] writing the League's draft board to the sheet (tab) named board_sheet and its rosters to
+    /// rosters_sheet, starting at A1 in each. Send it after every pick to keep a league spreadsheet in step with the draft.
+    ///
+    /// The board has a column for each seat, headed by its [`Team`](crate::Team) name or "Seat 1", "Seat 2"..., and a row
+    /// for each round of the whole draft, so rounds not reached yet and skipped picks are written as blank cells.
+    /// The rosters have a column for each seat listing its picks as they stand now, after trades and waivers, padded with
+    /// blank cells to the largest team size so items traded away do not linger in the sheet.
+    pub fn sheets_update(&self, board_sheet: &str, rosters_sheet: &str) -> SheetsUpdate {
+        let headings: Vec<String> = self
+            .players
+            .iter()
+            .enumerate()
+            .map(|(seat, player)| self.seat_heading(seat, player))
+            .collect();
+        let seats = self.players.len();
+        let mut grid = self.board_grid();
+        let rounds = self.rounds() as usize;
+        grid.resize(rounds.max(grid.len()), vec![None; seats]);
+        let mut board = vec![std::iter::once(String::new())
+            .chain(headings.iter().cloned())
+            .collect::<Vec<String>>()];
+        for (round, picks) in grid.into_iter().enumerate() {
+            let cells = picks.into_iter().map(|i| i.unwrap_or_default().to_string());
+            board.push(
+                std::iter::once(self.round_label(round))
+                    .chain(cells)
+                    .collect(),
+            );
+        }
+        let slots = self
+            .players
+            .iter()
+            .map(|p| {
+                p.team_size
+                    .unwrap_or(self.team_size)
+                    .max(p.picks.len() as u32)
+            })
+            .max()
+            .unwrap_or(0) as usize;
+        let mut rosters = vec![headings];
+        for slot in 0..slots {
+            rosters.push(
+                self.players
+                    .iter()
+                    .map(|p| {
+                        p.picks
+                            .get(slot)
+                            .map(|i| i.name().to_string())
+                            .unwrap_or_default()
+                    })
+                    .collect(),
+            );
+        }
+        SheetsUpdate {
+            value_input_option: "RAW".to_string(),
+            data: vec![
+                SheetRange::new(board_sheet, board),
+                SheetRange::new(rosters_sheet, rosters),
+            ],
+        }
+    }
+}
+
+impl SheetRange {
+    fn new(sheet: &str, values: Vec<Vec<String>>) -> SheetRange {
+        // sheet names are quoted in A1 notation, with any quote inside doubled
+        SheetRange {
+            range: format!("'{}'!A1", sheet.replace('\'', "''")),
+            major_dimension: "ROWS".to_string(),
+            values,
+        }
+    }
+}
+
+#[cfg(test)]
+mod sheets_tests {
+
+    use crate::test_support::new_league;
+    use crate::DraftType;
+
+    #[test]
+    fn sheets_mirror_the_board_and_rosters() {
+        let mut league = new_league(2, DraftType::Snake, 2);
+        league.activate().unwrap();
+        league.lock(Box::new("Pikachu")).unwrap();
+        league.skip().unwrap();
+        league.lock(Box::new("Eevee")).unwrap();
+        let update = league.sheets_update("Board", "Ash's Rosters");
+        let board = &update.data[0];
+        assert_eq!(board.range, "'Board'!A1");
+        assert_eq!(board.values[0], vec!["", "Seat 1", "Seat 2"]);
+        assert_eq!(board.values[1], vec!["R1", "Pikachu", ""]);
+        assert_eq!(board.values[2], vec!["R2", "", "Eevee"]);
+        let rosters = &update.data[1];
+        assert_eq!(rosters.range, "'Ash''s Rosters'!A1");
+        assert_eq!(rosters.values.len(), 3);
+        assert_eq!(rosters.values[1], vec!["Pikachu", "Eevee"]);
+        assert_eq!(rosters.values[2], vec!["", ""]);
+        let json = serde_json::to_value(&update).unwrap();
+        assert_eq!(json["valueInputOption"], "RAW");
+        assert_eq!(json["data"][0]["majorDimension"], "ROWS");
+    }
+}
@@ -0,0 +1,230 @@
+use crate::{DraftItem, DraftPool, Draftable};
+use serde::Deserialize;
+use std::collections::HashSet;
+
+/// A Pokémon read by [`parse_showdown_tiers`] or [`parse_points_sheet`], with the tier and points cost a draft league gave it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TieredPokemon {
+    /// The Pokémon's name, e.g. "Landorus-Therian".
+    pub name: String,
+    /// The tier as the league writes it, e.g. "OU" or "20 Points". This is the Pokémon's category, e.g. for emoji.
+    pub tier_name: String,
+    /// The tier's rank, 1 being the best.
+    pub tier: u32,
+    /// What the Pokémon costs out of a team's points budget.
+    pub points: u32,
+}
+
+impl DraftItem for TieredPokemon {
+    fn name(&self) -> &str {
+        &self.name
+    }
+    fn category(&self) -> Option<&str> {
+        Some(&self.tier_name)
+    }
+    fn cost(&self) -> Option<u32> {
+        Some(self.points)
+    }
+    fn tier(&self) -> Option<u32> {
+        Some(self.tier)
+    }
+}
+
+/// Errors from reading a draft pool from a Pokémon points sheet or tier list, or a Magic: the Gathering cube list.
+#[derive(Debug)]
+pub enum PoolImportError {
+    /// Carries the parser's message, or the line that could not be read.
+    MalformedPoolError(String),
+    /// Carries the name of an item listed more than once.
+    DuplicateItemError(String),
+}
+
+#[derive(Deserialize)]
+struct ShowdownEntry {
+    name: Option<String>,
+    tier: Option<String>,
+}
+
+/// Reads Pokémon Showdown's tiers, as its `formats-data` exported to JSON (an object keyed by Pokémon id, each with a
+/// `"tier"` and optionally a `"name"`), into a pool costed by points.
+///
+/// points lists the tiers the league drafts from, best first, with what a Pokémon in each costs, e.g.
+/// `&[("Uber", 20), ("OU", 15), ("UU", 10)]`. Pokémon in any other tier are left out of the pool. Showdown writes
+/// tiers a Pokémon is only placed in by default in brackets, e.g. "(PU)", and these count as the tier inside them.
+/// Pokémon with no `"name"` go by their id. The pool is ordered by tier, then by id.
+///
+/// # Errors
+///
+/// If the text is not a JSON object of tiers, returns [`PoolImportError::MalformedPoolError`] with the parser's message.
+///
+/// If two Pokémon have the same name, returns [`PoolImportError::DuplicateItemError`] with the name.
+pub fn parse_showdown_tiers(
+    json: &str,
+    points: &[(&str, u32)],
+) -> Result<DraftPool, PoolImportError> {
+    let entries: serde_json::Map<String, serde_json::Value> = serde_json::from_str(json)
+        .map_err(|e| PoolImportError::MalformedPoolError(e.to_string()))?;
+    let mut pokemon = Vec::new();
+    for (id, entry) in entries {
+        let entry: ShowdownEntry = serde_json::from_value(entry)
+            .map_err(|e| PoolImportError::MalformedPoolError(format!("{id}: {e}")))?;
+        let Some(tier) = entry.tier else { continue };
+        let tier = tier.trim_start_matches('(').trim_end_matches(')');
+        let Some(rank) = points.iter().position(|(name, _)| *name == tier) else {
+            continue;
+        };
+        pokemon.push(TieredPokemon {
+            name: entry.name.unwrap_or(id),
+            tier_name: points[rank].0.to_string(),
+            tier: rank as u32 + 1,
+            points: points[rank].1,
+        });
+    }
+    into_pool(pokemon)
+}
+
+/// Reads a points sheet, the list most Pokémon draft leagues keep in a spreadsheet, pasted or exported as CSV or TSV.
+/// Two layouts are understood:
+///
+/// - A column for each points value: the first row holds the values (e.g. "20" or "20 Points") and the Pokémon worth each
+///   are listed underneath it.
+/// - A row for each Pokémon, its name then its points, optionally under a header row.
+///
+/// Tiers rank the points values from the most expensive down, and the pool is ordered the same way, then as the sheet
+/// lists them. Blank cells are ignored.
+///
+/// # Errors
+///
+/// If a row's points are not a number, returns [`PoolImportError::MalformedPoolError`] with the row.
+///
+/// If the sheet lists a Pokémon twice, returns [`PoolImportError::DuplicateItemError`] with the name.
+pub fn parse_points_sheet(text: &str) -> Result<DraftPool, PoolImportError> {
+    let rows: Vec<Vec<&str>> = text
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            let delimiter = if line.contains('\t') { '\t' } else { ',' };
+            line.split(delimiter)
+                .map(|cell| cell.trim().trim_matches('"').trim())
+                .collect()
+        })
+        .collect();
+    let Some(header) = rows.first() else {
+        return Ok(DraftPool::new(Vec::new()));
+    };
+    let headings: Vec<Option<u32>> = header.iter().map(|cell| leading_number(cell)).collect();
+    let columns = headings.iter().any(Option::is_some)
+        && headings
+            .iter()
+            .zip(header)
+            .all(|(cost, cell)| cost.is_some() || cell.is_empty());
+    let mut entries = Vec::new();
+    if columns {
+        // a column for each points value
+        for row in &rows[1..] {
+            for (cell, (cost, heading)) in row.iter().zip(headings.iter().zip(header)) {
+                if let (false, Some(cost)) = (cell.is_empty(), cost) {
+                    entries.push((*cell, *cost, Some(*heading)));
+                }
+            }
+        }
+    } else {
+        // a row for each Pokémon, under an optional header
+        for (line, row) in rows.iter().enumerate() {
+            let (name, cost) = match row.as_slice() {
+                [name, cost, ..] => (*name, leading_number(cost)),
+                _ => (row[0], None),
+            };
+            match cost {
+                Some(cost) if !name.is_empty() => entries.push((name, cost, None)),
+                None if line == 0 => {}
+                _ => return Err(PoolImportError::MalformedPoolError(row.join(","))),
+            }
+        }
+    }
+    let mut costs: Vec<u32> = entries.iter().map(|(_, cost, _)| *cost).collect();
+    costs.sort_unstable_by(|a, b| b.cmp(a));
+    costs.dedup();
+    let pokemon = entries
+        .into_iter()
+        .map(|(name, cost, heading)| TieredPokemon {
+            name: name.to_string(),
+            tier_name: heading.map_or_else(|| cost.to_string(), String::from),
+            tier: costs.iter().position(|c| *c == cost).unwrap_or(0) as u32 + 1,
+            points: cost,
+        })
+        .collect();
+    into_pool(pokemon)
+}
+
+// "20", "20 Points" and "20pts" are all worth 20
+fn leading_number(cell: &str) -> Option<u32> {
+    let digits: String = cell.chars().take_while(|c| c.is_ascii_digit()).collect();
+    digits.parse().ok()
+}
+
+// orders the Pokémon best tier first, keeping the order they were listed in within a tier
+fn into_pool(mut pokemon: Vec<TieredPokemon>) -> Result<DraftPool, PoolImportError> {
+    let mut seen = HashSet::new();
+    if let Some(duplicate) = pokemon.iter().find(|p| !seen.insert(p.name.as_str())) {
+        return Err(PoolImportError::DuplicateItemError(duplicate.name.clone()));
+    }
+    pokemon.sort_by_key(|p| p.tier);
+    Ok(DraftPool::new(
+        pokemon
+            .into_iter()
+            .map(|p| Box::new(p) as Draftable)
+            .collect(),
+    ))
+}
+
+#[cfg(test)]
+mod showdown_tests {
+    use super::*;
+
+    const SHOWDOWN: &str = r#"{
+        "bulbasaur": {"tier": "LC"},
+        "venusaur": {"name": "Venusaur", "tier": "(PU)", "doublesTier": "DUU"},
+        "landorustherian": {"name": "Landorus-Therian", "tier": "OU"},
+        "kyurem": {"name": "Kyurem", "tier": "OU"},
+        "zacian": {"name": "Zacian", "tier": "Uber"}
+    }"#;
+
+    #[test]
+    fn showdown_tiers_are_costed_by_the_league() {
+        let pool = parse_showdown_tiers(SHOWDOWN, &[("OU", 15), ("UU", 10), ("PU", 5)]).unwrap();
+        let names: Vec<&str> = pool.iter().map(|p| p.name()).collect();
+        assert_eq!(names, vec!["Kyurem", "Landorus-Therian", "Venusaur"]);
+        let venusaur = pool.get("Venusaur").unwrap();
+        assert_eq!(venusaur.category(), Some("PU"));
+        assert_eq!((venusaur.tier(), venusaur.cost()), (Some(3), Some(5)));
+        match parse_showdown_tiers("[]", &[]) {
+            Err(PoolImportError::MalformedPoolError(_)) => {}
+            _ => panic!("not a tier list"),
+        }
+    }
+
+    #[test]
+    fn points_sheets_read_in_either_layout() {
+        let columns = "20 Points\t19 Points\t17 Points\nKyurem\tGarchomp\tRotom-Wash\n\tZapdos\t\n";
+        let pool = parse_points_sheet(columns).unwrap();
+        let names: Vec<&str> = pool.iter().map(|p| p.name()).collect();
+        assert_eq!(names, vec!["Kyurem", "Garchomp", "Zapdos", "Rotom-Wash"]);
+        let zapdos = pool.get("Zapdos").unwrap();
+        assert_eq!(zapdos.category(), Some("19 Points"));
+        assert_eq!((zapdos.tier(), zapdos.cost()), (Some(2), Some(19)));
+
+        let rows = "Pokemon,Points\nGarchomp,19\n\"Kyurem\",20\n";
+        let pool = parse_points_sheet(rows).unwrap();
+        assert_eq!(pool.best_available().unwrap().name(), "Kyurem");
+        assert_eq!(pool.get("Garchomp").unwrap().category(), Some("19"));
+        match parse_points_sheet("Garchomp,19\nKyurem,lots\n") {
+            Err(PoolImportError::MalformedPoolError(row)) => assert_eq!(row, "Kyurem,lots"),
+            _ => panic!("lots is not a number"),
+        }
+        match parse_points_sheet("Garchomp,19\nGarchomp,18\n") {
+            Err(PoolImportError::DuplicateItemError(name)) => assert_eq!(name, "Garchomp"),
+            _ => panic!("Garchomp is listed twice"),
+        }
+    }
+}
@@ -0,0 +1,252 @@
+use crate::{
+    ActivePlayer, AutopickStrategy, BestAvailable, Draftable, ItemName, League, LeagueError,
+    LeaguePhase, PickSource,
+};
+use poise::serenity_prelude as serenity;
+use std::collections::HashMap;
+
+/// Picks by following a script: the first name in the list that is still available.
+///
+/// Useful with [`Simulation`] to test a particular sequence of picks. Once the script runs dry the seat picks nothing,
+/// so the simulation stops with [`LeagueError::NoAutopickAvailableError`].
+pub struct Scripted {
+    names: Vec<String>,
+}
+
+impl Scripted {
+    /// Creates a script from names in order of preference.
+    pub fn new(names: Vec<String>) -> Scripted {
+        Scripted { names }
+    }
+}
+
+impl AutopickStrategy for Scripted {
+    fn choose(&self, _player: &ActivePlayer, available: &[&Draftable]) -> Option<String> {
+        self.names
+            .iter()
+            .find(|name| available.iter().any(|a| a.name() == name.as_str()))
+            .cloned()
+    }
+}
+
+/// One pick made during a [`Simulation`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SimulatedPick {
+    /// The overall pick number, starting at 1.
+    pub pick: u32,
+    /// The seat that made the pick, starting at 0.
+    pub seat: u32,
+    /// The owner of that seat.
+    pub user: serenity::UserId,
+    /// The name of the item picked.
+    pub item: ItemName,
+}
+
+/// The outcome of a [`Simulation`]: every pick in the order it was made, and the resulting board.
+#[derive(Debug, Clone)]
+pub struct SimulationResult {
+    picks: Vec<SimulatedPick>,
+    board: Vec<Vec<String>>,
+}
+
+impl SimulationResult {
+    /// Returns every pick in the order it was made.
+    pub fn picks(&self) -> &[SimulatedPick] {
+        &self.picks
+    }
+    /// Returns the seat that made each pick, in order - the League's draft order.
+    pub fn seat_order(&self) -> Vec<u32> {
+        self.picks.iter().map(|p| p.seat).collect()
+    }
+    /// Returns the names of the items each seat ended up with, indexed by seat.
+    pub fn board(&self) -> &[Vec<String>] {
+        &self.board
+    }
+}
+
+/// Runs a [`League`]'s draft to completion without anyone at the table, so bot authors can try out a configuration
+/// before real users draft with it.
+///
+/// Each seat picks with its own [`AutopickStrategy`], or the simulation's fallback strategy if it has none. Queued picks are
+/// still honored as they are in a real draft, and a seat's strategy may also name items in its queue.
+///
+/// ```ignore
+/// let result = Simulation::new()
+///     .fallback(Box::new(RandomAvailable::with_seed(7)))
+///     .seat(0, Box::new(Scripted::new(vec!["Mahomes".to_string()])))
+///     .run(&mut league)?;
+/// ```
+pub struct Simulation {
+    seats: HashMap<u32, Box<dyn AutopickStrategy>>,
+    fallback: Box<dyn AutopickStrategy>,
+}
+
+impl Simulation {
+    /// Creates a Simulation where every seat takes the best item available.
+    pub fn new() -> Simulation {
+        Simulation {
+            seats: HashMap::new(),
+            fallback: Box::new(BestAvailable),
+        }
+    }
+    /// Sets the strategy for one seat, starting at 0.
+    pub fn seat(mut self, seat: u32, strategy: Box<dyn AutopickStrategy>) -> Simulation {
+        self.seats.insert(seat, strategy);
+        self
+    }
+    /// Sets the strategy for every seat without one of its own.
+    pub fn fallback(mut self, strategy: Box<dyn AutopickStrategy>) -> Simulation {
+        self.fallback = strategy;
+        self
+    }
+    /// Runs the League's draft from wherever it stands until the final pick, activating the League first if it has not started.
+    /// Rochester packs are revealed as they are picked clean.
+    ///
+    /// The League is drafted for real, so pass it a League built for the purpose rather than one users are drafting in.
+    ///
+    /// # Errors
+    ///
+    /// If the League has neither a [`DraftPool`](crate::DraftPool) nor packs to pick from, returns [`LeagueError::NoPoolError`].
+    ///
+    /// If the league is paused, returns [`LeagueError::LeaguePausedError`].
+    ///
+    /// If a seat's strategy picks nothing, or a Rochester draft runs out of packs, returns [`LeagueError::NoAutopickAvailableError`].
+    /// The picks made up to that point stay made.
+    pub fn run(&self, league: &mut League) -> Result<SimulationResult, LeagueError> {
+        if league.pool.is_none() && league.packs.is_none() {
+            return Err(LeagueError::NoPoolError);
+        }
+        if league.paused() {
+            return Err(LeagueError::LeaguePausedError);
+        }
+        if league.phase.is_pre_draft() {
+            league.activate()?;
+        }
+        let mut picks = Vec::new();
+        while league.phase == LeaguePhase::Drafting {
+            if let Some(packs) = &mut league.packs {
+                if packs.revealed().is_empty() && packs.reveal().is_none() {
+                    return Err(LeagueError::NoAutopickAvailableError);
+                }
+            }
+            let strategy = self
+                .seats
+                .get(&league.current_seat)
+                .unwrap_or(&self.fallback);
+            let pick = league
+                .choose_for_current(strategy.as_ref())
+                .and_then(|name| league.take_for_current(&name))
+                .ok_or(LeagueError::NoAutopickAvailableError)?;
+            let (made, _) = league.lock_private(pick, PickSource::Autopick, Vec::new());
+            for made in made {
+                picks.push(SimulatedPick {
+                    pick: picks.len() as u32 + 1,
+                    seat: made.seat,
+                    user: made.user,
+                    item: made.item,
+                });
+            }
+        }
+        let board = league
+            .players
+            .iter()
+            .map(|p| p.picks.iter().map(|i| i.name().to_string()).collect())
+            .collect();
+        Ok(SimulationResult { picks, board })
+    }
+}
+
+impl Default for Simulation {
+    fn default() -> Self {
+        Simulation::new()
+    }
+}
+
+#[cfg(test)]
+mod simulate_tests {
+    use super::*;
+    use crate::test_support::{new_league, Pokemon};
+    use crate::{DraftPool, DraftType, RandomAvailable};
+
+    fn league(players: u64, draft_type: DraftType, team_size: u32) -> League {
+        let mut league = new_league(players, draft_type, team_size);
+        let pool = (0..players * team_size as u64)
+            .map(|i| {
+                Box::new(Pokemon {
+                    name: format!("Pokemon {i}"),
+                }) as Draftable
+            })
+            .collect();
+        league.set_pool(DraftPool::new(pool)).unwrap();
+        league
+    }
+
+    #[test]
+    fn linear_simulation_fills_every_seat() {
+        let mut league = league(3, DraftType::Linear, 2);
+        let result = Simulation::new()
+            .seat(
+                2,
+                Box::new(Scripted::new(vec![
+                    "Pokemon 5".to_string(),
+                    "Pokemon 4".to_string(),
+                ])),
+            )
+            .run(&mut league)
+            .unwrap();
+        assert_eq!(result.seat_order(), vec![0, 1, 2, 0, 1, 2]);
+        assert_eq!(result.board()[2][0], "Pokemon 5");
+        assert!(result.board().iter().all(|seat| seat.len() == 2));
+        assert!(!league.active());
+    }
+
+    #[test]
+    fn random_simulations_draft_the_whole_pool() {
+        for players in 1..6 {
+            for team_size in 1..5 {
+                let mut league = league(players, DraftType::Linear, team_size);
+                let result = Simulation::new()
+                    .fallback(Box::new(RandomAvailable::with_seed(
+                        players * 31 + team_size as u64,
+                    )))
+                    .run(&mut league)
+                    .unwrap();
+                assert_eq!(result.picks().len() as u64, players * team_size as u64);
+                assert!(league.pool().unwrap().is_empty());
+            }
+        }
+    }
+
+    #[test]
+    fn snake_simulations_reverse_every_other_round() {
+        for players in 1..8 {
+            for team_size in 1..6 {
+                let mut league = league(players, DraftType::Snake, team_size);
+                let result = Simulation::new().run(&mut league).unwrap();
+                let expected: Vec<u32> = (0..team_size)
+                    .flat_map(|round| {
+                        let seats: Vec<u32> = (0..players as u32).collect();
+                        match round % 2 {
+                            0 => seats,
+                            _ => seats.into_iter().rev().collect(),
+                        }
+                    })
+                    .collect();
+                assert_eq!(result.seat_order(), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn script_running_dry_stops_simulation() {
+        let mut league = league(2, DraftType::Linear, 2);
+        match Simulation::new()
+            .fallback(Box::new(Scripted::new(vec!["Pokemon 3".to_string()])))
+            .run(&mut league)
+        {
+            Err(LeagueError::NoAutopickAvailableError) => {}
+            _ => panic!("the script only has one pick"),
+        }
+        assert_eq!(league.pool().unwrap().len(), 3);
+    }
+}
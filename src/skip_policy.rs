@@ -0,0 +1,282 @@
+use crate::{
+    BestAvailable, DraftEvent, Draftable, League, LeagueError, LeaguePhase, PickResult, PickSource,
+    Rejected,
+};
+use chrono::{DateTime, Utc};
+use poise::serenity_prelude as serenity;
+use serde::{Deserialize, Serialize};
+
+/// What happens to the seat on the clock when it is skipped with [`League::skip`], or runs out of time
+/// (see [`League::skip_if_overdue`]). Set it with [`League::set_skip_policy`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum SkipPolicy {
+    /// The seat loses the pick, and ends the draft with one item fewer than everyone else.
+    #[default]
+    LoseThePick,
+    /// The seat loses its turn but owes the pick, and can make it whenever it comes back with [`League::lock_owed`].
+    PickLaterAnyTime,
+    /// The seat picks the first item in its queue it still can. With nothing to pick, it loses the pick.
+    AutoPickFromQueue,
+    /// The seat picks with [`BestAvailable`], as if it were on autopick. With nothing to pick, it loses the pick.
+    AutoPickBestAvailable,
+    /// The draft pauses with the seat still on the clock, until someone calls [`League::resume`].
+    PauseDraft,
+}
+
+impl League {
+    /// Sets what happens to the seat on the clock when it is skipped or runs out of time. Defaults to [`SkipPolicy::LoseThePick`].
+    pub fn set_skip_policy(&mut self, policy: SkipPolicy) {
+        self.skip_policy = policy;
+    }
+    /// Returns what happens to the seat on the clock when it is skipped or runs out of time.
+    pub fn skip_policy(&self) -> SkipPolicy {
+        self.skip_policy
+    }
+    /// Skips the player on the clock like [`League::skip`] if they have missed their deadline (see [`League::overdue_players`]),
+    /// and returns any picks made. Call this from the same background task as [`League::start_if_due`].
+    ///
+    /// Returns no picks, and changes nothing, if nobody is overdue.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`League::skip`].
+    pub fn skip_if_overdue(&mut self, now: DateTime<Utc>) -> Result<Vec<PickResult>, LeagueError> {
+        if self.overdue_players(now).is_empty() {
+            return Ok(Vec::new());
+        }
+        self.skip()
+    }
+    /// Makes a pick a player owes from a turn skipped under [`SkipPolicy::PickLaterAnyTime`], and returns their picks.
+    ///
+    /// Owed picks can be made at any time while the draft is underway, paused or over, whoever is on the clock. They do not
+    /// count towards the draft's pick numbers, so the seat on the clock keeps its turn and the order carries on as before.
+    ///
+    /// # Errors
+    ///
+    /// If the player is not in the draft, returns [`LeagueError::PlayerNotFoundError`].
+    ///
+    /// If the player does not owe a pick, returns [`LeagueError::NoOwedPicksError`].
+    ///
+    /// If the draft has not started, or the League is archived, returns [`LeagueError::WrongPhaseError`].
+    ///
+    /// If the league has a [`DraftPool`](crate::DraftPool) and the pick is not in it, returns [`LeagueError::DraftableNotFoundError`]
+    /// with the pool's closest names.
+    ///
+    /// Every error comes back as a [`Rejected`] holding the pick, and leaves the League unchanged.
+    pub fn lock_owed(
+        &mut self,
+        id: serenity::UserId,
+        pick: Draftable,
+    ) -> Result<&Vec<Draftable>, Rejected> {
+        let name = self.resolve_name(pick.name()).to_string();
+        let checked = self.owed_seat(id).and_then(|seat| {
+            self.require_available(seat, &name)?;
+            self.claim_shared(&name)?;
+            Ok(seat)
+        });
+        let seat = match checked {
+            Ok(seat) => seat,
+            Err(error) => return Err(Rejected { error, item: pick }),
+        };
+        let pick = self.resolve_item(pick);
+        self.unqueue_everywhere(pick.name());
+        let pick = match &mut self.pool {
+            Some(pool) => pool.take(pick.name()).unwrap_or(pick),
+            None => pick,
+        };
+        self.record(DraftEvent::OwedPicked {
+            user: self.players[seat as usize].id,
+            item: pick.name().to_string(),
+        });
+        self.players[seat as usize].owed_picks -= 1;
+        self.give_pick(seat, pick);
+        Ok(&self.players[seat as usize].picks)
+    }
+    fn owed_seat(&self, id: serenity::UserId) -> Result<u32, LeagueError> {
+        match self.phase {
+            LeaguePhase::Drafting | LeaguePhase::Paused | LeaguePhase::PostDraft => {}
+            _ => return Err(LeagueError::WrongPhaseError),
+        }
+        let seat = self.seat_of(id)?;
+        if self.players[seat as usize].owed_picks == 0 {
+            return Err(LeagueError::NoOwedPicksError);
+        }
+        Ok(seat)
+    }
+    // deals with the seat on the clock by the League's SkipPolicy, and moves the draft on unless it pauses
+    pub(crate) fn skip_by_policy(&mut self) -> Vec<PickResult> {
+        let pick = match self.skip_policy {
+            SkipPolicy::PauseDraft => {
+                self.pause(Utc::now());
+                return Vec::new();
+            }
+            SkipPolicy::AutoPickFromQueue => {
+                self.queued_pick().map(|pick| (pick, PickSource::Queue))
+            }
+            SkipPolicy::AutoPickBestAvailable => self
+                .strategy_pick(Some(&BestAvailable))
+                .map(|pick| (pick, PickSource::Autopick)),
+            SkipPolicy::PickLaterAnyTime => {
+                let player = &mut self.players[self.current_seat as usize];
+                player.owed_picks += 1;
+                let user = player.id;
+                self.record(DraftEvent::PickOwed { user });
+                None
+            }
+            SkipPolicy::LoseThePick => None,
+        };
+        let (picks, _) = match pick {
+            Some((pick, source)) => self.lock_private(pick, source, Vec::new()),
+            None => {
+                self.record(DraftEvent::Skipped);
+                self.advance_private(Vec::new())
+            }
+        };
+        picks
+    }
+    // takes back the picks owed for skips after overall pick number pick, which are being reverted
+    pub(crate) fn forgive_owed_since(&mut self, pick: u32) {
+        let mut made = 0;
+        let mut owed = Vec::new();
+        for event in &self.events {
+            match event {
+                DraftEvent::Picked { .. } | DraftEvent::Skipped => made += 1,
+                // the skip that comes with it is pick made + 1
+                DraftEvent::PickOwed { user } => owed.push((made, *user)),
+                DraftEvent::Rewound(to) => {
+                    made = made.min(*to);
+                    owed.retain(|(before, _)| *before < *to);
+                }
+                _ => {}
+            }
+        }
+        for (_, user) in owed.into_iter().filter(|(before, _)| *before >= pick) {
+            if let Some(player) = self.get_player_mut(user) {
+                player.owed_picks = player.owed_picks.saturating_sub(1);
+            }
+        }
+    }
+    // replays a PickOwed event
+    pub(crate) fn owe_pick(&mut self, user: serenity::UserId) -> Result<(), LeagueError> {
+        if self.phase != LeaguePhase::Drafting {
+            return Err(LeagueError::LeagueInactiveError);
+        }
+        let player = self
+            .get_player_mut(user)
+            .ok_or(LeagueError::PlayerNotFoundError)?;
+        player.owed_picks += 1;
+        self.record(DraftEvent::PickOwed { user });
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod skip_policy_tests {
+    use super::*;
+    use crate::test_support::new_league;
+    use crate::{DraftPool, DraftType, Draftable};
+    use chrono::Duration;
+
+    fn drafting(policy: SkipPolicy) -> League {
+        let mut league = new_league(2, DraftType::Snake, 2);
+        let pool = ["Pikachu", "Bulbasaur", "Charmander", "Squirtle"]
+            .iter()
+            .map(|name| Box::new(*name) as Draftable)
+            .collect();
+        league.set_pool(DraftPool::new(pool)).unwrap();
+        league.set_skip_policy(policy);
+        league.activate().unwrap();
+        league
+    }
+
+    #[test]
+    fn skipped_seats_follow_the_policy() {
+        let mut league = drafting(SkipPolicy::AutoPickBestAvailable);
+        let picks = league.skip().unwrap();
+        assert_eq!(picks[0].item, "Pikachu");
+        assert_eq!(picks[0].source, PickSource::Autopick);
+
+        let mut league = drafting(SkipPolicy::AutoPickFromQueue);
+        league
+            .add_to_player_queue(serenity::UserId(1), Box::new("Squirtle"))
+            .unwrap();
+        league.skip().unwrap();
+        league.skip().unwrap();
+        assert_eq!(
+            league.player_picks(serenity::UserId(1)).unwrap()[0].name(),
+            "Squirtle"
+        );
+        match league.player_picks(serenity::UserId(2)) {
+            Err(LeagueError::PlayerPicksEmptyError) => {}
+            _ => panic!("seat 1 has nothing queued"),
+        }
+
+        let mut league = drafting(SkipPolicy::PauseDraft);
+        assert!(league.skip().unwrap().is_empty());
+        assert!(league.paused());
+        assert_eq!(league.current_seat, 0);
+    }
+
+    #[test]
+    fn overdue_seats_owe_their_pick() {
+        let mut league = drafting(SkipPolicy::PickLaterAnyTime);
+        league.set_pick_time_limit(Some(Duration::minutes(1)));
+        assert!(league.skip_if_overdue(Utc::now()).unwrap().is_empty());
+        assert_eq!(league.current_seat, 0, "nobody is overdue yet");
+        league
+            .skip_if_overdue(Utc::now() + Duration::minutes(2))
+            .unwrap();
+        assert_eq!(league.current_seat, 1);
+        assert_eq!(
+            league.get_player(serenity::UserId(1)).unwrap().owed_picks(),
+            1
+        );
+
+        let mut replayed = new_league(2, DraftType::Snake, 2);
+        replayed
+            .replay(league.events(), |name| Some(Box::new(name.to_string())))
+            .unwrap();
+        assert_eq!(
+            replayed
+                .get_player(serenity::UserId(1))
+                .unwrap()
+                .owed_picks(),
+            1
+        );
+        league.rewind_to_pick(0).unwrap();
+        assert_eq!(
+            league.get_player(serenity::UserId(1)).unwrap().owed_picks(),
+            0,
+            "the skip was reverted"
+        );
+    }
+
+    #[test]
+    fn owed_picks_are_made_out_of_turn() {
+        let mut league = drafting(SkipPolicy::PickLaterAnyTime);
+        league.skip().unwrap();
+        let pick_number = league.pick_number();
+        let picks = league
+            .lock_owed(serenity::UserId(1), Box::new("Pikachu"))
+            .unwrap();
+        assert_eq!(picks[0].name(), "Pikachu");
+        assert_eq!(league.current_seat, 1, "seat 1 keeps its turn");
+        assert_eq!(league.pick_number(), pick_number);
+        match league.lock_owed(serenity::UserId(1), Box::new("Squirtle")) {
+            Err(Rejected {
+                error: LeagueError::NoOwedPicksError,
+                ..
+            }) => {}
+            _ => panic!("seat 0 has made up its pick"),
+        }
+        assert!(league.lock(Box::new("Pikachu")).is_err());
+        league.lock(Box::new("Bulbasaur")).unwrap();
+
+        let mut replayed = new_league(2, DraftType::Snake, 2);
+        replayed
+            .replay(league.events(), |name| Some(Box::new(name.to_string())))
+            .unwrap();
+        assert_eq!(replayed.player_picks(serenity::UserId(1)).unwrap().len(), 1);
+        assert_eq!(replayed.current_seat, 1);
+    }
+}
@@ -0,0 +1,135 @@
+use crate::{League, LeaguePhase};
+use chrono::{DateTime, Duration, Utc};
+use poise::serenity_prelude as serenity;
+
+/// How often a [`League`] nags the seat on the clock, and when it last did.
+pub(crate) struct Reminders {
+    pub(crate) every: Option<Duration>,
+    // the overall pick number last reminded about, when, and how many reminders it has had
+    pub(crate) last: Option<(u32, DateTime<Utc>, u32)>,
+}
+
+impl Reminders {
+    pub(crate) fn new() -> Reminders {
+        Reminders {
+            every: None,
+            last: None,
+        }
+    }
+}
+
+/// A seat due a nudge about its pick, as returned by [`League::reminders_due`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Reminder {
+    pub user: serenity::UserId,
+    /// The overall pick number on the clock, starting at 1.
+    pub pick: u32,
+    /// When the seat was put on the clock.
+    pub on_the_clock_since: DateTime<Utc>,
+    /// How long the seat has been on the clock, including time the League spent paused.
+    pub waited: Duration,
+    /// How long the seat has left before its deadline, if it has one (see [`League::next_deadline`]).
+    pub remaining: Option<Duration>,
+    /// How many reminders about this pick have already been sent.
+    pub reminders_sent: u32,
+}
+
+// Slow drafts run with no live session: each pick gets a window of hours or days (see League::set_pick_time_limit and
+// League::set_pick_deadline), and a periodic job nags whoever is holding things up.
+impl League {
+    /// Returns when the seat on the clock was put there, or None if the draft is not underway.
+    /// Unlike the [`PickClock`](crate::PickClock)'s elapsed time, this does not stop while the League is paused.
+    pub fn on_the_clock_since(&self) -> Option<DateTime<Utc>> {
+        self.clock.since()
+    }
+    /// Sets how long the seat on the clock may go between reminders, e.g. a day in a slow draft, or None to stop reminding.
+    pub fn set_reminder_interval(&mut self, every: Option<Duration>) {
+        self.reminders.every = every;
+    }
+    /// Returns how long the seat on the clock may go between reminders, if the League sends them.
+    pub fn reminder_interval(&self) -> Option<Duration> {
+        self.reminders.every
+    }
+    /// Returns the seat to remind about its pick, if it is due one: it has been on the clock for a whole
+    /// [`League::reminder_interval`] since it was put there or last reminded. Call this from a periodic job,
+    /// post the reminders, then call [`League::mark_reminded`].
+    ///
+    /// Only one seat is on the clock at a time, so this holds at most one reminder, and it is always empty unless the
+    /// draft is underway and unpaused and the League sends reminders.
+    pub fn reminders_due(&self, now: DateTime<Utc>) -> Vec<Reminder> {
+        let (Some(every), Some(since)) = (self.reminders.every, self.clock.since()) else {
+            return Vec::new();
+        };
+        if self.phase != LeaguePhase::Drafting {
+            return Vec::new();
+        }
+        let pick = self.total_picks + 1;
+        let (last, sent) = match self.reminders.last {
+            Some((reminded, at, sent)) if reminded == pick && at >= since => (at, sent),
+            _ => (since, 0),
+        };
+        if now - last < every {
+            return Vec::new();
+        }
+        vec![Reminder {
+            user: self.players[self.current_seat as usize].id,
+            pick,
+            on_the_clock_since: since,
+            waited: now - since,
+            remaining: self
+                .next_deadline(now)
+                .map(|deadline| (deadline - now).max(Duration::zero())),
+            reminders_sent: sent,
+        }]
+    }
+    /// Records that the seat on the clock was just reminded, so [`League::reminders_due`] waits another interval.
+    pub fn mark_reminded(&mut self, now: DateTime<Utc>) {
+        let Some(since) = self.clock.since() else {
+            return;
+        };
+        let pick = self.total_picks + 1;
+        let sent = match self.reminders.last {
+            Some((reminded, at, sent)) if reminded == pick && at >= since => sent + 1,
+            _ => 1,
+        };
+        self.reminders.last = Some((pick, now, sent));
+    }
+}
+
+#[cfg(test)]
+mod slow_tests {
+    use super::*;
+    use crate::test_support::{new_league, pokemon};
+    use crate::DraftType;
+
+    #[test]
+    fn reminders_wait_an_interval() {
+        let mut league = new_league(2, DraftType::Snake, 2);
+        league.set_reminder_interval(Some(Duration::hours(12)));
+        league.set_pick_time_limit(Some(Duration::days(2)));
+        league.activate().unwrap();
+        let since = league.on_the_clock_since().unwrap();
+        assert!(league.reminders_due(since + Duration::hours(11)).is_empty());
+
+        let later = since + Duration::hours(13);
+        let due = league.reminders_due(later);
+        assert_eq!(due.len(), 1);
+        assert_eq!(due[0].user, serenity::UserId(1));
+        assert_eq!(due[0].waited, Duration::hours(13));
+        assert_eq!(due[0].reminders_sent, 0);
+        assert!(due[0].remaining.is_some());
+        league.mark_reminded(later);
+        assert!(league.reminders_due(later + Duration::hours(1)).is_empty());
+        assert_eq!(
+            league.reminders_due(later + Duration::hours(12))[0].reminders_sent,
+            1
+        );
+
+        league.lock(pokemon("Pikachu")).unwrap();
+        let since = league.on_the_clock_since().unwrap();
+        assert!(league.reminders_due(since + Duration::hours(1)).is_empty());
+        let due = league.reminders_due(since + Duration::hours(12));
+        assert_eq!(due[0].user, serenity::UserId(2));
+        assert_eq!(due[0].reminders_sent, 0);
+    }
+}
@@ -0,0 +1,725 @@
+use crate::auction::Auction;
+use crate::expansion::Expansion;
+use crate::idempotency::Idempotency;
+use crate::ready_check::ReadyCheck;
+use crate::{
+    ActivePlayer, BestAvailable, DraftItem, DraftPool, DraftSummary, DraftType, Draftable,
+    GridDraft, League, LeaguePhase, MatchResult, Matchup, MessageKind, MessageTemplate, PackSet,
+    RateLimit, RateLimitedAction, SkipPolicy, SolomonDraft, Team, WinstonDraft,
+};
+use chrono::{DateTime, Duration, Utc};
+use poise::serenity_prelude as serenity;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet, VecDeque};
+
+/// The snapshot format this version of DRFTR writes. Snapshots from older versions can always be restored.
+///
+/// It goes up whenever snapshots gain settings older versions would drop, so those versions refuse the snapshot instead of
+/// restoring a League without them. Version 2 added roster slots and the salary cap.
+pub const SNAPSHOT_VERSION: u32 = 2;
+
+/// The ways restoring a [`League`] snapshot can fail.
+#[derive(Debug)]
+pub enum SnapshotError {
+    /// The snapshot is not valid JSON, or is missing something every snapshot has. Holds the parser's message.
+    MalformedSnapshotError(String),
+    /// The snapshot was written by a newer version of DRFTR. Holds the snapshot's version.
+    UnsupportedVersionError(u32),
+    /// The item resolver did not recognize an item. Holds the item's name.
+    UnknownItemError(String),
+}
+
+// Every field added after version 1 must be #[serde(default)], so older snapshots still restore.
+#[derive(Serialize, Deserialize)]
+struct Snapshot {
+    version: u32,
+    id: u64,
+    name: String,
+    output: Option<u64>,
+    phase: LeaguePhase,
+    draft_type: DraftType,
+    current_seat: u32,
+    total_picks: u32,
+    final_pick: u32,
+    team_size: u32,
+    players: Vec<PlayerSnapshot>,
+    #[serde(default)]
+    clock: ClockSnapshot,
+    #[serde(default)]
+    pool: Option<Vec<String>>,
+    #[serde(default)]
+    packs: Option<PacksSnapshot>,
+    #[serde(default)]
+    commissioners: Vec<u64>,
+    #[serde(default)]
+    observers: Vec<u64>,
+    #[serde(default)]
+    observers_see_queues: bool,
+    #[serde(default)]
+    item_emoji: HashMap<String, String>,
+    #[serde(default)]
+    category_emoji: HashMap<String, String>,
+    #[serde(default)]
+    start_time: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pick_deadlines: HashMap<u32, DateTime<Utc>>,
+    #[serde(default)]
+    templates: Vec<(String, String)>,
+    #[serde(default)]
+    seat_claims: HashMap<u64, u32>,
+    #[serde(default)]
+    ready: Vec<u64>,
+    #[serde(default)]
+    order: Option<Vec<u32>>,
+    #[serde(default)]
+    results: Vec<MatchResult>,
+    #[serde(default)]
+    schedule: Vec<Matchup>,
+    #[serde(default)]
+    locked_items: Vec<String>,
+    // copies of each pool item, in pool order, when any item has more than one
+    #[serde(default)]
+    pool_copies: Option<Vec<u32>>,
+    #[serde(default)]
+    pick_times_ms: Vec<(u32, i64)>,
+    #[serde(default)]
+    expansion: Option<Expansion>,
+    #[serde(default)]
+    reminder_interval_secs: Option<i64>,
+    #[serde(default)]
+    last_reminder: Option<(u32, DateTime<Utc>, u32)>,
+    #[serde(default)]
+    timezone: Option<String>,
+    #[serde(default)]
+    locale: Option<String>,
+    #[serde(default)]
+    aliases: HashMap<String, String>,
+    #[serde(default)]
+    thread: Option<(u64, u64)>,
+    #[serde(default)]
+    queue_limit: Option<u32>,
+    #[serde(default)]
+    queues_locked_off_turn: bool,
+    #[serde(default)]
+    round_categories: HashMap<u32, String>,
+    #[serde(default)]
+    roster_slots: HashMap<String, u32>,
+    #[serde(default)]
+    salary_cap: Option<u32>,
+    #[serde(default)]
+    auction: Auction,
+    #[serde(default)]
+    winston: Option<WinstonSnapshot>,
+    #[serde(default)]
+    grid: Option<GridSnapshot>,
+    #[serde(default)]
+    solomon: Option<SolomonSnapshot>,
+    #[serde(default)]
+    team_roles: HashMap<u64, u64>,
+    #[serde(default)]
+    ready_check: ReadyCheck,
+    #[serde(default)]
+    skip_policy: SkipPolicy,
+    #[serde(default)]
+    started_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    summary: Option<DraftSummary>,
+    #[serde(default)]
+    last_activity: Option<DateTime<Utc>>,
+    #[serde(default)]
+    max_duration_secs: Option<i64>,
+    #[serde(default)]
+    idempotency_window_secs: Option<i64>,
+    // (action, max, period in seconds)
+    #[serde(default)]
+    rate_limits: Vec<(RateLimitedAction, u32, i64)>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct PlayerSnapshot {
+    id: u64,
+    picks: Vec<String>,
+    #[serde(default)]
+    queue: Vec<String>,
+    #[serde(default)]
+    co_owners: Vec<u64>,
+    #[serde(default)]
+    team: Option<TeamSnapshot>,
+    #[serde(default)]
+    proxy: Option<(u64, u32)>,
+    #[serde(default)]
+    autopick: bool,
+    #[serde(default)]
+    forfeited: bool,
+    #[serde(default)]
+    division: Option<String>,
+    #[serde(default)]
+    team_size: Option<u32>,
+    #[serde(default)]
+    owed_picks: u32,
+    #[serde(default)]
+    autopicks: u32,
+}
+
+#[derive(Serialize, Deserialize)]
+struct TeamSnapshot {
+    name: String,
+    emoji: Option<String>,
+    color: Option<u32>,
+}
+
+#[derive(Serialize, Deserialize, Default)]
+struct ClockSnapshot {
+    limit_secs: Option<i64>,
+    elapsed_ms: i64,
+    #[serde(default)]
+    since: Option<DateTime<Utc>>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct PacksSnapshot {
+    sealed: Vec<Vec<String>>,
+    revealed: Vec<String>,
+    pack_round: u32,
+}
+
+#[derive(Serialize, Deserialize)]
+struct WinstonSnapshot {
+    deck: Vec<String>,
+    piles: [Vec<String>; 3],
+    pile: usize,
+    seat: u32,
+}
+
+#[derive(Serialize, Deserialize)]
+struct GridSnapshot {
+    deck: Vec<String>,
+    cells: Vec<Option<String>>,
+    grids: u32,
+    taken: u32,
+}
+
+#[derive(Serialize, Deserialize)]
+struct SolomonSnapshot {
+    deck: Vec<String>,
+    revealed: Vec<String>,
+    piles: Option<[Vec<String>; 2]>,
+    splitter: u32,
+    reveal: usize,
+}
+
+const TEMPLATE_KINDS: [(&str, MessageKind); 4] = [
+    ("pick", MessageKind::Pick),
+    ("on_the_clock", MessageKind::OnTheClock),
+    ("turn_ping", MessageKind::TurnPing),
+    ("complete", MessageKind::Complete),
+];
+
+fn names(items: &[Draftable]) -> Vec<String> {
+    items.iter().map(|i| i.name().to_string()).collect()
+}
+
+impl League {
+    /// Serializes everything about the League - settings, seats, picks, queues, pool and packs, the lobby and the pick clock -
+    /// into a versioned JSON blob, so bots can checkpoint after every pick and pick up where they left off after a crash.
+    ///
+    /// Items are saved by name, and rebuilt by the resolver given to [`League::restore`]. The League's [`AutopickStrategy`](crate::AutopickStrategy)
+    /// is not saved, and must be set again after restoring.
+    pub fn snapshot(&self) -> String {
+        let now = Utc::now();
+        let snapshot = Snapshot {
+            version: SNAPSHOT_VERSION,
+            id: self.id,
+            name: self.name.clone(),
+            output: self.output.map(|c| c.0),
+            phase: self.phase,
+            draft_type: self.draft_type,
+            current_seat: self.current_seat,
+            total_picks: self.total_picks,
+            final_pick: self.final_pick,
+            team_size: self.team_size,
+            players: self
+                .players
+                .iter()
+                .map(|p| PlayerSnapshot {
+                    id: p.id.0,
+                    picks: names(&p.picks),
+                    queue: p.queue.iter().map(|i| i.name().to_string()).collect(),
+                    co_owners: p.co_owners.iter().map(|c| c.0).collect(),
+                    team: p.team.as_ref().map(|t| TeamSnapshot {
+                        name: t.name().to_string(),
+                        emoji: t.emoji().map(str::to_string),
+                        color: t.color().map(|c| c.0),
+                    }),
+                    proxy: p.proxy.map(|(proxy, until)| (proxy.0, until)),
+                    autopick: p.autopick,
+                    forfeited: p.forfeited,
+                    division: p.division.clone(),
+                    team_size: p.team_size,
+                    owed_picks: p.owed_picks,
+                    autopicks: p.autopicks,
+                })
+                .collect(),
+            clock: ClockSnapshot {
+                limit_secs: self.clock.limit().map(|l| l.num_seconds()),
+                elapsed_ms: self.clock.elapsed(now).num_milliseconds(),
+                since: self.clock.since(),
+            },
+            pool: self
+                .pool
+                .as_ref()
+                .map(|pool| pool.iter().map(|i| i.name().to_string()).collect()),
+            packs: self.packs.as_ref().map(|packs| PacksSnapshot {
+                sealed: packs.sealed.iter().map(|pack| names(pack)).collect(),
+                revealed: names(&packs.revealed),
+                pack_round: packs.pack_round,
+            }),
+            commissioners: self.permissions.commissioners.iter().map(|c| c.0).collect(),
+            observers: self.permissions.observers.iter().map(|o| o.0).collect(),
+            observers_see_queues: self.permissions.observers_see_queues,
+            item_emoji: self.emoji.items.clone(),
+            category_emoji: self.emoji.categories.clone(),
+            start_time: self.deadlines.start,
+            pick_deadlines: self.deadlines.picks.clone(),
+            templates: TEMPLATE_KINDS
+                .iter()
+                .filter_map(|(key, kind)| {
+                    let template = self.custom_template(*kind)?;
+                    Some((key.to_string(), template.text().to_string()))
+                })
+                .collect(),
+            seat_claims: self
+                .lobby
+                .seat_claims
+                .iter()
+                .map(|(id, seat)| (id.0, *seat))
+                .collect(),
+            ready: self.lobby.ready.iter().map(|r| r.0).collect(),
+            order: self.order.clone(),
+            results: self.results.clone(),
+            schedule: self.schedule.clone(),
+            locked_items: self.locked_items.iter().cloned().collect(),
+            pool_copies: self
+                .pool
+                .as_ref()
+                .filter(|pool| pool.counts().any(|(_, copies)| copies > 1))
+                .map(|pool| pool.counts().map(|(_, copies)| copies).collect()),
+            pick_times_ms: self
+                .pick_times
+                .iter()
+                .map(|(pick, time)| (*pick, time.num_milliseconds()))
+                .collect(),
+            expansion: self.expansion.clone(),
+            reminder_interval_secs: self.reminders.every.map(|every| every.num_seconds()),
+            last_reminder: self.reminders.last,
+            timezone: self.timezone.map(|tz| tz.name().to_string()),
+            locale: self.locale.clone(),
+            aliases: self.aliases.clone(),
+            thread: self.thread.map(|(thread, parent)| (thread.0, parent.0)),
+            queue_limit: self.queue_limit,
+            queues_locked_off_turn: self.queues_locked_off_turn,
+            round_categories: self.round_categories.clone(),
+            roster_slots: self.roster_slots.clone(),
+            salary_cap: self.salary_cap,
+            auction: self.auction.clone(),
+            team_roles: self
+                .team_roles
+                .iter()
+                .map(|(captain, role)| (captain.0, role.0))
+                .collect(),
+            ready_check: self.lobby.check.clone(),
+            skip_policy: self.skip_policy,
+            started_at: self.started_at,
+            summary: self.summary.clone(),
+            last_activity: Some(self.last_activity),
+            max_duration_secs: self.deadlines.max_duration.map(|max| max.num_seconds()),
+            idempotency_window_secs: Some(self.idempotency.window.num_seconds()),
+            rate_limits: self
+                .rate_limits
+                .limits
+                .iter()
+                .map(|(action, limit)| (*action, limit.max, limit.period.num_seconds()))
+                .collect(),
+            winston: self.winston.as_ref().map(|winston| WinstonSnapshot {
+                deck: winston.deck.iter().map(|i| i.name().to_string()).collect(),
+                piles: winston.piles.each_ref().map(|pile| names(pile)),
+                pile: winston.pile,
+                seat: winston.seat,
+            }),
+            grid: self.grid.as_ref().map(|grid| GridSnapshot {
+                deck: grid.deck.iter().map(|i| i.name().to_string()).collect(),
+                cells: grid
+                    .cells
+                    .iter()
+                    .map(|cell| cell.as_ref().map(|i| i.name().to_string()))
+                    .collect(),
+                grids: grid.grids,
+                taken: grid.taken,
+            }),
+            solomon: self.solomon.as_ref().map(|solomon| SolomonSnapshot {
+                deck: solomon.deck.iter().map(|i| i.name().to_string()).collect(),
+                revealed: names(&solomon.revealed),
+                piles: solomon
+                    .piles
+                    .as_ref()
+                    .map(|piles| piles.each_ref().map(|pile| names(pile))),
+                splitter: solomon.splitter,
+                reveal: solomon.reveal,
+            }),
+        };
+        serde_json::to_string(&snapshot).expect("snapshots only hold serializable data")
+    }
+    /// Rebuilds a League from a blob made by [`League::snapshot`], by this or any earlier version of DRFTR.
+    ///
+    /// resolver turns each saved item name back into your [`DraftItem`] type, e.g. by looking it up in your item list.
+    /// A draft that was underway picks up with a fresh clock segment for the player on the clock, keeping the time they had already used.
+    ///
+    /// # Errors
+    ///
+    /// If the blob cannot be read, returns [`SnapshotError::MalformedSnapshotError`].
+    ///
+    /// If the blob was made by a newer version of DRFTR, returns [`SnapshotError::UnsupportedVersionError`].
+    ///
+    /// If resolver returns None for an item, returns [`SnapshotError::UnknownItemError`] with the item's name.
+    pub fn restore(
+        snapshot: &str,
+        mut resolver: impl FnMut(&str) -> Option<Box<dyn DraftItem>>,
+    ) -> Result<League, SnapshotError> {
+        let snapshot: Snapshot = serde_json::from_str(snapshot)
+            .map_err(|e| SnapshotError::MalformedSnapshotError(e.to_string()))?;
+        if snapshot.version > SNAPSHOT_VERSION {
+            return Err(SnapshotError::UnsupportedVersionError(snapshot.version));
+        }
+        if snapshot.players.is_empty() || snapshot.current_seat as usize >= snapshot.players.len() {
+            return Err(SnapshotError::MalformedSnapshotError(
+                "current seat is not at the table".to_string(),
+            ));
+        }
+        let mut resolve = |name: &String| {
+            resolver(name).ok_or_else(|| SnapshotError::UnknownItemError(name.clone()))
+        };
+        let ids: Vec<serenity::UserId> = snapshot
+            .players
+            .iter()
+            .map(|p| serenity::UserId(p.id))
+            .collect();
+        let mut league = League::new(
+            &ids,
+            snapshot.id,
+            snapshot.name,
+            snapshot.output.map(serenity::ChannelId),
+            snapshot.draft_type,
+            snapshot.team_size,
+        );
+        for (seat, saved) in snapshot.players.into_iter().enumerate() {
+            let player: &mut ActivePlayer = &mut league.players[seat];
+            player.picks = saved
+                .picks
+                .iter()
+                .map(&mut resolve)
+                .collect::<Result<_, _>>()?;
+            player.queue = saved
+                .queue
+                .iter()
+                .map(&mut resolve)
+                .collect::<Result<VecDeque<_>, _>>()?;
+            player.co_owners = saved.co_owners.into_iter().map(serenity::UserId).collect();
+            player.team = saved.team.map(|t| {
+                let mut team = Team::new(t.name);
+                team.set_emoji(t.emoji);
+                team.set_color(t.color.map(serenity::Colour));
+                team
+            });
+            player.proxy = saved
+                .proxy
+                .map(|(proxy, until)| (serenity::UserId(proxy), until));
+            player.autopick = saved.autopick;
+            player.forfeited = saved.forfeited;
+            player.division = saved.division;
+            player.team_size = saved.team_size;
+            player.owed_picks = saved.owed_picks;
+            player.autopicks = saved.autopicks;
+        }
+        league.reindex();
+        league.phase = snapshot.phase;
+        league.track_phase();
+        league.current_seat = snapshot.current_seat;
+        league.total_picks = snapshot.total_picks;
+        league.final_pick = snapshot.final_pick;
+        league.order = snapshot.order;
+        league.results = snapshot.results;
+        league.schedule = snapshot.schedule;
+        league.locked_items = snapshot.locked_items.into_iter().collect();
+        league.pick_times = snapshot
+            .pick_times_ms
+            .into_iter()
+            .map(|(pick, ms)| (pick, Duration::milliseconds(ms)))
+            .collect();
+        league.expansion = snapshot.expansion;
+        league.reminders.every = snapshot.reminder_interval_secs.map(Duration::seconds);
+        league.reminders.last = snapshot.last_reminder;
+        league.timezone = match snapshot.timezone {
+            Some(name) => Some(name.parse().map_err(|_| {
+                SnapshotError::MalformedSnapshotError(format!("unknown timezone {name}"))
+            })?),
+            None => None,
+        };
+        if let Some(pool) = snapshot.pool {
+            let items: Vec<Draftable> = pool.iter().map(&mut resolve).collect::<Result<_, _>>()?;
+            let copies = snapshot.pool_copies.unwrap_or_default();
+            let counts = (0..items.len()).map(|i| copies.get(i).copied().unwrap_or(1));
+            league.pool = Some(DraftPool::with_copies(
+                items.into_iter().zip(counts).collect(),
+            ));
+        }
+        if let Some(packs) = snapshot.packs {
+            let mut sealed = VecDeque::new();
+            for pack in &packs.sealed {
+                sealed.push_back(pack.iter().map(&mut resolve).collect::<Result<_, _>>()?);
+            }
+            league.packs = Some(PackSet {
+                sealed,
+                revealed: packs
+                    .revealed
+                    .iter()
+                    .map(&mut resolve)
+                    .collect::<Result<_, _>>()?,
+                pack_round: packs.pack_round,
+            });
+        }
+        league
+            .clock
+            .set_limit(snapshot.clock.limit_secs.map(Duration::seconds));
+        league.clock.banked = Duration::milliseconds(snapshot.clock.elapsed_ms);
+        match league.phase {
+            LeaguePhase::Drafting => league.clock.started = Some(Utc::now()),
+            LeaguePhase::Paused => league.clock.paused = true,
+            _ => {}
+        }
+        if league.phase.is_drafting() {
+            // older snapshots only know how much of the pick's time was used
+            league.clock.since = snapshot
+                .clock
+                .since
+                .or_else(|| Some(Utc::now() - league.clock.banked));
+        }
+        let permissions = &mut league.permissions;
+        permissions.commissioners = snapshot
+            .commissioners
+            .into_iter()
+            .map(serenity::UserId)
+            .collect();
+        permissions.observers = snapshot
+            .observers
+            .into_iter()
+            .map(serenity::UserId)
+            .collect();
+        permissions.observers_see_queues = snapshot.observers_see_queues;
+        league.emoji.items = snapshot.item_emoji;
+        league.emoji.categories = snapshot.category_emoji;
+        league.deadlines.start = snapshot.start_time;
+        league.deadlines.picks = snapshot.pick_deadlines;
+        league.locale = snapshot.locale;
+        league.aliases = snapshot.aliases;
+        league.queue_limit = snapshot.queue_limit;
+        league.queues_locked_off_turn = snapshot.queues_locked_off_turn;
+        league.round_categories = snapshot.round_categories;
+        league.roster_slots = snapshot.roster_slots;
+        league.salary_cap = snapshot.salary_cap;
+        league.auction = snapshot.auction;
+        league.skip_policy = snapshot.skip_policy;
+        league.started_at = snapshot.started_at;
+        league.summary = snapshot.summary;
+        if let Some(last_activity) = snapshot.last_activity {
+            league.last_activity = last_activity;
+        }
+        league.deadlines.max_duration = snapshot.max_duration_secs.map(Duration::seconds);
+        for (action, max, period) in snapshot.rate_limits {
+            let period = Duration::try_seconds(period).ok_or_else(|| {
+                SnapshotError::MalformedSnapshotError(format!(
+                    "rate limit period of {period} seconds is out of range"
+                ))
+            })?;
+            league
+                .rate_limits
+                .limits
+                .insert(action, RateLimit::new(max, period));
+        }
+        if let Some(window) = snapshot.idempotency_window_secs {
+            league.idempotency.window = Duration::seconds(window);
+        }
+        league.team_roles = snapshot
+            .team_roles
+            .into_iter()
+            .map(|(captain, role)| (serenity::UserId(captain), serenity::RoleId(role)))
+            .collect();
+        if let Some(winston) = snapshot.winston {
+            let mut piles: [Vec<Draftable>; 3] = Default::default();
+            for (pile, names) in piles.iter_mut().zip(&winston.piles) {
+                *pile = names.iter().map(&mut resolve).collect::<Result<_, _>>()?;
+            }
+            league.winston = Some(WinstonDraft {
+                deck: winston
+                    .deck
+                    .iter()
+                    .map(&mut resolve)
+                    .collect::<Result<_, _>>()?,
+                piles,
+                pile: winston.pile,
+                seat: winston.seat,
+            });
+        }
+        if let Some(grid) = snapshot.grid {
+            let mut cells = Vec::new();
+            for cell in &grid.cells {
+                cells.push(cell.as_ref().map(&mut resolve).transpose()?);
+            }
+            league.grid = Some(GridDraft {
+                deck: grid
+                    .deck
+                    .iter()
+                    .map(&mut resolve)
+                    .collect::<Result<_, _>>()?,
+                cells,
+                grids: grid.grids,
+                taken: grid.taken,
+            });
+        }
+        if let Some(solomon) = snapshot.solomon {
+            let piles = match &solomon.piles {
+                Some(names) => {
+                    let mut piles: [Vec<Draftable>; 2] = Default::default();
+                    for (pile, names) in piles.iter_mut().zip(names) {
+                        *pile = names.iter().map(&mut resolve).collect::<Result<_, _>>()?;
+                    }
+                    Some(piles)
+                }
+                None => None,
+            };
+            league.solomon = Some(SolomonDraft {
+                deck: solomon
+                    .deck
+                    .iter()
+                    .map(&mut resolve)
+                    .collect::<Result<_, _>>()?,
+                revealed: solomon
+                    .revealed
+                    .iter()
+                    .map(&mut resolve)
+                    .collect::<Result<_, _>>()?,
+                piles,
+                splitter: solomon.splitter,
+                reveal: solomon.reveal,
+            });
+        }
+        league.thread = snapshot
+            .thread
+            .map(|(thread, parent)| (serenity::ChannelId(thread), serenity::ChannelId(parent)));
+        for (key, text) in snapshot.templates {
+            let Some((_, kind)) = TEMPLATE_KINDS.iter().find(|(k, _)| *k == key) else {
+                continue;
+            };
+            // version 1 saved every template; ones left at the English default follow the locale instead
+            if crate::i18n::english(kind.text_key()) == Some(text.as_str()) {
+                continue;
+            }
+            if let Ok(template) = MessageTemplate::new(text) {
+                league.set_template(*kind, template);
+            }
+        }
+        league.lobby.seat_claims = snapshot
+            .seat_claims
+            .into_iter()
+            .map(|(id, seat)| (serenity::UserId(id), seat))
+            .collect();
+        league.lobby.ready = snapshot.ready.into_iter().map(serenity::UserId).collect();
+        league.lobby.check = snapshot.ready_check;
+        Ok(league)
+    }
+    // what a snapshot leaves out that rolling back a change must put back as it was, taken just before the change
+    pub(crate) fn checkpoint(&self) -> Checkpoint {
+        Checkpoint {
+            events: self.events.len(),
+            used: self.rate_limits.used.clone(),
+            keys: self.idempotency.keys(),
+        }
+    }
+    // puts the League back as it stood before a change, from a League restored from a snapshot taken then and the checkpoint
+    // taken with it, keeping what snapshots leave out: the events recorded before the change, subscribers, the autopick strategy,
+    // the shared pool, and the idempotency keys from before the change. Rate limits count only the uses from before it too
+    pub(crate) fn roll_back(&mut self, mut before: League, checkpoint: Checkpoint) {
+        if let Some(shared) = &self.shared_pool {
+            for name in self.picked.keys() {
+                if !before.picked.contains_key(name) {
+                    shared.release(name, self.id);
+                }
+            }
+        }
+        self.events.truncate(checkpoint.events);
+        before.events = std::mem::take(&mut self.events);
+        before.stream = self.stream.take();
+        before.autopick_strategy =
+            std::mem::replace(&mut self.autopick_strategy, Box::new(BestAvailable));
+        before.shared_pool = self.shared_pool.take();
+        before.idempotency = std::mem::replace(&mut self.idempotency, Idempotency::new());
+        // a retry of the change must run it again, rather than find the result of the one that was rolled back
+        before.idempotency.keep_only(&checkpoint.keys);
+        before.rate_limits.used = checkpoint.used;
+        *self = before;
+    }
+}
+
+// the parts of a League a rollback needs that its snapshot leaves out
+pub(crate) struct Checkpoint {
+    // how many events the League had recorded
+    events: usize,
+    used: HashMap<(serenity::UserId, RateLimitedAction), VecDeque<DateTime<Utc>>>,
+    keys: HashSet<u64>,
+}
+
+#[cfg(test)]
+mod snapshot_tests {
+    use super::*;
+    use crate::test_support::{new_league, pokemon, resolve};
+
+    #[test]
+    fn restore_resumes_mid_draft() {
+        let mut league = new_league(2, DraftType::Snake, 2);
+        let pool = ["Charizard", "Blastoise", "Venusaur", "Pikachu", "Mew"]
+            .iter()
+            .map(|n| pokemon(n))
+            .collect();
+        league.set_pool(DraftPool::new(pool)).unwrap();
+        league.set_pick_time_limit(Some(Duration::minutes(2)));
+        league.activate().unwrap();
+        league.lock(pokemon("Charizard")).unwrap();
+        league
+            .add_to_player_queue(serenity::UserId(1), pokemon("Mew"))
+            .unwrap();
+
+        let mut restored = League::restore(&league.snapshot(), resolve).unwrap();
+        assert_eq!(restored.phase(), LeaguePhase::Drafting);
+        assert_eq!(restored.current_player().unwrap().id, serenity::UserId(2));
+        assert_eq!(restored.pool().unwrap().len(), 4);
+        assert_eq!(restored.clock().limit(), Some(Duration::minutes(2)));
+        restored.lock(pokemon("Pikachu")).unwrap();
+        // seat 0's queue survived the restore and is picked from straight away
+        let picks = restored.lock(pokemon("Venusaur")).unwrap();
+        assert_eq!(picks[1].user, serenity::UserId(1));
+        assert_eq!(picks[1].item, "Mew");
+        assert!(!restored.active());
+    }
+
+    #[test]
+    fn newer_snapshots_are_refused() {
+        let league = new_league(1, DraftType::Linear, 1);
+        let version = format!("\"version\":{SNAPSHOT_VERSION}");
+        let blob = league.snapshot().replacen(&version, "\"version\":99", 1);
+        match League::restore(&blob, resolve) {
+            Err(SnapshotError::UnsupportedVersionError(99)) => {}
+            _ => panic!("version 99 is from the future"),
+        }
+        let blob = league.snapshot().replacen(&version, "\"version\":1", 1);
+        assert!(League::restore(&blob, resolve).is_ok());
+    }
+}
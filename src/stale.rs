@@ -0,0 +1,77 @@
+use crate::{DraftGuild, League};
+use chrono::{DateTime, Duration, Utc};
+
+impl League {
+    /// Returns when the League last recorded a [`DraftEvent`](crate::DraftEvent), or when it was created if it has recorded none.
+    /// A League restored from a snapshot taken before this version of DRFTR counts as active when it was restored.
+    pub fn last_activity(&self) -> DateTime<Utc> {
+        self.last_activity
+    }
+}
+
+impl DraftGuild {
+    /// Returns every [`League`] with no activity (see [`League::last_activity`]) for at least threshold before now,
+    /// least recently active first, e.g. to warn a server before its abandoned drafts are purged.
+    pub fn stale_leagues(&self, threshold: Duration, now: DateTime<Utc>) -> Vec<&League> {
+        let mut stale: Vec<&League> = self
+            .leagues
+            .values()
+            .filter(|league| now - league.last_activity >= threshold)
+            .collect();
+        stale.sort_by_key(|league| league.last_activity);
+        stale
+    }
+    /// Deletes every League [`DraftGuild::stale_leagues`] returns, drafting or not, and returns them, least recently active first,
+    /// e.g. from a daily task that keeps a bot hosted on thousands of servers from holding on to abandoned drafts.
+    /// Archived Leagues are kept.
+    pub fn purge_stale(&mut self, threshold: Duration, now: DateTime<Utc>) -> Vec<League> {
+        let names: Vec<String> = self
+            .stale_leagues(threshold, now)
+            .into_iter()
+            .map(|league| league.name.clone())
+            .collect();
+        names
+            .iter()
+            .filter_map(|name| self.leagues.remove(name))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod stale_tests {
+    use super::*;
+    use crate::test_support::new_league;
+    use crate::DraftType;
+    use poise::serenity_prelude as serenity;
+
+    fn league(name: &str) -> League {
+        let mut league = new_league(2, DraftType::Snake, 2);
+        league.name = name.to_string();
+        league
+    }
+
+    #[test]
+    fn idle_leagues_are_purged() {
+        let mut guild = DraftGuild::new(69420, serenity::ChannelId(1));
+        guild.add_league(league("Creenis")).unwrap();
+        guild.add_league(league("Ash")).unwrap();
+        for league in guild.leagues_mut() {
+            league.last_activity -= Duration::days(31);
+        }
+        guild
+            .league_by_name("Ash".to_string())
+            .unwrap()
+            .activate()
+            .unwrap();
+
+        let now = Utc::now();
+        let stale = guild.stale_leagues(Duration::days(30), now);
+        assert_eq!(stale.len(), 1);
+        assert_eq!(stale[0].name, "Creenis");
+        let purged = guild.purge_stale(Duration::days(30), now);
+        assert_eq!(purged.len(), 1);
+        assert!(!guild.contains("Creenis"));
+        assert!(guild.contains("Ash"));
+        assert!(guild.purge_stale(Duration::days(30), now).is_empty());
+    }
+}
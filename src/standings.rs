@@ -0,0 +1,192 @@
+use crate::{League, LeagueError, LeaguePhase};
+use poise::serenity_prelude as serenity;
+use serde::{Deserialize, Serialize};
+
+/// The result of a match between two players after the draft, recorded with [`League::record_result`].
+///
+/// Players are recorded by their seat's original owner.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MatchResult {
+    pub home: serenity::UserId,
+    pub away: serenity::UserId,
+    pub home_points: f64,
+    pub away_points: f64,
+}
+
+impl MatchResult {
+    /// Returns the winner, or None for a tie.
+    pub fn winner(&self) -> Option<serenity::UserId> {
+        if self.home_points > self.away_points {
+            Some(self.home)
+        } else if self.away_points > self.home_points {
+            Some(self.away)
+        } else {
+            None
+        }
+    }
+}
+
+/// A player's record across every [`MatchResult`] in a League, as returned by [`League::standings`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Standing {
+    pub user: serenity::UserId,
+    pub wins: u32,
+    pub losses: u32,
+    pub ties: u32,
+    pub points_for: f64,
+    pub points_against: f64,
+}
+
+impl Standing {
+    fn new(user: serenity::UserId) -> Standing {
+        Standing {
+            user,
+            wins: 0,
+            losses: 0,
+            ties: 0,
+            points_for: 0.0,
+            points_against: 0.0,
+        }
+    }
+    /// Returns the number of matches played.
+    pub fn played(&self) -> u32 {
+        self.wins + self.losses + self.ties
+    }
+    fn add(&mut self, scored: f64, conceded: f64) {
+        self.points_for += scored;
+        self.points_against += conceded;
+        if scored > conceded {
+            self.wins += 1;
+        } else if conceded > scored {
+            self.losses += 1;
+        } else {
+            self.ties += 1;
+        }
+    }
+}
+
+impl League {
+    /// Records the result of a match between two players, once the draft is over.
+    ///
+    /// # Errors
+    ///
+    /// If the draft is not over, returns [`LeagueError::WrongPhaseError`].
+    ///
+    /// If either player is not in the league, returns [`LeagueError::PlayerNotFoundError`].
+    ///
+    /// If both users play for the same seat, returns [`LeagueError::SameSeatError`].
+    pub fn record_result(
+        &mut self,
+        home: serenity::UserId,
+        home_points: f64,
+        away: serenity::UserId,
+        away_points: f64,
+    ) -> Result<&MatchResult, LeagueError> {
+        if self.phase != LeaguePhase::PostDraft {
+            return Err(LeagueError::WrongPhaseError);
+        }
+        let (Some(home), Some(away)) = (self.get_player(home), self.get_player(away)) else {
+            return Err(LeagueError::PlayerNotFoundError);
+        };
+        if home.id == away.id {
+            return Err(LeagueError::SameSeatError);
+        }
+        self.results.push(MatchResult {
+            home: home.id,
+            away: away.id,
+            home_points,
+            away_points,
+        });
+        Ok(self.results.last().unwrap())
+    }
+    /// Returns every recorded [`MatchResult`], oldest first.
+    pub fn results(&self) -> &[MatchResult] {
+        &self.results
+    }
+    /// Removes every recorded result, e.g. at the end of a season, and returns them.
+    pub fn clear_results(&mut self) -> Vec<MatchResult> {
+        std::mem::take(&mut self.results)
+    }
+    /// Returns every seat's [`Standing`], best first: by wins, then ties, then points scored. Seats level on all three keep their draft order.
+    ///
+    /// Waiver priority and next season's draft order usually run from the bottom of the standings up.
+    pub fn standings(&self) -> Vec<Standing> {
+        let mut standings: Vec<Standing> =
+            self.players.iter().map(|p| Standing::new(p.id)).collect();
+        for result in &self.results {
+            for (user, scored, conceded) in [
+                (result.home, result.home_points, result.away_points),
+                (result.away, result.away_points, result.home_points),
+            ] {
+                if let Some(standing) = standings.iter_mut().find(|s| s.user == user) {
+                    standing.add(scored, conceded);
+                }
+            }
+        }
+        standings.sort_by(|a, b| {
+            b.wins
+                .cmp(&a.wins)
+                .then(b.ties.cmp(&a.ties))
+                .then(b.points_for.total_cmp(&a.points_for))
+        });
+        standings
+    }
+}
+
+#[cfg(test)]
+mod standings_tests {
+    use super::*;
+    use crate::test_support::new_league;
+    use crate::DraftType;
+
+    fn league() -> League {
+        let mut league = new_league(3, DraftType::Snake, 1);
+        league.activate().unwrap();
+        league.deactivate().unwrap();
+        league
+    }
+
+    #[test]
+    fn standings_rank_by_record_then_points() {
+        let mut league = league();
+        league
+            .record_result(serenity::UserId(1), 100.0, serenity::UserId(2), 90.5)
+            .unwrap();
+        league
+            .record_result(serenity::UserId(3), 120.0, serenity::UserId(1), 80.0)
+            .unwrap();
+        league
+            .record_result(serenity::UserId(2), 75.0, serenity::UserId(3), 75.0)
+            .unwrap();
+        let standings = league.standings();
+        let order: Vec<_> = standings.iter().map(|s| s.user).collect();
+        assert_eq!(
+            order,
+            vec![
+                serenity::UserId(3),
+                serenity::UserId(1),
+                serenity::UserId(2)
+            ]
+        );
+        assert_eq!(standings[0].wins, 1);
+        assert_eq!(standings[0].ties, 1);
+        assert_eq!(standings[1].points_against, 210.5);
+        assert_eq!(standings[2].played(), 2);
+        assert_eq!(league.results()[2].winner(), None);
+    }
+
+    #[test]
+    fn results_wait_for_the_draft() {
+        let mut league = new_league(2, DraftType::Snake, 1);
+        match league.record_result(serenity::UserId(1), 1.0, serenity::UserId(2), 0.0) {
+            Err(LeagueError::WrongPhaseError) => {}
+            _ => panic!("the draft has not happened"),
+        }
+        league.activate().unwrap();
+        league.deactivate().unwrap();
+        match league.record_result(serenity::UserId(1), 1.0, serenity::UserId(1), 0.0) {
+            Err(LeagueError::SameSeatError) => {}
+            _ => panic!("players cannot play themselves"),
+        }
+    }
+}
@@ -0,0 +1,232 @@
+use crate::{Draftable, League};
+use chrono::Duration;
+use poise::serenity_prelude as serenity;
+use std::collections::BTreeMap;
+
+/// A pick made in the draft, with what is known about the item, as part of [`DraftStats`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct DraftedPick {
+    /// The overall pick number, starting at 1.
+    pub pick: u32,
+    pub user: serenity::UserId,
+    pub item: String,
+    pub category: Option<String>,
+    pub cost: Option<u32>,
+    /// Where the item's cost ranks among every item drafted or still in the pool, starting at 1 for the most expensive.
+    pub cost_rank: Option<u32>,
+    /// How long the pick took, if it was made on a running [`PickClock`](crate::PickClock).
+    pub time: Option<Duration>,
+}
+
+impl DraftedPick {
+    /// Returns how many picks later than its cost rank the item went, or None without a cost.
+    /// Positive values are bargains; negative ones are reaches.
+    pub fn value(&self) -> Option<i64> {
+        self.cost_rank
+            .map(|rank| i64::from(self.pick) - i64::from(rank))
+    }
+}
+
+/// What one player drafted, as part of [`DraftStats`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlayerStats {
+    pub user: serenity::UserId,
+    pub picks: u32,
+    /// The average cost of the player's picks that have one, or None if none do.
+    pub average_cost: Option<f64>,
+    /// How many of the player's picks fall in each category, most first.
+    pub categories: Vec<(String, u32)>,
+}
+
+/// A recap of a draft, as returned by [`League::stats`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct DraftStats {
+    /// Every pick still standing, in the order they were made. Skipped turns are left out.
+    pub picks: Vec<DraftedPick>,
+    /// Each player in seat order.
+    pub players: Vec<PlayerStats>,
+}
+
+impl DraftStats {
+    /// Returns how many picks fall in each category across the whole draft, most first.
+    pub fn categories(&self) -> Vec<(String, u32)> {
+        count_categories(self.picks.iter())
+    }
+    /// Returns the pick that took the longest, or None if no pick was timed.
+    pub fn longest_pick(&self) -> Option<&DraftedPick> {
+        self.picks
+            .iter()
+            .filter(|p| p.time.is_some())
+            .max_by_key(|p| p.time)
+    }
+    /// Returns the pick that went furthest after its cost rank, or None if no item had a cost.
+    pub fn best_value(&self) -> Option<&DraftedPick> {
+        self.picks
+            .iter()
+            .filter_map(|p| p.value().map(|value| (value, p)))
+            .max_by_key(|(value, p)| (*value, std::cmp::Reverse(p.pick)))
+            .map(|(_, p)| p)
+    }
+    /// Returns the pick that went furthest before its cost rank, or None if no item had a cost.
+    pub fn biggest_reach(&self) -> Option<&DraftedPick> {
+        self.picks
+            .iter()
+            .filter_map(|p| p.value().map(|value| (value, p)))
+            .min_by_key(|(value, p)| (*value, p.pick))
+            .map(|(_, p)| p)
+    }
+}
+
+fn count_categories<'a>(picks: impl Iterator<Item = &'a DraftedPick>) -> Vec<(String, u32)> {
+    let mut counts: BTreeMap<&str, u32> = BTreeMap::new();
+    for category in picks.filter_map(|p| p.category.as_deref()) {
+        *counts.entry(category).or_default() += 1;
+    }
+    let mut counts: Vec<(String, u32)> = counts
+        .into_iter()
+        .map(|(category, count)| (category.to_string(), count))
+        .collect();
+    // stable, so ties stay alphabetical
+    counts.sort_by_key(|(_, count)| std::cmp::Reverse(*count));
+    counts
+}
+
+impl League {
+    /// Returns a recap of the draft so far: every pick's cost and how it compares to where the item's cost ranks,
+    /// how long it took, and what each player drafted. Works at any stage, though it is most interesting once the draft is over.
+    ///
+    /// Items are looked up wherever they are now, so one traded or waivered since is still described, but one dropped back
+    /// into a League without a [`DraftPool`](crate::DraftPool) has no cost or category.
+    pub fn stats(&self) -> DraftStats {
+        let find = |name: &str| -> Option<&Draftable> {
+            self.players
+                .iter()
+                .flat_map(|p| p.picks.iter())
+                .chain(self.pool.iter().flat_map(|pool| pool.iter()))
+                .find(|i| i.name() == name)
+        };
+        let (made, _) = self.pick_history();
+        let mut picks: Vec<DraftedPick> = made
+            .into_iter()
+            .enumerate()
+            .filter_map(|(idx, pick)| pick.map(|pick| (idx as u32 + 1, pick)))
+            .map(|(number, (user, name))| {
+                let item = find(name);
+                DraftedPick {
+                    pick: number,
+                    user,
+                    item: name.to_string(),
+                    category: item.and_then(|i| i.category()).map(str::to_string),
+                    cost: item.and_then(|i| i.cost()),
+                    cost_rank: None,
+                    time: self
+                        .pick_times
+                        .iter()
+                        .find(|(n, _)| *n == number)
+                        .map(|(_, time)| *time),
+                }
+            })
+            .collect();
+        let mut costs: Vec<u32> = picks.iter().filter_map(|p| p.cost).collect();
+        if let Some(pool) = &self.pool {
+            costs.extend(
+                pool.iter()
+                    .filter(|i| picks.iter().all(|p| p.item != i.name()))
+                    .filter_map(|i| i.cost()),
+            );
+        }
+        for pick in &mut picks {
+            pick.cost_rank = pick
+                .cost
+                .map(|cost| costs.iter().filter(|c| **c > cost).count() as u32 + 1);
+        }
+        let players = self
+            .players
+            .iter()
+            .map(|player| {
+                let theirs: Vec<&DraftedPick> =
+                    picks.iter().filter(|p| p.user == player.id).collect();
+                let costs: Vec<u32> = theirs.iter().filter_map(|p| p.cost).collect();
+                PlayerStats {
+                    user: player.id,
+                    picks: theirs.len() as u32,
+                    average_cost: (!costs.is_empty()).then(|| {
+                        costs.iter().map(|c| f64::from(*c)).sum::<f64>() / costs.len() as f64
+                    }),
+                    categories: count_categories(theirs.into_iter()),
+                }
+            })
+            .collect();
+        DraftStats { picks, players }
+    }
+}
+
+#[cfg(test)]
+mod stats_tests {
+    use super::*;
+    use crate::test_support::new_league;
+    use crate::{DraftItem, DraftPool, DraftType};
+
+    struct Pokemon {
+        name: String,
+        category: String,
+        cost: u32,
+    }
+    impl DraftItem for Pokemon {
+        fn name(&self) -> &str {
+            &self.name
+        }
+        fn category(&self) -> Option<&str> {
+            Some(&self.category)
+        }
+        fn cost(&self) -> Option<u32> {
+            Some(self.cost)
+        }
+    }
+
+    fn pokemon(name: &str, category: &str, cost: u32) -> Draftable {
+        Box::new(Pokemon {
+            name: name.to_string(),
+            category: category.to_string(),
+            cost,
+        })
+    }
+
+    #[test]
+    fn recap_covers_costs_and_categories() {
+        let mut league = new_league(2, DraftType::Snake, 2);
+        league
+            .set_pool(DraftPool::new(vec![
+                pokemon("Charizard", "Fire", 20),
+                pokemon("Blastoise", "Water", 18),
+                pokemon("Venusaur", "Grass", 16),
+                pokemon("Charmander", "Fire", 5),
+                pokemon("Squirtle", "Water", 4),
+            ]))
+            .unwrap();
+        league.activate().unwrap();
+        league.lock(pokemon("Charmander", "Fire", 5)).unwrap();
+        league.lock(pokemon("Charizard", "Fire", 20)).unwrap();
+        league.lock(pokemon("Blastoise", "Water", 18)).unwrap();
+        league.lock(pokemon("Squirtle", "Water", 4)).unwrap();
+
+        let stats = league.stats();
+        assert_eq!(stats.picks.len(), 4);
+        assert_eq!(stats.picks[0].cost_rank, Some(4));
+        assert_eq!(stats.biggest_reach().unwrap().item, "Charmander");
+        assert_eq!(stats.best_value().unwrap().item, "Charizard");
+        assert!(stats.longest_pick().is_some());
+        assert_eq!(
+            stats.categories(),
+            vec![("Fire".to_string(), 2), ("Water".to_string(), 2)]
+        );
+        assert_eq!(stats.players[0].user, serenity::UserId(1));
+        assert_eq!(stats.players[0].average_cost, Some(4.5));
+        assert_eq!(stats.players[1].average_cost, Some(19.0));
+
+        league.rewind_to_pick(2).unwrap();
+        let stats = league.stats();
+        assert_eq!(stats.picks.len(), 2);
+        assert_eq!(stats.picks[1].cost_rank, Some(1));
+    }
+}
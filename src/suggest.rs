@@ -0,0 +1,111 @@
+use crate::{ActivePlayer, DraftPool, Draftable};
+use std::cmp::Reverse;
+use std::collections::HashMap;
+
+impl DraftPool {
+    /// Suggests up to n items for a player to pick next, best first, e.g. for a "suggested picks" embed when their turn comes up.
+    ///
+    /// Items are ranked by [`DraftItem::tier`](crate::DraftItem::tier) first. Within a tier, items in the categories the player
+    /// holds the fewest of come first, so open spots on their roster get filled, then the most expensive by
+    /// [`DraftItem::cost`](crate::DraftItem::cost), then the pool's own ranking. Items without a tier or cost come after those
+    /// with one, and items the player already holds are never suggested.
+    pub fn suggest(&self, player: &ActivePlayer, n: usize) -> Vec<&Draftable> {
+        let mut held: HashMap<&str, usize> = HashMap::new();
+        for item in player.picks() {
+            if let Some(category) = item.category() {
+                *held.entry(category).or_default() += 1;
+            }
+        }
+        let mut suggestions: Vec<&Draftable> = self
+            .iter()
+            .filter(|item| player.picks().iter().all(|p| p.name() != item.name()))
+            .collect();
+        // stable, so ties keep their ranking order
+        suggestions.sort_by_key(|item| {
+            (
+                item.tier().unwrap_or(u32::MAX),
+                item.category()
+                    .map_or(0, |c| held.get(c).copied().unwrap_or(0)),
+                Reverse(item.cost()),
+            )
+        });
+        suggestions.truncate(n);
+        suggestions
+    }
+}
+
+#[cfg(test)]
+mod suggest_tests {
+    use crate::test_support::new_league;
+    use crate::{DraftItem, DraftPool, DraftType, Draftable, League};
+    use poise::serenity_prelude as serenity;
+
+    struct Pokemon {
+        name: String,
+        category: String,
+        cost: u32,
+        tier: u32,
+    }
+    impl DraftItem for Pokemon {
+        fn name(&self) -> &str {
+            &self.name
+        }
+        fn category(&self) -> Option<&str> {
+            Some(&self.category)
+        }
+        fn cost(&self) -> Option<u32> {
+            Some(self.cost)
+        }
+        fn tier(&self) -> Option<u32> {
+            Some(self.tier)
+        }
+    }
+
+    fn pokemon(name: &str, category: &str, cost: u32, tier: u32) -> Draftable {
+        Box::new(Pokemon {
+            name: name.to_string(),
+            category: category.to_string(),
+            cost,
+            tier,
+        })
+    }
+
+    fn league() -> League {
+        let mut league = new_league(2, DraftType::Snake, 3);
+        league
+            .set_pool(DraftPool::new(vec![
+                pokemon("Pikachu", "Electric", 10, 1),
+                pokemon("Raichu", "Electric", 12, 1),
+                pokemon("Charizard", "Fire", 9, 1),
+                pokemon("Blastoise", "Water", 11, 2),
+                pokemon("Squirtle", "Water", 5, 3),
+            ]))
+            .unwrap();
+        league.activate().unwrap();
+        league
+    }
+
+    fn names(items: Vec<&Draftable>) -> Vec<&str> {
+        items.into_iter().map(|i| i.name()).collect()
+    }
+
+    #[test]
+    fn suggestions_rank_by_tier_then_cost() {
+        let league = league();
+        let player = league.current_player().unwrap();
+        let suggestions = league.pool().unwrap().suggest(player, 3);
+        assert_eq!(names(suggestions), vec!["Raichu", "Pikachu", "Charizard"]);
+    }
+
+    #[test]
+    fn suggestions_fill_open_categories() {
+        let mut league = league();
+        league.lock(pokemon("Raichu", "Electric", 12, 1)).unwrap();
+        league.lock(pokemon("Blastoise", "Water", 11, 2)).unwrap();
+        league.lock(pokemon("Squirtle", "Water", 5, 3)).unwrap();
+        let player = league.current_player().unwrap();
+        assert_eq!(player.id, serenity::UserId(1));
+        let suggestions = league.pool().unwrap().suggest(player, 5);
+        assert_eq!(names(suggestions), vec!["Charizard", "Pikachu"]);
+    }
+}
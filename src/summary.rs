@@ -0,0 +1,117 @@
+use crate::League;
+use chrono::{DateTime, Duration, Utc};
+use poise::serenity_prelude as serenity;
+use serde::{Deserialize, Serialize};
+
+/// One seat's part in a [`DraftSummary`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SeatSummary {
+    /// The seat's original owner.
+    pub user: serenity::UserId,
+    /// The names of the seat's picks when the draft ended, in the order they were made.
+    pub roster: Vec<String>,
+    /// How many of the seat's picks were chosen by the League's [`AutopickStrategy`](crate::AutopickStrategy),
+    /// for a seat on autopick or by [`League::autopick`]. Picks rebuilt with [`League::replay`] count as made by hand.
+    pub autopicks: u32,
+    /// How many of the seat's turns were skipped.
+    pub skipped: u32,
+}
+
+/// A wrap-up of a finished draft, made on the final pick, from [`League::draft_summary`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DraftSummary {
+    /// When the draft started, if this League saw it start. A League restored from a snapshot taken before
+    /// this version of DRFTR does not know.
+    pub started: Option<DateTime<Utc>>,
+    /// When the draft ended.
+    pub finished: DateTime<Utc>,
+    /// How many picks were made, not counting skipped turns.
+    pub total_picks: u32,
+    /// Each seat, in seat order.
+    pub seats: Vec<SeatSummary>,
+}
+
+impl DraftSummary {
+    /// Returns how long the draft took from start to finish, pauses included, if the start is known.
+    pub fn duration(&self) -> Option<Duration> {
+        self.started.map(|started| self.finished - started)
+    }
+}
+
+impl League {
+    /// Returns the wrap-up made when the draft ended: how long it took, how many picks were made, and each seat's roster,
+    /// autopicks and skipped turns, e.g. for a bot to post once [`League::lock`] makes the final pick.
+    ///
+    /// Returns None until the draft is over. Trades and waivers after it do not change the summary.
+    pub fn draft_summary(&self) -> Option<&DraftSummary> {
+        self.summary.as_ref()
+    }
+    // sums up the draft as it ends
+    pub(crate) fn summarize(&mut self) {
+        let (made, _) = self.pick_history();
+        let mut skipped = vec![0; self.players.len()];
+        for (pick, made) in made.iter().enumerate() {
+            if made.is_none() {
+                if let Some(count) = skipped.get_mut(self.seat_at(pick as u32) as usize) {
+                    *count += 1;
+                }
+            }
+        }
+        let total_picks = made.iter().flatten().count() as u32;
+        let seats = self
+            .players
+            .iter()
+            .zip(skipped)
+            .map(|(player, skipped)| SeatSummary {
+                user: player.id,
+                roster: player.picks.iter().map(|i| i.name().to_string()).collect(),
+                autopicks: player.autopicks,
+                skipped,
+            })
+            .collect();
+        self.summary = Some(DraftSummary {
+            started: self.started_at,
+            finished: Utc::now(),
+            total_picks,
+            seats,
+        });
+    }
+}
+
+#[cfg(test)]
+mod summary_tests {
+    use super::*;
+    use crate::test_support::new_league;
+    use crate::{DraftPool, DraftType, Draftable};
+
+    #[test]
+    fn the_final_pick_sums_up_the_draft() {
+        let mut league = new_league(2, DraftType::Snake, 2);
+        let pool = ["Pikachu", "Bulbasaur", "Charmander", "Squirtle"]
+            .iter()
+            .map(|name| Box::new(*name) as Draftable)
+            .collect();
+        league.set_pool(DraftPool::new(pool)).unwrap();
+        league.activate().unwrap();
+        league.lock(Box::new("Squirtle")).unwrap();
+        league.skip().unwrap();
+        league.autopick().unwrap();
+        assert!(league.draft_summary().is_none());
+        league.lock(Box::new("Bulbasaur")).unwrap();
+
+        let summary = league.draft_summary().unwrap().clone();
+        assert_eq!(summary.total_picks, 3);
+        assert!(summary.duration().unwrap() >= Duration::zero());
+        assert_eq!(summary.seats[0].roster, vec!["Squirtle", "Bulbasaur"]);
+        assert_eq!(summary.seats[1].roster, vec!["Pikachu"]);
+        assert_eq!(summary.seats[1].autopicks, 1);
+        assert_eq!(summary.seats[1].skipped, 1);
+        assert_eq!(summary.seats[0].skipped, 0);
+
+        let restored =
+            League::restore(&league.snapshot(), |name| Some(Box::new(name.to_string()))).unwrap();
+        assert_eq!(restored.draft_summary(), Some(&summary));
+        league.rewind_to_pick(3).unwrap();
+        assert!(league.draft_summary().is_none(), "the draft is back on");
+    }
+}
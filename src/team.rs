@@ -0,0 +1,177 @@
+use crate::{ActivePlayer, League, LeagueError};
+use poise::serenity_prelude as serenity;
+use serenity::Mentionable;
+
+/// The identity of a seat in a [`League`]: a display name with optional flair.
+///
+/// The users behind a Team are the owners of its seat (see [`ActivePlayer::owners`]), so a Team survives
+/// co-owners coming and going. Once a seat has a Team, the crate's outputs refer to the seat by team name instead of a user mention.
+#[derive(Debug, Clone)]
+pub struct Team {
+    name: String,
+    emoji: Option<String>,
+    color: Option<serenity::Colour>,
+}
+
+impl Team {
+    /// Creates a Team with no emoji or color.
+    pub fn new(name: String) -> Team {
+        Team {
+            name,
+            emoji: None,
+            color: None,
+        }
+    }
+    /// The Team's display name.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+    /// The Team's emoji, as a unicode emoji or a custom emoji in Discord's `<:name:id>` format.
+    pub fn emoji(&self) -> Option<&str> {
+        self.emoji.as_deref()
+    }
+    /// Sets the Team's emoji.
+    pub fn set_emoji(&mut self, emoji: Option<String>) {
+        self.emoji = emoji;
+    }
+    /// The Team's color, for embeds.
+    pub fn color(&self) -> Option<serenity::Colour> {
+        self.color
+    }
+    /// Sets the Team's color.
+    pub fn set_color(&mut self, color: Option<serenity::Colour>) {
+        self.color = color;
+    }
+}
+
+impl ActivePlayer {
+    /// Returns the seat's [`Team`], if one has been set.
+    pub fn team(&self) -> Option<&Team> {
+        self.team.as_ref()
+    }
+    /// Returns how the crate refers to this seat in messages: the team name (with its emoji, if any), or a mention of the seat's owner.
+    pub fn display_name(&self) -> String {
+        match &self.team {
+            Some(Team {
+                name,
+                emoji: Some(emoji),
+                ..
+            }) => format!("{emoji} {name}"),
+            Some(team) => team.name.clone(),
+            None => self.id.mention().to_string(),
+        }
+    }
+}
+
+impl League {
+    /// Gives a player's seat a [`Team`] identity, replacing any it had, and returns it.
+    ///
+    /// # Errors
+    ///
+    /// If the player is not in the league, returns [`LeagueError::PlayerNotFoundError`].
+    ///
+    /// If the team name is empty, returns [`LeagueError::InvalidTeamNameError`].
+    ///
+    /// If another seat's team already uses the name (ignoring case), returns [`LeagueError::TeamNameTakenError`].
+    pub fn set_team(&mut self, id: serenity::UserId, team: Team) -> Result<&Team, LeagueError> {
+        let seat = self.seat_of(id)? as usize;
+        self.validate_team_name(seat, &team.name)?;
+        Ok(self.players[seat].team.insert(team))
+    }
+    /// Renames a player's [`Team`], keeping its emoji and color, and returns it.
+    ///
+    /// # Errors
+    ///
+    /// If the player is not in the league, returns [`LeagueError::PlayerNotFoundError`].
+    ///
+    /// If the player's seat has no team, returns [`LeagueError::TeamNotFoundError`].
+    ///
+    /// If the new name is empty or taken by another seat's team, returns the same errors as [`League::set_team`].
+    pub fn rename_team(
+        &mut self,
+        id: serenity::UserId,
+        name: String,
+    ) -> Result<&Team, LeagueError> {
+        let seat = self.seat_of(id)? as usize;
+        if self.players[seat].team.is_none() {
+            return Err(LeagueError::TeamNotFoundError);
+        }
+        self.validate_team_name(seat, &name)?;
+        let team = self.players[seat].team.as_mut().unwrap();
+        team.name = name;
+        Ok(team)
+    }
+    /// Removes a player's [`Team`], so the seat is shown by its owner's mention again, and returns the removed team.
+    ///
+    /// # Errors
+    ///
+    /// If the player is not in the league, returns [`LeagueError::PlayerNotFoundError`].
+    ///
+    /// If the player's seat has no team, returns [`LeagueError::TeamNotFoundError`].
+    pub fn clear_team(&mut self, id: serenity::UserId) -> Result<Team, LeagueError> {
+        let Some(player) = self.get_player_mut(id) else {
+            return Err(LeagueError::PlayerNotFoundError);
+        };
+        player.team.take().ok_or(LeagueError::TeamNotFoundError)
+    }
+    /// Finds the seat whose team has the given name (ignoring case), for commands where users name a team rather than mention a user.
+    ///
+    /// # Errors
+    ///
+    /// If no seat has a team with that name, returns [`LeagueError::TeamNotFoundError`].
+    pub fn player_by_team(&self, name: &str) -> Result<&ActivePlayer, LeagueError> {
+        self.players
+            .iter()
+            .find(|p| matches!(&p.team, Some(team) if team.name.eq_ignore_ascii_case(name)))
+            .ok_or(LeagueError::TeamNotFoundError)
+    }
+    fn validate_team_name(&self, seat: usize, name: &str) -> Result<(), LeagueError> {
+        if name.trim().is_empty() {
+            return Err(LeagueError::InvalidTeamNameError);
+        }
+        match self.player_by_team(name) {
+            Ok(other) if !std::ptr::eq(other, &self.players[seat]) => {
+                Err(LeagueError::TeamNameTakenError)
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod team_tests {
+    use super::*;
+    use crate::test_support::new_league;
+    use crate::DraftType;
+
+    fn league() -> League {
+        new_league(2, DraftType::Snake, 2)
+    }
+
+    #[test]
+    fn team_names_are_unique() {
+        let mut league = league();
+        league
+            .set_team(serenity::UserId(1), Team::new("Rockets".to_string()))
+            .unwrap();
+        match league.set_team(serenity::UserId(2), Team::new("rockets".to_string())) {
+            Err(LeagueError::TeamNameTakenError) => {}
+            _ => panic!("team names must be unique"),
+        }
+        league
+            .rename_team(serenity::UserId(1), "ROCKETS".to_string())
+            .expect("renaming to your own name is fine");
+    }
+
+    #[test]
+    fn display_name_prefers_team() {
+        let mut league = league();
+        assert_eq!(league.players[0].display_name(), "<@1>");
+        let mut team = Team::new("Rockets".to_string());
+        team.set_emoji(Some("🚀".to_string()));
+        league.set_team(serenity::UserId(1), team).unwrap();
+        assert_eq!(league.players[0].display_name(), "🚀 Rockets");
+        let seat = league.player_by_team("rockets").unwrap();
+        assert_eq!(seat.id, serenity::UserId(1));
+    }
+}
@@ -0,0 +1,195 @@
+use crate::{League, LeagueError};
+use poise::serenity_prelude as serenity;
+use std::future::Future;
+
+const AUDIT_LOG_REASON: &str = "Drafted onto a team";
+
+impl League {
+    /// Gives a captain's team a Discord role, which [`League::sync_team_roles`] gives every user they draft as a
+    /// [`UserDraftItem`](crate::UserDraftItem). Returns the team's previous role, if it had one.
+    ///
+    /// # Errors
+    ///
+    /// If the captain does not own a seat, returns [`LeagueError::PlayerNotFoundError`].
+    pub fn set_team_role(
+        &mut self,
+        captain: serenity::UserId,
+        role: serenity::RoleId,
+    ) -> Result<Option<serenity::RoleId>, LeagueError> {
+        let captain = self
+            .get_player(captain)
+            .ok_or(LeagueError::PlayerNotFoundError)?
+            .id;
+        Ok(self.team_roles.insert(captain, role))
+    }
+    /// Stops giving a captain's team a role, and returns the role it had. Users who already have it keep it.
+    pub fn remove_team_role(&mut self, captain: serenity::UserId) -> Option<serenity::RoleId> {
+        self.team_roles.remove(&self.seat_owner(captain))
+    }
+    /// Returns the role of a captain's team, if it has one.
+    pub fn team_role(&self, captain: serenity::UserId) -> Option<serenity::RoleId> {
+        self.team_roles.get(&self.seat_owner(captain)).copied()
+    }
+    /// Brings the team roles of drafted users in line with the teams holding them: each gets their team's role (see
+    /// [`League::set_team_role`]) and loses the role of any other team. Resolves to the number of roles added or removed.
+    ///
+    /// Pass the users traded away after a [`League::trade`] of [`UserDraftItem`](crate::UserDraftItem)s, or None to sync every drafted user,
+    /// e.g. once the draft is over. Users no team holds lose every team role.
+    ///
+    /// Each user's roles are looked up first, so only roles that actually change are added or removed.
+    /// The future holds no reference to the League:
+    ///
+    /// ```ignore
+    /// league.trade(captain1, "<@10>", captain2, "<@11>")?;
+    /// let traded = [serenity::UserId(10), serenity::UserId(11)];
+    /// league.sync_team_roles(&ctx.http(), guild_id, Some(&traded)).await?;
+    /// ```
+    pub fn sync_team_roles<'a>(
+        &self,
+        http: &'a serenity::Http,
+        guild: serenity::GuildId,
+        users: Option<&[serenity::UserId]>,
+    ) -> impl Future<Output = serenity::Result<usize>> + Send + 'a {
+        let wanted = self.wanted_roles(users);
+        let team_roles: Vec<serenity::RoleId> = self.team_roles.values().copied().collect();
+        async move {
+            let mut changed = 0;
+            for (user, team) in wanted {
+                let member = http.get_member(guild.0, user.0).await?;
+                for (role, add) in role_changes(&member.roles, team, &team_roles) {
+                    match add {
+                        true => {
+                            http.add_member_role(guild.0, user.0, role.0, Some(AUDIT_LOG_REASON))
+                                .await?
+                        }
+                        false => {
+                            http.remove_member_role(guild.0, user.0, role.0, Some(AUDIT_LOG_REASON))
+                                .await?
+                        }
+                    }
+                    changed += 1;
+                }
+            }
+            Ok(changed)
+        }
+    }
+    // (user, the role of the team holding them, if any) for every user to sync
+    fn wanted_roles(
+        &self,
+        users: Option<&[serenity::UserId]>,
+    ) -> Vec<(serenity::UserId, Option<serenity::RoleId>)> {
+        let rosters = self.user_rosters();
+        let users: Vec<serenity::UserId> = match users {
+            Some(users) => users.to_vec(),
+            None => rosters
+                .iter()
+                .flat_map(|(_, users)| users.clone())
+                .collect(),
+        };
+        users
+            .into_iter()
+            .map(|user| {
+                let team = rosters
+                    .iter()
+                    .find(|(_, drafted)| drafted.contains(&user))
+                    .and_then(|(captain, _)| self.team_roles.get(captain))
+                    .copied();
+                (user, team)
+            })
+            .collect()
+    }
+}
+
+// (role, true to add it or false to remove it) for every team role a member should gain or lose, given the roles they hold
+fn role_changes(
+    held: &[serenity::RoleId],
+    team: Option<serenity::RoleId>,
+    team_roles: &[serenity::RoleId],
+) -> Vec<(serenity::RoleId, bool)> {
+    let mut changes: Vec<(serenity::RoleId, bool)> = team_roles
+        .iter()
+        .filter(|role| Some(**role) != team && held.contains(role))
+        .map(|role| (*role, false))
+        .collect();
+    if let Some(team) = team.filter(|team| !held.contains(team)) {
+        changes.push((team, true));
+    }
+    changes
+}
+
+#[cfg(test)]
+mod team_roles_tests {
+    use super::*;
+    use crate::test_support::new_league;
+    use crate::{DraftType, UserDraftItem};
+
+    #[test]
+    fn traded_users_swap_team_roles() {
+        let mut league = new_league(2, DraftType::Snake, 1);
+        league.activate().unwrap();
+        league
+            .lock(Box::new(UserDraftItem::new(
+                serenity::UserId(10),
+                "Creenis",
+            )))
+            .unwrap();
+        league
+            .lock(Box::new(UserDraftItem::new(serenity::UserId(11), "Ash")))
+            .unwrap();
+        league
+            .set_team_role(serenity::UserId(1), serenity::RoleId(100))
+            .unwrap();
+        league
+            .set_team_role(serenity::UserId(2), serenity::RoleId(200))
+            .unwrap();
+        match league.set_team_role(serenity::UserId(3), serenity::RoleId(300)) {
+            Err(LeagueError::PlayerNotFoundError) => {}
+            _ => panic!("user 3 is not a captain"),
+        }
+        let all = [serenity::RoleId(100), serenity::RoleId(200)];
+        let mut wanted = league.wanted_roles(None);
+        wanted.sort();
+        assert_eq!(
+            wanted,
+            vec![
+                (serenity::UserId(10), Some(serenity::RoleId(100))),
+                (serenity::UserId(11), Some(serenity::RoleId(200))),
+            ]
+        );
+        assert_eq!(
+            role_changes(&[], Some(serenity::RoleId(100)), &all),
+            vec![(serenity::RoleId(100), true)],
+            "roles the member does not hold are left alone"
+        );
+
+        league
+            .trade(serenity::UserId(1), "<@10>", serenity::UserId(2), "<@11>")
+            .unwrap();
+        let [(_, team)] = league.wanted_roles(Some(&[serenity::UserId(10)]))[..] else {
+            panic!("one user was asked for");
+        };
+        assert_eq!(team, Some(serenity::RoleId(200)));
+        let held = [serenity::RoleId(100), serenity::RoleId(5)];
+        assert_eq!(
+            role_changes(&held, team, &all),
+            vec![
+                (serenity::RoleId(100), false),
+                (serenity::RoleId(200), true)
+            ]
+        );
+        assert_eq!(
+            role_changes(&[serenity::RoleId(200)], team, &all),
+            vec![],
+            "nothing changes for a member with the right role"
+        );
+        assert_eq!(
+            league.remove_team_role(serenity::UserId(2)),
+            Some(serenity::RoleId(200))
+        );
+        assert_eq!(
+            league.wanted_roles(Some(&[serenity::UserId(10)])),
+            vec![(serenity::UserId(10), None)],
+            "a team without a role has none to give"
+        );
+    }
+}
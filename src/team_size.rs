@@ -0,0 +1,110 @@
+use crate::{DraftType, League, LeagueError};
+use poise::serenity_prelude as serenity;
+
+impl League {
+    /// Gives a player's seat its own team size, e.g. a smaller roster for a late joiner, or None to go back to the League's.
+    /// Seats with smaller teams drop out of the rotation once their team is full, and the rest of the draft runs in the same
+    /// order without them.
+    ///
+    /// # Errors
+    ///
+    /// If the draft has started, returns [`LeagueError::LeagueActiveError`] (or [`LeagueError::WrongPhaseError`] once it is over).
+    ///
+    /// If the League is a Rochester draft, whose packs deal every seat the same number of picks, returns [`LeagueError::WrongDraftTypeError`].
+    ///
+    /// If the player is not in the league, returns [`LeagueError::PlayerNotFoundError`].
+    ///
+    /// If the team size is 0, returns [`LeagueError::InvalidTeamSizeError`].
+    pub fn set_player_team_size(
+        &mut self,
+        id: serenity::UserId,
+        team_size: Option<u32>,
+    ) -> Result<(), LeagueError> {
+        self.require_pre_draft()?;
+        if self.draft_type == DraftType::Rochester {
+            return Err(LeagueError::WrongDraftTypeError);
+        }
+        if team_size == Some(0) {
+            return Err(LeagueError::InvalidTeamSizeError);
+        }
+        let player = self
+            .get_player_mut(id)
+            .ok_or(LeagueError::PlayerNotFoundError)?;
+        player.team_size = team_size;
+        self.recalculate_final_pick();
+        Ok(())
+    }
+    /// Returns the number of picks a player's seat makes: its own team size if it has one, or the League's.
+    ///
+    /// # Errors
+    ///
+    /// If the player is not in the league, returns [`LeagueError::PlayerNotFoundError`].
+    pub fn player_team_size(&self, id: serenity::UserId) -> Result<u32, LeagueError> {
+        let player = self
+            .get_player(id)
+            .ok_or(LeagueError::PlayerNotFoundError)?;
+        Ok(player.team_size.unwrap_or(self.team_size))
+    }
+    // the seat for every pick when team sizes differ, walking the usual rotation and passing over full teams;
+    // None when every seat makes the same number of picks and the draft type alone decides the order
+    pub(crate) fn uneven_order(&self) -> Option<Vec<u32>> {
+        let sizes: Vec<u32> = self
+            .players
+            .iter()
+            .map(|p| p.team_size.unwrap_or(self.team_size))
+            .collect();
+        let largest = *sizes.iter().max()?;
+        if sizes.iter().all(|size| *size == largest) {
+            return None;
+        }
+        let mut made = vec![0; sizes.len()];
+        let mut order = Vec::new();
+        for pick in 0..sizes.len() as u32 * largest {
+            let seat = self.rotation_seat(pick) as usize;
+            if made[seat] < sizes[seat] {
+                made[seat] += 1;
+                order.push(seat as u32);
+            }
+        }
+        Some(order)
+    }
+}
+
+#[cfg(test)]
+mod team_size_tests {
+    use super::*;
+    use crate::test_support::{new_league, pokemon};
+    use crate::LeaguePhase;
+
+    #[test]
+    fn small_teams_leave_the_rotation() {
+        let mut league = new_league(3, DraftType::Snake, 3);
+        league
+            .set_player_team_size(serenity::UserId(3), Some(1))
+            .unwrap();
+        assert_eq!(league.player_team_size(serenity::UserId(3)).unwrap(), 1);
+        assert_eq!(league.final_pick, 6);
+        league.activate().unwrap();
+        // 1 2 3 | 2 1 | 1 2
+        let mut seats = Vec::new();
+        for n in 0..7 {
+            seats.push(league.current_player().unwrap().id.0);
+            league.lock(pokemon(&format!("Pokemon {n}"))).unwrap();
+        }
+        assert_eq!(seats, vec![1, 2, 3, 2, 1, 1, 2]);
+        assert_eq!(league.phase(), LeaguePhase::PostDraft);
+        match league.set_player_team_size(serenity::UserId(3), None) {
+            Err(LeagueError::WrongPhaseError) => {}
+            _ => panic!("the draft is over"),
+        }
+    }
+
+    #[test]
+    fn teams_need_a_pick() {
+        let mut league = new_league(1, DraftType::Linear, 3);
+        match league.set_player_team_size(serenity::UserId(1), Some(0)) {
+            Err(LeagueError::InvalidTeamSizeError) => {}
+            _ => panic!("an empty team drafts nothing"),
+        }
+    }
+}
@@ -0,0 +1,173 @@
+use crate::{League, LeagueError, PickResult};
+use poise::serenity_prelude as serenity;
+use std::collections::HashMap;
+
+const PLACEHOLDERS: [&str; 8] = [
+    "player",
+    "item",
+    "source",
+    "round",
+    "pick",
+    "next_player",
+    "mentions",
+    "league",
+];
+
+/// The messages a [`League`] sends that servers can reword with a [`MessageTemplate`].
+///
+/// The defaults below are in English, and come from the League's [`Translations`](crate::Translations) if it has a locale.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MessageKind {
+    /// One line per pick in [`League::pick_announcement`]. Default: `{player} picked **{item}**{source}`
+    Pick,
+    /// The line naming who is up next in [`League::pick_announcement`]. Default: `{next_player} is on the clock.`
+    OnTheClock,
+    /// [`League::turn_ping`]. Default: `{mentions} you're on the clock in **{league}** (round {round}, pick {pick}).`
+    TurnPing,
+    /// Replaces the on the clock line once the final pick is made. Default: `The **{league}** draft is complete!`
+    Complete,
+}
+
+/// Text with placeholders that are filled in when a message is sent:
+///
+/// - `{player}`: the seat that picked, by team name or mention
+/// - `{item}`: the item picked, with its emoji
+/// - `{source}`: a note on picks nobody chose when they were made, like " (auto-drafted from queue)" (see [`PickSource`](crate::PickSource))
+/// - `{round}`: the round of the pick
+/// - `{pick}`: the overall pick number
+/// - `{next_player}`: the seat on the clock, by team name or mention
+/// - `{mentions}`: mentions of everyone who can pick for the seat on the clock
+/// - `{league}`: the League's name
+///
+/// Placeholders that do not apply to a message are left empty.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MessageTemplate {
+    text: String,
+}
+
+impl MessageTemplate {
+    /// Creates a template, checking its placeholders.
+    ///
+    /// # Errors
+    ///
+    /// If the text uses a placeholder that does not exist, returns [`LeagueError::UnknownPlaceholderError`] with its name.
+    pub fn new(text: String) -> Result<MessageTemplate, LeagueError> {
+        let mut rest = text.as_str();
+        while let Some(start) = rest.find('{') {
+            let Some(len) = rest[start..].find('}') else {
+                break;
+            };
+            let name = &rest[start + 1..start + len];
+            if !PLACEHOLDERS.contains(&name) {
+                return Err(LeagueError::UnknownPlaceholderError(name.to_string()));
+            }
+            rest = &rest[start + len + 1..];
+        }
+        Ok(MessageTemplate { text })
+    }
+    /// Returns the template's text, placeholders and all.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+    /// Fills in the template's placeholders. Placeholders without a value are left empty.
+    pub fn render(&self, values: &[(&str, String)]) -> String {
+        crate::i18n::fill_placeholders(&self.text, |name| {
+            match values.iter().find(|(key, _)| *key == name) {
+                Some((_, value)) => Some(value.as_str()),
+                None => PLACEHOLDERS.contains(&name).then_some(""),
+            }
+        })
+    }
+}
+
+impl MessageKind {
+    // the message's key in Translations
+    pub(crate) fn text_key(self) -> &'static str {
+        match self {
+            MessageKind::Pick => "template.pick",
+            MessageKind::OnTheClock => "template.on_the_clock",
+            MessageKind::TurnPing => "template.turn_ping",
+            MessageKind::Complete => "template.complete",
+        }
+    }
+}
+
+/// The templates a server has reworded; every other message uses the default for the League's locale.
+pub(crate) type Templates = HashMap<MessageKind, MessageTemplate>;
+
+impl League {
+    /// Rewords one of the League's messages, returning the template it replaced.
+    pub fn set_template(
+        &mut self,
+        kind: MessageKind,
+        template: MessageTemplate,
+    ) -> MessageTemplate {
+        let previous = self.template(kind);
+        self.templates.insert(kind, template);
+        previous
+    }
+    /// Returns the template the League uses for a message.
+    pub fn template(&self, kind: MessageKind) -> MessageTemplate {
+        match self.templates.get(&kind) {
+            Some(template) => template.clone(),
+            // bundles are checked when translations are set, so defaults always have valid placeholders
+            None => MessageTemplate {
+                text: self.text(kind.text_key(), &[]),
+            },
+        }
+    }
+    /// Puts a message back to its default wording.
+    pub fn reset_template(&mut self, kind: MessageKind) {
+        self.templates.remove(&kind);
+    }
+    // the templates the server has reworded, for snapshots
+    pub(crate) fn custom_template(&self, kind: MessageKind) -> Option<&MessageTemplate> {
+        self.templates.get(&kind)
+    }
+    /// Renders [`League::pick_announcement`] as an embed, colored with the picking team's color if it has one.
+    pub fn pick_embed(&self, picks: &[PickResult], embed: &mut serenity::CreateEmbed) {
+        embed.description(self.pick_announcement(picks));
+        let color = picks
+            .last()
+            .and_then(|pick| self.get_player(pick.user))
+            .and_then(|player| player.team())
+            .and_then(|team| team.color());
+        if let Some(color) = color {
+            embed.color(color);
+        }
+    }
+}
+
+#[cfg(test)]
+mod template_tests {
+    use super::*;
+
+    #[test]
+    fn unknown_placeholders_are_rejected() {
+        match MessageTemplate::new("{player} yoinked {itme}".to_string()) {
+            Err(LeagueError::UnknownPlaceholderError(name)) => assert_eq!(name, "itme"),
+            _ => panic!("itme is a typo"),
+        }
+    }
+
+    #[test]
+    fn render_fills_known_placeholders() {
+        let template =
+            MessageTemplate::new("{player} yoinked {item} in round {round}!".to_string()).unwrap();
+        let rendered = template.render(&[
+            ("player", "Rockets".to_string()),
+            ("item", "Charizard".to_string()),
+        ]);
+        assert_eq!(rendered, "Rockets yoinked Charizard in round !");
+    }
+
+    #[test]
+    fn values_are_not_filled_in_themselves() {
+        let template = MessageTemplate::new("{player} picked {item}".to_string()).unwrap();
+        let rendered = template.render(&[
+            ("player", "Team {item}".to_string()),
+            ("item", "Charizard".to_string()),
+        ]);
+        assert_eq!(rendered, "Team {item} picked Charizard");
+    }
+}
@@ -0,0 +1,40 @@
+// what the unit tests share: Leagues of numbered users, and Pokemon to draft in them
+use crate::{DraftItem, DraftPool, DraftType, Draftable, League};
+use poise::serenity_prelude as serenity;
+
+pub(crate) struct Pokemon {
+    pub(crate) name: String,
+}
+impl DraftItem for Pokemon {
+    fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+pub(crate) fn pokemon(name: &str) -> Draftable {
+    Box::new(Pokemon {
+        name: name.to_string(),
+    })
+}
+
+// turns saved names back into Pokemon, for League::restore and League::replay
+pub(crate) fn resolve(name: &str) -> Option<Draftable> {
+    Some(pokemon(name))
+}
+
+pub(crate) fn pokemon_pool(names: &[&str]) -> DraftPool {
+    DraftPool::new(names.iter().map(|name| pokemon(name)).collect())
+}
+
+// a League called Creenis, with users 1 to players in seat order
+pub(crate) fn new_league(players: u64, draft_type: DraftType, team_size: u32) -> League {
+    let users: Vec<serenity::UserId> = (1..=players).map(serenity::UserId).collect();
+    League::new(
+        &users,
+        69420,
+        "Creenis".to_string(),
+        None,
+        draft_type,
+        team_size,
+    )
+}
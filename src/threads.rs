@@ -0,0 +1,108 @@
+use crate::{League, LeaguePhase};
+use poise::serenity_prelude as serenity;
+use std::future::Future;
+
+// a week, the longest Discord lets a thread sit idle before archiving it; slow drafts can go days between picks
+const AUTO_ARCHIVE_MINUTES: u16 = 10080;
+
+impl League {
+    /// Opens a public thread named after the League in its output channel, so the draft doesn't clutter the channel itself.
+    ///
+    /// The future holds no reference to the League; once it resolves, hand the thread to [`League::set_thread`]
+    /// to post the League's announcements there:
+    ///
+    /// ```ignore
+    /// let parent = league.output_channel(guild.default_output());
+    /// let thread = league.create_thread(&ctx.http(), parent).await?;
+    /// league.set_thread(thread.id, parent);
+    /// ```
+    pub fn create_thread<'a>(
+        &self,
+        http: &'a serenity::Http,
+        parent: serenity::ChannelId,
+    ) -> impl Future<Output = serenity::Result<serenity::GuildChannel>> + Send + 'a {
+        let name = self.name.clone();
+        async move {
+            // serenity only opens threads without a starter message through create_private_thread; the kind makes it public
+            parent
+                .create_private_thread(http, |t| {
+                    t.name(name)
+                        .kind(serenity::ChannelType::PublicThread)
+                        .auto_archive_duration(AUTO_ARCHIVE_MINUTES)
+                })
+                .await
+        }
+    }
+    /// Makes a thread opened with [`League::create_thread`] the League's output, remembering the channel it was opened in
+    /// so the next season (see [`League::rollover`]) posts there instead of the finished thread. Returns the previous output, if any.
+    pub fn set_thread(
+        &mut self,
+        thread: serenity::ChannelId,
+        parent: serenity::ChannelId,
+    ) -> Option<serenity::ChannelId> {
+        let previous = self.output.replace(thread);
+        self.thread = Some((thread, parent));
+        previous
+    }
+    /// Returns the League's own thread, if it has one and still posts there.
+    pub fn thread(&self) -> Option<serenity::ChannelId> {
+        self.thread.map(|(thread, _)| thread)
+    }
+    // the channel the League posted to before it had a thread
+    pub(crate) fn channel_outside_thread(&self) -> Option<serenity::ChannelId> {
+        match self.thread {
+            Some((_, parent)) => Some(parent),
+            None => self.output,
+        }
+    }
+    /// Archives and locks the League's thread once the draft is over, keeping its picks readable but closed to new messages.
+    ///
+    /// Resolves to Ok(None) without touching Discord if the League has no thread of its own, or if the draft is not over yet.
+    pub fn archive_thread<'a>(
+        &self,
+        http: &'a serenity::Http,
+    ) -> impl Future<Output = serenity::Result<Option<serenity::GuildChannel>>> + Send + 'a {
+        let thread = match self.phase {
+            LeaguePhase::PostDraft | LeaguePhase::Archived => self.thread(),
+            _ => None,
+        };
+        async move {
+            match thread {
+                Some(thread) => thread
+                    .edit_thread(http, |t| t.archived(true).locked(true))
+                    .await
+                    .map(Some),
+                None => Ok(None),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod threads_tests {
+    use super::*;
+    use crate::test_support::new_league;
+    use crate::DraftType;
+
+    #[test]
+    fn threads_follow_the_output() {
+        let mut league = new_league(1, DraftType::Snake, 1);
+        league.set_output(serenity::ChannelId(5));
+        assert_eq!(
+            league.set_thread(serenity::ChannelId(6), serenity::ChannelId(5)),
+            Some(serenity::ChannelId(5))
+        );
+        assert_eq!(league.thread(), Some(serenity::ChannelId(6)));
+        assert_eq!(league.output(), Some(serenity::ChannelId(6)));
+        assert_eq!(
+            league.channel_outside_thread(),
+            Some(serenity::ChannelId(5))
+        );
+        league.set_output(serenity::ChannelId(7));
+        assert_eq!(league.thread(), None);
+        assert_eq!(
+            league.channel_outside_thread(),
+            Some(serenity::ChannelId(7))
+        );
+    }
+}
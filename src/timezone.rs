@@ -0,0 +1,115 @@
+use crate::{League, LeagueError};
+use chrono::{DateTime, Utc};
+use chrono_tz::Tz;
+
+/// How Discord shows a timestamp built with [`discord_timestamp`]. Discord renders it in each reader's own timezone and language.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimestampStyle {
+    /// 16:20
+    ShortTime,
+    /// 16:20:30
+    LongTime,
+    /// 20/04/2021
+    ShortDate,
+    /// 20 April 2021
+    LongDate,
+    /// 20 April 2021 16:20
+    ShortDateTime,
+    /// Tuesday, 20 April 2021 16:20
+    LongDateTime,
+    /// 2 months ago, in 3 hours...
+    Relative,
+}
+
+impl TimestampStyle {
+    fn flag(self) -> char {
+        match self {
+            TimestampStyle::ShortTime => 't',
+            TimestampStyle::LongTime => 'T',
+            TimestampStyle::ShortDate => 'd',
+            TimestampStyle::LongDate => 'D',
+            TimestampStyle::ShortDateTime => 'f',
+            TimestampStyle::LongDateTime => 'F',
+            TimestampStyle::Relative => 'R',
+        }
+    }
+}
+
+/// Builds a Discord dynamic timestamp (`<t:1618953630:R>`), which every reader sees in their own timezone.
+pub fn discord_timestamp(at: DateTime<Utc>, style: TimestampStyle) -> String {
+    format!("<t:{}:{}>", at.timestamp(), style.flag())
+}
+
+impl League {
+    /// Sets the timezone the League's times are written in by [`League::local_time`], by its IANA name (e.g. "Europe/Madrid"),
+    /// or goes back to UTC with None.
+    ///
+    /// # Errors
+    ///
+    /// If the name is not a timezone, returns [`LeagueError::InvalidTimezoneError`].
+    pub fn set_timezone(&mut self, name: Option<&str>) -> Result<(), LeagueError> {
+        self.timezone = match name {
+            Some(name) => Some(
+                name.parse()
+                    .map_err(|_| LeagueError::InvalidTimezoneError)?,
+            ),
+            None => None,
+        };
+        Ok(())
+    }
+    /// Returns the League's timezone, if it has one. Times are written in UTC otherwise.
+    pub fn timezone(&self) -> Option<Tz> {
+        self.timezone
+    }
+    /// Writes a time in the League's timezone, e.g. "2024-03-01 18:30 CET", for places Discord's dynamic timestamps do not
+    /// render, like embed footers, code blocks and logs.
+    pub fn local_time(&self, at: DateTime<Utc>) -> String {
+        let tz = self.timezone.unwrap_or(Tz::UTC);
+        at.with_timezone(&tz)
+            .format("%Y-%m-%d %H:%M %Z")
+            .to_string()
+    }
+    /// Writes the League's next deadline (see [`League::next_deadline`]) in its timezone, followed by a Discord timestamp
+    /// counting down to it, e.g. "2024-03-01 18:30 CET (<t:1709314200:R>)".
+    ///
+    /// Returns None if there is no deadline.
+    pub fn deadline_text(&self, now: DateTime<Utc>) -> Option<String> {
+        let deadline = self.next_deadline(now)?;
+        Some(format!(
+            "{} ({})",
+            self.local_time(deadline),
+            discord_timestamp(deadline, TimestampStyle::Relative)
+        ))
+    }
+}
+
+#[cfg(test)]
+mod timezone_tests {
+    use super::*;
+    use crate::test_support::new_league;
+    use crate::DraftType;
+    use chrono::TimeZone;
+
+    #[test]
+    fn times_follow_the_league() {
+        let mut league = new_league(1, DraftType::Snake, 1);
+        let at = Utc.with_ymd_and_hms(2024, 7, 1, 18, 30, 0).unwrap();
+        assert_eq!(league.local_time(at), "2024-07-01 18:30 UTC");
+        match league.set_timezone(Some("Mars/Olympus_Mons")) {
+            Err(LeagueError::InvalidTimezoneError) => {}
+            _ => panic!("not a timezone"),
+        }
+        league.set_timezone(Some("Europe/Madrid")).unwrap();
+        assert_eq!(league.local_time(at), "2024-07-01 20:30 CEST");
+
+        league.set_start_time(Some(at)).unwrap();
+        assert_eq!(
+            league.deadline_text(at).unwrap(),
+            "2024-07-01 20:30 CEST (<t:1719858600:R>)"
+        );
+        assert_eq!(
+            discord_timestamp(at, TimestampStyle::LongDateTime),
+            "<t:1719858600:F>"
+        );
+    }
+}
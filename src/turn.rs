@@ -0,0 +1,108 @@
+use crate::{DraftType, League};
+use poise::serenity_prelude as serenity;
+
+/// Which way the draft is moving around the table, from [`League::direction`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    /// From seat 0 towards the last seat. Linear drafts always go forward.
+    Forward,
+    /// From the last seat back towards seat 0, in the even rounds of a snake draft.
+    Backward,
+}
+
+/// Where the draft stands on the pick on the clock, from [`League::turn_info`], e.g. to explain the order in an announcement.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TurnInfo {
+    /// The overall pick number, counting every pick in the draft from 1.
+    pub pick: u32,
+    /// The round the pick is in, starting at 1.
+    pub round: u32,
+    /// The seat on the clock.
+    pub seat: u32,
+    /// The seat's original owner.
+    pub user: serenity::UserId,
+    /// Which way the draft is moving.
+    pub direction: Direction,
+    /// True if the seat also makes the pick just before or just after this one, at the turn of a snake.
+    pub double_pick: bool,
+}
+
+impl League {
+    /// Returns which way the draft is moving around the table on the pick on the clock. Snake and Rochester drafts go
+    /// [`Direction::Forward`] in odd rounds and [`Direction::Backward`] in even ones. Other drafts always go forward.
+    pub fn direction(&self) -> Direction {
+        match self.draft_type {
+            DraftType::Snake | DraftType::Rochester
+                if self.round_of(self.total_picks).is_multiple_of(2) =>
+            {
+                Direction::Backward
+            }
+            _ => Direction::Forward,
+        }
+    }
+    /// Returns the pick on the clock with its round, direction and whether it is one of a double pick at the turn,
+    /// or None unless the draft is underway. Auctions and head-to-head drafts have no picks in turn, so they return None too.
+    pub fn turn_info(&self) -> Option<TurnInfo> {
+        if !self.phase.is_drafting() || self.require_turns().is_err() {
+            return None;
+        }
+        let pick = self.total_picks;
+        let seat = self.current_seat;
+        let double_pick = self.players.len() > 1
+            && ((pick > 0 && self.seat_at(pick - 1) == seat)
+                || (pick < self.final_pick && self.seat_at(pick + 1) == seat));
+        Some(TurnInfo {
+            pick: pick + 1,
+            round: self.round_of(pick),
+            seat,
+            user: self.players[seat as usize].id,
+            direction: self.direction(),
+            double_pick,
+        })
+    }
+}
+
+#[cfg(test)]
+mod turn_tests {
+    use super::*;
+    use crate::test_support::new_league;
+
+    fn league(draft_type: DraftType) -> League {
+        let mut league = new_league(3, draft_type, 2);
+        league.activate().unwrap();
+        league
+    }
+
+    #[test]
+    fn snakes_turn_around_with_a_double_pick() {
+        let mut league = league(DraftType::Snake);
+        let first = league.turn_info().unwrap();
+        assert_eq!(first.direction, Direction::Forward);
+        assert!(!first.double_pick);
+        league.lock(Box::new("Pikachu")).unwrap();
+        league.lock(Box::new("Eevee")).unwrap();
+        let turn = league.turn_info().unwrap();
+        assert_eq!((turn.pick, turn.seat), (3, 2));
+        assert_eq!(turn.direction, Direction::Forward);
+        assert!(turn.double_pick);
+        league.lock(Box::new("Ditto")).unwrap();
+        let turn = league.turn_info().unwrap();
+        assert_eq!((turn.round, turn.seat), (2, 2));
+        assert_eq!(turn.direction, Direction::Backward);
+        assert!(turn.double_pick);
+        league.lock(Box::new("Mew")).unwrap();
+        assert!(!league.turn_info().unwrap().double_pick);
+    }
+
+    #[test]
+    fn linear_drafts_always_go_forward() {
+        let mut league = league(DraftType::Linear);
+        for item in ["Pikachu", "Eevee", "Ditto"] {
+            league.lock(Box::new(item)).unwrap();
+        }
+        let turn = league.turn_info().unwrap();
+        assert_eq!((turn.round, turn.seat), (2, 0));
+        assert_eq!(turn.direction, Direction::Forward);
+        assert!(!turn.double_pick);
+    }
+}
@@ -0,0 +1,116 @@
+use crate::{DraftType, League, LeaguePhase, UpcomingPick};
+use chrono::{DateTime, Utc};
+use poise::serenity_prelude as serenity;
+use serde::{Deserialize, Serialize};
+
+/// A read-only copy of where a [`League`] stands, from [`League::view`], for spectators: a web dashboard or API endpoint
+/// alongside the bot can hold, clone and serialize it freely without any way to change the League.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LeagueView {
+    /// The League's ID.
+    pub id: u64,
+    /// The League's name.
+    pub name: String,
+    /// The League's phase.
+    pub phase: LeaguePhase,
+    /// The League's draft type.
+    pub draft_type: DraftType,
+    /// The number of picks each seat makes, unless it has its own.
+    pub team_size: u32,
+    /// The overall number of the pick on the clock, counting every pick in the draft from 1.
+    pub pick: u32,
+    /// The seat on the clock's original owner, while the draft is underway.
+    pub on_the_clock: Option<serenity::UserId>,
+    /// When the pick on the clock is due, if it is timed or has a deadline (see [`League::next_deadline`]).
+    pub deadline: Option<DateTime<Utc>>,
+    /// The time each seat has for a pick, in seconds, if picks are timed.
+    pub pick_time_limit_secs: Option<i64>,
+    /// Every seat, in seat order.
+    pub seats: Vec<SeatView>,
+    /// The draft board: a row for each round, and in each row the name of every seat's pick, in seat order.
+    /// Skipped picks are None.
+    pub board: Vec<Vec<Option<String>>>,
+    /// The next round's worth of picks, starting with the one on the clock (see [`League::upcoming_picks`]).
+    pub upcoming: Vec<UpcomingPick>,
+}
+
+/// A seat in a [`LeagueView`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SeatView {
+    /// The seat, starting at 0.
+    pub seat: u32,
+    /// The seat's original owner.
+    pub user: serenity::UserId,
+    /// The seat's team name, or its seat label if it has no team.
+    pub name: String,
+    /// The names of the seat's picks, in the order it got them.
+    pub picks: Vec<String>,
+    /// True if the seat is on autopick.
+    pub autopick: bool,
+    /// True if the seat was forfeited.
+    pub forfeited: bool,
+}
+
+impl League {
+    /// Returns a [`LeagueView`] of the League as it stands at now.
+    pub fn view(&self, now: DateTime<Utc>) -> LeagueView {
+        let drafting = self.phase.is_drafting();
+        LeagueView {
+            id: self.id,
+            name: self.name.clone(),
+            phase: self.phase,
+            draft_type: self.draft_type,
+            team_size: self.team_size,
+            pick: self.pick_number(),
+            on_the_clock: drafting.then(|| self.players[self.current_seat as usize].id),
+            deadline: if drafting {
+                self.next_deadline(now)
+            } else {
+                None
+            },
+            pick_time_limit_secs: self.clock.limit().map(|limit| limit.num_seconds()),
+            seats: self
+                .players
+                .iter()
+                .enumerate()
+                .map(|(seat, player)| SeatView {
+                    seat: seat as u32,
+                    user: player.id,
+                    name: self.seat_heading(seat, player),
+                    picks: player.picks.iter().map(|p| p.name().to_string()).collect(),
+                    autopick: player.autopick,
+                    forfeited: player.forfeited,
+                })
+                .collect(),
+            board: self
+                .board_grid()
+                .into_iter()
+                .map(|round| round.into_iter().map(|i| i.map(str::to_string)).collect())
+                .collect(),
+            upcoming: self.upcoming_picks(self.players.len()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod view_tests {
+    use super::*;
+    use crate::test_support::new_league;
+
+    #[test]
+    fn views_follow_the_draft() {
+        let mut league = new_league(2, DraftType::Snake, 2);
+        let now = Utc::now();
+        assert_eq!(league.view(now).on_the_clock, None);
+        league.activate().unwrap();
+        league.lock(Box::new("Pikachu")).unwrap();
+        let view = league.view(now);
+        assert_eq!(view.on_the_clock, Some(serenity::UserId(2)));
+        assert_eq!(view.pick, 2);
+        assert_eq!(view.seats[0].picks, vec!["Pikachu".to_string()]);
+        assert_eq!(view.board[0], vec![Some("Pikachu".to_string()), None]);
+        assert_eq!(view.upcoming[0].user, serenity::UserId(2));
+        let json = serde_json::to_string(&view).unwrap();
+        assert_eq!(serde_json::from_str::<LeagueView>(&json).unwrap(), view);
+    }
+}
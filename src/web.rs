@@ -0,0 +1,265 @@
+use crate::{DraftRegistry, League, LeagueError, LeagueView, PickResult, UpcomingPick};
+use axum::extract::{Path, Query, State};
+use axum::http::{header, HeaderMap, StatusCode};
+use axum::routing::{get, post};
+use axum::{Json, Router};
+use chrono::Utc;
+use poise::serenity_prelude as serenity;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+// a failed request's response
+type Failure = (StatusCode, Json<WebError>);
+
+// what every handler shares
+struct Web {
+    registry: Arc<DraftRegistry>,
+    secret: String,
+}
+
+/// A pick sent to the pick webhook of [`web_router`], made as if `user` had run a /pick command in Discord.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PickSubmission {
+    /// The user making the pick. They must be able to pick for the seat on the clock (see [`League::lock_as`]).
+    pub user: serenity::UserId,
+    /// The name of the item picked.
+    pub item: String,
+    /// The overall pick number the user thinks is on the clock, if the submission should fail rather than take a later pick
+    /// (see [`League::lock_if_seat`]).
+    #[serde(default)]
+    pub pick: Option<u32>,
+}
+
+/// The body of every error response from [`web_router`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WebError {
+    /// The [`LeagueError`] variant, e.g. `"NotYourTurnError"`, or `"LeagueNotFoundError"` and `"UnauthorizedError"`
+    /// for problems with the request itself.
+    pub error: String,
+    /// A message for users, in the League's locale (see [`League::error_text`]).
+    pub message: String,
+}
+
+/// Returns an [axum](https://docs.rs/axum) router serving a read-only JSON API over the Leagues in a [`DraftRegistry`],
+/// plus a webhook to submit picks from outside Discord, so a League can have a companion website.
+/// Needs the `web` feature.
+///
+/// Leagues are addressed by their guild's id and their name:
+///
+/// - `GET /guilds/{guild}/leagues/{league}`: the League's [`LeagueView`]
+/// - `GET /guilds/{guild}/leagues/{league}/board`: its draft board, as in [`LeagueView::board`]
+/// - `GET /guilds/{guild}/leagues/{league}/upcoming?count=10`: its next picks, as [`UpcomingPick`]s
+/// - `POST /guilds/{guild}/leagues/{league}/picks`: locks in a [`PickSubmission`], returning the [`PickResult`]s like [`League::lock`]
+///
+/// Picks must carry the secret as `Authorization: Bearer <secret>`, and are refused with 401 otherwise. Failed requests
+/// get a [`WebError`]: 404 for an unknown guild or League, and 422 for a pick the League rejects.
+///
+/// Picks are locked in on the League directly, as [`League::lock_as`] does, and not through a [`LeagueService`](crate::LeagueService):
+/// they are not saved to a [`DraftStore`](crate::DraftStore) or announced, and a retried request is not recognized as one.
+/// Save and announce them from a [`League::subscribe`] feed, and send [`PickSubmission::pick`] so a retry fails with
+/// [`LeagueError::StalePickError`] rather than take the next pick.
+///
+/// # Panics
+///
+/// If the secret is empty, the program will panic, since anyone could pick with it.
+///
+/// ```ignore
+/// let app = drftr::web_router(Arc::clone(&registry), std::env::var("PICK_SECRET")?);
+/// let listener = tokio::net::TcpListener::bind("0.0.0.0:3000").await?;
+/// axum::serve(listener, app).await?;
+/// ```
+pub fn web_router(registry: Arc<DraftRegistry>, secret: impl Into<String>) -> Router {
+    let secret = secret.into();
+    assert!(!secret.is_empty(), "the pick secret must not be empty");
+    let web = Arc::new(Web { registry, secret });
+    Router::new()
+        .route("/guilds/{guild}/leagues/{league}", get(view))
+        .route("/guilds/{guild}/leagues/{league}/board", get(board))
+        .route("/guilds/{guild}/leagues/{league}/upcoming", get(upcoming))
+        .route("/guilds/{guild}/leagues/{league}/picks", post(pick))
+        .with_state(web)
+}
+
+#[derive(Deserialize)]
+struct UpcomingQuery {
+    count: Option<usize>,
+}
+
+async fn view(
+    State(web): State<Arc<Web>>,
+    Path((guild, league)): Path<(u64, String)>,
+) -> Result<Json<LeagueView>, Failure> {
+    web.league(guild, &league, |league| league.view(Utc::now()))
+        .map(Json)
+}
+
+async fn board(
+    State(web): State<Arc<Web>>,
+    Path((guild, league)): Path<(u64, String)>,
+) -> Result<Json<Vec<Vec<Option<String>>>>, Failure> {
+    web.league(guild, &league, |league| league.view(Utc::now()).board)
+        .map(Json)
+}
+
+async fn upcoming(
+    State(web): State<Arc<Web>>,
+    Path((guild, league)): Path<(u64, String)>,
+    Query(query): Query<UpcomingQuery>,
+) -> Result<Json<Vec<UpcomingPick>>, Failure> {
+    let count = query.count.unwrap_or(10);
+    web.league(guild, &league, |league| league.upcoming_picks(count))
+        .map(Json)
+}
+
+async fn pick(
+    State(web): State<Arc<Web>>,
+    Path((guild, league)): Path<(u64, String)>,
+    headers: HeaderMap,
+    Json(submission): Json<PickSubmission>,
+) -> Result<Json<Vec<PickResult>>, Failure> {
+    let authorized = headers
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .is_some_and(|token| same_secret(token.as_bytes(), web.secret.as_bytes()));
+    if !authorized {
+        return Err(failure(
+            StatusCode::UNAUTHORIZED,
+            "UnauthorizedError",
+            "The pick secret is missing or wrong.".to_string(),
+        ));
+    }
+    web.league(guild, &league, |league| {
+        if submission
+            .pick
+            .is_some_and(|pick| pick != league.pick_number())
+        {
+            return Err(rejected(league, LeagueError::StalePickError));
+        }
+        league
+            .lock_as(submission.user, Box::new(submission.item))
+            .map_err(|error| rejected(league, error))
+    })?
+    .map(Json)
+}
+
+impl Web {
+    // runs f on a League, or responds 404 if there is no such League
+    fn league<R>(
+        &self,
+        guild: u64,
+        name: &str,
+        f: impl FnOnce(&mut League) -> R,
+    ) -> Result<R, Failure> {
+        self.registry.league(guild, name, f).map_err(|_| {
+            failure(
+                StatusCode::NOT_FOUND,
+                "LeagueNotFoundError",
+                format!("There is no league named {name}."),
+            )
+        })
+    }
+}
+
+// compares every byte whatever the first difference, so response times do not give away how much of the secret a guess got right
+fn same_secret(token: &[u8], secret: &[u8]) -> bool {
+    token.len() == secret.len()
+        && token
+            .iter()
+            .zip(secret)
+            .fold(0, |difference, (a, b)| difference | (a ^ b))
+            == 0
+}
+
+fn rejected(league: &League, error: LeagueError) -> Failure {
+    failure(
+        StatusCode::UNPROCESSABLE_ENTITY,
+        &error.kind(),
+        league.error_text(&error),
+    )
+}
+
+fn failure(status: StatusCode, error: &str, message: String) -> Failure {
+    let body = WebError {
+        error: error.to_string(),
+        message,
+    };
+    (status, Json(body))
+}
+
+#[cfg(test)]
+mod web_tests {
+    use super::*;
+    use crate::test_support::new_league;
+    use crate::{DraftType, SharedGuild};
+    use axum::body::{to_bytes, Body};
+    use axum::http::Request;
+    use axum::response::Response;
+    use tower::ServiceExt;
+
+    fn app() -> Router {
+        let registry = Arc::new(DraftRegistry::new());
+        let guild = registry.insert_guild(SharedGuild::new(1, serenity::ChannelId(2)));
+        let mut league = new_league(2, DraftType::Snake, 2);
+        league.activate().unwrap();
+        guild.add_league(league).unwrap();
+        web_router(registry, "hunter2")
+    }
+
+    fn submit(secret: &str, user: u64) -> Request<Body> {
+        let submission = PickSubmission {
+            user: serenity::UserId(user),
+            item: "Pikachu".to_string(),
+            pick: Some(1),
+        };
+        Request::post("/guilds/1/leagues/Creenis/picks")
+            .header(header::AUTHORIZATION, format!("Bearer {secret}"))
+            .header(header::CONTENT_TYPE, "application/json")
+            .body(Body::from(serde_json::to_vec(&submission).unwrap()))
+            .unwrap()
+    }
+
+    async fn body<T: serde::de::DeserializeOwned>(response: Response) -> T {
+        let bytes = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        serde_json::from_slice(&bytes).unwrap()
+    }
+
+    #[tokio::test]
+    async fn serves_leagues_and_takes_picks() {
+        let app = app();
+        for wrong in ["", "hunter", "hunter22", "hunter3"] {
+            let response = app.clone().oneshot(submit(wrong, 1)).await.unwrap();
+            assert_eq!(response.status(), StatusCode::UNAUTHORIZED, "{wrong}");
+        }
+        let response = app.clone().oneshot(submit("hunter2", 2)).await.unwrap();
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(body::<WebError>(response).await.error, "NotYourTurnError");
+        let response = app.clone().oneshot(submit("hunter2", 1)).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(body::<Vec<PickResult>>(response).await[0].item, "Pikachu");
+
+        let view = Request::get("/guilds/1/leagues/Creenis")
+            .body(Body::empty())
+            .unwrap();
+        let view: LeagueView = body(app.clone().oneshot(view).await.unwrap()).await;
+        assert_eq!(view.pick, 2);
+        let upcoming = Request::get("/guilds/1/leagues/Creenis/upcoming?count=2")
+            .body(Body::empty())
+            .unwrap();
+        let upcoming: Vec<UpcomingPick> = body(app.clone().oneshot(upcoming).await.unwrap()).await;
+        assert_eq!(upcoming.len(), 2);
+        let missing = Request::get("/guilds/1/leagues/Nope/board")
+            .body(Body::empty())
+            .unwrap();
+        assert_eq!(
+            app.oneshot(missing).await.unwrap().status(),
+            StatusCode::NOT_FOUND
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "the pick secret must not be empty")]
+    fn empty_secrets_are_refused() {
+        let _ = web_router(Arc::new(DraftRegistry::new()), "");
+    }
+}